@@ -66,6 +66,7 @@ pub fn build_testing_state() -> DictStateReader {
     DictStateReader {
         address_to_class_hash,
         class_hash_to_class,
+        ..Default::default()
     }
 }
 
@@ -0,0 +1,141 @@
+use crate::forking::cache::latest_cached_block_number;
+use crate::forking::client::block_on;
+use crate::forking::state_dump::{is_state_dump_url, state_dump_block_number};
+use crate::runtime_extensions::forge_config_extension::config::{BlockId as ForkBlockId, BlockTag};
+use anyhow::{Result, anyhow, bail, ensure};
+use camino::Utf8Path;
+use conversions::{IntoConv, string::IntoHexStr};
+use starknet::{
+    core::types::{BlockId, BlockStatus, BlockWithTxHashes, MaybePendingBlockWithTxHashes},
+    providers::{JsonRpcClient, Provider, jsonrpc::HttpTransport},
+};
+use starknet_api::block::BlockNumber;
+use starknet_types_core::felt::Felt;
+use tokio::runtime::Handle;
+use url::Url;
+
+/// Resolves the number of the block `block_id` of the fork at `url` outside of an async context.
+/// In offline mode only the latest block can be resolved, from the fork cache in `cache_dir`.
+pub fn resolve_block_number(
+    url: &Url,
+    block_id: &ForkBlockId,
+    cache_dir: &Utf8Path,
+    offline: bool,
+) -> Result<BlockNumber> {
+    match block_id {
+        ForkBlockId::BlockNumber(block_number) => Ok(BlockNumber(*block_number)),
+        // A state dump has a single block, which every tag refers to
+        _ if is_state_dump_url(url) => {
+            ensure!(
+                matches!(block_id, ForkBlockId::BlockTag(_)),
+                "Fork from the state dump {url} cannot be configured with `{block_id}`, use `block_number` or `block_tag`"
+            );
+            state_dump_block_number(url)
+        }
+        ForkBlockId::BlockTag(BlockTag::Latest) if offline => {
+            latest_cached_block_number(url, cache_dir)
+        }
+        _ if offline => bail!(
+            "cache miss: `{block_id}` cannot be resolved in offline mode, use a block number instead"
+        ),
+        _ => {
+            let (url, block_id) = (url.clone(), block_id.clone());
+            block_on(async move { fetch_block_number_for_block_id(url, &block_id).await })
+        }
+    }
+}
+
+pub async fn fetch_latest_block_number(url: Url) -> Result<BlockNumber> {
+    let client = JsonRpcClient::new(HttpTransport::new(url));
+
+    Ok(Handle::current()
+        .spawn(async move { client.block_number().await })
+        .await?
+        .map(BlockNumber)?)
+}
+
+pub async fn fetch_block_number_for_hash(url: Url, block_hash: Felt) -> Result<BlockNumber> {
+    let client = JsonRpcClient::new(HttpTransport::new(url));
+
+    let hash = BlockId::Hash(block_hash.into_());
+
+    match Handle::current()
+        .spawn(async move { client.get_block_with_tx_hashes(hash).await })
+        .await?
+    {
+        Ok(MaybePendingBlockWithTxHashes::Block(block)) => Ok(BlockNumber(block.block_number)),
+        _ => Err(anyhow!(
+            "Could not get the block number for block with hash 0x{}",
+            block_hash.into_hex_string()
+        )),
+    }
+}
+
+pub async fn fetch_block_number_for_block_id(
+    url: Url,
+    block_id: &ForkBlockId,
+) -> Result<BlockNumber> {
+    match block_id {
+        ForkBlockId::BlockNumber(block_number) => Ok(BlockNumber(*block_number)),
+        ForkBlockId::BlockHash(hash) => fetch_block_number_for_hash(url, *hash).await,
+        ForkBlockId::BlockTag(BlockTag::Latest) => fetch_latest_block_number(url).await,
+        // The pending block gets the number following the latest one
+        ForkBlockId::BlockTag(BlockTag::Pending) => {
+            Ok(BlockNumber(fetch_latest_block_number(url).await?.0 + 1))
+        }
+        ForkBlockId::BlockTag(BlockTag::L1Accepted) => find_last_block(url.clone(), |block| {
+            block.status == BlockStatus::AcceptedOnL1
+        })
+        .await?
+        .ok_or_else(|| anyhow!("No block is accepted on L1 at url = {url}")),
+        ForkBlockId::BlockTimestamp(timestamp) => {
+            find_last_block(url.clone(), |block| block.timestamp <= *timestamp)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "No block was created at or before timestamp {timestamp} at url = {url}"
+                    )
+                })
+        }
+    }
+}
+
+/// Returns the number of the last block for which `predicate` holds, using binary search
+/// between the first and the latest block. `predicate` has to hold for all blocks before it.
+async fn find_last_block(
+    url: Url,
+    predicate: impl Fn(&BlockWithTxHashes) -> bool,
+) -> Result<Option<BlockNumber>> {
+    // Blocks before `low` satisfy `predicate`, blocks from `high` onwards do not
+    let mut low = 0;
+    let mut high = fetch_latest_block_number(url.clone()).await?.0 + 1;
+
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if predicate(&fetch_block(url.clone(), middle).await?) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    Ok(low.checked_sub(1).map(BlockNumber))
+}
+
+async fn fetch_block(url: Url, block_number: u64) -> Result<BlockWithTxHashes> {
+    let client = JsonRpcClient::new(HttpTransport::new(url));
+
+    match Handle::current()
+        .spawn(async move {
+            client
+                .get_block_with_tx_hashes(BlockId::Number(block_number))
+                .await
+        })
+        .await??
+    {
+        MaybePendingBlockWithTxHashes::Block(block) => Ok(block),
+        MaybePendingBlockWithTxHashes::PendingBlock(_) => {
+            Err(anyhow!("Block {block_number} is still pending"))
+        }
+    }
+}
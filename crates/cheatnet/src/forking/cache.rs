@@ -1,101 +1,150 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, anyhow, bail, ensure};
 use camino::{Utf8Path, Utf8PathBuf};
+use flate2::Compression;
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
 use fs2::FileExt;
 use regex::Regex;
-use runtime::starknet::context::SerializableBlockInfo;
+use runtime::starknet::context::{DEFAULT_CHAIN_ID, SerializableBlockInfo};
 use serde::{Deserialize, Serialize};
+use shared::print::print_as_warning;
 use starknet::core::types::ContractClass;
 use starknet_api::block::{BlockInfo, BlockNumber};
 use starknet_api::core::{ClassHash, ContractAddress, Nonce};
 use starknet_api::state::StorageKey;
 use starknet_types_core::felt::Felt;
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 use std::fs;
 use std::fs::{File, OpenOptions};
-use std::io::{Read, Seek, Write};
-use std::string::ToString;
+use std::io::{BufReader, ErrorKind, Seek, SeekFrom, Write};
+use std::mem;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::SystemTime;
 use url::Url;
 
-#[must_use]
-pub fn cache_version() -> String {
-    env!("CARGO_PKG_VERSION").replace('.', "_")
-}
+/// Version of the layout of fork cache files, bumped on every incompatible change.
+/// Files with a different version are discarded and rebuilt.
+pub const FORK_CACHE_FORMAT_VERSION: u32 = 1;
 
-#[derive(Serialize, Deserialize, Debug)]
-struct ForkCacheContent {
-    cache_version: String,
-    storage_at: HashMap<ContractAddress, HashMap<StorageKey, Felt>>,
-    nonce_at: HashMap<ContractAddress, Nonce>,
-    class_hash_at: HashMap<ContractAddress, ClassHash>,
-    compiled_contract_class: HashMap<ClassHash, ContractClass>,
-    block_info: Option<SerializableBlockInfo>,
+const FORK_CACHE_EXTENSION: &str = "fork_cache";
+
+// Number of appended records after which the file is compacted into a single one
+const MAX_RECORDS_BEFORE_COMPACTION: usize = 32;
+
+type SharedForkCacheKey = (Url, BlockNumber, Utf8PathBuf, bool);
+
+/// Fork caches loaded by this process, shared by all tests forking the same url and block
+static FORK_CACHES: LazyLock<Mutex<HashMap<SharedForkCacheKey, Arc<Mutex<ForkCache>>>>> =
+    LazyLock::new(Mutex::default);
+
+static CACHE_FILE_NAME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?<url>.+)_(?<block_number>[0-9]+)(?<suffix>\.fork_cache|_v[0-9_]+\.json)$")
+        .unwrap()
+});
+
+/// Identifies the fork a cache file belongs to
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ForkCacheHeader {
+    pub format_version: u32,
+    pub url: Url,
+    pub block_number: BlockNumber,
 }
 
-impl Default for ForkCacheContent {
-    fn default() -> Self {
+impl ForkCacheHeader {
+    fn new(url: &Url, block_number: BlockNumber) -> Self {
         Self {
-            cache_version: cache_version(),
-            storage_at: HashMap::default(),
-            nonce_at: HashMap::default(),
-            class_hash_at: HashMap::default(),
-            compiled_contract_class: HashMap::default(),
-            block_info: Option::default(),
+            format_version: FORK_CACHE_FORMAT_VERSION,
+            url: url.clone(),
+            block_number,
         }
     }
 }
 
-impl ForkCacheContent {
-    fn from_str(serialized: &str) -> Self {
-        let cache: Self =
-            serde_json::from_str(serialized).expect("Could not deserialize cache from json");
-
-        assert_eq!(
-            cache.cache_version,
-            cache_version(),
-            "Expected the Version {}",
-            cache_version()
-        );
+/// A cache file is a sequence of gzip members, each holding one record: the header followed by
+/// entries appended by every run which fetched something new from the network.
+/// Bundles created by `export_fork_caches` use the same records, with a header for each fork.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "snake_case")]
+enum ForkCacheRecord<H = ForkCacheHeader, C = ForkCacheContent> {
+    Header(H),
+    Entries(C),
+}
 
-        cache
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub(crate) struct ForkCacheContent {
+    #[serde(default)]
+    pub(crate) storage_at: HashMap<ContractAddress, HashMap<StorageKey, Felt>>,
+    #[serde(default)]
+    pub(crate) nonce_at: HashMap<ContractAddress, Nonce>,
+    #[serde(default)]
+    pub(crate) class_hash_at: HashMap<ContractAddress, ClassHash>,
+    #[serde(default)]
+    pub(crate) compiled_contract_class: HashMap<ClassHash, ContractClass>,
+    #[serde(default)]
+    pub(crate) block_info: Option<SerializableBlockInfo>,
+    #[serde(default)]
+    pub(crate) chain_id: Option<String>,
+}
+
+impl ForkCacheContent {
+    fn is_empty(&self) -> bool {
+        self.storage_at.is_empty()
+            && self.nonce_at.is_empty()
+            && self.class_hash_at.is_empty()
+            && self.compiled_contract_class.is_empty()
+            && self.block_info.is_none()
+            && self.chain_id.is_none()
     }
 
-    fn extend(&mut self, other: &Self) {
-        // storage_at
-        for (other_contract_address, other_storage) in &other.storage_at {
-            if let Some(self_contract_storage) = self.storage_at.get_mut(other_contract_address) {
-                self_contract_storage.extend(other_storage.clone());
-            } else {
-                self.storage_at
-                    .insert(*other_contract_address, other_storage.clone());
-            }
+    fn extend(&mut self, other: Self) {
+        for (other_contract_address, other_storage) in other.storage_at {
+            self.storage_at
+                .entry(other_contract_address)
+                .or_default()
+                .extend(other_storage);
         }
 
-        self.nonce_at.extend(other.nonce_at.clone());
-        self.class_hash_at.extend(other.class_hash_at.clone());
+        self.nonce_at.extend(other.nonce_at);
+        self.class_hash_at.extend(other.class_hash_at);
         self.compiled_contract_class
-            .extend(other.compiled_contract_class.clone());
+            .extend(other.compiled_contract_class);
         if other.block_info.is_some() {
-            self.block_info.clone_from(&other.block_info);
+            self.block_info = other.block_info;
+        }
+        if other.chain_id.is_some() {
+            self.chain_id = other.chain_id;
         }
-    }
-}
-
-#[expect(clippy::to_string_trait_impl)]
-impl ToString for ForkCacheContent {
-    fn to_string(&self) -> String {
-        serde_json::to_string(self).expect("Could not serialize json cache")
     }
 }
 
 #[derive(Debug)]
 pub struct ForkCache {
+    header: ForkCacheHeader,
     fork_cache_content: ForkCacheContent,
+    // Entries fetched since the cache was loaded, appended to the file on drop
+    new_content: ForkCacheContent,
     cache_file: Utf8PathBuf,
+    // Set when the file is outdated, corrupted or has too many records and is rewritten on drop
+    needs_rewrite: bool,
+    // Caches loaded in offline mode are never written back
+    read_only: bool,
+    // Set for caches built from a complete state dump, which holds the whole state of the fork
+    is_complete_state_dump: bool,
 }
 
 impl Drop for ForkCache {
     fn drop(&mut self) {
-        self.save();
+        if self.read_only {
+            return;
+        }
+        // Entries which could not be saved are fetched again by the next run
+        if let Err(err) = self.save() {
+            print_as_warning(&anyhow!(
+                "Could not save fork cache {}: {err:#}",
+                self.cache_file
+            ));
+        }
     }
 }
 
@@ -112,65 +161,172 @@ impl FileTruncateExtension for File {
 }
 
 impl ForkCache {
-    pub(crate) fn load_or_new(
+    /// Returns the cache of the fork at `url` at `block_number`, which is loaded once per process.
+    /// Entries fetched by all tests using it are saved together by [`save_fork_caches`].
+    pub(crate) fn shared(
         url: &Url,
         block_number: BlockNumber,
         cache_dir: &Utf8Path,
-    ) -> Result<Self> {
+        offline: bool,
+    ) -> Result<Arc<Mutex<Self>>> {
+        let mut fork_caches = FORK_CACHES.lock().expect("Fork caches lock is poisoned");
+
+        match fork_caches.entry((url.clone(), block_number, cache_dir.to_owned(), offline)) {
+            Entry::Occupied(entry) => Ok(entry.get().clone()),
+            Entry::Vacant(entry) => {
+                let cache = if offline {
+                    ForkCache::load(url, block_number, cache_dir)?
+                } else {
+                    ForkCache::load_or_new(url, block_number, cache_dir)
+                        .context("Could not create fork cache")?
+                };
+                Ok(entry.insert(Arc::new(Mutex::new(cache))).clone())
+            }
+        }
+    }
+
+    fn load_or_new(url: &Url, block_number: BlockNumber, cache_dir: &Utf8Path) -> Result<Self> {
+        let header = ForkCacheHeader::new(url, block_number);
         let cache_file = cache_file_path_from_fork_config(url, block_number, cache_dir)?;
-        let mut file = OpenOptions::new()
-            .write(true)
-            .read(true)
-            .create(true)
-            .truncate(false)
-            .open(&cache_file)
-            .context("Could not open cache file")?;
-
-        let mut cache_file_content = String::new();
-        file.read_to_string(&mut cache_file_content)
-            .context("Could not read cache file")?;
+        migrate_legacy_caches(&header, &cache_file, cache_dir)?;
 
-        // File was just created
-        let fork_cache_content = if cache_file_content.is_empty() {
-            ForkCacheContent::default()
-        } else {
-            ForkCacheContent::from_str(cache_file_content.as_str())
-        };
+        let file = open_cache_file(&cache_file)?;
+        file.lock_shared().context("Could not lock on cache file")?;
+        let (fork_cache_content, needs_rewrite) = read_or_discard_cache_file(&file, &cache_file);
+        fs2::FileExt::unlock(&file).context("Could not unlock cache file")?;
+
+        // Marks the cache as used for `snforge cache prune --older-than`, failure is not critical
+        let _ = file.set_modified(SystemTime::now());
 
         Ok(ForkCache {
+            header,
             fork_cache_content,
+            new_content: ForkCacheContent::default(),
             cache_file,
+            needs_rewrite,
+            read_only: false,
+            is_complete_state_dump: false,
         })
     }
 
-    fn save(&self) {
-        let mut file = OpenOptions::new()
-            .write(true)
-            .read(true)
-            .create(true)
-            .truncate(false)
-            .open(&self.cache_file)
-            .unwrap();
+    /// Loads an existing cache without creating it, used in offline mode
+    pub(crate) fn load(url: &Url, block_number: BlockNumber, cache_dir: &Utf8Path) -> Result<Self> {
+        let header = ForkCacheHeader::new(url, block_number);
+        let cache_file = cache_file_path_from_fork_config(url, block_number, cache_dir)?;
+        migrate_legacy_caches(&header, &cache_file, cache_dir)?;
+
+        ensure!(
+            cache_file.exists(),
+            "cache miss: no fork cache for url = {url} at block {} (expected {cache_file})",
+            block_number.0
+        );
+
+        let file = File::open(&cache_file).context("Could not open cache file")?;
+        file.lock_shared().context("Could not lock on cache file")?;
+        // Outdated or corrupted caches can't be rebuilt in offline mode
+        let (fork_cache_content, _) = read_cache_file(&file, &cache_file)?;
+        fs2::FileExt::unlock(&file).context("Could not unlock cache file")?;
+
+        Ok(ForkCache {
+            header,
+            fork_cache_content,
+            new_content: ForkCacheContent::default(),
+            cache_file,
+            needs_rewrite: false,
+            read_only: true,
+            is_complete_state_dump: false,
+        })
+    }
+
+    /// Creates a cache serving the state of the fork from a state dump.
+    /// Entries missing from a `partial` dump are cache misses instead of being empty.
+    pub(crate) fn from_state_dump(
+        url: &Url,
+        block_number: BlockNumber,
+        mut fork_cache_content: ForkCacheContent,
+        partial: bool,
+    ) -> Self {
+        fork_cache_content
+            .block_info
+            .get_or_insert_with(|| SerializableBlockInfo {
+                block_number,
+                ..Default::default()
+            });
+        fork_cache_content
+            .chain_id
+            .get_or_insert_with(|| DEFAULT_CHAIN_ID.to_string());
+
+        ForkCache {
+            header: ForkCacheHeader::new(url, block_number),
+            fork_cache_content,
+            new_content: ForkCacheContent::default(),
+            cache_file: Utf8PathBuf::default(),
+            needs_rewrite: false,
+            read_only: true,
+            is_complete_state_dump: !partial,
+        }
+    }
+
+    /// Creates an empty cache which is never saved, for forks whose state changes between runs
+    pub(crate) fn in_memory(url: &Url, block_number: BlockNumber) -> Self {
+        ForkCache {
+            header: ForkCacheHeader::new(url, block_number),
+            fork_cache_content: ForkCacheContent::default(),
+            new_content: ForkCacheContent::default(),
+            cache_file: Utf8PathBuf::default(),
+            needs_rewrite: false,
+            read_only: true,
+            is_complete_state_dump: false,
+        }
+    }
+
+    /// Takes the content out of a cache loaded with [`ForkCache::load`]
+    pub(crate) fn into_content(mut self) -> ForkCacheContent {
+        mem::take(&mut self.fork_cache_content)
+    }
+
+    pub(crate) fn is_complete_state_dump(&self) -> bool {
+        self.is_complete_state_dump
+    }
+
+    fn save(&mut self) -> Result<()> {
+        if self.new_content.is_empty() && !self.needs_rewrite {
+            return Ok(());
+        }
 
-        file.lock_exclusive().expect("Could not lock on cache file");
+        let mut file = open_cache_file(&self.cache_file)?;
+        file.lock_exclusive()
+            .context("Could not lock on cache file")?;
+        let result = self.write_to(&mut file);
+        fs2::FileExt::unlock(&file).context("Could not unlock cache file")?;
 
-        let mut cache_file_content = String::new();
-        file.read_to_string(&mut cache_file_content)
-            .expect("Should have been able to read the cache");
+        result
+    }
 
-        let output = if cache_file_content.is_empty() {
-            self.fork_cache_content.to_string()
+    fn write_to(&mut self, file: &mut File) -> Result<()> {
+        // Other processes could have appended entries or rewritten the file since it was loaded,
+        // so whether it still has to be rewritten is checked under the lock
+        let rewritten_content = if self.needs_rewrite {
+            match read_cache_file(file, &self.cache_file) {
+                Ok((_, false)) => None,
+                Ok((mut content, true)) => {
+                    content.extend(mem::take(&mut self.fork_cache_content));
+                    Some(content)
+                }
+                Err(_) => Some(mem::take(&mut self.fork_cache_content)),
+            }
         } else {
-            let mut fs_fork_cache_content = ForkCacheContent::from_str(&cache_file_content);
-            fs_fork_cache_content.extend(&self.fork_cache_content);
-            fs_fork_cache_content.to_string()
+            None
         };
 
-        file.clear().expect("Failed to clear file");
-        file.write_all(output.as_bytes())
-            .expect("Could not write cache to file");
-
-        fs2::FileExt::unlock(&file).unwrap();
+        match rewritten_content {
+            Some(content) => {
+                file.clear()?;
+                append_entries(file, &self.header, &content)
+            }
+            None if self.new_content.is_empty() => Ok(()),
+            None => append_entries(file, &self.header, &self.new_content),
+        }
     }
 
     pub(crate) fn get_storage_at(
@@ -178,11 +334,15 @@ impl ForkCache {
         contract_address: &ContractAddress,
         key: &StorageKey,
     ) -> Option<Felt> {
-        self.fork_cache_content
+        let value = self
+            .fork_cache_content
             .storage_at
-            .get(contract_address)?
-            .get(key)
-            .copied()
+            .get(contract_address)
+            .and_then(|storage| storage.get(key))
+            .copied();
+
+        // Storage missing from a complete state dump is empty
+        value.or_else(|| self.is_complete_state_dump.then(Felt::default))
     }
 
     pub(crate) fn cache_get_storage_at(
@@ -191,31 +351,39 @@ impl ForkCache {
         key: StorageKey,
         value: Felt,
     ) {
-        self.fork_cache_content
-            .storage_at
-            .entry(contract_address)
-            .or_default()
-            .insert(key, value);
+        for content in [&mut self.fork_cache_content, &mut self.new_content] {
+            content
+                .storage_at
+                .entry(contract_address)
+                .or_default()
+                .insert(key, value);
+        }
     }
 
     pub(crate) fn get_nonce_at(&self, address: &ContractAddress) -> Option<Nonce> {
-        self.fork_cache_content.nonce_at.get(address).copied()
+        let nonce = self.fork_cache_content.nonce_at.get(address).copied();
+
+        nonce.or_else(|| self.is_complete_state_dump.then(Nonce::default))
     }
 
     pub(crate) fn cache_get_nonce_at(&mut self, contract_address: ContractAddress, nonce: Nonce) {
-        self.fork_cache_content
-            .nonce_at
-            .insert(contract_address, nonce);
+        for content in [&mut self.fork_cache_content, &mut self.new_content] {
+            content.nonce_at.insert(contract_address, nonce);
+        }
     }
 
     pub(crate) fn get_class_hash_at(
         &self,
         contract_address: &ContractAddress,
     ) -> Option<ClassHash> {
-        self.fork_cache_content
+        let class_hash = self
+            .fork_cache_content
             .class_hash_at
             .get(contract_address)
-            .copied()
+            .copied();
+
+        // Contracts missing from a complete state dump are not deployed
+        class_hash.or_else(|| self.is_complete_state_dump.then(ClassHash::default))
     }
 
     pub(crate) fn cache_get_class_hash_at(
@@ -223,9 +391,9 @@ impl ForkCache {
         contract_address: ContractAddress,
         class_hash: ClassHash,
     ) {
-        self.fork_cache_content
-            .class_hash_at
-            .insert(contract_address, class_hash);
+        for content in [&mut self.fork_cache_content, &mut self.new_content] {
+            content.class_hash_at.insert(contract_address, class_hash);
+        }
     }
 
     pub(crate) fn get_compiled_contract_class(
@@ -241,11 +409,13 @@ impl ForkCache {
         &mut self,
         class_hash: ClassHash,
         contract_class: ContractClass,
-    ) -> &ContractClass {
+    ) {
+        self.new_content
+            .compiled_contract_class
+            .insert(class_hash, contract_class.clone());
         self.fork_cache_content
             .compiled_contract_class
-            .entry(class_hash)
-            .or_insert(contract_class)
+            .insert(class_hash, contract_class);
     }
 
     pub(crate) fn get_block_info(&self) -> Option<BlockInfo> {
@@ -253,23 +423,378 @@ impl ForkCache {
     }
 
     pub(crate) fn cache_get_block_info(&mut self, block_info: BlockInfo) {
-        self.fork_cache_content.block_info = Some(block_info.into());
+        let block_info: SerializableBlockInfo = block_info.into();
+        self.new_content.block_info = Some(block_info.clone());
+        self.fork_cache_content.block_info = Some(block_info);
+    }
+
+    pub(crate) fn get_chain_id(&self) -> Option<String> {
+        self.fork_cache_content.chain_id.clone()
+    }
+
+    pub(crate) fn cache_get_chain_id(&mut self, chain_id: String) {
+        self.new_content.chain_id = Some(chain_id.clone());
+        self.fork_cache_content.chain_id = Some(chain_id);
     }
 }
 
-fn cache_file_path_from_fork_config(
+/// Saves entries fetched since the fork caches in `cache_dir` were loaded, once the tests still
+/// using them finish. Forks created later load the caches again.
+pub fn save_fork_caches(cache_dir: &Utf8Path) {
+    let mut fork_caches = FORK_CACHES.lock().expect("Fork caches lock is poisoned");
+    let (saved, kept): (HashMap<_, _>, HashMap<_, _>) = mem::take(&mut *fork_caches)
+        .into_iter()
+        .partition(|((_, _, dir, _), _)| dir == cache_dir);
+    *fork_caches = kept;
+    drop(fork_caches);
+
+    // Caches are written without holding the lock
+    drop(saved);
+}
+
+/// A fork cache file found in the cache directory
+#[derive(Debug, Clone)]
+pub struct ForkCacheFile {
+    pub path: Utf8PathBuf,
+    /// `None` for caches written by older versions of snforge, which are converted to the
+    /// current format on their first use, and for corrupted caches
+    pub url: Option<Url>,
+    pub block_number: BlockNumber,
+    /// Size of the file in bytes
+    pub size: u64,
+    /// Time the cache was last used
+    pub modified: SystemTime,
+    name: CacheFileName,
+}
+
+impl ForkCacheFile {
+    #[must_use]
+    pub fn is_for_url(&self, url: &Url) -> bool {
+        self.name.is_for_url(url)
+    }
+}
+
+/// Returns all fork caches in `cache_dir`, including the ones in the format of older versions
+pub fn list_fork_caches(cache_dir: &Utf8Path) -> Result<Vec<ForkCacheFile>> {
+    let mut cache_files = vec![];
+    for name in cache_file_names(cache_dir)? {
+        let metadata = fs::metadata(&name.path)?;
+        let url = if name.is_legacy {
+            None
+        } else {
+            read_header(&name.path).map(|header| header.url)
+        };
+
+        cache_files.push(ForkCacheFile {
+            path: name.path.clone(),
+            url,
+            block_number: name.block_number,
+            size: metadata.len(),
+            modified: metadata.modified()?,
+            name,
+        });
+    }
+
+    Ok(cache_files)
+}
+
+/// Returns the highest block number of `url` with a fork cache in `cache_dir`, used to resolve
+/// the latest block in offline mode
+pub fn latest_cached_block_number(url: &Url, cache_dir: &Utf8Path) -> Result<BlockNumber> {
+    cache_file_names(cache_dir)?
+        .into_iter()
+        .filter(|name| name.is_for_url(url))
+        .map(|name| name.block_number)
+        .max()
+        .ok_or_else(|| anyhow!("cache miss: no fork cache for url = {url} in {cache_dir}"))
+}
+
+/// Returns the content of the fork cache of `url` at the highest block other than `block_number`,
+/// used to prefetch the state read by previous runs
+pub(crate) fn previous_cache_content(
     url: &Url,
-    BlockNumber(block_number): BlockNumber,
+    block_number: BlockNumber,
     cache_dir: &Utf8Path,
-) -> Result<Utf8PathBuf> {
+) -> Result<Option<ForkCacheContent>> {
+    cache_file_names(cache_dir)?
+        .into_iter()
+        .filter(|name| name.is_for_url(url) && name.block_number != block_number)
+        .map(|name| name.block_number)
+        .max()
+        .map(|previous_block_number| {
+            ForkCache::load(url, previous_block_number, cache_dir).map(ForkCache::into_content)
+        })
+        .transpose()
+}
+
+#[derive(Debug, Clone)]
+struct CacheFileName {
+    path: Utf8PathBuf,
+    sanitized_url: String,
+    block_number: BlockNumber,
+    // JSON cache written by an older version of snforge
+    is_legacy: bool,
+}
+
+impl CacheFileName {
+    fn is_for_url(&self, url: &Url) -> bool {
+        self.sanitized_url == sanitize_url(url)
+    }
+}
+
+fn cache_file_names(cache_dir: &Utf8Path) -> Result<Vec<CacheFileName>> {
+    let entries = match cache_dir.read_dir_utf8() {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err).context(format!("Could not read directory {cache_dir}")),
+    };
+
+    let mut names = vec![];
+    for entry in entries {
+        let entry = entry?;
+        let Some(captures) = CACHE_FILE_NAME_REGEX.captures(entry.file_name()) else {
+            continue;
+        };
+        let Ok(block_number) = captures["block_number"].parse() else {
+            continue;
+        };
+
+        names.push(CacheFileName {
+            path: entry.path().to_path_buf(),
+            sanitized_url: captures["url"].to_string(),
+            block_number: BlockNumber(block_number),
+            is_legacy: captures["suffix"].ends_with(".json"),
+        });
+    }
+    names.sort_by(|a, b| {
+        (&a.sanitized_url, a.block_number).cmp(&(&b.sanitized_url, b.block_number))
+    });
+
+    Ok(names)
+}
+
+/// Writes `cache_files` into a single bundle file, which can be imported with
+/// `import_fork_caches`. Returns the number of exported caches, caches in the format of older
+/// versions of snforge are skipped.
+pub fn export_fork_caches(cache_files: &[ForkCacheFile], bundle: &Utf8Path) -> Result<usize> {
+    let mut bundle_file =
+        File::create(bundle).with_context(|| format!("Could not create bundle {bundle}"))?;
+    let mut exported = 0;
+
+    for cache_file in cache_files {
+        let Some(header) = read_header(&cache_file.path) else {
+            continue;
+        };
+
+        let file = File::open(&cache_file.path).context("Could not open cache file")?;
+        file.lock_shared().context("Could not lock on cache file")?;
+        let content = read_cache_file(&file, &cache_file.path);
+        fs2::FileExt::unlock(&file).context("Could not unlock cache file")?;
+        let Ok((content, _)) = content else {
+            continue;
+        };
+
+        write_record(&mut bundle_file, &ForkCacheRecord::Header(&header))?;
+        write_record(&mut bundle_file, &ForkCacheRecord::Entries(&content))?;
+        exported += 1;
+    }
+
+    Ok(exported)
+}
+
+/// Merges fork caches from a bundle created with `export_fork_caches` into `cache_dir`.
+/// Returns the number of imported caches.
+pub fn import_fork_caches(bundle: &Utf8Path, cache_dir: &Utf8Path) -> Result<usize> {
+    let bundle_file =
+        File::open(bundle).with_context(|| format!("Could not open bundle {bundle}"))?;
+    let records =
+        serde_json::Deserializer::from_reader(MultiGzDecoder::new(BufReader::new(bundle_file)))
+            .into_iter::<ForkCacheRecord>();
+
+    let mut header = None;
+    let mut imported = 0;
+    for record in records {
+        match record.with_context(|| format!("Invalid fork cache bundle {bundle}"))? {
+            ForkCacheRecord::Header(bundle_header) => {
+                ensure!(
+                    bundle_header.format_version == FORK_CACHE_FORMAT_VERSION,
+                    "Fork cache bundle {bundle} has format version {}, expected {FORK_CACHE_FORMAT_VERSION}",
+                    bundle_header.format_version
+                );
+                header = Some(bundle_header);
+            }
+            ForkCacheRecord::Entries(content) => {
+                let Some(header) = header.take() else {
+                    bail!("Invalid fork cache bundle {bundle}: entries without a fork");
+                };
+                let cache_file =
+                    cache_file_path_from_fork_config(&header.url, header.block_number, cache_dir)?;
+
+                let mut file = open_cache_file(&cache_file)?;
+                file.lock_exclusive()
+                    .context("Could not lock on cache file")?;
+                append_entries(&mut file, &header, &content)?;
+                fs2::FileExt::unlock(&file).context("Could not unlock cache file")?;
+                imported += 1;
+            }
+        }
+    }
+
+    Ok(imported)
+}
+
+fn open_cache_file(cache_file: &Utf8Path) -> Result<File> {
+    OpenOptions::new()
+        .write(true)
+        .read(true)
+        .create(true)
+        .truncate(false)
+        .open(cache_file)
+        .context("Could not open cache file")
+}
+
+fn read_header(cache_file: &Utf8Path) -> Option<ForkCacheHeader> {
+    let file = File::open(cache_file).ok()?;
+    let mut records =
+        serde_json::Deserializer::from_reader(MultiGzDecoder::new(BufReader::new(file)))
+            .into_iter::<ForkCacheRecord>();
+
+    match records.next()? {
+        Ok(ForkCacheRecord::Header(header)) => Some(header),
+        _ => None,
+    }
+}
+
+/// Reads all entries of the cache file, returning them with information whether the file has to
+/// be rewritten. Fails for outdated and corrupted files, which are discarded by the callers.
+fn read_cache_file(mut file: &File, cache_file: &Utf8Path) -> Result<(ForkCacheContent, bool)> {
+    file.rewind().context("Failed to rewind file")?;
+    let mut records =
+        serde_json::Deserializer::from_reader(MultiGzDecoder::new(BufReader::new(file)))
+            .into_iter::<ForkCacheRecord>();
+
+    match records.next() {
+        // File was just created
+        None => return Ok((ForkCacheContent::default(), false)),
+        Some(Ok(ForkCacheRecord::Header(header))) => ensure!(
+            header.format_version == FORK_CACHE_FORMAT_VERSION,
+            "Fork cache {cache_file} has format version {}, expected {FORK_CACHE_FORMAT_VERSION}",
+            header.format_version
+        ),
+        Some(_) => bail!("Fork cache {cache_file} is corrupted"),
+    }
+
+    let mut content = ForkCacheContent::default();
+    let mut records_count = 0;
+    for record in records {
+        match record {
+            Ok(ForkCacheRecord::Entries(entries)) => {
+                content.extend(entries);
+                records_count += 1;
+            }
+            // Record cut short by an interrupted write, the entries read so far are kept
+            _ => return Ok((content, true)),
+        }
+    }
+
+    Ok((content, records_count > MAX_RECORDS_BEFORE_COMPACTION))
+}
+
+/// Reads the cache file, discarding it with a warning if it is outdated or corrupted
+fn read_or_discard_cache_file(file: &File, cache_file: &Utf8Path) -> (ForkCacheContent, bool) {
+    read_cache_file(file, cache_file).unwrap_or_else(|err| {
+        print_as_warning(&anyhow!("{err}. It will be rebuilt"));
+        (ForkCacheContent::default(), true)
+    })
+}
+
+/// Appends `content` to the cache file, writing the header first if the file is empty
+fn append_entries(
+    file: &mut File,
+    header: &ForkCacheHeader,
+    content: &ForkCacheContent,
+) -> Result<()> {
+    let is_empty = file.seek(SeekFrom::End(0))? == 0;
+    if is_empty {
+        write_record(file, &ForkCacheRecord::Header(header))?;
+    }
+    write_record(file, &ForkCacheRecord::Entries(content))
+}
+
+fn write_record(
+    file: &mut File,
+    record: &ForkCacheRecord<&ForkCacheHeader, &ForkCacheContent>,
+) -> Result<()> {
+    let mut encoder = GzEncoder::new(file, Compression::fast());
+    serde_json::to_writer(&mut encoder, record).context("Could not serialize fork cache")?;
+    encoder.write_all(b"\n")?;
+    encoder.finish().context("Could not write fork cache")?;
+    Ok(())
+}
+
+/// Converts JSON caches written by older versions of snforge into the current format
+fn migrate_legacy_caches(
+    header: &ForkCacheHeader,
+    cache_file: &Utf8Path,
+    cache_dir: &Utf8Path,
+) -> Result<()> {
+    let legacy_files: Vec<_> = cache_file_names(cache_dir)?
+        .into_iter()
+        .filter(|name| {
+            name.is_legacy
+                && name.is_for_url(&header.url)
+                && name.block_number == header.block_number
+        })
+        .collect();
+    if legacy_files.is_empty() {
+        return Ok(());
+    }
+
+    let mut file = open_cache_file(cache_file)?;
+    file.lock_exclusive()
+        .context("Could not lock on cache file")?;
+
+    let mut migrated = ForkCacheContent::default();
+    for legacy_file in legacy_files {
+        // Could have been migrated by another process in the meantime
+        let Ok(serialized) = fs::read_to_string(&legacy_file.path) else {
+            continue;
+        };
+        match serde_json::from_str::<ForkCacheContent>(&serialized) {
+            Ok(content) => migrated.extend(content),
+            Err(_) => print_as_warning(&anyhow!(
+                "Could not migrate fork cache {}, it will be rebuilt",
+                legacy_file.path
+            )),
+        }
+        fs::remove_file(&legacy_file.path)
+            .with_context(|| format!("Could not remove fork cache {}", legacy_file.path))?;
+    }
+
+    if !migrated.is_empty() {
+        append_entries(&mut file, header, &migrated)?;
+    }
+    fs2::FileExt::unlock(&file).context("Could not unlock cache file")?;
+
+    Ok(())
+}
+
+fn sanitize_url(url: &Url) -> String {
     let re = Regex::new(r"[^a-zA-Z0-9]").unwrap();
 
     // replace non-alphanumeric characters with underscores
-    let sanitized_path = re.replace_all(url.as_str(), "_");
+    re.replace_all(url.as_str(), "_").to_string()
+}
+
+fn cache_file_path_from_fork_config(
+    url: &Url,
+    BlockNumber(block_number): BlockNumber,
+    cache_dir: &Utf8Path,
+) -> Result<Utf8PathBuf> {
+    let sanitized_path = sanitize_url(url);
 
     let cache_file_path = cache_dir.join(format!(
-        "{sanitized_path}_{block_number}_v{}.json",
-        cache_version()
+        "{sanitized_path}_{block_number}.{FORK_CACHE_EXTENSION}"
     ));
 
     fs::create_dir_all(cache_file_path.parent().unwrap())
@@ -0,0 +1,620 @@
+use serde_json::Value;
+use starknet::core::types::requests::{
+    ChainIdRequest, GetBlockWithTxHashesRequest, GetClassHashAtRequest, GetClassRequest,
+    GetNonceRequest, GetStorageAtRequest,
+};
+use starknet::core::types::{
+    BlockId, BlockTag, ContractClass, MaybePendingBlockWithTxHashes, StarknetError,
+};
+use starknet::providers::jsonrpc::{
+    HttpTransport, JsonRpcError, JsonRpcResponse, JsonRpcTransport,
+};
+use starknet::providers::{
+    JsonRpcClient, Provider, ProviderError, ProviderRequestData, ProviderResponseData,
+};
+use starknet_api::block::BlockNumber;
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::{Arc, LazyLock, Mutex, Once};
+use thiserror::Error;
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use tokio::sync::{Semaphore, oneshot};
+use url::Url;
+
+/// Maximal number of requests sent to the node in a single JSON-RPC batch
+const MAX_BATCH_SIZE: usize = 100;
+/// Maximal number of batches sent to the node of a fork at the same time
+const MAX_CONCURRENT_BATCHES: usize = 8;
+
+/// Runtime shared by all forks, on which requests of all tests are batched
+static RUNTIME: LazyLock<Runtime> =
+    LazyLock::new(|| Runtime::new().expect("Could not instantiate Runtime"));
+
+/// Runs `future` on the runtime shared by all forks and blocks until it completes
+pub(crate) fn block_on<T: Send + 'static>(future: impl Future<Output = T> + Send + 'static) -> T {
+    let (sender, receiver) = oneshot::channel();
+    RUNTIME.spawn(async move {
+        // The receiver is only dropped if the blocked thread panicked
+        let _ = sender.send(future.await);
+    });
+
+    receiver
+        .blocking_recv()
+        .expect("Task on the fork runtime stopped unexpectedly")
+}
+
+type ForkClientKey = (Url, BlockNumber, bool);
+
+static FORK_CLIENTS: LazyLock<Mutex<HashMap<ForkClientKey, Arc<ForkClient>>>> =
+    LazyLock::new(Mutex::default);
+
+/// Request for the state of a fork at its block
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum ForkRequest {
+    ChainId,
+    Block,
+    StorageAt { contract_address: Felt, key: Felt },
+    Nonce { contract_address: Felt },
+    ClassHashAt { contract_address: Felt },
+    Class { class_hash: Felt },
+}
+
+impl ForkRequest {
+    fn to_provider_request(&self, block_id: BlockId) -> ProviderRequestData {
+        match *self {
+            ForkRequest::ChainId => ProviderRequestData::ChainId(ChainIdRequest),
+            ForkRequest::Block => {
+                ProviderRequestData::GetBlockWithTxHashes(GetBlockWithTxHashesRequest { block_id })
+            }
+            ForkRequest::StorageAt {
+                contract_address,
+                key,
+            } => ProviderRequestData::GetStorageAt(GetStorageAtRequest {
+                contract_address,
+                key,
+                block_id,
+            }),
+            ForkRequest::Nonce { contract_address } => {
+                ProviderRequestData::GetNonce(GetNonceRequest {
+                    block_id,
+                    contract_address,
+                })
+            }
+            ForkRequest::ClassHashAt { contract_address } => {
+                ProviderRequestData::GetClassHashAt(GetClassHashAtRequest {
+                    block_id,
+                    contract_address,
+                })
+            }
+            ForkRequest::Class { class_hash } => ProviderRequestData::GetClass(GetClassRequest {
+                block_id,
+                class_hash,
+            }),
+        }
+    }
+
+    fn parse_response(&self, result: Value) -> serde_json::Result<ProviderResponseData> {
+        Ok(match self {
+            ForkRequest::ChainId => ProviderResponseData::ChainId(serde_json::from_value(result)?),
+            ForkRequest::Block => {
+                ProviderResponseData::GetBlockWithTxHashes(serde_json::from_value(result)?)
+            }
+            ForkRequest::StorageAt { .. } => {
+                ProviderResponseData::GetStorageAt(serde_json::from_value(result)?)
+            }
+            ForkRequest::Nonce { .. } => {
+                ProviderResponseData::GetNonce(serde_json::from_value(result)?)
+            }
+            ForkRequest::ClassHashAt { .. } => {
+                ProviderResponseData::GetClassHashAt(serde_json::from_value(result)?)
+            }
+            ForkRequest::Class { .. } => {
+                ProviderResponseData::GetClass(serde_json::from_value(result)?)
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Error)]
+pub(crate) enum ForkRequestError {
+    /// The contract or class does not exist at the block of the fork
+    #[error("Not found at the block of the fork")]
+    NotFound,
+    /// The node could not be reached or the transport failed otherwise
+    #[error("{0}")]
+    Transport(String),
+    #[error("{0}")]
+    Other(String),
+}
+
+impl From<ProviderError> for ForkRequestError {
+    fn from(error: ProviderError) -> Self {
+        match error {
+            ProviderError::StarknetError(
+                StarknetError::ContractNotFound | StarknetError::ClassHashNotFound,
+            ) => ForkRequestError::NotFound,
+            ProviderError::Other(boxed) => ForkRequestError::Transport(boxed.to_string()),
+            error => ForkRequestError::Other(error.to_string()),
+        }
+    }
+}
+
+impl From<JsonRpcError> for ForkRequestError {
+    fn from(error: JsonRpcError) -> Self {
+        match StarknetError::try_from(&error) {
+            Ok(error) => ProviderError::StarknetError(error).into(),
+            Err(_) => ForkRequestError::Other(error.message),
+        }
+    }
+}
+
+type ForkResponse = Result<Arc<ProviderResponseData>, ForkRequestError>;
+
+#[derive(Debug, Default)]
+struct ForkClientState {
+    // Prefetched responses no test has asked for yet, each is handed out once.
+    // Other responses are not kept, tests save them in their fork caches.
+    prefetched: HashMap<ForkRequest, ForkResponse>,
+    // Tests waiting for requests which have already been sent
+    in_flight: HashMap<ForkRequest, Vec<oneshot::Sender<ForkResponse>>>,
+}
+
+/// RPC client of a fork, shared by all tests forking the same url and block.
+/// Lookups of all tests are deduplicated while in flight and sent to the node in JSON-RPC batches.
+#[derive(Debug)]
+pub(crate) struct ForkClient {
+    client: JsonRpcClient<HttpTransport>,
+    // Used for batches, whose requests can fail independently
+    transport: HttpTransport,
+    block_id: BlockId,
+    state: Mutex<ForkClientState>,
+    queue: UnboundedSender<ForkRequest>,
+    prefetched: Once,
+}
+
+impl ForkClient {
+    /// Returns the client of the fork at `url` at `block_number`, or at the pending block
+    /// which will have `block_number`
+    pub(crate) fn shared(url: &Url, block_number: BlockNumber, pending: bool) -> Arc<Self> {
+        let mut fork_clients = FORK_CLIENTS.lock().expect("Fork clients lock is poisoned");
+
+        fork_clients
+            .entry((url.clone(), block_number, pending))
+            .or_insert_with(|| {
+                let block_id = if pending {
+                    BlockId::Tag(BlockTag::Pending)
+                } else {
+                    BlockId::Number(block_number.0)
+                };
+                ForkClient::new(url.clone(), block_id)
+            })
+            .clone()
+    }
+
+    fn new(url: Url, block_id: BlockId) -> Arc<Self> {
+        let (queue, receiver) = unbounded_channel();
+        let fork_client = Arc::new(ForkClient {
+            client: JsonRpcClient::new(HttpTransport::new(url.clone())),
+            transport: HttpTransport::new(url),
+            block_id,
+            state: Mutex::default(),
+            queue,
+            prefetched: Once::new(),
+        });
+
+        RUNTIME.spawn(fork_client.clone().send_batches(receiver));
+
+        fork_client
+    }
+
+    /// Sends `requests` in the background, only for the first test forking this url and block.
+    /// Tests wait for prefetched responses instead of sending the same requests again.
+    pub(crate) fn prefetch_once(&self, requests: impl FnOnce() -> Vec<ForkRequest>) {
+        self.prefetched.call_once(|| {
+            for request in requests() {
+                self.submit(request, None);
+            }
+        });
+    }
+
+    pub(crate) fn chain_id(&self) -> Result<Felt, ForkRequestError> {
+        self.request_as(ForkRequest::ChainId, |response| match response {
+            ProviderResponseData::ChainId(chain_id) => Some(*chain_id),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn get_block_with_tx_hashes(
+        &self,
+    ) -> Result<MaybePendingBlockWithTxHashes, ForkRequestError> {
+        self.request_as(ForkRequest::Block, |response| match response {
+            ProviderResponseData::GetBlockWithTxHashes(block) => Some(block.clone()),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn get_storage_at(
+        &self,
+        contract_address: Felt,
+        key: Felt,
+    ) -> Result<Felt, ForkRequestError> {
+        let request = ForkRequest::StorageAt {
+            contract_address,
+            key,
+        };
+        self.request_as(request, |response| match response {
+            ProviderResponseData::GetStorageAt(value) => Some(*value),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn get_nonce(&self, contract_address: Felt) -> Result<Felt, ForkRequestError> {
+        self.request_as(
+            ForkRequest::Nonce { contract_address },
+            |response| match response {
+                ProviderResponseData::GetNonce(nonce) => Some(*nonce),
+                _ => None,
+            },
+        )
+    }
+
+    pub(crate) fn get_class_hash_at(
+        &self,
+        contract_address: Felt,
+    ) -> Result<Felt, ForkRequestError> {
+        self.request_as(
+            ForkRequest::ClassHashAt { contract_address },
+            |response| match response {
+                ProviderResponseData::GetClassHashAt(class_hash) => Some(*class_hash),
+                _ => None,
+            },
+        )
+    }
+
+    pub(crate) fn get_class(&self, class_hash: Felt) -> Result<ContractClass, ForkRequestError> {
+        self.request_as(
+            ForkRequest::Class { class_hash },
+            |response| match response {
+                ProviderResponseData::GetClass(contract_class) => Some(contract_class.clone()),
+                _ => None,
+            },
+        )
+    }
+
+    fn request_as<T>(
+        &self,
+        request: ForkRequest,
+        extract: impl FnOnce(&ProviderResponseData) -> Option<T>,
+    ) -> Result<T, ForkRequestError> {
+        let response = self.request(request.clone())?;
+
+        extract(&response).ok_or_else(|| {
+            ForkRequestError::Other(format!("Unexpected response of the node to {request:?}"))
+        })
+    }
+
+    /// Blocks until the response to `request` is received
+    fn request(&self, request: ForkRequest) -> ForkResponse {
+        let (sender, receiver) = oneshot::channel();
+        self.submit(request, Some(sender));
+
+        receiver
+            .blocking_recv()
+            .expect("Batching of fork requests stopped unexpectedly")
+    }
+
+    /// Queues `request` unless it has already been prefetched or sent,
+    /// `waiter` is notified with the response
+    fn submit(&self, request: ForkRequest, waiter: Option<oneshot::Sender<ForkResponse>>) {
+        let mut state = self.state.lock().expect("Fork client lock is poisoned");
+
+        if let Entry::Occupied(entry) = state.prefetched.entry(request.clone()) {
+            if let Some(waiter) = waiter {
+                let _ = waiter.send(entry.remove());
+            }
+            return;
+        }
+
+        match state.in_flight.entry(request) {
+            Entry::Occupied(mut entry) => entry.get_mut().extend(waiter),
+            Entry::Vacant(entry) => {
+                self.queue
+                    .send(entry.key().clone())
+                    .expect("Batching of fork requests stopped unexpectedly");
+                entry.insert(waiter.into_iter().collect());
+            }
+        }
+    }
+
+    async fn send_batches(self: Arc<Self>, mut receiver: UnboundedReceiver<ForkRequest>) {
+        let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_BATCHES));
+
+        loop {
+            // Requests queued while all batches are in flight are sent together in the next one
+            let permit = semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("Semaphore is never closed");
+            let Some(request) = receiver.recv().await else {
+                return;
+            };
+
+            let mut requests = vec![request];
+            while requests.len() < MAX_BATCH_SIZE {
+                match receiver.try_recv() {
+                    Ok(request) => requests.push(request),
+                    Err(_) => break,
+                }
+            }
+
+            let fork_client = self.clone();
+            tokio::spawn(async move {
+                let responses = fork_client.send_batch(&requests).await;
+                fork_client.complete(requests, responses);
+                drop(permit);
+            });
+        }
+    }
+
+    async fn send_batch(
+        &self,
+        requests: &[ForkRequest],
+    ) -> Vec<Result<ProviderResponseData, ForkRequestError>> {
+        if let [request] = requests {
+            return vec![self.send(request).await.map_err(ForkRequestError::from)];
+        }
+
+        let batch: Vec<_> = requests
+            .iter()
+            .map(|request| request.to_provider_request(self.block_id))
+            .collect();
+
+        // `batch_requests` of the provider fails the whole batch if a single request fails,
+        // so results are parsed one by one instead
+        let responses = match self.transport.send_requests(batch).await {
+            Ok(responses) => responses,
+            Err(error) => {
+                let error = ForkRequestError::Transport(error.to_string());
+                return requests.iter().map(|_| Err(error.clone())).collect();
+            }
+        };
+
+        let mut results: Vec<_> = requests
+            .iter()
+            .map(|request| {
+                Err(ForkRequestError::Other(format!(
+                    "No response of the node to {request:?}"
+                )))
+            })
+            .collect();
+
+        for response in responses {
+            let (id, result) = match response {
+                JsonRpcResponse::Success { id, result } => (id, Ok(result)),
+                JsonRpcResponse::Error { id, error } => (id, Err(error)),
+            };
+            // Requests of a batch are numbered with their indices
+            let Some(index) = usize::try_from(id).ok().filter(|&id| id < requests.len()) else {
+                continue;
+            };
+            let request = &requests[index];
+
+            results[index] = match result {
+                Ok(result) => request.parse_response(result).map_err(|error| {
+                    ForkRequestError::Other(format!(
+                        "Unexpected response of the node to {request:?}: {error}"
+                    ))
+                }),
+                Err(error) => Err(error.into()),
+            };
+        }
+
+        results
+    }
+
+    async fn send(&self, request: &ForkRequest) -> Result<ProviderResponseData, ProviderError> {
+        let block_id = self.block_id;
+
+        Ok(match *request {
+            ForkRequest::ChainId => ProviderResponseData::ChainId(self.client.chain_id().await?),
+            ForkRequest::Block => ProviderResponseData::GetBlockWithTxHashes(
+                self.client.get_block_with_tx_hashes(block_id).await?,
+            ),
+            ForkRequest::StorageAt {
+                contract_address,
+                key,
+            } => ProviderResponseData::GetStorageAt(
+                self.client
+                    .get_storage_at(contract_address, key, block_id)
+                    .await?,
+            ),
+            ForkRequest::Nonce { contract_address } => ProviderResponseData::GetNonce(
+                self.client.get_nonce(block_id, contract_address).await?,
+            ),
+            ForkRequest::ClassHashAt { contract_address } => ProviderResponseData::GetClassHashAt(
+                self.client
+                    .get_class_hash_at(block_id, contract_address)
+                    .await?,
+            ),
+            ForkRequest::Class { class_hash } => {
+                ProviderResponseData::GetClass(self.client.get_class(block_id, class_hash).await?)
+            }
+        })
+    }
+
+    fn complete(
+        &self,
+        requests: Vec<ForkRequest>,
+        responses: Vec<Result<ProviderResponseData, ForkRequestError>>,
+    ) {
+        let mut state = self.state.lock().expect("Fork client lock is poisoned");
+
+        for (request, response) in requests.into_iter().zip(responses) {
+            let response = response.map(Arc::new);
+            let waiters = state.in_flight.remove(&request).unwrap_or_default();
+
+            // Transport failures are not saved, so that later tests can retry the request
+            if waiters.is_empty() && !matches!(response, Err(ForkRequestError::Transport(_))) {
+                state.prefetched.insert(request, response);
+                continue;
+            }
+
+            for waiter in waiters {
+                // Tests which timed out do not wait for the response anymore
+                let _ = waiter.send(response.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::thread;
+    use std::time::Duration;
+    use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate, matchers::method};
+
+    /// Storage key for which the node responds with an error
+    const MISSING_KEY: Felt = Felt::ZERO;
+
+    /// Node which responds to `starknet_getStorageAt` with the requested key.
+    /// Responses to batches are returned in reverse order.
+    pub(crate) struct MockNode {
+        runtime: Runtime,
+        server: MockServer,
+    }
+
+    struct StorageResponder {
+        delay: Duration,
+    }
+
+    impl Respond for StorageResponder {
+        fn respond(&self, request: &Request) -> ResponseTemplate {
+            let body: Value = serde_json::from_slice(&request.body).unwrap();
+            let response = match body {
+                Value::Array(calls) => Value::Array(calls.iter().rev().map(respond_to).collect()),
+                call => respond_to(&call),
+            };
+
+            ResponseTemplate::new(200)
+                .set_body_json(response)
+                .set_delay(self.delay)
+        }
+    }
+
+    fn respond_to(call: &Value) -> Value {
+        let key = &call["params"]["key"];
+
+        if call["method"] != "starknet_getStorageAt" {
+            json!({ "jsonrpc": "2.0", "id": call["id"], "error": { "code": -32601, "message": "Method not found" } })
+        } else if Felt::from_hex(key.as_str().unwrap()).unwrap() == MISSING_KEY {
+            json!({ "jsonrpc": "2.0", "id": call["id"], "error": { "code": 20, "message": "Contract not found" } })
+        } else {
+            json!({ "jsonrpc": "2.0", "id": call["id"], "result": key })
+        }
+    }
+
+    impl MockNode {
+        pub(crate) fn start(delay: Duration) -> Self {
+            let runtime = Runtime::new().unwrap();
+            let server = runtime.block_on(async {
+                let server = MockServer::start().await;
+                Mock::given(method("POST"))
+                    .respond_with(StorageResponder { delay })
+                    .mount(&server)
+                    .await;
+                server
+            });
+
+            MockNode { runtime, server }
+        }
+
+        pub(crate) fn url(&self) -> Url {
+            Url::parse(&self.server.uri()).unwrap()
+        }
+
+        /// Bodies of HTTP requests received by the node, a batch is a single request
+        pub(crate) fn received_requests(&self) -> Vec<Value> {
+            self.runtime
+                .block_on(self.server.received_requests())
+                .unwrap()
+                .iter()
+                .map(|request| serde_json::from_slice(&request.body).unwrap())
+                .collect()
+        }
+
+        /// JSON-RPC calls received by the node, including the ones sent in batches
+        pub(crate) fn received_calls(&self) -> Vec<Value> {
+            self.received_requests()
+                .into_iter()
+                .flat_map(|body| match body {
+                    Value::Array(calls) => calls,
+                    call => vec![call],
+                })
+                .collect()
+        }
+    }
+
+    fn storage_request(key: u64) -> ForkRequest {
+        ForkRequest::StorageAt {
+            contract_address: Felt::ONE,
+            key: Felt::from(key),
+        }
+    }
+
+    #[test]
+    fn requests_are_sent_in_batches() {
+        let node = MockNode::start(Duration::ZERO);
+        let client = ForkClient::new(node.url(), BlockId::Number(1));
+
+        client.prefetch_once(|| (1..=250).map(storage_request).collect());
+
+        for key in 1..=250_u64 {
+            let value = client.get_storage_at(Felt::ONE, Felt::from(key)).unwrap();
+            assert_eq!(value, Felt::from(key));
+        }
+
+        assert_eq!(node.received_calls().len(), 250);
+        assert!(node.received_requests().len() < 250);
+    }
+
+    #[test]
+    fn failed_request_does_not_fail_batch() {
+        let node = MockNode::start(Duration::ZERO);
+        let client = ForkClient::new(node.url(), BlockId::Number(1));
+
+        client.prefetch_once(|| (0..10).map(storage_request).collect());
+
+        let error = client.get_storage_at(Felt::ONE, MISSING_KEY).unwrap_err();
+        assert!(matches!(error, ForkRequestError::NotFound));
+        for key in 1..10_u64 {
+            let value = client.get_storage_at(Felt::ONE, Felt::from(key)).unwrap();
+            assert_eq!(value, Felt::from(key));
+        }
+
+        // Other requests of the batch are not sent again
+        assert_eq!(node.received_calls().len(), 10);
+    }
+
+    #[test]
+    fn requests_in_flight_are_deduplicated() {
+        let node = MockNode::start(Duration::from_millis(500));
+        let client = ForkClient::new(node.url(), BlockId::Number(1));
+
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let client = client.clone();
+                thread::spawn(move || client.get_storage_at(Felt::ONE, Felt::from(7)).unwrap())
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), Felt::from(7));
+        }
+        assert_eq!(node.received_calls().len(), 1);
+    }
+}
@@ -1,2 +1,6 @@
+pub mod block_number;
 pub mod cache;
+pub(crate) mod client;
+pub mod multi_fork;
 pub mod state;
+pub mod state_dump;
@@ -0,0 +1,358 @@
+use crate::constants::build_testing_state;
+use crate::forking::block_number::resolve_block_number;
+use crate::forking::state::ForkStateReader;
+use crate::forking::state_dump::is_state_dump_url;
+use crate::runtime_extensions::forge_config_extension::config::{BlockId, BlockTag};
+use crate::state::{BlockInfoReader, ExtendedStateReader};
+use anyhow::{Result, anyhow, ensure};
+use blockifier::execution::contract_class::RunnableCompiledClass;
+use blockifier::state::cached_state::{CachedState, StateMaps};
+use blockifier::state::state_api::{State, StateReader, StateResult};
+use camino::{Utf8Path, Utf8PathBuf};
+use runtime::starknet::state::DictStateReader;
+use starknet_api::block::{BlockInfo, BlockNumber};
+use starknet_api::core::{ClassHash, CompiledClassHash, ContractAddress, Nonce};
+use starknet_api::state::StorageKey;
+use starknet_types_core::felt::Felt;
+use std::cell::{RefCell, RefMut};
+use std::collections::HashMap;
+use std::rc::Rc;
+use url::Url;
+
+/// Name of the fork the test starts with (configured with `#[fork]` or not forked at all)
+pub const DEFAULT_FORK_NAME: &str = "default";
+
+/// Fork created by the test with `create_fork` cheatcode
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatedFork {
+    pub name: String,
+    pub url: Url,
+    /// Block the fork was created with
+    pub block_id: BlockId,
+    pub block_number: BlockNumber,
+}
+
+#[derive(Debug)]
+struct Forks {
+    // Each fork has its own dict state (and cache of writes) on top of the forked network
+    states: Vec<CachedState<ExtendedStateReader>>,
+    names: HashMap<String, usize>,
+    active: usize,
+    // Declared classes are shared between all forks
+    declared_classes: HashMap<ClassHash, RunnableCompiledClass>,
+    compiled_class_hashes: HashMap<ClassHash, CompiledClassHash>,
+    cache_dir: Utf8PathBuf,
+    // Forks are served only from the cache in offline mode
+    offline: bool,
+    // Storage read by previous runs is fetched in the background for new forks
+    prefetch: bool,
+    // Only the default fork is carried over from setup functions, so they can't create forks
+    forking_disabled: bool,
+    // All forks created by the test, including ones removed by reverting to a snapshot
+    created: Vec<CreatedFork>,
+}
+
+/// State of all forks saved with [`MultiForkState::snapshot`]
+#[derive(Debug, Clone)]
+pub struct ForksSnapshot {
+    // Writes made to each fork on top of its state reader
+    writes: Vec<StateMaps>,
+    names: HashMap<String, usize>,
+    active: usize,
+    declared_classes: HashMap<ClassHash, RunnableCompiledClass>,
+    compiled_class_hashes: HashMap<ClassHash, CompiledClassHash>,
+}
+
+impl ForksSnapshot {
+    /// Writes the state of the default fork saved in the snapshot into `state_reader`,
+    /// so that a state built on top of it starts where the snapshot was taken
+    pub fn write_to_state_reader(&self, state_reader: &mut DictStateReader) {
+        let writes = &self.writes[self.names[DEFAULT_FORK_NAME]];
+
+        state_reader.storage_view.extend(writes.storage.clone());
+        state_reader.address_to_nonce.extend(writes.nonces.clone());
+        state_reader
+            .address_to_class_hash
+            .extend(writes.class_hashes.clone());
+        state_reader
+            .class_hash_to_compiled_class
+            .extend(self.declared_classes.clone());
+        state_reader
+            .class_hash_to_compiled_class_hash
+            .extend(self.compiled_class_hashes.clone());
+    }
+}
+
+/// Blockifier state that consists of multiple forks, of which only one is selected at a time.
+/// All reads and writes go to the currently selected fork.
+/// Cloned instances share the same forks, which lets cheatcodes create and select forks
+/// while the state itself is borrowed by the syscall handler.
+#[derive(Debug, Clone)]
+pub struct MultiForkState(Rc<RefCell<Forks>>);
+
+impl MultiForkState {
+    #[must_use]
+    pub fn new(
+        default_fork: CachedState<ExtendedStateReader>,
+        cache_dir: &Utf8Path,
+        offline: bool,
+        prefetch: bool,
+    ) -> Self {
+        Self(Rc::new(RefCell::new(Forks {
+            states: vec![default_fork],
+            names: HashMap::from([(DEFAULT_FORK_NAME.to_string(), 0)]),
+            active: 0,
+            declared_classes: HashMap::default(),
+            compiled_class_hashes: HashMap::default(),
+            cache_dir: cache_dir.to_path_buf(),
+            offline,
+            prefetch,
+            forking_disabled: false,
+            created: vec![],
+        })))
+    }
+
+    /// Makes [`MultiForkState::create_fork`] fail, used when running `#[setup]` functions
+    pub fn disable_forking(&self) {
+        self.0.borrow_mut().forking_disabled = true;
+    }
+
+    /// Creates a new fork of the network at `url` on the block `block_id`.
+    /// The fork is not selected.
+    pub fn create_fork(&self, name: &str, url: Url, block_id: BlockId) -> Result<()> {
+        let mut forks = self.0.borrow_mut();
+
+        ensure!(
+            !forks.forking_disabled,
+            "Forks cannot be created in #[setup] functions, create them in the tests instead"
+        );
+        ensure!(
+            !forks.names.contains_key(name),
+            "Fork with name = {name} already exists"
+        );
+
+        let block_number = resolve_block_number(&url, &block_id, &forks.cache_dir, forks.offline)?;
+        // A state dump has no pending block, every tag refers to the block of the dump
+        let fork_state_reader =
+            if block_id == BlockId::BlockTag(BlockTag::Pending) && !is_state_dump_url(&url) {
+                ForkStateReader::new_pending(url.clone(), block_number)
+            } else {
+                ForkStateReader::new(
+                    url.clone(),
+                    block_number,
+                    &forks.cache_dir,
+                    forks.offline,
+                    forks.prefetch,
+                )?
+            };
+
+        let mut state = CachedState::new(ExtendedStateReader {
+            dict_state_reader: build_testing_state(),
+            fork_state_reader: Some(fork_state_reader),
+        });
+
+        for (class_hash, class) in &forks.declared_classes {
+            state.set_contract_class(*class_hash, class.clone())?;
+        }
+        for (class_hash, compiled_class_hash) in &forks.compiled_class_hashes {
+            state.set_compiled_class_hash(*class_hash, *compiled_class_hash)?;
+        }
+
+        let id = forks.states.len();
+        forks.states.push(state);
+        forks.names.insert(name.to_string(), id);
+        forks.created.push(CreatedFork {
+            name: name.to_string(),
+            url,
+            block_id,
+            block_number,
+        });
+
+        Ok(())
+    }
+
+    /// Returns all forks created by the test, in the order of creation
+    #[must_use]
+    pub fn created_forks(&self) -> Vec<CreatedFork> {
+        self.0.borrow().created.clone()
+    }
+
+    /// Selects the fork with the given `name` and returns its block info
+    pub fn select_fork(&self, name: &str) -> Result<BlockInfo> {
+        let mut forks = self.0.borrow_mut();
+
+        let id = *forks
+            .names
+            .get(name)
+            .ok_or_else(|| anyhow!("Fork with name = {name} does not exist"))?;
+        forks.active = id;
+
+        Ok(forks.states[id].state.get_block_info()?)
+    }
+
+    /// Saves the state of all forks, including the currently selected one
+    pub fn snapshot(&self) -> StateResult<ForksSnapshot> {
+        let mut forks = self.0.borrow_mut();
+
+        let writes = forks
+            .states
+            .iter_mut()
+            .map(|state| Ok(state.get_actual_state_changes()?.state_maps))
+            .collect::<StateResult<_>>()?;
+
+        Ok(ForksSnapshot {
+            writes,
+            names: forks.names.clone(),
+            active: forks.active,
+            declared_classes: forks.declared_classes.clone(),
+            compiled_class_hashes: forks.compiled_class_hashes.clone(),
+        })
+    }
+
+    /// Restores the state of all forks saved in the `snapshot`.
+    /// Forks created after the snapshot was taken are removed.
+    pub fn revert_to(&self, snapshot: &ForksSnapshot) -> StateResult<()> {
+        let mut forks = self.0.borrow_mut();
+
+        forks.states.truncate(snapshot.writes.len());
+        for (state, writes) in forks.states.iter_mut().zip(&snapshot.writes) {
+            // Writes are kept only in the cache, so the state is rebuilt on top of its reader
+            let state_reader = std::mem::replace(
+                &mut state.state,
+                ExtendedStateReader {
+                    dict_state_reader: DictStateReader::default(),
+                    fork_state_reader: None,
+                },
+            );
+            *state = CachedState::new(state_reader);
+
+            for (class_hash, class) in &snapshot.declared_classes {
+                state.set_contract_class(*class_hash, class.clone())?;
+            }
+            for (class_hash, compiled_class_hash) in &snapshot.compiled_class_hashes {
+                state.set_compiled_class_hash(*class_hash, *compiled_class_hash)?;
+            }
+            for (&(contract_address, key), &value) in &writes.storage {
+                state.set_storage_at(contract_address, key, value)?;
+            }
+            for (&contract_address, &class_hash) in &writes.class_hashes {
+                state.set_class_hash_at(contract_address, class_hash)?;
+            }
+            // Nonces can only be incremented, the state reader holds the initial ones
+            for (&contract_address, &nonce) in &writes.nonces {
+                while state.get_nonce_at(contract_address)? < nonce {
+                    state.increment_nonce(contract_address)?;
+                }
+            }
+        }
+
+        forks.names.clone_from(&snapshot.names);
+        forks.active = snapshot.active;
+        forks
+            .declared_classes
+            .clone_from(&snapshot.declared_classes);
+        forks
+            .compiled_class_hashes
+            .clone_from(&snapshot.compiled_class_hashes);
+
+        Ok(())
+    }
+
+    /// Returns the states of all forks, the default one first
+    #[must_use]
+    pub fn states(&self) -> RefMut<'_, Vec<CachedState<ExtendedStateReader>>> {
+        RefMut::map(self.0.borrow_mut(), |forks| &mut forks.states)
+    }
+
+    /// Returns the state of the currently selected fork
+    #[must_use]
+    pub fn active_state(&self) -> RefMut<'_, CachedState<ExtendedStateReader>> {
+        RefMut::map(self.0.borrow_mut(), |forks| {
+            let active = forks.active;
+            &mut forks.states[active]
+        })
+    }
+}
+
+impl StateReader for MultiForkState {
+    fn get_storage_at(
+        &self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+    ) -> StateResult<Felt> {
+        self.active_state().get_storage_at(contract_address, key)
+    }
+
+    fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
+        self.active_state().get_nonce_at(contract_address)
+    }
+
+    fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
+        self.active_state().get_class_hash_at(contract_address)
+    }
+
+    fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        self.active_state().get_compiled_class(class_hash)
+    }
+
+    fn get_compiled_class_hash(&self, class_hash: ClassHash) -> StateResult<CompiledClassHash> {
+        self.active_state().get_compiled_class_hash(class_hash)
+    }
+}
+
+impl State for MultiForkState {
+    fn set_storage_at(
+        &mut self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+        value: Felt,
+    ) -> StateResult<()> {
+        self.active_state()
+            .set_storage_at(contract_address, key, value)
+    }
+
+    fn increment_nonce(&mut self, contract_address: ContractAddress) -> StateResult<()> {
+        self.active_state().increment_nonce(contract_address)
+    }
+
+    fn set_class_hash_at(
+        &mut self,
+        contract_address: ContractAddress,
+        class_hash: ClassHash,
+    ) -> StateResult<()> {
+        self.active_state()
+            .set_class_hash_at(contract_address, class_hash)
+    }
+
+    fn set_contract_class(
+        &mut self,
+        class_hash: ClassHash,
+        contract_class: RunnableCompiledClass,
+    ) -> StateResult<()> {
+        let mut forks = self.0.borrow_mut();
+
+        for state in &mut forks.states {
+            state.set_contract_class(class_hash, contract_class.clone())?;
+        }
+        forks.declared_classes.insert(class_hash, contract_class);
+
+        Ok(())
+    }
+
+    fn set_compiled_class_hash(
+        &mut self,
+        class_hash: ClassHash,
+        compiled_class_hash: CompiledClassHash,
+    ) -> StateResult<()> {
+        let mut forks = self.0.borrow_mut();
+
+        for state in &mut forks.states {
+            state.set_compiled_class_hash(class_hash, compiled_class_hash)?;
+        }
+        forks
+            .compiled_class_hashes
+            .insert(class_hash, compiled_class_hash);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
-use crate::forking::cache::ForkCache;
+use crate::forking::cache::{ForkCache, ForkCacheContent, previous_cache_content};
+use crate::forking::client::{ForkClient, ForkRequest, ForkRequestError};
+use crate::forking::state_dump::is_state_dump_url;
 use crate::state::BlockInfoReader;
-use anyhow::{Context, Result};
+use anyhow::Result;
 use blockifier::execution::contract_class::{
     CompiledClassV0, CompiledClassV0Inner, CompiledClassV1, RunnableCompiledClass,
 };
@@ -9,64 +11,155 @@ use blockifier::state::state_api::{StateReader, StateResult};
 use cairo_lang_utils::bigint::BigUintAsHex;
 use cairo_vm::types::program::Program;
 use camino::Utf8Path;
+use conversions::string::IntoHexStr;
 use conversions::{FromConv, IntoConv};
 use flate2::read::GzDecoder;
 use num_bigint::BigUint;
 use runtime::starknet::context::SerializableGasPrices;
 use starknet::core::types::{
-    BlockId, ContractClass as ContractClassStarknet, MaybePendingBlockWithTxHashes, StarknetError,
+    ContractClass as ContractClassStarknet, MaybePendingBlockWithTxHashes,
 };
 use starknet::core::utils::parse_cairo_short_string;
-use starknet::providers::jsonrpc::HttpTransport;
-use starknet::providers::{JsonRpcClient, Provider, ProviderError};
 use starknet_api::block::{BlockInfo, BlockNumber, BlockTimestamp};
 use starknet_api::contract_class::SierraVersion;
 use starknet_api::core::{ChainId, ClassHash, CompiledClassHash, ContractAddress, Nonce};
 use starknet_api::state::StorageKey;
 use starknet_types_core::felt::Felt;
-use std::cell::RefCell;
 use std::io::Read;
-use std::sync::Arc;
-use tokio::runtime::Runtime;
+use std::sync::{Arc, Mutex, MutexGuard};
 use universal_sierra_compiler_api::{SierraType, compile_sierra};
 use url::Url;
 
 #[derive(Debug)]
 pub struct ForkStateReader {
-    client: JsonRpcClient<HttpTransport>,
+    // `None` in offline mode and for state dumps, which are served only from the cache
+    client: Option<Arc<ForkClient>>,
     block_number: BlockNumber,
-    runtime: Runtime,
-    cache: RefCell<ForkCache>,
+    // Shared with other tests forking the same url and block
+    cache: Arc<Mutex<ForkCache>>,
+    is_state_dump: bool,
 }
 
 impl ForkStateReader {
-    pub fn new(url: Url, block_number: BlockNumber, cache_dir: &Utf8Path) -> Result<Self> {
+    pub fn new(
+        url: Url,
+        block_number: BlockNumber,
+        cache_dir: &Utf8Path,
+        offline: bool,
+        prefetch: bool,
+    ) -> Result<Self> {
+        let is_state_dump = is_state_dump_url(&url);
+        let cache = if is_state_dump {
+            Arc::new(Mutex::new(ForkCache::load_state_dump(&url, block_number)?))
+        } else {
+            ForkCache::shared(&url, block_number, cache_dir, offline)?
+        };
+
+        let client =
+            (!offline && !is_state_dump).then(|| ForkClient::shared(&url, block_number, false));
+
+        if let Some(client) = client.as_ref().filter(|_| prefetch) {
+            // Prefetching is best-effort, lookups missing from the previous cache are sent later
+            client.prefetch_once(|| {
+                previous_cache_content(&url, block_number, cache_dir)
+                    .ok()
+                    .flatten()
+                    .map(|previous_content| {
+                        prefetch_requests(
+                            &cache.lock().expect("Fork cache lock is poisoned"),
+                            previous_content,
+                        )
+                    })
+                    .unwrap_or_default()
+            });
+        }
+
         Ok(ForkStateReader {
-            cache: RefCell::new(
-                ForkCache::load_or_new(&url, block_number, cache_dir)
-                    .context("Could not create fork cache")?,
-            ),
-            client: JsonRpcClient::new(HttpTransport::new(url)),
+            cache,
+            client,
             block_number,
-            runtime: Runtime::new().expect("Could not instantiate Runtime"),
+            is_state_dump,
         })
     }
 
+    /// Creates a reader of the pending block of the fork at `url`, which will have `block_number`
+    #[must_use]
+    pub fn new_pending(url: Url, block_number: BlockNumber) -> Self {
+        ForkStateReader {
+            cache: Arc::new(Mutex::new(ForkCache::in_memory(&url, block_number))),
+            client: Some(ForkClient::shared(&url, block_number, true)),
+            block_number,
+            is_state_dump: false,
+        }
+    }
+
     pub fn chain_id(&self) -> Result<ChainId> {
-        let id = self.runtime.block_on(self.client.chain_id())?;
+        if let Some(cache_hit) = self.cache().get_chain_id() {
+            return Ok(ChainId::from(cache_hit));
+        }
+
+        let client = self.client(|| "chain id".to_string())?;
+        let id = client.chain_id()?;
         let id = parse_cairo_short_string(&id)?;
+        self.cache().cache_get_chain_id(id.clone());
         Ok(ChainId::from(id))
     }
 
-    fn block_id(&self) -> BlockId {
-        BlockId::Number(self.block_number.0)
+    fn cache(&self) -> MutexGuard<'_, ForkCache> {
+        self.cache.lock().expect("Fork cache lock is poisoned")
+    }
+
+    /// Returns the client used on cache misses, or an error describing the missing `entry`
+    /// in offline mode and for partial state dumps
+    fn client(&self, entry: impl FnOnce() -> String) -> StateResult<&ForkClient> {
+        self.client.as_deref().ok_or_else(|| {
+            let reason = if self.is_state_dump {
+                "not in state dump"
+            } else {
+                "cache miss"
+            };
+            StateReadError(format!(
+                "{reason}: {} at block {}",
+                entry(),
+                self.block_number.0
+            ))
+        })
     }
 }
 
-#[expect(clippy::needless_pass_by_value)]
-fn other_provider_error<T>(boxed: impl ToString) -> Result<T, StateError> {
-    let err_str = boxed.to_string();
+/// Returns requests for the entries of `previous_content` which are missing from `cache`
+fn prefetch_requests(cache: &ForkCache, previous_content: ForkCacheContent) -> Vec<ForkRequest> {
+    let mut requests = vec![];
 
+    for (contract_address, storage) in previous_content.storage_at {
+        for key in storage.into_keys() {
+            if cache.get_storage_at(&contract_address, &key).is_none() {
+                requests.push(ForkRequest::StorageAt {
+                    contract_address: Felt::from_(contract_address),
+                    key: Felt::from_(*key.0.key()),
+                });
+            }
+        }
+    }
+    for contract_address in previous_content.nonce_at.into_keys() {
+        if cache.get_nonce_at(&contract_address).is_none() {
+            requests.push(ForkRequest::Nonce {
+                contract_address: Felt::from_(contract_address),
+            });
+        }
+    }
+    for contract_address in previous_content.class_hash_at.into_keys() {
+        if cache.get_class_hash_at(&contract_address).is_none() {
+            requests.push(ForkRequest::ClassHashAt {
+                contract_address: Felt::from_(contract_address),
+            });
+        }
+    }
+
+    requests
+}
+
+fn transport_error<T>(err_str: &str) -> Result<T, StateError> {
     Err(StateReadError(
         if err_str.contains("error sending request for url") {
             "Unable to reach the node. Check your internet connection and node url".to_string()
@@ -78,37 +171,40 @@ fn other_provider_error<T>(boxed: impl ToString) -> Result<T, StateError> {
 
 impl BlockInfoReader for ForkStateReader {
     fn get_block_info(&mut self) -> StateResult<BlockInfo> {
-        if let Some(cache_hit) = self.cache.borrow().get_block_info() {
+        if let Some(cache_hit) = self.cache().get_block_info() {
             return Ok(cache_hit);
         }
 
-        match self
-            .runtime
-            .block_on(self.client.get_block_with_tx_hashes(self.block_id()))
-        {
-            Ok(MaybePendingBlockWithTxHashes::Block(block)) => {
-                let block_info = BlockInfo {
-                    block_number: BlockNumber(block.block_number),
-                    sequencer_address: block.sequencer_address.into_(),
-                    block_timestamp: BlockTimestamp(block.timestamp),
-                    gas_prices: SerializableGasPrices::default().into(),
-                    use_kzg_da: true,
-                };
-
-                self.cache
-                    .borrow_mut()
-                    .cache_get_block_info(block_info.clone());
-
-                Ok(block_info)
+        let client = self.client(|| "block info".to_string())?;
+        // The pending block does not have a number yet, it gets the one it was resolved to
+        let (block_number, sequencer_address, timestamp) = match client.get_block_with_tx_hashes() {
+            Ok(MaybePendingBlockWithTxHashes::Block(block)) => (
+                BlockNumber(block.block_number),
+                block.sequencer_address,
+                block.timestamp,
+            ),
+            Ok(MaybePendingBlockWithTxHashes::PendingBlock(block)) => {
+                (self.block_number, block.sequencer_address, block.timestamp)
             }
-            Ok(MaybePendingBlockWithTxHashes::PendingBlock(_)) => {
-                unreachable!("Pending block is not be allowed at the configuration level")
+            Err(ForkRequestError::Transport(err)) => return transport_error(&err),
+            Err(err) => {
+                return Err(StateReadError(format!(
+                    "Unable to get block with tx hashes from fork ({err})"
+                )));
             }
-            Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
-            Err(err) => Err(StateReadError(format!(
-                "Unable to get block with tx hashes from fork ({err})"
-            ))),
-        }
+        };
+
+        let block_info = BlockInfo {
+            block_number,
+            sequencer_address: sequencer_address.into_(),
+            block_timestamp: BlockTimestamp(timestamp),
+            gas_prices: SerializableGasPrices::default().into(),
+            use_kzg_da: true,
+        };
+
+        self.cache().cache_get_block_info(block_info.clone());
+
+        Ok(block_info)
     }
 }
 
@@ -118,29 +214,28 @@ impl StateReader for ForkStateReader {
         contract_address: ContractAddress,
         key: StorageKey,
     ) -> StateResult<Felt> {
-        if let Some(cache_hit) = self.cache.borrow().get_storage_at(&contract_address, &key) {
+        if let Some(cache_hit) = self.cache().get_storage_at(&contract_address, &key) {
             return Ok(cache_hit);
         }
 
-        match self.runtime.block_on(self.client.get_storage_at(
-            Felt::from_(contract_address),
-            Felt::from_(*key.0.key()),
-            self.block_id(),
-        )) {
+        let client = self.client(|| {
+            format!(
+                "storage key {} of contract {}",
+                Felt::from_(*key.0.key()).into_hex_string(),
+                Felt::from_(contract_address).into_hex_string()
+            )
+        })?;
+        match client.get_storage_at(Felt::from_(contract_address), Felt::from_(*key.0.key())) {
             Ok(value) => {
                 let value_sf = value.into_();
-                self.cache
-                    .borrow_mut()
+                self.cache()
                     .cache_get_storage_at(contract_address, key, value_sf);
                 Ok(value_sf)
             }
-            Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
-            Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => {
-                self.cache.borrow_mut().cache_get_storage_at(
-                    contract_address,
-                    key,
-                    Felt::default(),
-                );
+            Err(ForkRequestError::Transport(err)) => transport_error(&err),
+            Err(ForkRequestError::NotFound) => {
+                self.cache()
+                    .cache_get_storage_at(contract_address, key, Felt::default());
                 Ok(Felt::default())
             }
             Err(x) => Err(StateReadError(format!(
@@ -150,25 +245,25 @@ impl StateReader for ForkStateReader {
     }
 
     fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
-        if let Some(cache_hit) = self.cache.borrow().get_nonce_at(&contract_address) {
+        if let Some(cache_hit) = self.cache().get_nonce_at(&contract_address) {
             return Ok(cache_hit);
         }
 
-        match self.runtime.block_on(
-            self.client
-                .get_nonce(self.block_id(), Felt::from_(contract_address)),
-        ) {
+        let client = self.client(|| {
+            format!(
+                "nonce of contract {}",
+                Felt::from_(contract_address).into_hex_string()
+            )
+        })?;
+        match client.get_nonce(Felt::from_(contract_address)) {
             Ok(nonce) => {
                 let nonce = nonce.into_();
-                self.cache
-                    .borrow_mut()
-                    .cache_get_nonce_at(contract_address, nonce);
+                self.cache().cache_get_nonce_at(contract_address, nonce);
                 Ok(nonce)
             }
-            Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
-            Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => {
-                self.cache
-                    .borrow_mut()
+            Err(ForkRequestError::Transport(err)) => transport_error(&err),
+            Err(ForkRequestError::NotFound) => {
+                self.cache()
                     .cache_get_nonce_at(contract_address, Nonce::default());
                 Ok(Nonce::default())
             }
@@ -179,28 +274,29 @@ impl StateReader for ForkStateReader {
     }
 
     fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
-        if let Some(cache_hit) = self.cache.borrow().get_class_hash_at(&contract_address) {
+        if let Some(cache_hit) = self.cache().get_class_hash_at(&contract_address) {
             return Ok(cache_hit);
         }
 
-        match self.runtime.block_on(
-            self.client
-                .get_class_hash_at(self.block_id(), Felt::from_(contract_address)),
-        ) {
+        let client = self.client(|| {
+            format!(
+                "class hash of contract {}",
+                Felt::from_(contract_address).into_hex_string()
+            )
+        })?;
+        match client.get_class_hash_at(Felt::from_(contract_address)) {
             Ok(class_hash) => {
                 let class_hash = class_hash.into_();
-                self.cache
-                    .borrow_mut()
+                self.cache()
                     .cache_get_class_hash_at(contract_address, class_hash);
                 Ok(class_hash)
             }
-            Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => {
-                self.cache
-                    .borrow_mut()
+            Err(ForkRequestError::NotFound) => {
+                self.cache()
                     .cache_get_class_hash_at(contract_address, ClassHash::default());
                 Ok(ClassHash::default())
             }
-            Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
+            Err(ForkRequestError::Transport(err)) => transport_error(&err),
             Err(x) => Err(StateReadError(format!(
                 "Unable to get class hash at {contract_address:?} from fork ({x})"
             ))),
@@ -208,27 +304,33 @@ impl StateReader for ForkStateReader {
     }
 
     fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
-        let mut cache = self.cache.borrow_mut();
+        let (cache_hit, is_complete_state_dump) = {
+            let cache = self.cache();
+            (
+                cache.get_compiled_contract_class(&class_hash).cloned(),
+                cache.is_complete_state_dump(),
+            )
+        };
 
-        let contract_class = {
-            if let Some(cache_hit) = cache.get_compiled_contract_class(&class_hash) {
-                Ok(cache_hit)
-            } else {
-                match self.runtime.block_on(
-                    self.client
-                        .get_class(self.block_id(), Felt::from_(class_hash)),
-                ) {
-                    Ok(contract_class) => {
-                        Ok(cache.insert_compiled_contract_class(class_hash, contract_class))
-                    }
-                    Err(ProviderError::StarknetError(StarknetError::ClassHashNotFound)) => {
-                        Err(UndeclaredClassHash(class_hash))
-                    }
-                    Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
-                    Err(x) => Err(StateReadError(format!(
-                        "Unable to get compiled class at {class_hash} from fork ({x})"
-                    ))),
+        // The lock is not held while the class is fetched, so that other tests can use the cache
+        let contract_class = if let Some(cache_hit) = cache_hit {
+            Ok(cache_hit)
+        } else if is_complete_state_dump {
+            Err(UndeclaredClassHash(class_hash))
+        } else {
+            let client =
+                self.client(|| format!("class {}", Felt::from_(class_hash).into_hex_string()))?;
+            match client.get_class(Felt::from_(class_hash)) {
+                Ok(contract_class) => {
+                    self.cache()
+                        .insert_compiled_contract_class(class_hash, contract_class.clone());
+                    Ok(contract_class)
                 }
+                Err(ForkRequestError::NotFound) => Err(UndeclaredClassHash(class_hash)),
+                Err(ForkRequestError::Transport(err)) => transport_error(&err),
+                Err(x) => Err(StateReadError(format!(
+                    "Unable to get compiled class at {class_hash} from fork ({x})"
+                ))),
             }
         };
 
@@ -286,3 +388,53 @@ impl StateReader for ForkStateReader {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forking::cache::save_fork_caches;
+    use crate::forking::client::tests::MockNode;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use tempfile::TempDir;
+    use test_case::test_case;
+
+    #[test_case(true; "with_prefetching")]
+    #[test_case(false; "without_prefetching")]
+    fn storage_read_by_previous_run_is_prefetched(prefetch: bool) {
+        let node = MockNode::start(Duration::ZERO);
+        let temp_dir = TempDir::new().unwrap();
+        let cache_dir = Utf8Path::from_path(temp_dir.path()).unwrap();
+        let contract_address = ContractAddress::try_from(Felt::ONE).unwrap();
+        let key = StorageKey::try_from(Felt::from(5)).unwrap();
+
+        // Previous run at an older block
+        ForkCache::shared(&node.url(), BlockNumber(1), cache_dir, false)
+            .unwrap()
+            .lock()
+            .unwrap()
+            .cache_get_storage_at(contract_address, key, Felt::from(5));
+        save_fork_caches(cache_dir);
+
+        let _fork_state_reader =
+            ForkStateReader::new(node.url(), BlockNumber(2), cache_dir, false, prefetch).unwrap();
+
+        // Nothing is read, so requests can only be sent by prefetching
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while node.received_calls().is_empty() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        let calls = node.received_calls();
+        if prefetch {
+            assert_eq!(calls.len(), 1);
+            assert_eq!(calls[0]["method"], "starknet_getStorageAt");
+            assert_eq!(
+                Felt::from_hex(calls[0]["params"]["key"].as_str().unwrap()).unwrap(),
+                Felt::from(5)
+            );
+        } else {
+            assert!(calls.is_empty());
+        }
+    }
+}
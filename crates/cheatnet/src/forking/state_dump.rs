@@ -0,0 +1,250 @@
+use crate::forking::cache::{ForkCache, ForkCacheContent};
+use anyhow::{Context, Result, anyhow, bail, ensure};
+use camino::{Utf8Path, Utf8PathBuf};
+use conversions::IntoConv;
+use runtime::starknet::context::SerializableGasPrices;
+use serde::{Deserialize, Serialize};
+use starknet::core::types::{
+    BlockId, ContractStorageDiffItem, DeployedContractItem, MaybePendingBlockWithTxHashes,
+    MaybePendingStateUpdate, NonceUpdate, ReplacedClassItem, StorageEntry,
+};
+use starknet::core::utils::parse_cairo_short_string;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet_api::block::{BlockInfo, BlockNumber, BlockTimestamp};
+use starknet_api::core::{ClassHash, ContractAddress};
+use starknet_api::state::StorageKey;
+use std::collections::HashSet;
+use std::fs;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use url::Url;
+
+/// Version of the layout of state dump files, bumped on every incompatible change
+pub const STATE_DUMP_FORMAT_VERSION: u32 = 1;
+
+/// State of a network at a block, used as a fork source instead of an RPC node.
+/// State which is not included in a complete dump is empty, while reading it from a partial
+/// dump fails.
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) struct StateDump {
+    format_version: u32,
+    block_number: BlockNumber,
+    // Set for dumps written from a fork cache, which hold only the state read by the tests
+    #[serde(default)]
+    partial: bool,
+    state: ForkCacheContent,
+}
+
+#[derive(Deserialize)]
+struct StateDumpHeader {
+    format_version: u32,
+    block_number: BlockNumber,
+}
+
+/// Whether the fork `url` points to a state dump file instead of an RPC node
+#[must_use]
+pub fn is_state_dump_url(url: &Url) -> bool {
+    url.scheme() == "file"
+}
+
+/// Returns the url of the state dump at `path`, relative paths are resolved against
+/// `package_root`
+pub fn state_dump_url(path: &Utf8Path, package_root: &Utf8Path) -> Result<Url> {
+    let path = package_root.join(path);
+
+    Url::from_file_path(&path).map_err(|()| anyhow!("Invalid state dump path {path}"))
+}
+
+/// Returns the block number at which the state in the state dump `url` was taken
+pub fn state_dump_block_number(url: &Url) -> Result<BlockNumber> {
+    let path = state_dump_path(url)?;
+    let file = File::open(&path).with_context(|| format!("Could not open state dump {path}"))?;
+    let header: StateDumpHeader = serde_json::from_reader(BufReader::new(file))
+        .with_context(|| format!("Could not parse state dump {path}"))?;
+    check_format_version(header.format_version, &path)?;
+
+    Ok(header.block_number)
+}
+
+/// Writes the fork cache of `url` at `block_number` in `cache_dir` as a partial state dump
+/// to `output`
+pub fn dump_fork_cache(
+    url: &Url,
+    block_number: BlockNumber,
+    cache_dir: &Utf8Path,
+    output: &Utf8Path,
+) -> Result<()> {
+    let state_dump = StateDump {
+        format_version: STATE_DUMP_FORMAT_VERSION,
+        block_number,
+        partial: true,
+        state: ForkCache::load(url, block_number, cache_dir)?.into_content(),
+    };
+
+    write_state_dump(&state_dump, output)
+}
+
+/// Writes the whole state of the network at `url` at `block_number` (the latest block by default)
+/// as a complete state dump to `output`, returns the block number of the dump.
+/// The state is built from state updates of all blocks up to `block_number`, so it is meant for
+/// local networks with few blocks, like `starknet-devnet` loaded from its own dump.
+pub async fn dump_network_state(
+    url: &Url,
+    block_number: Option<BlockNumber>,
+    output: &Utf8Path,
+) -> Result<BlockNumber> {
+    let client = JsonRpcClient::new(HttpTransport::new(url.clone()));
+    let block_number = match block_number {
+        Some(block_number) => block_number,
+        None => BlockNumber(client.block_number().await?),
+    };
+
+    let mut state = ForkCacheContent::default();
+    let mut class_hashes = HashSet::new();
+
+    for number in 0..=block_number.0 {
+        let state_update = client
+            .get_state_update(BlockId::Number(number))
+            .await
+            .with_context(|| format!("Could not get state update of block {number} from {url}"))?;
+        let MaybePendingStateUpdate::Update(state_update) = state_update else {
+            bail!("Block {number} of {url} is still pending");
+        };
+        let state_diff = state_update.state_diff;
+
+        for ContractStorageDiffItem {
+            address,
+            storage_entries,
+        } in state_diff.storage_diffs
+        {
+            let storage = state.storage_at.entry(address.into_()).or_default();
+            for StorageEntry { key, value } in storage_entries {
+                storage.insert(StorageKey::try_from(key)?, value);
+            }
+        }
+        for NonceUpdate {
+            contract_address,
+            nonce,
+        } in state_diff.nonces
+        {
+            state
+                .nonce_at
+                .insert(contract_address.into_(), nonce.into_());
+        }
+        let deployed_contracts = state_diff.deployed_contracts.into_iter().map(
+            |DeployedContractItem {
+                 address,
+                 class_hash,
+             }| (address, class_hash),
+        );
+        let replaced_classes = state_diff.replaced_classes.into_iter().map(
+            |ReplacedClassItem {
+                 contract_address,
+                 class_hash,
+             }| (contract_address, class_hash),
+        );
+        for (contract_address, class_hash) in deployed_contracts.chain(replaced_classes) {
+            let contract_address: ContractAddress = contract_address.into_();
+            state
+                .class_hash_at
+                .insert(contract_address, class_hash.into_());
+            class_hashes.insert(class_hash);
+        }
+        class_hashes.extend(state_diff.deprecated_declared_classes);
+        class_hashes.extend(
+            state_diff
+                .declared_classes
+                .into_iter()
+                .map(|declared_class| declared_class.class_hash),
+        );
+    }
+
+    for class_hash in class_hashes {
+        let contract_class = client
+            .get_class(BlockId::Number(block_number.0), class_hash)
+            .await
+            .with_context(|| format!("Could not get class {class_hash:#x} from {url}"))?;
+        let class_hash: ClassHash = class_hash.into_();
+        state
+            .compiled_contract_class
+            .insert(class_hash, contract_class);
+    }
+
+    let MaybePendingBlockWithTxHashes::Block(block) = client
+        .get_block_with_tx_hashes(BlockId::Number(block_number.0))
+        .await?
+    else {
+        bail!("Block {} of {url} is still pending", block_number.0);
+    };
+    state.block_info = Some(
+        BlockInfo {
+            block_number,
+            sequencer_address: block.sequencer_address.into_(),
+            block_timestamp: BlockTimestamp(block.timestamp),
+            gas_prices: SerializableGasPrices::default().into(),
+            use_kzg_da: true,
+        }
+        .into(),
+    );
+    state.chain_id = Some(parse_cairo_short_string(&client.chain_id().await?)?);
+
+    let state_dump = StateDump {
+        format_version: STATE_DUMP_FORMAT_VERSION,
+        block_number,
+        partial: false,
+        state,
+    };
+    write_state_dump(&state_dump, output)?;
+
+    Ok(block_number)
+}
+
+fn write_state_dump(state_dump: &StateDump, output: &Utf8Path) -> Result<()> {
+    let file =
+        File::create(output).with_context(|| format!("Could not create state dump {output}"))?;
+    serde_json::to_writer_pretty(BufWriter::new(file), state_dump)
+        .with_context(|| format!("Could not write state dump {output}"))
+}
+
+impl ForkCache {
+    /// Loads the state dump `url`, which has to be taken at `block_number`
+    pub(crate) fn load_state_dump(url: &Url, block_number: BlockNumber) -> Result<Self> {
+        let path = state_dump_path(url)?;
+        let serialized = fs::read_to_string(&path)
+            .with_context(|| format!("Could not read state dump {path}"))?;
+        let state_dump: StateDump = serde_json::from_str(&serialized)
+            .with_context(|| format!("Could not parse state dump {path}"))?;
+        check_format_version(state_dump.format_version, &path)?;
+
+        ensure!(
+            state_dump.block_number == block_number,
+            "State dump {path} was taken at block {}, but the fork is configured at block {}",
+            state_dump.block_number.0,
+            block_number.0
+        );
+
+        Ok(ForkCache::from_state_dump(
+            url,
+            block_number,
+            state_dump.state,
+            state_dump.partial,
+        ))
+    }
+}
+
+fn state_dump_path(url: &Url) -> Result<Utf8PathBuf> {
+    let path = url
+        .to_file_path()
+        .map_err(|()| anyhow!("Invalid state dump url = {url}"))?;
+
+    Ok(Utf8PathBuf::try_from(path)?)
+}
+
+fn check_format_version(format_version: u32, path: &Utf8Path) -> Result<()> {
+    ensure!(
+        format_version == STATE_DUMP_FORMAT_VERSION,
+        "State dump {path} has format version {format_version}, expected {STATE_DUMP_FORMAT_VERSION}"
+    );
+    Ok(())
+}
@@ -68,7 +68,10 @@ pub fn execute_entry_point_call_cairo1(
     // region: Modified blockifier code
 
     let mut cheatable_runtime = ExtendedRuntime {
-        extension: CheatableStarknetRuntimeExtension { cheatnet_state },
+        extension: CheatableStarknetRuntimeExtension {
+            cheatnet_state,
+            pending_storage_access: None,
+        },
         extended_runtime: StarknetRuntime {
             hint_handler: syscall_handler,
             user_args: vec![],
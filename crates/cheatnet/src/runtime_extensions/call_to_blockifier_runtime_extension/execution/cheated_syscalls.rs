@@ -2,7 +2,10 @@ use super::calls::{execute_inner_call, execute_library_call};
 use super::execution_info::get_cheated_exec_info_ptr;
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::CheatnetState;
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::entry_point::execute_constructor_entry_point;
-use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
+use blockifier::execution::common_hints::ExecutionMode;
+use blockifier::execution::syscalls::hint_processor::{
+    SyscallExecutionError, SyscallHintProcessor,
+};
 use blockifier::execution::syscalls::{
     DeployRequest, DeployResponse, GetBlockHashRequest, GetBlockHashResponse, LibraryCallRequest,
     SyscallResponse, syscall_base::SyscallResult,
@@ -179,6 +182,15 @@ pub fn call_contract_syscall(
     remaining_gas: &mut u64,
 ) -> SyscallResult<SingleSegmentResponse> {
     let storage_address = request.contract_address;
+    // Check that the call is legal if in Validate execution mode.
+    if syscall_handler.base.context.execution_mode == ExecutionMode::Validate
+        && syscall_handler.storage_address() != storage_address
+    {
+        return Err(SyscallExecutionError::InvalidSyscallInExecutionMode {
+            syscall_name: "call_contract".to_string(),
+            execution_mode: ExecutionMode::Validate,
+        });
+    }
     let mut entry_point = CallEntryPoint {
         class_hash: None,
         code_address: Some(storage_address),
@@ -213,6 +225,13 @@ pub fn get_block_hash_syscall(
     cheatnet_state: &mut CheatnetState,
     _remaining_gas: &mut u64,
 ) -> SyscallResult<GetBlockHashResponse> {
+    if syscall_handler.base.context.execution_mode == ExecutionMode::Validate {
+        return Err(SyscallExecutionError::InvalidSyscallInExecutionMode {
+            syscall_name: "get_block_hash".to_string(),
+            execution_mode: ExecutionMode::Validate,
+        });
+    }
+
     let contract_address = syscall_handler.storage_address();
     let block_number = request.block_number.0;
 
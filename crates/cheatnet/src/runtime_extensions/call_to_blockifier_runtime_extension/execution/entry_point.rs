@@ -1,11 +1,13 @@
 use super::cairo1_execution::execute_entry_point_call_cairo1;
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::deprecated::cairo0_execution::execute_entry_point_call_cairo0;
-use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{AddressOrClassHash, CallResult};
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{AddressOrClassHash, CallFailure, CallResult};
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::CheatnetState;
 use crate::runtime_extensions::common::{get_relocated_vm_trace, get_syscalls_gas_consumed, sum_syscall_usage};
-use crate::state::{CallTrace, CallTraceNode, CheatStatus, EncounteredError};
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::{MockTarget, MockedResult};
+use crate::state::{CallTrace, CallTraceNode, EncounteredError};
 use blockifier::execution::call_info::{CallExecution, Retdata};
 use blockifier::execution::contract_class::{RunnableCompiledClass, TrackedResource};
+use blockifier::execution::stack_trace::{Cairo1RevertHeader, Cairo1RevertSummary};
 use blockifier::execution::syscalls::hint_processor::SyscallUsageMap;
 use blockifier::{
     execution::{
@@ -33,7 +35,6 @@ use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use blockifier::execution::entry_point::ExecutableCallEntryPoint;
 use thiserror::Error;
-use conversions::FromConv;
 
 pub(crate) type ContractClassEntryPointExecutionResult = Result<
     (CallInfo, SyscallUsageMap, Option<Vec<RelocatedTraceEntry>>),
@@ -67,31 +68,49 @@ pub fn execute_call_entry_point(
         .trace_data
         .enter_nested_call(entry_point.clone(), cheated_data);
 
-    if let Some(cheat_status) = get_mocked_function_cheat_status(entry_point, cheatnet_state) {
-        if let CheatStatus::Cheated(ret_data, _) = (*cheat_status).clone() {
-            cheat_status.decrement_cheat_span();
-            let ret_data_f252: Vec<Felt> =
-                ret_data.iter().map(|datum| Felt::from_(*datum)).collect();
-            cheatnet_state.trace_data.exit_nested_call(
-                ExecutionResources::default(),
-                u64::default(),
-                HashMap::default(),
-                CallResult::Success {
-                    ret_data: ret_data_f252,
-                },
-                &[],
-                None,
-            );
-            let tracked_resource = *context
-                .tracked_resource_stack
-                .last()
-                .expect("Unexpected empty tracked resource.");
-            return Ok(mocked_call_info(
-                entry_point.clone(),
-                ret_data.clone(),
-                tracked_resource,
-            ));
-        }
+    if let Some(mocked_result) = get_mocked_result(entry_point, cheatnet_state) {
+        return match mocked_result {
+            MockedResult::Return(ret_data) => {
+                cheatnet_state.trace_data.exit_nested_call(
+                    ExecutionResources::default(),
+                    u64::default(),
+                    HashMap::default(),
+                    CallResult::Success {
+                        ret_data: ret_data.clone(),
+                    },
+                    &[],
+                    None,
+                );
+                let tracked_resource = *context
+                    .tracked_resource_stack
+                    .last()
+                    .expect("Unexpected empty tracked resource.");
+                Ok(mocked_call_info(
+                    entry_point.clone(),
+                    ret_data,
+                    tracked_resource,
+                ))
+            }
+            MockedResult::Revert(panic_data) => {
+                cheatnet_state.trace_data.exit_nested_call(
+                    ExecutionResources::default(),
+                    u64::default(),
+                    HashMap::default(),
+                    CallResult::Failure(CallFailure::Panic {
+                        panic_data: panic_data.clone(),
+                    }),
+                    &[],
+                    None,
+                );
+                Err(EntryPointExecutionError::ExecutionFailed {
+                    error_trace: Cairo1RevertSummary {
+                        header: Cairo1RevertHeader::Execution,
+                        stack: vec![],
+                        last_retdata: Retdata(panic_data),
+                    },
+                })
+            }
+        };
     }
     // endregion
 
@@ -288,18 +307,17 @@ pub fn execute_constructor_entry_point(
     // endregion
 }
 
-fn get_mocked_function_cheat_status<'a>(
+fn get_mocked_result(
     call: &CallEntryPoint,
-    cheatnet_state: &'a mut CheatnetState,
-) -> Option<&'a mut CheatStatus<Vec<Felt>>> {
-    if call.call_type == CallType::Delegate {
-        return None;
-    }
+    cheatnet_state: &mut CheatnetState,
+) -> Option<MockedResult> {
+    let target = match call.call_type {
+        CallType::Call => MockTarget::Contract(call.storage_address),
+        // Library calls are mocked by the hash of the called class, not by the calling contract
+        CallType::Delegate => MockTarget::LibraryCall(call.class_hash?),
+    };
 
-    cheatnet_state
-        .mocked_functions
-        .get_mut(&call.storage_address)
-        .and_then(|contract_functions| contract_functions.get_mut(&call.entry_point_selector))
+    cheatnet_state.next_mocked_result(target, call.entry_point_selector, &call.calldata.0)
 }
 
 fn mocked_call_info(
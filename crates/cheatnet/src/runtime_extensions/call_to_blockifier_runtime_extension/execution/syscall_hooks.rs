@@ -1,14 +1,26 @@
+use crate::runtime_extensions::cheatable_starknet_runtime_extension::SyscallSelector;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_messages_to_l1::MessageToL1;
+use crate::state::{StorageAccess, StorageAccessKind};
 use crate::{
     runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event,
     state::CheatnetState,
 };
 use blockifier::execution::call_info::OrderedL2ToL1Message;
+use blockifier::execution::syscalls::hint_processor::SyscallExecutionError;
+use blockifier::execution::syscalls::{
+    StorageReadRequest, StorageWriteRequest, SyscallRequestWrapper,
+};
 use blockifier::execution::{
     call_info::OrderedEvent, deprecated_syscalls::hint_processor::DeprecatedSyscallHintProcessor,
     syscalls::hint_processor::SyscallHintProcessor,
 };
+use blockifier::state::state_api::StateReader;
+use cairo_vm::types::relocatable::Relocatable;
+use cairo_vm::vm::errors::hint_errors::HintError;
+use cairo_vm::vm::vm_core::VirtualMachine;
 use starknet_api::core::ContractAddress;
+use starknet_api::state::StorageKey;
+use starknet_types_core::felt::Felt;
 
 pub trait SyscallHintProcessorExt {
     fn contract_address(&self) -> ContractAddress;
@@ -50,9 +62,98 @@ pub fn emit_event_hook(
 ) {
     let contract_address = syscall_handler.contract_address();
     let last_event = syscall_handler.last_event();
-    cheatnet_state
-        .detected_events
-        .push(Event::from_ordered_event(last_event, contract_address));
+    let event = Event::from_ordered_event(last_event, contract_address);
+
+    cheatnet_state.trace_data.add_event(event.clone());
+    cheatnet_state.detected_events.push(event);
+}
+
+/// Storage access of a syscall which is being executed, recorded once the syscall succeeds
+#[derive(Debug)]
+pub struct PendingStorageAccess {
+    contract_address: ContractAddress,
+    key: StorageKey,
+    // Values of the slot before and after a write, the value read is taken from the response
+    written: Option<(Felt, Felt)>,
+    // Response is written by the syscall right after the request
+    response_ptr: Relocatable,
+}
+
+/// Reads the storage slot accessed by the syscall from its request, before the syscall is executed.
+/// Value of the slot before a write is taken from the state, where it is already cached,
+/// as blockifier reads it before writing anyway to be able to revert the write.
+pub fn read_storage_access(
+    selector: SyscallSelector,
+    syscall_handler: &SyscallHintProcessor,
+    vm: &VirtualMachine,
+) -> Result<PendingStorageAccess, HintError> {
+    // Selector was peeked into before, the request starts right after it
+    let mut request_ptr = syscall_handler.syscall_ptr;
+    request_ptr += 1;
+
+    let contract_address = syscall_handler.base.call.storage_address;
+    let (key, written) = match selector {
+        SyscallSelector::StorageRead => {
+            let request =
+                SyscallRequestWrapper::<StorageReadRequest>::read(vm, &mut request_ptr)?.request;
+            (request.address, None)
+        }
+        SyscallSelector::StorageWrite => {
+            let request =
+                SyscallRequestWrapper::<StorageWriteRequest>::read(vm, &mut request_ptr)?.request;
+            let old_value = syscall_handler
+                .base
+                .state
+                .get_storage_at(contract_address, request.address)
+                .map_err(SyscallExecutionError::from)?;
+            (request.address, Some((old_value, request.value)))
+        }
+        _ => unreachable!("Only storage syscalls access storage"),
+    };
+
+    Ok(PendingStorageAccess {
+        contract_address,
+        key,
+        written,
+        response_ptr: request_ptr,
+    })
+}
+
+/// Records the storage access in the trace of the current call and, if storage accesses
+/// are being recorded with `start_record_storage`, in the recording.
+/// Accesses of syscalls which failed, e.g. because of running out of gas, are not recorded.
+pub fn storage_access_hook(
+    pending_access: PendingStorageAccess,
+    vm: &VirtualMachine,
+    cheatnet_state: &mut CheatnetState,
+) {
+    // Response starts with the remaining gas and the failure flag
+    let response = vm
+        .get_integer_range(pending_access.response_ptr, 2)
+        .expect("Storage syscall response should be written");
+    if *response[1] != Felt::ZERO {
+        return;
+    }
+
+    let (kind, old_value, new_value) = match pending_access.written {
+        Some((old_value, new_value)) => (StorageAccessKind::Write, old_value, new_value),
+        None => {
+            let value = *vm
+                .get_integer_range(pending_access.response_ptr, 3)
+                .expect("Storage read response should contain the value")[2];
+            (StorageAccessKind::Read, value, value)
+        }
+    };
+
+    let storage_access = StorageAccess {
+        kind,
+        contract_address: pending_access.contract_address,
+        key: *pending_access.key.0.key(),
+        old_value,
+        new_value,
+    };
+    cheatnet_state.record_storage_access(&storage_access);
+    cheatnet_state.trace_data.add_storage_access(storage_access);
 }
 
 pub fn send_message_to_l1_syscall_hook(
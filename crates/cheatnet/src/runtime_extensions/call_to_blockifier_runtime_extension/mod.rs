@@ -27,6 +27,7 @@ use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::{
     execution::cheated_syscalls::SingleSegmentResponse,
     rpc::{CallFailure, CallResult},
+    transaction::execute_call_as_transaction,
 };
 
 use super::cheatable_starknet_runtime_extension::CheatableStarknetRuntime;
@@ -36,6 +37,7 @@ use runtime::starknet::constants::TEST_ADDRESS;
 pub mod execution;
 pub mod panic_data;
 pub mod rpc;
+pub mod transaction;
 
 pub struct CallToBlockifierExtension<'a> {
     pub lifetime: &'a PhantomData<()>,
@@ -52,6 +54,24 @@ impl<'a> ExtensionLogic for CallToBlockifierExtension<'a> {
         vm: &mut VirtualMachine,
         extended_runtime: &mut Self::Runtime,
     ) -> Result<SyscallHandlingResult, HintError> {
+        // Inside `interact_with_state` the test code accesses storage of another contract,
+        // calls made there would be made neither by the test nor by that contract
+        if matches!(
+            selector,
+            DeprecatedSyscallSelector::CallContract
+                | DeprecatedSyscallSelector::LibraryCall
+                | DeprecatedSyscallSelector::Deploy
+        ) && extended_runtime
+            .extended_runtime
+            .hint_handler
+            .storage_address()
+            != TryFromHexStr::try_from_hex_str(TEST_ADDRESS).unwrap()
+        {
+            return Err(HintError::CustomHint(Box::from(
+                "Calls and deployments can't be made inside `interact_with_state`",
+            )));
+        }
+
         match selector {
             // We execute contract calls and library calls with modified blockifier
             // This is redirected to drop ForgeRuntimeExtension
@@ -102,6 +122,17 @@ impl ExecuteCall for CallContractRequest {
     ) -> CallResult {
         let contract_address = self.contract_address;
 
+        if let Some(account) = cheatnet_state.transaction_account() {
+            return execute_call_as_transaction(
+                syscall_handler,
+                cheatnet_state,
+                &account,
+                contract_address,
+                self.function_selector,
+                &self.calldata,
+            );
+        }
+
         let entry_point = CallEntryPoint {
             class_hash: None,
             code_address: Some(contract_address),
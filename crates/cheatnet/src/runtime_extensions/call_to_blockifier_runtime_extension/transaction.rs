@@ -0,0 +1,781 @@
+use super::CheatnetState;
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::entry_point::execute_call_entry_point;
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
+    AddressOrClassHash, CallFailure, CallResult,
+};
+use crate::runtime_extensions::common::create_execute_calldata;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage::{
+    calculate_variable_address, load, store,
+};
+use anyhow::{Result, anyhow};
+use blockifier::abi::constants;
+use blockifier::context::TransactionContext;
+use blockifier::execution::call_info::{CallInfo, EventSummary};
+use blockifier::execution::common_hints::ExecutionMode;
+use blockifier::execution::contract_class::{RunnableCompiledClass, TrackedResource};
+use blockifier::execution::entry_point::{
+    CallEntryPoint, CallType, EntryPointExecutionContext, EntryPointExecutionResult,
+};
+use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
+use blockifier::fee::fee_utils::get_fee_by_gas_vector;
+use blockifier::fee::resources::{
+    ArchivalDataResources, ComputationResources, MessageResources, StarknetResources,
+    StateResources, TransactionResources,
+};
+use blockifier::state::cached_state::StateChangesCountForFee;
+use blockifier::state::state_api::{State, StateReader, StateResult};
+use blockifier::transaction::objects::{
+    CommonAccountFields, CurrentTransactionInfo, HasRelatedFeeType, TransactionInfo,
+};
+use blockifier::utils::u64_from_usize;
+use cairo_vm::vm::runners::cairo_runner::ExecutionResources;
+use conversions::IntoConv;
+use conversions::byte_array::ByteArray;
+use conversions::serde::deserialize::CairoDeserialize;
+use conversions::serde::serialize::CairoSerialize;
+use num_bigint::BigUint;
+use shared::utils::build_readable_text;
+use starknet::core::crypto::ecdsa_sign;
+use starknet::core::utils::get_selector_from_name;
+use starknet_api::block::{BlockInfo, GasPriceVector, NonzeroGasPrice};
+use starknet_api::contract_class::EntryPointType;
+use starknet_api::core::{
+    ClassHash, CompiledClassHash, ContractAddress, EntryPointSelector, Nonce,
+};
+use starknet_api::data_availability::DataAvailabilityMode;
+use starknet_api::execution_resources::GasAmount;
+use starknet_api::state::StorageKey;
+use starknet_api::transaction::fields::{
+    AccountDeploymentData, AllResourceBounds, Calldata, GasVectorComputationMode, PaymasterData,
+    ResourceBounds, Tip, TransactionSignature, ValidResourceBounds,
+};
+use starknet_api::transaction::{TransactionHash, TransactionVersion};
+use starknet_types_core::felt::Felt;
+use starknet_types_core::hash::{Poseidon, StarkHash};
+use std::collections::{HashMap, HashSet};
+use std::mem;
+use std::sync::Arc;
+
+const VALIDATE_ENTRY_POINT_NAME: &str = "__validate__";
+const EXECUTE_ENTRY_POINT_NAME: &str = "__execute__";
+const FEE_TOKEN_BALANCES_VARIABLE_NAME: &str = "ERC20_balances";
+
+/// How transactions sent from the account are signed
+#[derive(CairoDeserialize, Clone, Debug)]
+pub enum TransactionSigner {
+    /// Transaction hash is signed on the Stark curve with the given private key
+    StarkCurve(Felt),
+    /// The given signature is attached to every transaction as is
+    Signature(Vec<Felt>),
+}
+
+#[derive(Clone, Debug)]
+pub struct TransactionAccount {
+    pub address: ContractAddress,
+    pub signer: TransactionSigner,
+}
+
+#[derive(CairoSerialize, Clone, Debug, PartialEq, Eq)]
+pub enum TransactionExecutionStatus {
+    Succeeded,
+    Reverted,
+}
+
+#[derive(CairoSerialize, Clone, Debug)]
+pub struct TransactionReceipt {
+    pub transaction_hash: Felt,
+    pub actual_fee: u128,
+    pub execution_status: TransactionExecutionStatus,
+    pub revert_reason: Option<ByteArray>,
+}
+
+/// State of the transactional test mode, present only in tests running in that mode
+#[derive(Clone, Debug, Default)]
+pub struct TransactionsState {
+    /// Account that calls from the test are sent from, calls are executed directly when not set
+    pub account: Option<TransactionAccount>,
+    /// Receipt of the last transaction, `None` if it was rejected in validation
+    pub last_receipt: Option<TransactionReceipt>,
+}
+
+/// Executes a call from the test as an `INVOKE` v3 transaction sent from `account`.
+/// The account validates the transaction in `__validate__` and performs the call in `__execute__`,
+/// after which the fee is charged in STRK and the nonce of the account is incremented.
+/// Returns the result of the call, or the panic data if the transaction was rejected or reverted.
+pub fn execute_call_as_transaction(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    account: &TransactionAccount,
+    contract_address: ContractAddress,
+    function_selector: EntryPointSelector,
+    calldata: &Calldata,
+) -> CallResult {
+    let context = &mut *syscall_handler.base.context;
+    let original_tx_context = context.tx_context.clone();
+
+    let result = execute_transaction(
+        syscall_handler.base.state,
+        cheatnet_state,
+        context,
+        account,
+        &build_execute_calldata(contract_address, function_selector, calldata),
+    );
+
+    context.tx_context = original_tx_context;
+
+    match result {
+        Ok((result, call_infos)) => {
+            syscall_handler.base.inner_calls.extend(call_infos);
+            result
+        }
+        Err(error) => CallResult::Failure(CallFailure::Error {
+            msg: ByteArray::from(error.to_string().as_str()),
+        }),
+    }
+}
+
+fn execute_transaction(
+    state: &mut dyn State,
+    cheatnet_state: &mut CheatnetState,
+    context: &mut EntryPointExecutionContext,
+    account: &TransactionAccount,
+    execute_calldata: &[Felt],
+) -> Result<(CallResult, Vec<CallInfo>)> {
+    let account_identifier = AddressOrClassHash::ContractAddress(account.address);
+    let nonce = state.get_nonce_at(account.address)?;
+    // Gas prices and the sequencer receiving the fee come from the block seen by the account
+    let block_info = cheatnet_state.get_cheated_block_info(account.address);
+
+    let tx_info = build_tx_info(
+        account,
+        nonce,
+        &block_info.gas_prices.strk_gas_prices,
+        execute_calldata,
+        context,
+    )?;
+    let transaction_hash = tx_info.common_fields.transaction_hash.0;
+    let signature_length = tx_info.common_fields.signature.0.len();
+    context.tx_context = Arc::new(TransactionContext {
+        block_context: context.tx_context.block_context.clone(),
+        tx_info: TransactionInfo::Current(tx_info),
+    });
+    let fee_token_address = context
+        .tx_context
+        .block_context
+        .chain_info()
+        .fee_token_addresses
+        .strk_fee_token_address;
+
+    let detected_before_validate = DetectedCounts::new(cheatnet_state);
+    let mut journaled_state = JournaledState::new(state);
+    let execution_mode = mem::replace(&mut context.execution_mode, ExecutionMode::Validate);
+    let validate_result = call_account(
+        &mut journaled_state,
+        cheatnet_state,
+        context,
+        account.address,
+        VALIDATE_ENTRY_POINT_NAME,
+        execute_calldata,
+    );
+    context.execution_mode = execution_mode;
+
+    // Rejected transactions do not change the state, nor are they charged a fee
+    let validate_call_info = match validate_result {
+        Ok(call_info) if is_valid(&call_info) => call_info,
+        rejected => {
+            journaled_state.revert()?;
+            detected_before_validate.discard_newer(cheatnet_state);
+            cheatnet_state.transactions_mut().last_receipt = None;
+            let result = match rejected {
+                Ok(_) => panic_with_message(
+                    "Transaction rejected: `__validate__` did not return 'VALID'",
+                ),
+                Err(error) => CallResult::from_err(&error, &account_identifier),
+            };
+            return Ok((result, vec![]));
+        }
+    };
+    let mut state_changes = TransactionStateChanges::default();
+    state_changes.record(&journaled_state);
+
+    let detected_before_execute = DetectedCounts::new(cheatnet_state);
+    let mut journaled_state = JournaledState::new(state);
+    let execute_result = call_account(
+        &mut journaled_state,
+        cheatnet_state,
+        context,
+        account.address,
+        EXECUTE_ENTRY_POINT_NAME,
+        execute_calldata,
+    );
+
+    let mut result = match &execute_result {
+        Ok(call_info) => first_call_result(CallResult::from_success(call_info)),
+        Err(error) => CallResult::from_err(error, &account_identifier),
+    };
+    let execute_gas = execute_result
+        .as_ref()
+        .map_or(0, |call_info| call_info.execution.gas_consumed);
+
+    let succeeded = matches!(result, CallResult::Success { .. });
+    let mut executed = None;
+    if let Some(execute_call_info) = execute_result.ok().filter(|_| succeeded) {
+        let mut executed_state_changes = state_changes.clone();
+        executed_state_changes.record(&journaled_state);
+        let resources = transaction_resources(
+            &[&validate_call_info, &execute_call_info],
+            0,
+            executed_state_changes.count_for_fee(
+                &journaled_state,
+                account.address,
+                fee_token_address,
+            )?,
+            execute_calldata.len(),
+            signature_length,
+        );
+        let fee = calculate_fee(context, &block_info, &resources);
+
+        let balance = read_balance(&mut journaled_state, fee_token_address, account.address)?;
+        if balance < BigUint::from(fee) {
+            result = panic_with_message("Insufficient balance of the account to pay the fee");
+        } else {
+            executed = Some((execute_call_info, fee));
+        }
+    }
+
+    let (fee, call_infos, revert_reason) = match executed {
+        Some((execute_call_info, fee)) => (fee, vec![validate_call_info, execute_call_info], None),
+        None => {
+            let CallResult::Failure(failure) = &result else {
+                unreachable!("Only failed transactions are reverted")
+            };
+            journaled_state.revert()?;
+            detected_before_execute.discard_newer(cheatnet_state);
+
+            // Reverted transactions pay for the gas used in `__execute__`, but not for its state changes
+            let resources = transaction_resources(
+                &[&validate_call_info],
+                execute_gas,
+                state_changes.count_for_fee(
+                    &journaled_state,
+                    account.address,
+                    fee_token_address,
+                )?,
+                execute_calldata.len(),
+                signature_length,
+            );
+            (
+                calculate_fee(context, &block_info, &resources),
+                vec![validate_call_info],
+                Some(revert_reason(failure)),
+            )
+        }
+    };
+
+    // Reverted transactions are charged as much as the account can pay
+    let balance = read_balance(state, fee_token_address, account.address)?;
+    let actual_fee = u128::try_from(balance).map_or(fee, |balance| fee.min(balance));
+    charge_fee(
+        state,
+        fee_token_address,
+        account.address,
+        block_info.sequencer_address,
+        actual_fee,
+    )?;
+    state.increment_nonce(account.address)?;
+
+    cheatnet_state.transactions_mut().last_receipt = Some(TransactionReceipt {
+        transaction_hash,
+        actual_fee,
+        execution_status: if revert_reason.is_some() {
+            TransactionExecutionStatus::Reverted
+        } else {
+            TransactionExecutionStatus::Succeeded
+        },
+        revert_reason,
+    });
+
+    Ok((result, call_infos))
+}
+
+/// Numbers of events and messages detected before a part of the transaction was executed
+#[derive(Clone, Copy)]
+struct DetectedCounts {
+    events: usize,
+    messages_to_l1: usize,
+}
+
+impl DetectedCounts {
+    fn new(cheatnet_state: &CheatnetState) -> Self {
+        Self {
+            events: cheatnet_state.detected_events.len(),
+            messages_to_l1: cheatnet_state.detected_messages_to_l1.len(),
+        }
+    }
+
+    /// Discards events and messages detected since the counts were taken
+    fn discard_newer(self, cheatnet_state: &mut CheatnetState) {
+        cheatnet_state.detected_events.truncate(self.events);
+        cheatnet_state
+            .detected_messages_to_l1
+            .truncate(self.messages_to_l1);
+    }
+}
+
+/// Storage and class hashes modified by a transaction, with their values from before it
+#[derive(Clone, Debug, Default)]
+struct TransactionStateChanges {
+    storage: HashMap<(ContractAddress, StorageKey), Felt>,
+    class_hashes: HashMap<ContractAddress, ClassHash>,
+}
+
+impl TransactionStateChanges {
+    fn record(&mut self, journaled_state: &JournaledState) {
+        for &(contract_address, key, value) in &journaled_state.storage {
+            self.storage.entry((contract_address, key)).or_insert(value);
+        }
+        for &(contract_address, class_hash) in &journaled_state.class_hashes {
+            self.class_hashes
+                .entry(contract_address)
+                .or_insert(class_hash);
+        }
+    }
+
+    /// Counts the changes the way blockifier does when charging a fee,
+    /// including the change of the account balance made by the fee transfer itself
+    fn count_for_fee<S: StateReader + ?Sized>(
+        &self,
+        state: &S,
+        account_address: ContractAddress,
+        fee_token_address: ContractAddress,
+    ) -> Result<StateChangesCountForFee> {
+        let mut count = StateChangesCountForFee::default();
+        let mut modified_contracts = HashSet::from([account_address]);
+
+        for (&(contract_address, key), &original) in &self.storage {
+            let value = state.get_storage_at(contract_address, key)?;
+            if value == original {
+                continue;
+            }
+            count.state_changes_count.n_storage_updates += 1;
+            if original == Felt::ZERO {
+                count.n_allocated_keys += 1;
+            }
+            modified_contracts.insert(contract_address);
+        }
+        for (&contract_address, &original) in &self.class_hashes {
+            if state.get_class_hash_at(contract_address)? != original {
+                count.state_changes_count.n_class_hash_updates += 1;
+                modified_contracts.insert(contract_address);
+            }
+        }
+
+        let balance_key = StorageKey::try_from(balance_address(account_address))?;
+        if !self.storage.contains_key(&(fee_token_address, balance_key)) {
+            count.state_changes_count.n_storage_updates += 1;
+        }
+        // Modifications of the fee token are amortized over the whole block
+        modified_contracts.remove(&fee_token_address);
+        count.state_changes_count.n_modified_contracts = modified_contracts.len();
+
+        Ok(count)
+    }
+}
+
+/// Resources of a transaction which executed `call_infos` and reverted calls using `reverted_gas`
+fn transaction_resources(
+    call_infos: &[&CallInfo],
+    reverted_gas: u64,
+    state_changes_for_fee: StateChangesCountForFee,
+    calldata_length: usize,
+    signature_length: usize,
+) -> TransactionResources {
+    let mut vm_resources = ExecutionResources::default();
+    let mut sierra_gas = 0;
+    for call_info in call_infos {
+        match call_info.tracked_resource {
+            TrackedResource::CairoSteps => vm_resources += &call_info.resources,
+            TrackedResource::SierraGas => sierra_gas += call_info.execution.gas_consumed,
+        }
+    }
+
+    let mut event_summary = EventSummary::default();
+    let mut l2_to_l1_payload_lengths = vec![];
+    for call_info in call_infos.iter().flat_map(|call_info| call_info.iter()) {
+        for ordered_event in &call_info.execution.events {
+            event_summary.n_events += 1;
+            event_summary.total_event_keys += u64_from_usize(ordered_event.event.keys.len());
+            event_summary.total_event_data_size += u64_from_usize(ordered_event.event.data.0.len());
+        }
+        l2_to_l1_payload_lengths.extend(
+            call_info
+                .execution
+                .l2_to_l1_messages
+                .iter()
+                .map(|ordered_message| ordered_message.message.payload.0.len()),
+        );
+    }
+    let message_segment_length = l2_to_l1_payload_lengths
+        .iter()
+        .map(|payload_length| constants::L2_TO_L1_MSG_HEADER_SIZE + payload_length)
+        .sum();
+
+    TransactionResources {
+        starknet_resources: StarknetResources {
+            archival_data: ArchivalDataResources {
+                event_summary,
+                calldata_length,
+                signature_length,
+                code_size: 0,
+            },
+            messages: MessageResources {
+                l2_to_l1_payload_lengths,
+                message_segment_length,
+                l1_handler_payload_size: None,
+            },
+            state: StateResources {
+                state_changes_for_fee,
+            },
+        },
+        computation: ComputationResources {
+            vm_resources,
+            n_reverted_steps: 0,
+            sierra_gas: GasAmount(sierra_gas),
+            reverted_sierra_gas: GasAmount(reverted_gas),
+        },
+    }
+}
+
+/// Fee of the transaction in STRK, it saturates instead of overflowing
+fn calculate_fee(
+    context: &EntryPointExecutionContext,
+    block_info: &BlockInfo,
+    resources: &TransactionResources,
+) -> u128 {
+    let gas_vector = resources.to_gas_vector(
+        context.tx_context.block_context.versioned_constants(),
+        block_info.use_kzg_da,
+        &GasVectorComputationMode::All,
+    );
+
+    get_fee_by_gas_vector(
+        block_info,
+        gas_vector,
+        &context.tx_context.tx_info.fee_type(),
+    )
+    .0
+}
+
+fn call_account(
+    state: &mut dyn State,
+    cheatnet_state: &mut CheatnetState,
+    context: &mut EntryPointExecutionContext,
+    account_address: ContractAddress,
+    entry_point_name: &str,
+    calldata: &[Felt],
+) -> EntryPointExecutionResult<CallInfo> {
+    let mut entry_point = CallEntryPoint {
+        class_hash: None,
+        code_address: Some(account_address),
+        entry_point_type: EntryPointType::External,
+        entry_point_selector: get_selector_from_name(entry_point_name).unwrap().into_(),
+        calldata: create_execute_calldata(calldata),
+        storage_address: account_address,
+        caller_address: ContractAddress::default(),
+        call_type: CallType::Call,
+        initial_gas: i64::MAX as u64,
+    };
+
+    execute_call_entry_point(&mut entry_point, state, cheatnet_state, context)
+}
+
+/// Serializes `Array<Call>` with a single call, as expected by `__validate__` and `__execute__`
+fn build_execute_calldata(
+    contract_address: ContractAddress,
+    function_selector: EntryPointSelector,
+    calldata: &Calldata,
+) -> Vec<Felt> {
+    let mut execute_calldata = vec![
+        Felt::ONE,
+        contract_address.into_(),
+        function_selector.into_(),
+        Felt::from(calldata.0.len()),
+    ];
+    execute_calldata.extend(calldata.0.iter());
+    execute_calldata
+}
+
+fn is_valid(call_info: &CallInfo) -> bool {
+    let retdata = &call_info.execution.retdata.0;
+    retdata.is_empty() || retdata == &[Felt::from_bytes_be_slice(b"VALID")]
+}
+
+/// `__execute__` returns `Array<Span<felt252>>`, the test expects the result of its only call
+fn first_call_result(result: CallResult) -> CallResult {
+    match result {
+        CallResult::Success { ret_data } => {
+            let ret_data = ret_data
+                .get(1)
+                .and_then(|len| usize::try_from(len.to_biguint()).ok())
+                .and_then(|len| ret_data.get(2..2 + len))
+                .map_or(ret_data.clone(), <[Felt]>::to_vec);
+            CallResult::Success { ret_data }
+        }
+        failure @ CallResult::Failure(_) => failure,
+    }
+}
+
+fn revert_reason(failure: &CallFailure) -> ByteArray {
+    match failure {
+        CallFailure::Panic { panic_data } => {
+            ByteArray::from(build_readable_text(panic_data).unwrap_or_default().trim())
+        }
+        CallFailure::Error { msg } => msg.clone(),
+    }
+}
+
+fn panic_with_message(message: &str) -> CallResult {
+    CallResult::Failure(CallFailure::Panic {
+        panic_data: ByteArray::from(message).serialize_with_magic(),
+    })
+}
+
+fn build_tx_info(
+    account: &TransactionAccount,
+    nonce: Nonce,
+    gas_prices: &GasPriceVector,
+    execute_calldata: &[Felt],
+    context: &EntryPointExecutionContext,
+) -> Result<CurrentTransactionInfo> {
+    let bounds = |gas_price: NonzeroGasPrice| ResourceBounds {
+        max_amount: GasAmount(i64::MAX as u64),
+        max_price_per_unit: gas_price.get(),
+    };
+    let resource_bounds = AllResourceBounds {
+        l1_gas: bounds(gas_prices.l1_gas_price),
+        l2_gas: bounds(gas_prices.l2_gas_price),
+        l1_data_gas: bounds(gas_prices.l1_data_gas_price),
+    };
+    let chain_id = context
+        .tx_context
+        .block_context
+        .chain_info()
+        .chain_id
+        .to_string();
+
+    // https://docs.starknet.io/architecture-and-concepts/network-architecture/transactions/#v3_hash_calculation
+    let transaction_hash = Poseidon::hash_array(&[
+        Felt::from_bytes_be_slice(b"invoke"),
+        Felt::THREE,
+        account.address.into_(),
+        Poseidon::hash_array(&[
+            Felt::ZERO,
+            resource_bound_felt(b"L1_GAS", resource_bounds.l1_gas),
+            resource_bound_felt(b"L2_GAS", resource_bounds.l2_gas),
+            resource_bound_felt(b"L1_DATA", resource_bounds.l1_data_gas),
+        ]),
+        Poseidon::hash_array(&[]),
+        Felt::from_bytes_be_slice(chain_id.as_bytes()),
+        nonce.0,
+        // Both nonce and fee data availability modes are L1
+        Felt::ZERO,
+        Poseidon::hash_array(&[]),
+        Poseidon::hash_array(execute_calldata),
+    ]);
+
+    let signature = match &account.signer {
+        TransactionSigner::StarkCurve(private_key) => {
+            let signature = ecdsa_sign(private_key, &transaction_hash)
+                .map_err(|error| anyhow!("Failed to sign the transaction: {error}"))?;
+            vec![signature.r, signature.s]
+        }
+        TransactionSigner::Signature(signature) => signature.clone(),
+    };
+
+    Ok(CurrentTransactionInfo {
+        common_fields: CommonAccountFields {
+            transaction_hash: TransactionHash(transaction_hash),
+            version: TransactionVersion::THREE,
+            signature: TransactionSignature(signature),
+            nonce,
+            sender_address: account.address,
+            only_query: false,
+        },
+        resource_bounds: ValidResourceBounds::AllResources(resource_bounds),
+        tip: Tip::default(),
+        nonce_data_availability_mode: DataAvailabilityMode::L1,
+        fee_data_availability_mode: DataAvailabilityMode::L1,
+        paymaster_data: PaymasterData::default(),
+        account_deployment_data: AccountDeploymentData::default(),
+    })
+}
+
+fn resource_bound_felt(name: &[u8], bounds: ResourceBounds) -> Felt {
+    let name = Felt::from_bytes_be_slice(name) * Felt::TWO.pow(192_u32);
+    let max_amount = Felt::from(bounds.max_amount.0) * Felt::TWO.pow(128_u32);
+    name + max_amount + Felt::from(bounds.max_price_per_unit.0)
+}
+
+fn balance_address(holder: ContractAddress) -> Felt {
+    let selector = get_selector_from_name(FEE_TOKEN_BALANCES_VARIABLE_NAME).unwrap();
+    calculate_variable_address(selector, Some(&[holder.into_()]))
+}
+
+/// Reads the `u256` balance of `holder` from the storage of the fee token
+fn read_balance(
+    state: &mut dyn State,
+    fee_token_address: ContractAddress,
+    holder: ContractAddress,
+) -> Result<BigUint> {
+    let address = balance_address(holder);
+    let low = load(state, fee_token_address, address)?;
+    let high = load(state, fee_token_address, address + Felt::ONE)?;
+
+    Ok(low.to_biguint() + (high.to_biguint() << 128))
+}
+
+fn write_balance(
+    state: &mut dyn State,
+    fee_token_address: ContractAddress,
+    holder: ContractAddress,
+    balance: &BigUint,
+) -> Result<()> {
+    let address = balance_address(holder);
+    let low = balance & BigUint::from(u128::MAX);
+    let high: BigUint = balance >> 128;
+
+    store(
+        state,
+        fee_token_address,
+        address,
+        Felt::from_bytes_be_slice(&low.to_bytes_be()),
+    )?;
+    store(
+        state,
+        fee_token_address,
+        address + Felt::ONE,
+        Felt::from_bytes_be_slice(&high.to_bytes_be()),
+    )
+}
+
+fn charge_fee(
+    state: &mut dyn State,
+    fee_token_address: ContractAddress,
+    account_address: ContractAddress,
+    sequencer_address: ContractAddress,
+    fee: u128,
+) -> Result<()> {
+    let account_balance = read_balance(state, fee_token_address, account_address)?;
+    write_balance(
+        state,
+        fee_token_address,
+        account_address,
+        &(account_balance - fee),
+    )?;
+
+    let sequencer_balance = read_balance(state, fee_token_address, sequencer_address)?;
+    write_balance(
+        state,
+        fee_token_address,
+        sequencer_address,
+        &(sequencer_balance + fee),
+    )
+}
+
+/// Blockifier state that records previous values of modified storage and class hashes,
+/// so that the changes made by a reverted transaction can be undone
+pub(crate) struct JournaledState<'a> {
+    state: &'a mut dyn State,
+    storage: Vec<(ContractAddress, StorageKey, Felt)>,
+    class_hashes: Vec<(ContractAddress, ClassHash)>,
+}
+
+impl<'a> JournaledState<'a> {
+    pub(crate) fn new(state: &'a mut dyn State) -> Self {
+        Self {
+            state,
+            storage: vec![],
+            class_hashes: vec![],
+        }
+    }
+
+    pub(crate) fn revert(&mut self) -> StateResult<()> {
+        while let Some((contract_address, key, value)) = self.storage.pop() {
+            self.state.set_storage_at(contract_address, key, value)?;
+        }
+        while let Some((contract_address, class_hash)) = self.class_hashes.pop() {
+            self.state.set_class_hash_at(contract_address, class_hash)?;
+        }
+        Ok(())
+    }
+}
+
+impl StateReader for JournaledState<'_> {
+    fn get_storage_at(
+        &self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+    ) -> StateResult<Felt> {
+        self.state.get_storage_at(contract_address, key)
+    }
+
+    fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
+        self.state.get_nonce_at(contract_address)
+    }
+
+    fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
+        self.state.get_class_hash_at(contract_address)
+    }
+
+    fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        self.state.get_compiled_class(class_hash)
+    }
+
+    fn get_compiled_class_hash(&self, class_hash: ClassHash) -> StateResult<CompiledClassHash> {
+        self.state.get_compiled_class_hash(class_hash)
+    }
+}
+
+impl State for JournaledState<'_> {
+    fn set_storage_at(
+        &mut self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+        value: Felt,
+    ) -> StateResult<()> {
+        let previous = self.state.get_storage_at(contract_address, key)?;
+        self.storage.push((contract_address, key, previous));
+        self.state.set_storage_at(contract_address, key, value)
+    }
+
+    fn increment_nonce(&mut self, contract_address: ContractAddress) -> StateResult<()> {
+        self.state.increment_nonce(contract_address)
+    }
+
+    fn set_class_hash_at(
+        &mut self,
+        contract_address: ContractAddress,
+        class_hash: ClassHash,
+    ) -> StateResult<()> {
+        let previous = self.state.get_class_hash_at(contract_address)?;
+        self.class_hashes.push((contract_address, previous));
+        self.state.set_class_hash_at(contract_address, class_hash)
+    }
+
+    fn set_contract_class(
+        &mut self,
+        class_hash: ClassHash,
+        contract_class: RunnableCompiledClass,
+    ) -> StateResult<()> {
+        self.state.set_contract_class(class_hash, contract_class)
+    }
+
+    fn set_compiled_class_hash(
+        &mut self,
+        class_hash: ClassHash,
+        compiled_class_hash: CompiledClassHash,
+    ) -> StateResult<()> {
+        self.state
+            .set_compiled_class_hash(class_hash, compiled_class_hash)
+    }
+}
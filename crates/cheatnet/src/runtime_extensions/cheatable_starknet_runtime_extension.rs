@@ -31,6 +31,8 @@ pub type SyscallSelector = DeprecatedSyscallSelector;
 
 pub struct CheatableStarknetRuntimeExtension<'a> {
     pub cheatnet_state: &'a mut CheatnetState,
+    /// Storage access of the storage syscall being executed
+    pub pending_storage_access: Option<syscall_hooks::PendingStorageAccess>,
 }
 
 pub type CheatableStarknetRuntime<'a> = ExtendedRuntime<CheatableStarknetRuntimeExtension<'a>>;
@@ -88,6 +90,14 @@ impl<'a> ExtensionLogic for CheatableStarknetRuntimeExtension<'a> {
                     SyscallSelector::GetBlockHash,
                 )
                 .map(|()| SyscallHandlingResult::Handled),
+            SyscallSelector::StorageRead | SyscallSelector::StorageWrite => {
+                self.pending_storage_access = Some(syscall_hooks::read_storage_access(
+                    selector,
+                    syscall_handler,
+                    vm,
+                )?);
+                Ok(SyscallHandlingResult::Forwarded)
+            }
             _ => Ok(SyscallHandlingResult::Forwarded),
         }
     }
@@ -95,11 +105,16 @@ impl<'a> ExtensionLogic for CheatableStarknetRuntimeExtension<'a> {
     fn handle_system_call_signal(
         &mut self,
         selector: DeprecatedSyscallSelector,
-        _vm: &mut VirtualMachine,
+        vm: &mut VirtualMachine,
         extended_runtime: &mut Self::Runtime,
     ) {
         let syscall_handler = &extended_runtime.hint_handler;
         match selector {
+            SyscallSelector::StorageRead | SyscallSelector::StorageWrite => {
+                if let Some(pending_access) = self.pending_storage_access.take() {
+                    syscall_hooks::storage_access_hook(pending_access, vm, self.cheatnet_state);
+                }
+            }
             SyscallSelector::EmitEvent => {
                 syscall_hooks::emit_event_hook(syscall_handler, self.cheatnet_state);
             }
@@ -112,6 +127,10 @@ impl<'a> ExtensionLogic for CheatableStarknetRuntimeExtension<'a> {
             _ => {}
         }
     }
+
+    fn is_interrupted(&self) -> bool {
+        self.cheatnet_state.is_interrupted()
+    }
 }
 
 pub fn felt_from_ptr_immutable(
@@ -199,6 +199,10 @@ impl<'a> DeprecatedExtensionLogic for DeprecatedCheatableStarknetRuntimeExtensio
             _ => {}
         }
     }
+
+    fn is_interrupted(&self) -> bool {
+        self.cheatnet_state.is_interrupted()
+    }
 }
 
 impl DeprecatedCheatableStarknetRuntimeExtension<'_> {
@@ -166,7 +166,7 @@ impl<Extension: DeprecatedExtensionLogic> SyscallPtrAccess
 
 impl<Extension: DeprecatedExtensionLogic> ResourceTracker for DeprecatedExtendedRuntime<Extension> {
     fn consumed(&self) -> bool {
-        self.extended_runtime.consumed()
+        self.extension.is_interrupted() || self.extended_runtime.consumed()
     }
 
     fn consume_step(&mut self) {
@@ -199,4 +199,9 @@ pub trait DeprecatedExtensionLogic {
         _selector: &DeprecatedSyscallSelector,
         _extended_runtime: &mut Self::Runtime,
     );
+
+    /// Checked before every step of the VM, the execution stops once it returns `true`
+    fn is_interrupted(&self) -> bool {
+        false
+    }
 }
@@ -32,6 +32,9 @@ impl<'a> ExtensionLogic for ForgeConfigExtension<'a> {
             "set_config_ignore" => config_cheatcode!(ignore),
             "set_config_should_panic" => config_cheatcode!(should_panic),
             "set_config_fuzzer" => config_cheatcode!(fuzzer),
+            "set_config_invariant" => config_cheatcode!(invariant),
+            "set_config_transactional" => config_cheatcode!(transactional),
+            "set_config_timeout" => config_cheatcode!(timeout),
             "is_config_mode" => Ok(CheatcodeHandlingResult::from_serializable(true)),
             _ => Ok(CheatcodeHandlingResult::Forwarded),
         }
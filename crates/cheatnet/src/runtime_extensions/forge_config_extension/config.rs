@@ -1,12 +1,16 @@
 use conversions::{byte_array::ByteArray, serde::deserialize::CairoDeserialize};
 use serde::{
-    Deserialize, Deserializer,
+    Deserialize, Deserializer, Serialize, Serializer,
     de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
 };
 use starknet_api::execution_resources::{GasAmount, GasVector};
 use starknet_types_core::felt::Felt;
 use std::str::FromStr;
-use std::{fmt, num::NonZeroU32};
+use std::{
+    fmt,
+    num::{NonZeroU32, NonZeroU64},
+};
 use url::Url;
 // available gas
 
@@ -48,11 +52,75 @@ impl RawAvailableResourceBoundsConfig {
 
 // fork
 
-#[derive(Debug, Clone, CairoDeserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, CairoDeserialize, PartialEq, Eq, Hash)]
+pub enum BlockTag {
+    Latest,
+    Pending,
+    L1Accepted,
+}
+
+impl BlockTag {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BlockTag::Latest => "latest",
+            BlockTag::Pending => "pending",
+            BlockTag::L1Accepted => "l1_accepted",
+        }
+    }
+}
+
+impl FromStr for BlockTag {
+    type Err = String;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        match tag {
+            "latest" => Ok(BlockTag::Latest),
+            "pending" => Ok(BlockTag::Pending),
+            "l1_accepted" => Ok(BlockTag::L1Accepted),
+            _ => Err(format!(
+                "block_id.tag can only be equal to latest, pending or l1_accepted, got {tag}"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, CairoDeserialize, PartialEq, Eq, Hash)]
 pub enum BlockId {
-    BlockTag,
+    BlockTag(BlockTag),
     BlockHash(Felt),
     BlockNumber(u64),
+    /// Unix timestamp, the fork is pinned to the last block created at or before it
+    BlockTimestamp(u64),
+}
+
+impl fmt::Display for BlockId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockId::BlockTag(tag) => write!(f, "block_tag: {}", tag.as_str()),
+            BlockId::BlockHash(hash) => write!(f, "block_hash: {}", hash.to_hex_string()),
+            BlockId::BlockNumber(number) => write!(f, "block_number: {number}"),
+            BlockId::BlockTimestamp(timestamp) => write!(f, "block_timestamp: {timestamp}"),
+        }
+    }
+}
+
+impl Serialize for BlockId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        match self {
+            BlockId::BlockTag(tag) => map.serialize_entry("tag", tag.as_str())?,
+            BlockId::BlockHash(hash) => map.serialize_entry("hash", &hash.to_hex_string())?,
+            BlockId::BlockNumber(number) => map.serialize_entry("number", &number.to_string())?,
+            BlockId::BlockTimestamp(timestamp) => {
+                map.serialize_entry("timestamp", &timestamp.to_string())?;
+            }
+        }
+        map.end()
+    }
 }
 
 impl<'de> Deserialize<'de> for BlockId {
@@ -66,7 +134,7 @@ impl<'de> Deserialize<'de> for BlockId {
             type Value = BlockId;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("a map with exactly one of: tag, hash, or number")
+                formatter.write_str("a map with exactly one of: tag, hash, number, or timestamp")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -78,20 +146,16 @@ impl<'de> Deserialize<'de> for BlockId {
                 while let Some(key) = map.next_key::<String>()? {
                     if block_id.is_some() {
                         return Err(de::Error::custom(
-                            "block_id must contain exactly one key: 'tag', 'hash', or 'number'",
+                            "block_id must contain exactly one key: 'tag', 'hash', 'number', or 'timestamp'",
                         ));
                     }
 
                     block_id = Some(match key.as_str() {
-                        "tag" => {
-                            let tag = map.next_value::<String>()?;
-                            if tag != "latest" {
-                                return Err(de::Error::custom(
-                                    "block_id.tag can only be equal to latest",
-                                ));
-                            }
-                            BlockId::BlockTag
-                        }
+                        "tag" => BlockId::BlockTag(
+                            map.next_value::<String>()?
+                                .parse()
+                                .map_err(de::Error::custom)?,
+                        ),
                         "hash" => BlockId::BlockHash(
                             Felt::from_str(&map.next_value::<String>()?)
                                 .map_err(de::Error::custom)?,
@@ -101,10 +165,15 @@ impl<'de> Deserialize<'de> for BlockId {
                                 .parse()
                                 .map_err(de::Error::custom)?,
                         ),
+                        "timestamp" => BlockId::BlockTimestamp(
+                            map.next_value::<String>()?
+                                .parse()
+                                .map_err(de::Error::custom)?,
+                        ),
                         unknown => {
                             return Err(de::Error::unknown_field(
                                 unknown,
-                                &["tag", "hash", "number"],
+                                &["tag", "hash", "number", "timestamp"],
                             ));
                         }
                     });
@@ -135,6 +204,8 @@ pub enum RawForkConfig {
     Inline(InlineForkConfig),
     Named(ByteArray),
     Overridden(OverriddenForkConfig),
+    /// Path of a state dump file, relative to the package root
+    StateFile(ByteArray),
 }
 
 // fuzzer
@@ -145,6 +216,15 @@ pub struct RawFuzzerConfig {
     pub seed: Option<u64>,
 }
 
+// invariant
+
+#[derive(Debug, Clone, CairoDeserialize, PartialEq)]
+pub struct RawInvariantConfig {
+    pub runs: Option<NonZeroU32>,
+    pub depth: Option<NonZeroU32>,
+    pub seed: Option<u64>,
+}
+
 // should panic
 
 #[derive(Debug, Clone, CairoDeserialize)]
@@ -167,6 +247,20 @@ pub struct RawIgnoreConfig {
     pub is_ignored: bool,
 }
 
+// transactional
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawTransactionalConfig {
+    pub is_transactional: bool,
+}
+
+// timeout
+
+#[derive(Debug, Clone, Copy, CairoDeserialize, PartialEq)]
+pub struct RawTimeoutConfig {
+    pub seconds: NonZeroU64,
+}
+
 // config
 
 #[derive(Debug, Default, Clone)]
@@ -176,4 +270,7 @@ pub struct RawForgeConfig {
     pub ignore: Option<RawIgnoreConfig>,
     pub should_panic: Option<RawShouldPanicConfig>,
     pub fuzzer: Option<RawFuzzerConfig>,
+    pub invariant: Option<RawInvariantConfig>,
+    pub transactional: Option<RawTransactionalConfig>,
+    pub timeout: Option<RawTimeoutConfig>,
 }
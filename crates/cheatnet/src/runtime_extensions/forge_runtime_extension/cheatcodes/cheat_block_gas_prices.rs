@@ -0,0 +1,65 @@
+use super::cheat_execution_info::{
+    BlockGasPrices, BlockInfoMockOperations, CheatArguments, ExecutionInfoMockOperations, Operation,
+};
+use crate::CheatnetState;
+use crate::state::CheatSpan;
+use starknet_api::core::ContractAddress;
+
+impl CheatnetState {
+    pub fn cheat_block_gas_prices(
+        &mut self,
+        contract_address: ContractAddress,
+        gas_prices: BlockGasPrices,
+        span: CheatSpan,
+    ) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            block_info: BlockInfoMockOperations {
+                gas_prices: Operation::Start(CheatArguments {
+                    value: gas_prices,
+                    span,
+                    target: contract_address,
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+
+    pub fn start_cheat_block_gas_prices_global(&mut self, gas_prices: BlockGasPrices) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            block_info: BlockInfoMockOperations {
+                gas_prices: Operation::StartGlobal(gas_prices),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+
+    pub fn start_cheat_block_gas_prices(
+        &mut self,
+        contract_address: ContractAddress,
+        gas_prices: BlockGasPrices,
+    ) {
+        self.cheat_block_gas_prices(contract_address, gas_prices, CheatSpan::Indefinite);
+    }
+
+    pub fn stop_cheat_block_gas_prices(&mut self, contract_address: ContractAddress) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            block_info: BlockInfoMockOperations {
+                gas_prices: Operation::Stop(contract_address),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+
+    pub fn stop_cheat_block_gas_prices_global(&mut self) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            block_info: BlockInfoMockOperations {
+                gas_prices: Operation::StopGlobal,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+}
@@ -3,6 +3,8 @@ use crate::{
     state::{CheatSpan, CheatStatus},
 };
 use conversions::serde::{deserialize::CairoDeserialize, serialize::CairoSerialize};
+use starknet_api::StarknetApiError;
+use starknet_api::block::{GasPrice, GasPriceVector, NonzeroGasPrice};
 use starknet_api::core::ContractAddress;
 use starknet_types_core::felt::Felt;
 
@@ -30,6 +32,44 @@ pub struct ResourceBounds {
     pub max_price_per_unit: u128,
 }
 
+/// Gas prices in a single fee token
+#[derive(CairoDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GasPrices {
+    pub l1_gas_price: u128,
+    pub l1_data_gas_price: u128,
+    pub l2_gas_price: u128,
+}
+
+/// Gas prices of a block, denominated in ETH and STRK
+#[derive(CairoDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BlockGasPrices {
+    pub eth: GasPrices,
+    pub strk: GasPrices,
+}
+
+impl TryFrom<&GasPrices> for GasPriceVector {
+    type Error = StarknetApiError;
+
+    fn try_from(gas_prices: &GasPrices) -> Result<Self, Self::Error> {
+        Ok(GasPriceVector {
+            l1_gas_price: NonzeroGasPrice::new(GasPrice(gas_prices.l1_gas_price))?,
+            l1_data_gas_price: NonzeroGasPrice::new(GasPrice(gas_prices.l1_data_gas_price))?,
+            l2_gas_price: NonzeroGasPrice::new(GasPrice(gas_prices.l2_gas_price))?,
+        })
+    }
+}
+
+impl TryFrom<&BlockGasPrices> for starknet_api::block::GasPrices {
+    type Error = StarknetApiError;
+
+    fn try_from(gas_prices: &BlockGasPrices) -> Result<Self, Self::Error> {
+        Ok(Self {
+            eth_gas_prices: (&gas_prices.eth).try_into()?,
+            strk_gas_prices: (&gas_prices.strk).try_into()?,
+        })
+    }
+}
+
 #[derive(Clone, Default, Debug, PartialEq, Eq)]
 pub struct TxInfoMock {
     pub version: CheatStatus<Felt>,
@@ -52,6 +92,8 @@ pub struct BlockInfoMock {
     pub block_number: CheatStatus<u64>,
     pub block_timestamp: CheatStatus<u64>,
     pub sequencer_address: CheatStatus<ContractAddress>,
+    pub gas_prices: CheatStatus<BlockGasPrices>,
+    pub use_kzg_da: CheatStatus<bool>,
 }
 
 #[derive(Clone, Default, Debug)]
@@ -83,6 +125,8 @@ pub struct BlockInfoMockOperations {
     pub block_number: Operation<u64>,
     pub block_timestamp: Operation<u64>,
     pub sequencer_address: Operation<ContractAddress>,
+    pub gas_prices: Operation<BlockGasPrices>,
+    pub use_kzg_da: Operation<bool>,
 }
 
 #[derive(CairoDeserialize, Clone, Default, Debug)]
@@ -92,6 +136,20 @@ pub struct ExecutionInfoMockOperations {
     pub caller_address: Operation<ContractAddress>,
 }
 
+impl ExecutionInfoMockOperations {
+    /// Checks that the cheated values can be set, gas prices have to be non-zero
+    pub fn validate(&self) -> Result<(), StarknetApiError> {
+        if let Operation::StartGlobal(gas_prices)
+        | Operation::Start(CheatArguments {
+            value: gas_prices, ..
+        }) = &self.block_info.gas_prices
+        {
+            starknet_api::block::GasPrices::try_from(gas_prices)?;
+        }
+        Ok(())
+    }
+}
+
 macro_rules! for_all_fields {
     ($macro:ident!) => {
         $macro!(caller_address);
@@ -99,6 +157,8 @@ macro_rules! for_all_fields {
         $macro!(block_info.block_number);
         $macro!(block_info.block_timestamp);
         $macro!(block_info.sequencer_address);
+        $macro!(block_info.gas_prices);
+        $macro!(block_info.use_kzg_da);
 
         $macro!(tx_info.version);
         $macro!(tx_info.account_contract_address);
@@ -0,0 +1,61 @@
+use super::cheat_execution_info::{
+    BlockInfoMockOperations, CheatArguments, ExecutionInfoMockOperations, Operation,
+};
+use crate::CheatnetState;
+use crate::state::CheatSpan;
+use starknet_api::core::ContractAddress;
+
+impl CheatnetState {
+    pub fn cheat_use_kzg_da(
+        &mut self,
+        contract_address: ContractAddress,
+        use_kzg_da: bool,
+        span: CheatSpan,
+    ) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            block_info: BlockInfoMockOperations {
+                use_kzg_da: Operation::Start(CheatArguments {
+                    value: use_kzg_da,
+                    span,
+                    target: contract_address,
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+
+    pub fn start_cheat_use_kzg_da_global(&mut self, use_kzg_da: bool) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            block_info: BlockInfoMockOperations {
+                use_kzg_da: Operation::StartGlobal(use_kzg_da),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+
+    pub fn start_cheat_use_kzg_da(&mut self, contract_address: ContractAddress, use_kzg_da: bool) {
+        self.cheat_use_kzg_da(contract_address, use_kzg_da, CheatSpan::Indefinite);
+    }
+
+    pub fn stop_cheat_use_kzg_da(&mut self, contract_address: ContractAddress) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            block_info: BlockInfoMockOperations {
+                use_kzg_da: Operation::Stop(contract_address),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+
+    pub fn stop_cheat_use_kzg_da_global(&mut self) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            block_info: BlockInfoMockOperations {
+                use_kzg_da: Operation::StopGlobal,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+}
@@ -1,8 +1,73 @@
 use crate::CheatnetState;
 use crate::state::{CheatSpan, CheatStatus};
-use starknet_api::core::{ContractAddress, EntryPointSelector};
+use conversions::serde::deserialize::CairoDeserialize;
+use starknet_api::core::{ClassHash, ContractAddress, EntryPointSelector};
 use starknet_types_core::felt::Felt;
 use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+
+/// Calls a mock applies to
+#[derive(CairoDeserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MockTarget {
+    /// Calls to the contract at the given address
+    Contract(ContractAddress),
+    /// Library calls to the class with the given hash
+    LibraryCall(ClassHash),
+}
+
+/// Calldata of calls a mock or a call expectation applies to
+#[derive(CairoDeserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CalldataMatcher {
+    Any,
+    Prefix(Vec<Felt>),
+    Exact(Vec<Felt>),
+}
+
+impl CalldataMatcher {
+    pub(crate) fn matches(&self, calldata: &[Felt]) -> bool {
+        match self {
+            CalldataMatcher::Any => true,
+            CalldataMatcher::Prefix(prefix) => calldata.starts_with(prefix),
+            CalldataMatcher::Exact(expected) => calldata == expected.as_slice(),
+        }
+    }
+
+    /// If multiple mocks match a call, the most specific one is used
+    fn specificity(&self) -> (bool, usize) {
+        match self {
+            CalldataMatcher::Any => (false, 0),
+            CalldataMatcher::Prefix(prefix) => (false, prefix.len()),
+            CalldataMatcher::Exact(expected) => (true, expected.len()),
+        }
+    }
+}
+
+/// Outcome of a mocked call
+#[derive(CairoDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum MockedResult {
+    /// The call returns the given data
+    Return(Vec<Felt>),
+    /// The call panics with the given data
+    Revert(Vec<Felt>),
+}
+
+/// Results of successive calls matching a mock, the last one is used for all further calls
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockedResults(VecDeque<MockedResult>);
+
+impl MockedResults {
+    fn next(&mut self) -> MockedResult {
+        if self.0.len() > 1 {
+            self.0.pop_front()
+        } else {
+            self.0.front().cloned()
+        }
+        .expect("Mocked results should not be empty")
+    }
+}
+
+/// Mocks of a single function, by calldata of calls they apply to
+pub type FunctionMocks = HashMap<CalldataMatcher, CheatStatus<MockedResults>>;
 
 impl CheatnetState {
     pub fn mock_call(
@@ -12,11 +77,12 @@ impl CheatnetState {
         ret_data: &[Felt],
         span: CheatSpan,
     ) {
-        let contract_mocked_functions = self.mocked_functions.entry(contract_address).or_default();
-
-        contract_mocked_functions.insert(
+        self.mock_calls(
+            MockTarget::Contract(contract_address),
             function_selector,
-            CheatStatus::Cheated(ret_data.to_vec(), span),
+            CalldataMatcher::Any,
+            vec![MockedResult::Return(ret_data.to_vec())],
+            span,
         );
     }
 
@@ -34,14 +100,71 @@ impl CheatnetState {
         );
     }
 
+    /// Mocks calls to `function_selector` of `target` with calldata matching `calldata`,
+    /// successive calls get successive `results`
+    pub fn mock_calls(
+        &mut self,
+        target: MockTarget,
+        function_selector: EntryPointSelector,
+        calldata: CalldataMatcher,
+        results: Vec<MockedResult>,
+        span: CheatSpan,
+    ) {
+        assert!(!results.is_empty(), "Mocked results should not be empty");
+
+        let function_mocks = self
+            .mocked_functions
+            .entry(target)
+            .or_default()
+            .entry(function_selector)
+            .or_default();
+
+        function_mocks.insert(
+            calldata,
+            CheatStatus::Cheated(MockedResults(results.into()), span),
+        );
+    }
+
     pub fn stop_mock_call(
         &mut self,
         contract_address: ContractAddress,
         function_selector: EntryPointSelector,
     ) {
-        if let Entry::Occupied(mut e) = self.mocked_functions.entry(contract_address) {
-            let contract_mocked_functions = e.get_mut();
-            contract_mocked_functions.remove(&function_selector);
+        self.stop_mock_calls(MockTarget::Contract(contract_address), function_selector);
+    }
+
+    /// Cancels all mocks of `function_selector` of `target`, regardless of their calldata
+    pub fn stop_mock_calls(&mut self, target: MockTarget, function_selector: EntryPointSelector) {
+        if let Entry::Occupied(mut e) = self.mocked_functions.entry(target) {
+            let target_mocked_functions = e.get_mut();
+            target_mocked_functions.remove(&function_selector);
         }
     }
+
+    /// Returns the result of the most specific active mock matching the call, if there is one
+    pub(crate) fn next_mocked_result(
+        &mut self,
+        target: MockTarget,
+        function_selector: EntryPointSelector,
+        calldata: &[Felt],
+    ) -> Option<MockedResult> {
+        let cheat_status = self
+            .mocked_functions
+            .get_mut(&target)?
+            .get_mut(&function_selector)?
+            .iter_mut()
+            .filter(|(mock_calldata, cheat_status)| {
+                matches!(cheat_status, CheatStatus::Cheated(..)) && mock_calldata.matches(calldata)
+            })
+            .max_by_key(|(mock_calldata, _)| mock_calldata.specificity())
+            .map(|(_, cheat_status)| cheat_status)?;
+
+        let CheatStatus::Cheated(results, _) = cheat_status else {
+            unreachable!("Only active mocks are matched")
+        };
+        let result = results.next();
+        cheat_status.decrement_cheat_span();
+
+        Some(result)
+    }
 }
@@ -3,12 +3,14 @@ use cairo_vm::vm::errors::hint_errors::HintError;
 use runtime::EnhancedHintError;
 use starknet_types_core::felt::Felt;
 
+pub mod cheat_block_gas_prices;
 pub mod cheat_block_hash;
 pub mod cheat_block_number;
 pub mod cheat_block_timestamp;
 pub mod cheat_caller_address;
 pub mod cheat_execution_info;
 pub mod cheat_sequencer_address;
+pub mod cheat_use_kzg_da;
 pub mod declare;
 pub mod deploy;
 pub mod generate_random_felt;
@@ -16,7 +18,10 @@ pub mod get_class_hash;
 pub mod l1_handler_execute;
 pub mod mock_call;
 pub mod precalculate_address;
+pub mod record_storage;
 pub mod replace_bytecode;
+pub mod snapshot;
+pub mod spy_calls;
 pub mod spy_events;
 pub mod spy_messages_to_l1;
 pub mod storage;
@@ -0,0 +1,64 @@
+use crate::CheatnetState;
+use crate::state::{StorageAccess, StorageAccessKind};
+use conversions::serde::serialize::CairoSerialize;
+use starknet_api::core::ContractAddress;
+use starknet_types_core::felt::Felt;
+
+/// Storage accesses made by calls since recording was started with `start_record_storage`
+#[derive(Clone, Debug, Default)]
+pub struct StorageRecording {
+    pub is_active: bool,
+    pub accesses: Vec<StorageAccess>,
+}
+
+/// Storage slots of a contract read and written while storage accesses were recorded
+#[derive(CairoSerialize, Debug, Default, PartialEq, Clone)]
+pub struct StorageAccesses {
+    pub reads: Vec<Felt>,
+    pub writes: Vec<Felt>,
+}
+
+impl CheatnetState {
+    /// Starts recording storage accesses, discarding the ones recorded before
+    pub fn start_record_storage(&mut self) {
+        self.storage_recording = StorageRecording {
+            is_active: true,
+            accesses: vec![],
+        };
+    }
+
+    /// Stops recording storage accesses, the ones recorded so far are kept
+    pub fn stop_record_storage(&mut self) {
+        self.storage_recording.is_active = false;
+    }
+
+    /// Returns keys of storage slots of `contract_address` read and written while recording,
+    /// each key listed once, in order of the first access
+    #[must_use]
+    pub fn get_storage_accesses(&self, contract_address: ContractAddress) -> StorageAccesses {
+        let mut storage_accesses = StorageAccesses::default();
+
+        for access in self
+            .storage_recording
+            .accesses
+            .iter()
+            .filter(|access| access.contract_address == contract_address)
+        {
+            let keys = match access.kind {
+                StorageAccessKind::Read => &mut storage_accesses.reads,
+                StorageAccessKind::Write => &mut storage_accesses.writes,
+            };
+            if !keys.contains(&access.key) {
+                keys.push(access.key);
+            }
+        }
+
+        storage_accesses
+    }
+
+    pub(crate) fn record_storage_access(&mut self, storage_access: &StorageAccess) {
+        if self.storage_recording.is_active {
+            self.storage_recording.accesses.push(storage_access.clone());
+        }
+    }
+}
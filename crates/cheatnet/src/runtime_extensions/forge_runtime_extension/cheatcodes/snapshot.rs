@@ -0,0 +1,153 @@
+use crate::CheatnetState;
+use crate::forking::multi_fork::{ForksSnapshot, MultiForkState};
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::transaction::TransactionsState;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::ExecutionInfoMock;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::{
+    FunctionMocks, MockTarget,
+};
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::record_storage::StorageRecording;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_calls::ExpectedCall;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_messages_to_l1::MessageToL1;
+use crate::state::CheatSpan;
+use anyhow::{Result, anyhow};
+use runtime::starknet::state::DictStateReader;
+use starknet_api::block::BlockInfo;
+use starknet_api::core::{ClassHash, ContractAddress, EntryPointSelector};
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+
+/// Test state saved with `snapshot` cheatcode
+pub struct Snapshot {
+    state: ForksSnapshot,
+    cheats: CheatsSnapshot,
+    // Number of calls made directly by the test when the snapshot was taken
+    calls_offset: usize,
+}
+
+/// Cheats, mocks, call expectations, spied events and messages, recorded storage accesses
+/// and transactions state active when a snapshot was taken
+#[derive(Clone)]
+struct CheatsSnapshot {
+    cheated_execution_info_contracts: HashMap<ContractAddress, ExecutionInfoMock>,
+    global_cheated_execution_info: ExecutionInfoMock,
+    mocked_functions: HashMap<MockTarget, HashMap<EntryPointSelector, FunctionMocks>>,
+    replaced_bytecode_contracts: HashMap<ContractAddress, ClassHash>,
+    detected_events: Vec<Event>,
+    detected_messages_to_l1: Vec<MessageToL1>,
+    deploy_salt_base: u32,
+    block_info: BlockInfo,
+    block_hash_contracts: HashMap<(ContractAddress, u64), (CheatSpan, Felt)>,
+    global_block_hash: HashMap<u64, (Felt, Vec<ContractAddress>)>,
+    transactions: Option<TransactionsState>,
+    expected_calls: Vec<ExpectedCall>,
+    storage_recording: StorageRecording,
+}
+
+impl Snapshot {
+    /// Writes the state saved in the snapshot into `state_reader`, so that a test built on top of it
+    /// starts from the state left by a setup function.
+    /// Changes made by the setup function are not counted as changes made by the test.
+    pub fn write_to_state_reader(&self, state_reader: &mut DictStateReader) {
+        self.state.write_to_state_reader(state_reader);
+    }
+}
+
+impl CheatnetState {
+    /// Saves the state of all forks together with active cheats, mocks and spies
+    pub fn take_snapshot(&self, forks: &MultiForkState) -> Result<Snapshot> {
+        Ok(Snapshot {
+            state: forks.snapshot()?,
+            cheats: CheatsSnapshot {
+                cheated_execution_info_contracts: self.cheated_execution_info_contracts.clone(),
+                global_cheated_execution_info: self.global_cheated_execution_info.clone(),
+                mocked_functions: self.mocked_functions.clone(),
+                replaced_bytecode_contracts: self.replaced_bytecode_contracts.clone(),
+                detected_events: self.detected_events.clone(),
+                detected_messages_to_l1: self.detected_messages_to_l1.clone(),
+                deploy_salt_base: self.deploy_salt_base,
+                block_info: self.block_info.clone(),
+                block_hash_contracts: self.block_hash_contracts.clone(),
+                global_block_hash: self.global_block_hash.clone(),
+                transactions: self.transactions.clone(),
+                expected_calls: self.expected_calls.clone(),
+                storage_recording: self.storage_recording.clone(),
+            },
+            calls_offset: self.calls_offset(),
+        })
+    }
+
+    /// Saves the state of all forks together with active cheats, mocks and spies,
+    /// returning id of the snapshot
+    pub fn snapshot(&mut self, forks: &MultiForkState) -> Result<usize> {
+        let snapshot = self.take_snapshot(forks)?;
+        self.snapshots.push(snapshot);
+
+        Ok(self.snapshots.len() - 1)
+    }
+
+    /// Restores the state saved in the snapshot with the given `id`.
+    /// The snapshot is kept, so the state can be reverted to it again.
+    pub fn revert_to(&mut self, forks: &MultiForkState, id: usize) -> Result<()> {
+        let snapshot = self
+            .snapshots
+            .get(id)
+            .ok_or_else(|| anyhow!("Snapshot with id = {id} does not exist"))?;
+
+        forks.revert_to(&snapshot.state)?;
+        let cheats = snapshot.cheats.clone();
+        let reverted_calls = snapshot.calls_offset..self.calls_offset();
+        self.restore_cheats(cheats);
+        // Calls made since the snapshot are not counted by call expectations and spies
+        self.reverted_calls.push(reverted_calls);
+
+        Ok(())
+    }
+
+    /// Restores cheats, mocks and spies saved in the snapshot of a setup function.
+    /// The state itself is restored with [`Snapshot::write_to_state_reader`].
+    pub fn start_from_setup(&mut self, setup_state: &Snapshot) {
+        // Block info comes from the fork of the test, not from the setup function
+        let block_info = self.block_info.clone();
+        self.restore_cheats(setup_state.cheats.clone());
+        self.block_info = block_info;
+
+        // Calls made by the setup function are not part of the test, so expectations set in it
+        // count all calls of the test
+        for expected_call in &mut self.expected_calls {
+            expected_call.calls_offset = 0;
+        }
+    }
+
+    fn restore_cheats(&mut self, cheats: CheatsSnapshot) {
+        let CheatsSnapshot {
+            cheated_execution_info_contracts,
+            global_cheated_execution_info,
+            mocked_functions,
+            replaced_bytecode_contracts,
+            detected_events,
+            detected_messages_to_l1,
+            deploy_salt_base,
+            block_info,
+            block_hash_contracts,
+            global_block_hash,
+            transactions,
+            expected_calls,
+            storage_recording,
+        } = cheats;
+
+        self.cheated_execution_info_contracts = cheated_execution_info_contracts;
+        self.global_cheated_execution_info = global_cheated_execution_info;
+        self.mocked_functions = mocked_functions;
+        self.replaced_bytecode_contracts = replaced_bytecode_contracts;
+        self.detected_events = detected_events;
+        self.detected_messages_to_l1 = detected_messages_to_l1;
+        self.deploy_salt_base = deploy_salt_base;
+        self.block_info = block_info;
+        self.block_hash_contracts = block_hash_contracts;
+        self.global_block_hash = global_block_hash;
+        self.transactions = transactions;
+        self.expected_calls = expected_calls;
+        self.storage_recording = storage_recording;
+    }
+}
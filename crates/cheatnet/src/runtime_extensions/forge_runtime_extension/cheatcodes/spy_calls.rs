@@ -0,0 +1,202 @@
+use crate::CheatnetState;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::CalldataMatcher;
+use crate::state::{CallTrace, CallTraceNode};
+use blockifier::execution::entry_point::{CallEntryPoint, CallType};
+use conversions::serde::deserialize::CairoDeserialize;
+use conversions::serde::serialize::CairoSerialize;
+use conversions::string::IntoHexStr;
+use starknet_api::core::{ContractAddress, EntryPointSelector};
+use starknet_types_core::felt::Felt;
+
+/// Represents a call to a contract made during the test, directly or by another contract
+#[derive(CairoSerialize, Debug, PartialEq, Clone)]
+pub struct Call {
+    pub contract_address: ContractAddress,
+    pub function_selector: EntryPointSelector,
+    pub calldata: Vec<Felt>,
+}
+
+impl From<&CallEntryPoint> for Call {
+    fn from(entry_point: &CallEntryPoint) -> Self {
+        Self {
+            contract_address: entry_point.storage_address,
+            function_selector: entry_point.entry_point_selector,
+            calldata: entry_point.calldata.0.to_vec(),
+        }
+    }
+}
+
+/// How many times an expected call has to be made
+#[derive(CairoDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpectedCallCount {
+    Exactly(usize),
+    AtLeastOnce,
+    Never,
+}
+
+impl ExpectedCallCount {
+    fn is_met_by(self, calls_count: usize) -> bool {
+        match self {
+            ExpectedCallCount::Exactly(count) => calls_count == count,
+            ExpectedCallCount::AtLeastOnce => calls_count > 0,
+            ExpectedCallCount::Never => calls_count == 0,
+        }
+    }
+}
+
+/// Call expectation verified when the test ends
+#[derive(Clone, Debug)]
+pub struct ExpectedCall {
+    pub contract_address: ContractAddress,
+    pub function_selector: EntryPointSelector,
+    pub calldata: CalldataMatcher,
+    pub count: ExpectedCallCount,
+    /// Only calls made after the expectation was set are counted
+    pub calls_offset: usize,
+}
+
+impl CheatnetState {
+    /// Number of calls made directly by the test so far, used to skip them when getting calls
+    #[must_use]
+    pub fn calls_offset(&self) -> usize {
+        self.trace_data
+            .current_call_stack
+            .borrow_full_trace()
+            .nested_calls
+            .len()
+    }
+
+    /// Returns calls made after the first `calls_offset` calls of the test, including calls
+    /// made by the called contracts, in order of execution.
+    /// Library calls and calls reverted with `revert_to` are not included, but calls made by
+    /// library calls are.
+    #[must_use]
+    pub fn get_calls(&self, calls_offset: usize) -> Vec<Call> {
+        let test_trace = self.trace_data.current_call_stack.borrow_full_trace();
+        let mut calls = vec![];
+
+        for call_trace in test_trace
+            .nested_calls
+            .iter()
+            .enumerate()
+            .skip(calls_offset)
+            .filter(|(index, _)| {
+                !self
+                    .reverted_calls
+                    .iter()
+                    .any(|reverted_calls| reverted_calls.contains(index))
+            })
+            .filter_map(|(_, call_trace)| call_trace.extract_entry_point_call())
+        {
+            collect_calls(&call_trace.borrow(), &mut calls);
+        }
+
+        calls
+    }
+
+    pub fn expect_call(
+        &mut self,
+        contract_address: ContractAddress,
+        function_selector: EntryPointSelector,
+        calldata: CalldataMatcher,
+        count: ExpectedCallCount,
+    ) {
+        let calls_offset = self.calls_offset();
+        self.expected_calls.push(ExpectedCall {
+            contract_address,
+            function_selector,
+            calldata,
+            count,
+            calls_offset,
+        });
+    }
+
+    /// Returns messages describing expectations set with `expect_call` which were not met,
+    /// together with the calls actually made to the expected function
+    #[must_use]
+    pub fn unmet_call_expectations(&self) -> Vec<String> {
+        self.expected_calls
+            .iter()
+            .filter_map(|expected_call| {
+                let calls: Vec<Call> = self
+                    .get_calls(expected_call.calls_offset)
+                    .into_iter()
+                    .filter(|call| {
+                        call.contract_address == expected_call.contract_address
+                            && call.function_selector == expected_call.function_selector
+                    })
+                    .collect();
+                let matching_calls_count = calls
+                    .iter()
+                    .filter(|call| expected_call.calldata.matches(&call.calldata))
+                    .count();
+
+                (!expected_call.count.is_met_by(matching_calls_count))
+                    .then(|| unmet_expectation_message(expected_call, &calls, matching_calls_count))
+            })
+            .collect()
+    }
+}
+
+fn collect_calls(call_trace: &CallTrace, calls: &mut Vec<Call>) {
+    if call_trace.entry_point.call_type == CallType::Call {
+        calls.push(Call::from(&call_trace.entry_point));
+    }
+
+    for nested_call in call_trace
+        .nested_calls
+        .iter()
+        .filter_map(CallTraceNode::extract_entry_point_call)
+    {
+        collect_calls(&nested_call.borrow(), calls);
+    }
+}
+
+fn unmet_expectation_message(
+    expected_call: &ExpectedCall,
+    calls: &[Call],
+    matching_calls_count: usize,
+) -> String {
+    let count = match expected_call.count {
+        ExpectedCallCount::Exactly(count) => format!("{count} call(s)"),
+        ExpectedCallCount::AtLeastOnce => "at least one call".to_string(),
+        ExpectedCallCount::Never => "no calls".to_string(),
+    };
+    let calldata = match &expected_call.calldata {
+        CalldataMatcher::Any => "any calldata".to_string(),
+        CalldataMatcher::Prefix(prefix) => format!("calldata starting with {}", felts(prefix)),
+        CalldataMatcher::Exact(calldata) => format!("calldata {}", felts(calldata)),
+    };
+    let function = format!(
+        "function {} of contract {}",
+        expected_call.function_selector.into_hex_string(),
+        expected_call.contract_address.into_hex_string()
+    );
+
+    let mut message =
+        format!("Expected {count} to {function} with {calldata}, but got {matching_calls_count}\n");
+    if calls.is_empty() {
+        message.push_str(&format!("No calls were made to {function}\n"));
+    } else {
+        message.push_str(&format!("Actual calls to {function}:\n"));
+        for call in calls {
+            let marker = if expected_call.calldata.matches(&call.calldata) {
+                '+'
+            } else {
+                '-'
+            };
+            message.push_str(&format!("  {marker} {}\n", felts(&call.calldata)));
+        }
+    }
+
+    message
+}
+
+fn felts(felts: &[Felt]) -> String {
+    let felts: Vec<String> = felts
+        .iter()
+        .copied()
+        .map(IntoHexStr::into_hex_string)
+        .collect();
+    format!("[{}]", felts.join(", "))
+}
@@ -40,6 +40,10 @@ impl Event {
 
 impl CheatnetState {
     pub fn get_events(&mut self, event_offset: usize) -> Vec<Event> {
-        self.detected_events[event_offset..].to_vec()
+        // Offset of a spy created after the snapshot the state was reverted to can be out of range
+        self.detected_events
+            .get(event_offset..)
+            .unwrap_or_default()
+            .to_vec()
     }
 }
@@ -93,6 +93,15 @@ impl ContractsData {
             .map(|contract| &contract.source_sierra_path)
     }
 
+    /// Deserializes ABI of the contract from its Sierra artifact
+    #[must_use]
+    pub fn get_abi(&self, contract_name: &str) -> Option<Vec<AbiEntry>> {
+        let artifacts = self.get_artifacts(contract_name)?;
+        serde_json::from_str::<SierraClass>(&artifacts.sierra)
+            .ok()
+            .map(|sierra_class| sierra_class.abi)
+    }
+
     #[must_use]
     pub fn get_contract_name(&self, class_hash: &ClassHash) -> Option<&ContractName> {
         self.class_hashes.get_by_right(class_hash)
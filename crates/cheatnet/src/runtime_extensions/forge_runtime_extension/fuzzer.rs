@@ -1,14 +1,53 @@
 use anyhow::ensure;
-use num_bigint::RandBigInt;
+use num_bigint::{BigInt, RandBigInt};
+use num_traits::Zero;
 use rand::prelude::StdRng;
 use starknet_types_core::felt::Felt;
 use std::sync::{Arc, Mutex};
 
+/// Source of values returned by the `generate_arg` cheatcode in fuzz tests
+#[derive(Debug, Clone)]
+pub enum FuzzerArgsSource {
+    /// Values are drawn from the random number generator shared by all runs of the test
+    Random(Arc<Mutex<StdRng>>),
+    /// Values are replayed in order, e.g. when shrinking a failing input or rerunning a saved one.
+    /// Values outside the requested range, or missing ones, are replaced with the simplest value in the range
+    Replay(Vec<BigInt>),
+}
+
+/// Value returned by the `generate_arg` cheatcode together with the range it was generated from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzerChoice {
+    pub min: BigInt,
+    pub max: BigInt,
+    pub value: BigInt,
+}
+
+impl FuzzerChoice {
+    /// The simplest value in the range of the choice, which failing inputs are shrunk towards.
+    /// It's zero if the range contains it, otherwise the bound closest to zero.
+    #[must_use]
+    pub fn simplest_value(&self) -> BigInt {
+        simplest_value(&self.min, &self.max)
+    }
+}
+
+fn simplest_value(min: &BigInt, max: &BigInt) -> BigInt {
+    if min > &BigInt::zero() {
+        min.clone()
+    } else if max < &BigInt::zero() {
+        max.clone()
+    } else {
+        BigInt::zero()
+    }
+}
+
 pub(crate) fn generate_arg(
-    fuzzer_rng: Option<Arc<Mutex<StdRng>>>,
+    fuzzer_args_source: Option<&FuzzerArgsSource>,
+    choice_index: usize,
     min_value: Felt,
     max_value: Felt,
-) -> anyhow::Result<Felt> {
+) -> anyhow::Result<(Felt, FuzzerChoice)> {
     let min_big_int = if min_value > (Felt::MAX + Felt::from(i128::MIN)) && min_value > max_value {
         // Negative value x is serialized as P + x, where P is the STARK prime number
         // hence to deserialize and get the actual x we need to subtract P (== Felt::MAX + 1)
@@ -26,15 +65,31 @@ pub(crate) fn generate_arg(
         )
     );
 
-    let value = if let Some(fuzzer_rng) = fuzzer_rng {
-        fuzzer_rng
+    let choice = choose_value(fuzzer_args_source, choice_index, min_big_int, max_big_int);
+
+    Ok((Felt::from(choice.value.clone()), choice))
+}
+
+/// Chooses a value from the inclusive range `min..=max` according to the `fuzzer_args_source`
+pub(crate) fn choose_value(
+    fuzzer_args_source: Option<&FuzzerArgsSource>,
+    choice_index: usize,
+    min: BigInt,
+    max: BigInt,
+) -> FuzzerChoice {
+    let value = match fuzzer_args_source {
+        Some(FuzzerArgsSource::Random(fuzzer_rng)) => fuzzer_rng
             .lock()
             .expect("Failed to acquire lock on fuzzer_rng")
-            .gen_bigint_range(&min_big_int, &(max_big_int + 1))
-    } else {
+            .gen_bigint_range(&min, &(max.clone() + 1)),
+        Some(FuzzerArgsSource::Replay(values)) => values
+            .get(choice_index)
+            .filter(|value| (&min..=&max).contains(value))
+            .cloned()
+            .unwrap_or_else(|| simplest_value(&min, &max)),
         // `generate_arg` cheatcode can be also used outside the fuzzer context
-        rand::thread_rng().gen_bigint_range(&min_big_int, &(max_big_int + 1))
+        None => rand::thread_rng().gen_bigint_range(&min, &(max.clone() + 1)),
     };
 
-    Ok(Felt::from(value))
+    FuzzerChoice { min, max, value }
 }
@@ -0,0 +1,355 @@
+use super::contracts_data::ContractsData;
+use super::fuzzer::{FuzzerArgsSource, FuzzerChoice, choose_value};
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::entry_point::execute_call_entry_point;
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
+    AddressOrClassHash, CallResult,
+};
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::transaction::JournaledState;
+use crate::state::CheatnetState;
+use anyhow::{Context, Result, anyhow, ensure};
+use blockifier::execution::entry_point::{CallEntryPoint, CallType};
+use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
+use blockifier::state::state_api::State;
+use conversions::IntoConv;
+use conversions::string::TryFromHexStr;
+use data_transformer::{ArgumentType, resolve_input_types, reverse_transform_input};
+use num_bigint::BigInt;
+use runtime::starknet::constants::TEST_ADDRESS;
+use serde::Deserialize;
+use starknet::core::types::contract::{AbiEntry, AbiFunction, StateMutability};
+use starknet::core::utils::get_selector_from_name;
+use starknet_api::contract_class::EntryPointType;
+use starknet_api::core::{ClassHash, ContractAddress, EntryPointSelector};
+use starknet_api::transaction::fields::Calldata;
+use starknet_types_core::felt::Felt;
+use std::sync::Arc;
+
+/// State of an invariant test: contracts called in random sequences and calls made so far
+#[derive(Debug)]
+pub struct InvariantState {
+    depth: u32,
+    steps: u32,
+    targets: Vec<InvariantTarget>,
+    /// Calls made in the sequence, displayed when an invariant is broken
+    pub calls: Vec<String>,
+}
+
+#[derive(Debug)]
+struct InvariantTarget {
+    address: ContractAddress,
+    contract_name: String,
+    abi: Vec<AbiEntry>,
+    functions: Vec<TargetFunction>,
+}
+
+#[derive(Debug)]
+struct TargetFunction {
+    function: AbiFunction,
+    selector: EntryPointSelector,
+    input_types: Vec<ArgumentType>,
+}
+
+/// Only the ABI is needed from the Sierra artifact of the contract
+#[derive(Deserialize)]
+struct SierraAbi {
+    abi: Vec<AbiEntry>,
+}
+
+impl InvariantState {
+    /// Creates the state of a test which makes `depth` calls in every sequence
+    #[must_use]
+    pub fn new(depth: u32) -> Self {
+        Self {
+            depth,
+            steps: 0,
+            targets: vec![],
+            calls: vec![],
+        }
+    }
+}
+
+/// Adds the contract deployed at `contract_address` to contracts called in the sequences.
+/// Its external functions are looked up in the ABI of the contract from the tested package,
+/// functions with arguments of types that can't be generated (e.g. recursive ones) are skipped.
+pub fn target_contract(
+    state: &mut dyn State,
+    cheatnet_state: &mut CheatnetState,
+    contracts_data: &ContractsData,
+    contract_address: ContractAddress,
+) -> Result<()> {
+    let class_hash = match cheatnet_state
+        .replaced_bytecode_contracts
+        .get(&contract_address)
+    {
+        Some(class_hash) => *class_hash,
+        None => state.get_class_hash_at(contract_address)?,
+    };
+    let invariant = get_invariant_state(cheatnet_state, "target_contract")?;
+
+    if invariant
+        .targets
+        .iter()
+        .any(|target| target.address == contract_address)
+    {
+        return Ok(());
+    }
+
+    ensure!(
+        class_hash != ClassHash::default(),
+        "Contract at address {:#x} is not deployed",
+        contract_address.into_::<Felt>()
+    );
+
+    let contract_name = contracts_data.get_contract_name(&class_hash).with_context(|| {
+        format!(
+            "Contract at address {:#x} is not one of the contracts of the package, its ABI is not known",
+            contract_address.into_::<Felt>()
+        )
+    })?;
+    let artifacts = contracts_data
+        .get_artifacts(contract_name)
+        .expect("Artifacts should exist for every contract with a class hash");
+    let SierraAbi { abi } = serde_json::from_str(&artifacts.sierra)?;
+
+    let functions: Vec<_> = external_functions(&abi)
+        .into_iter()
+        .filter_map(|function| {
+            let input_types = resolve_input_types(&function, &abi).ok()?;
+            let selector = get_selector_from_name(&function.name).ok()?.into_();
+
+            Some(TargetFunction {
+                function,
+                selector,
+                input_types,
+            })
+        })
+        .collect();
+
+    ensure!(
+        !functions.is_empty(),
+        "Contract {contract_name} has no external functions that can be called in invariant tests"
+    );
+
+    invariant.targets.push(InvariantTarget {
+        address: contract_address,
+        contract_name: contract_name.clone(),
+        abi,
+        functions,
+    });
+
+    Ok(())
+}
+
+/// Makes the next call of the sequence, returns `false` once the sequence is complete.
+///
+/// The call is chosen randomly from external functions of target contracts, together with its arguments.
+/// All values are drawn like the ones returned by the `generate_arg` cheatcode,
+/// so call sequences of failing runs are shrunk and replayed the same way as fuzzer arguments.
+pub fn invariant_step(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    fuzzer_args_source: Option<&FuzzerArgsSource>,
+) -> Result<bool> {
+    // Fields are borrowed separately, as choices are recorded while targets are in use
+    let invariant = cheatnet_state
+        .invariant
+        .as_mut()
+        .ok_or_else(|| not_invariant_test("run_invariants"))?;
+    ensure!(
+        !invariant.targets.is_empty(),
+        "No contracts to call in the invariant test, add them with `target_contract`"
+    );
+
+    if invariant.steps == invariant.depth {
+        return Ok(false);
+    }
+    invariant.steps += 1;
+
+    let choices = &mut cheatnet_state.fuzzer_choices;
+    let is_called = choose_is_called(fuzzer_args_source, choices);
+    let mut choose = |min: BigInt, max: BigInt| -> Result<BigInt> {
+        let choice = choose_value(fuzzer_args_source, choices.len(), min, max);
+        let value = choice.value.clone();
+        choices.push(choice);
+        Ok(value)
+    };
+
+    let functions: Vec<_> = invariant
+        .targets
+        .iter()
+        .flat_map(|target| {
+            target
+                .functions
+                .iter()
+                .map(move |function| (target, function))
+        })
+        .collect();
+    let index = usize::try_from(choose(BigInt::ZERO, BigInt::from(functions.len() - 1))?)?;
+    let (target, function) = functions[index];
+
+    let mut addresses: Vec<Felt> = invariant
+        .targets
+        .iter()
+        .map(|target| target.address.into_())
+        .collect();
+    addresses.push(TryFromHexStr::try_from_hex_str(TEST_ADDRESS).unwrap());
+
+    let mut calldata = vec![];
+    for input_type in &function.input_types {
+        input_type.generate(&mut choose, &addresses, &mut calldata)?;
+    }
+
+    // Arguments are generated for skipped calls too, so that values of the following calls stay the same
+    if !is_called {
+        return Ok(true);
+    }
+
+    let contract_address = target.address;
+    let selector = function.selector;
+    let description = target.describe_call(function, &calldata);
+
+    let is_reverted = !execute_call(
+        syscall_handler,
+        cheatnet_state,
+        contract_address,
+        selector,
+        calldata,
+    )?;
+
+    get_invariant_state(cheatnet_state, "run_invariants")?
+        .calls
+        .push(if is_reverted {
+            format!("{description} (reverted)")
+        } else {
+            description
+        });
+
+    Ok(true)
+}
+
+fn get_invariant_state<'a>(
+    cheatnet_state: &'a mut CheatnetState,
+    function_name: &str,
+) -> Result<&'a mut InvariantState> {
+    cheatnet_state
+        .invariant
+        .as_mut()
+        .ok_or_else(|| not_invariant_test(function_name))
+}
+
+fn not_invariant_test(function_name: &str) -> anyhow::Error {
+    anyhow!(
+        "`{function_name}` can only be used in invariant tests, mark the test with #[invariant]"
+    )
+}
+
+/// Random sequences call every function they choose. Replayed sequences can skip calls,
+/// which lets shrinking remove calls that are not needed to break the invariant.
+fn choose_is_called(
+    fuzzer_args_source: Option<&FuzzerArgsSource>,
+    choices: &mut Vec<FuzzerChoice>,
+) -> bool {
+    let min = match fuzzer_args_source {
+        Some(FuzzerArgsSource::Replay(_)) => BigInt::ZERO,
+        _ => BigInt::from(1),
+    };
+
+    let mut choice = choose_value(fuzzer_args_source, choices.len(), min, BigInt::from(1));
+    choice.min = BigInt::ZERO;
+    let is_called = choice.value == BigInt::from(1);
+    choices.push(choice);
+
+    is_called
+}
+
+fn external_functions(abi: &[AbiEntry]) -> Vec<AbiFunction> {
+    abi.iter()
+        .flat_map(|entry| match entry {
+            AbiEntry::Function(function) => vec![function.clone()],
+            AbiEntry::Interface(interface) => external_functions(&interface.items),
+            _ => vec![],
+        })
+        .filter(|function| matches!(function.state_mutability, StateMutability::External))
+        .collect()
+}
+
+impl InvariantTarget {
+    fn describe_call(&self, function: &TargetFunction, calldata: &[Felt]) -> String {
+        let arguments = reverse_transform_input(calldata, &self.abi, &function.function)
+            .map_or_else(
+                |_| {
+                    calldata
+                        .iter()
+                        .map(|felt| format!("{felt:#x}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                },
+                |arguments| {
+                    arguments
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                },
+            );
+
+        format!(
+            "{}::{}({arguments})",
+            self.contract_name, function.function.name
+        )
+    }
+}
+
+/// Calls the target directly from the test address, reverting state changes of a failed call.
+/// Returns whether the call succeeded.
+fn execute_call(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    contract_address: ContractAddress,
+    selector: EntryPointSelector,
+    calldata: Vec<Felt>,
+) -> Result<bool> {
+    let mut entry_point = CallEntryPoint {
+        class_hash: None,
+        code_address: Some(contract_address),
+        entry_point_type: EntryPointType::External,
+        entry_point_selector: selector,
+        calldata: Calldata(Arc::new(calldata)),
+        storage_address: contract_address,
+        caller_address: TryFromHexStr::try_from_hex_str(TEST_ADDRESS).unwrap(),
+        call_type: CallType::Call,
+        initial_gas: i64::MAX as u64,
+    };
+
+    let events_before = cheatnet_state.detected_events.len();
+    let messages_before = cheatnet_state.detected_messages_to_l1.len();
+
+    let mut journaled_state = JournaledState::new(syscall_handler.base.state);
+    let exec_result = execute_call_entry_point(
+        &mut entry_point,
+        &mut journaled_state,
+        cheatnet_state,
+        syscall_handler.base.context,
+    );
+
+    let is_successful = match CallResult::from_execution_result(
+        &exec_result,
+        &AddressOrClassHash::ContractAddress(contract_address),
+    ) {
+        CallResult::Success { .. } => true,
+        CallResult::Failure(_) => {
+            journaled_state.revert()?;
+            cheatnet_state.detected_events.truncate(events_before);
+            cheatnet_state
+                .detected_messages_to_l1
+                .truncate(messages_before);
+            false
+        }
+    };
+
+    if let Ok(call_info) = exec_result {
+        syscall_handler.base.inner_calls.push(call_info);
+    }
+
+    Ok(is_successful)
+}
@@ -1,4 +1,6 @@
 use self::contracts_data::ContractsData;
+use self::fuzzer::FuzzerArgsSource;
+use crate::forking::multi_fork::MultiForkState;
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
 use crate::runtime_extensions::common::{get_syscalls_gas_consumed, sum_syscall_usage};
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::replace_bytecode::ReplaceBytecodeError;
@@ -6,11 +8,14 @@ use crate::runtime_extensions::{
     call_to_blockifier_runtime_extension::{
         CallToBlockifierRuntime,
         rpc::{CallFailure, CallResult},
+        transaction::{TransactionAccount, TransactionsState},
     },
     cheatable_starknet_runtime_extension::SyscallSelector,
     common::get_relocated_vm_trace,
+    forge_config_extension::config::BlockId,
     forge_runtime_extension::cheatcodes::{
         CheatcodeError,
+        cheat_execution_info::ExecutionInfoMockOperations,
         declare::declare,
         deploy::{deploy, deploy_at},
         generate_random_felt::generate_random_felt,
@@ -48,7 +53,6 @@ use conversions::felt::TryInferFormat;
 use conversions::serde::deserialize::BufferReader;
 use conversions::serde::serialize::CairoSerialize;
 use data_transformer::cairo_types::CairoU256;
-use rand::prelude::StdRng;
 use runtime::starknet::constants::TEST_CONTRACT_CLASS_HASH;
 use runtime::{
     CheatcodeHandlingResult, EnhancedHintError, ExtendedRuntime, ExtensionLogic,
@@ -60,20 +64,23 @@ use starknet_api::{contract_class::EntryPointType::L1Handler, core::ClassHash};
 use starknet_types_core::felt::Felt;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::mem;
 use std::rc::Rc;
-use std::sync::{Arc, Mutex};
+use url::Url;
 
 pub mod cheatcodes;
 pub mod contracts_data;
 mod file_operations;
-mod fuzzer;
+pub mod fuzzer;
+pub mod invariant;
 
 pub type ForgeRuntime<'a> = ExtendedRuntime<ForgeExtension<'a>>;
 
 pub struct ForgeExtension<'a> {
     pub environment_variables: &'a HashMap<String, String>,
     pub contracts_data: &'a ContractsData,
-    pub fuzzer_rng: Option<Arc<Mutex<StdRng>>>,
+    pub fuzzer_args_source: Option<FuzzerArgsSource>,
+    pub forks: MultiForkState,
 }
 
 // This runtime extension provides an implementation logic for functions from snforge_std library.
@@ -90,7 +97,10 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
         match selector {
             "is_config_mode" => Ok(CheatcodeHandlingResult::from_serializable(false)),
             "cheat_execution_info" => {
-                let execution_info = input_reader.read()?;
+                let execution_info: ExecutionInfoMockOperations = input_reader.read()?;
+                execution_info
+                    .validate()
+                    .context("Invalid cheated execution info")?;
 
                 extended_runtime
                     .extended_runtime
@@ -114,6 +124,31 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .mock_call(contract_address, function_selector, &ret_data, span);
                 Ok(CheatcodeHandlingResult::from_serializable(()))
             }
+            "mock_calls" => {
+                let target = input_reader.read()?;
+                let function_selector = input_reader.read()?;
+                let calldata = input_reader.read()?;
+                let results = input_reader.read()?;
+                let span = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .mock_calls(target, function_selector, calldata, results, span);
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "stop_mock_calls" => {
+                let target = input_reader.read()?;
+                let function_selector = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_mock_calls(target, function_selector);
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
             "stop_mock_call" => {
                 let contract_address = input_reader.read()?;
                 let function_selector = input_reader.read()?;
@@ -316,6 +351,40 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(events))
             }
+            "spy_calls" => {
+                let calls_offset = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .calls_offset();
+
+                Ok(CheatcodeHandlingResult::from_serializable(calls_offset))
+            }
+            "get_calls" => {
+                let calls_offset = input_reader.read()?;
+
+                let calls = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_calls(calls_offset);
+
+                Ok(CheatcodeHandlingResult::from_serializable(calls))
+            }
+            "expect_call" => {
+                let contract_address = input_reader.read()?;
+                let function_selector = input_reader.read()?;
+                let calldata = input_reader.read()?;
+                let count = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .expect_call(contract_address, function_selector, calldata, count);
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
             "spy_messages_to_l1" => {
                 let messages_offset = extended_runtime
                     .extended_runtime
@@ -499,6 +568,53 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     map_entry_address,
                 ))
             }
+            "start_record_storage" => {
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .start_record_storage();
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "stop_record_storage" => {
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_record_storage();
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "get_storage_accesses" => {
+                let contract_address = input_reader.read()?;
+
+                let storage_accesses = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_storage_accesses(contract_address);
+
+                Ok(CheatcodeHandlingResult::from_serializable(storage_accesses))
+            }
+            "set_test_storage_address" => {
+                let contract_address = input_reader.read()?;
+
+                // Storage syscalls made by the test code access the storage at this address,
+                // the previous one is returned so that `interact_with_state` can restore it
+                let previous_address = mem::replace(
+                    &mut extended_runtime
+                        .extended_runtime
+                        .extended_runtime
+                        .hint_handler
+                        .base
+                        .call
+                        .storage_address,
+                    contract_address,
+                );
+
+                Ok(CheatcodeHandlingResult::from_serializable(previous_address))
+            }
             "generate_random_felt" => Ok(CheatcodeHandlingResult::from_serializable(
                 generate_random_felt(),
             )),
@@ -506,9 +622,17 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                 let min_value = input_reader.read()?;
                 let max_value = input_reader.read()?;
 
-                Ok(CheatcodeHandlingResult::from_serializable(
-                    fuzzer::generate_arg(self.fuzzer_rng.clone(), min_value, max_value)?,
-                ))
+                let cheatnet_state =
+                    &mut extended_runtime.extended_runtime.extension.cheatnet_state;
+                let (value, choice) = fuzzer::generate_arg(
+                    self.fuzzer_args_source.as_ref(),
+                    cheatnet_state.fuzzer_choices.len(),
+                    min_value,
+                    max_value,
+                )?;
+                cheatnet_state.fuzzer_choices.push(choice);
+
+                Ok(CheatcodeHandlingResult::from_serializable(value))
             }
             "save_fuzzer_arg" => {
                 let arg = input_reader.read::<ByteArray>()?.to_string();
@@ -532,6 +656,99 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .cheat_block_hash(block_number, operation);
                 Ok(CheatcodeHandlingResult::from_serializable(()))
             }
+            "create_fork" => {
+                let name = input_reader.read::<ByteArray>()?.to_string();
+                let url = input_reader.read::<ByteArray>()?.to_string();
+                let block_id = input_reader.read::<BlockId>()?;
+
+                let url = Url::parse(&url).with_context(|| format!("Invalid fork url = {url}"))?;
+
+                self.forks
+                    .create_fork(&name, url, block_id)
+                    .with_context(|| format!("Failed to create fork with name = {name}"))?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "select_fork" => {
+                let name = input_reader.read::<ByteArray>()?.to_string();
+
+                let block_info = self.forks.select_fork(&name)?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .block_info = block_info;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "snapshot" => {
+                let id = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .snapshot(&self.forks)
+                    .context("Failed to take a snapshot of the state")?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(id))
+            }
+            "revert_to" => {
+                let id = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .revert_to(&self.forks, id)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "set_transaction_account" => {
+                let address = input_reader.read()?;
+                let signer = input_reader.read()?;
+
+                get_transactions_state(extended_runtime)?.account =
+                    Some(TransactionAccount { address, signer });
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "remove_transaction_account" => {
+                get_transactions_state(extended_runtime)?.account = None;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "last_transaction_receipt" => {
+                let receipt = get_transactions_state(extended_runtime)?
+                    .last_receipt
+                    .clone();
+
+                Ok(CheatcodeHandlingResult::from_serializable(receipt))
+            }
+            "target_contract" => {
+                let contract_address = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let state = &mut cheatnet_runtime.extended_runtime.hint_handler.base.state;
+                invariant::target_contract(
+                    *state,
+                    cheatnet_runtime.extension.cheatnet_state,
+                    self.contracts_data,
+                    contract_address,
+                )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "invariant_step" => {
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let syscall_handler = &mut cheatnet_runtime.extended_runtime.hint_handler;
+                let has_next_call = invariant::invariant_step(
+                    syscall_handler,
+                    cheatnet_runtime.extension.cheatnet_state,
+                    self.fuzzer_args_source.as_ref(),
+                )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(has_next_call))
+            }
             _ => Ok(CheatcodeHandlingResult::Forwarded),
         }
     }
@@ -557,6 +774,22 @@ enum SignError {
     HashOutOfRange,
 }
 
+fn get_transactions_state<'a>(
+    extended_runtime: &'a mut CallToBlockifierRuntime,
+) -> Result<&'a mut TransactionsState> {
+    extended_runtime
+        .extended_runtime
+        .extension
+        .cheatnet_state
+        .transactions
+        .as_mut()
+        .ok_or_else(|| {
+            anyhow!(
+                "Transactions can only be sent in transactional tests, mark the test with #[transactional] or set `transactional = true` in [tool.snforge]"
+            )
+        })
+}
+
 fn handle_declare_deploy_result<T: CairoSerialize>(
     declare_result: Result<T, CheatcodeError>,
 ) -> Result<CheatcodeHandlingResult, EnhancedHintError> {
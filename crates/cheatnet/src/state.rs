@@ -1,11 +1,22 @@
 use crate::constants::build_test_entry_point;
 use crate::forking::state::ForkStateReader;
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::CallResult;
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::transaction::{
+    TransactionAccount, TransactionsState,
+};
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
     ExecutionInfoMock, ResourceBounds,
 };
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::{
+    FunctionMocks, MockTarget,
+};
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::record_storage::StorageRecording;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::snapshot::Snapshot;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_calls::ExpectedCall;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_messages_to_l1::MessageToL1;
+use crate::runtime_extensions::forge_runtime_extension::fuzzer::FuzzerChoice;
+use crate::runtime_extensions::forge_runtime_extension::invariant::InvariantState;
 use blockifier::execution::call_info::OrderedL2ToL1Message;
 use blockifier::execution::contract_class::RunnableCompiledClass;
 use blockifier::execution::entry_point::CallEntryPoint;
@@ -22,7 +33,7 @@ use conversions::string::TryFromHexStr;
 use runtime::starknet::constants::TEST_CONTRACT_CLASS_HASH;
 use runtime::starknet::context::SerializableBlockInfo;
 use runtime::starknet::state::DictStateReader;
-use starknet_api::block::BlockInfo;
+use starknet_api::block::{BlockInfo, BlockNumber, BlockTimestamp};
 use starknet_api::core::{ChainId, EntryPointSelector};
 use starknet_api::transaction::fields::ContractAddressSalt;
 use starknet_api::{
@@ -32,7 +43,10 @@ use starknet_api::{
 use starknet_types_core::felt::Felt;
 use std::cell::{Ref, RefCell};
 use std::collections::HashMap;
+use std::ops::Range;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 // Specifies the duration of the cheat
 #[derive(CairoDeserialize, Copy, Clone, Debug, PartialEq, Eq)]
@@ -167,6 +181,10 @@ pub struct CallTrace {
     pub entry_point: CallEntryPoint,
     pub nested_calls: Vec<CallTraceNode>,
     pub result: CallResult,
+    /// Storage reads and writes made by the call itself, not including internal calls
+    pub storage_accesses: Vec<StorageAccess>,
+    /// Events emitted by the call itself, not including internal calls
+    pub events: Vec<Event>,
     // serialize end
 
     // These also include resources used by internal calls
@@ -190,6 +208,8 @@ impl CairoSerialize for CallTrace {
         visible_calls.serialize(output);
 
         self.result.serialize(output);
+        self.storage_accesses.serialize(output);
+        self.events.serialize(output);
     }
 }
 
@@ -203,12 +223,32 @@ impl CallTrace {
             used_syscalls: SyscallUsageMap::default(),
             nested_calls: vec![],
             result: CallResult::Success { ret_data: vec![] },
+            storage_accesses: vec![],
+            events: vec![],
             vm_trace: None,
             gas_consumed: u64::default(),
         }
     }
 }
 
+/// Read or write of a storage slot made by a call
+#[derive(Debug, Clone, PartialEq, CairoSerialize)]
+pub struct StorageAccess {
+    pub kind: StorageAccessKind,
+    pub contract_address: ContractAddress,
+    pub key: Felt,
+    /// Value of the slot before the access
+    pub old_value: Felt,
+    /// Value of the slot after the access, equal to `old_value` for reads
+    pub new_value: Felt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CairoSerialize)]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+}
+
 /// Enum representing node of a trace of a call.
 #[derive(Clone, Debug)]
 pub enum CallTraceNode {
@@ -326,8 +366,7 @@ pub struct CheatnetState {
     pub cheated_execution_info_contracts: HashMap<ContractAddress, ExecutionInfoMock>,
     pub global_cheated_execution_info: ExecutionInfoMock,
 
-    pub mocked_functions:
-        HashMap<ContractAddress, HashMap<EntryPointSelector, CheatStatus<Vec<Felt>>>>,
+    pub mocked_functions: HashMap<MockTarget, HashMap<EntryPointSelector, FunctionMocks>>,
     pub replaced_bytecode_contracts: HashMap<ContractAddress, ClassHash>,
     pub detected_events: Vec<Event>,
     pub detected_messages_to_l1: Vec<MessageToL1>,
@@ -336,8 +375,18 @@ pub struct CheatnetState {
     pub trace_data: TraceData,
     pub encountered_errors: Vec<EncounteredError>,
     pub fuzzer_args: Vec<String>,
+    pub fuzzer_choices: Vec<FuzzerChoice>,
     pub block_hash_contracts: HashMap<(ContractAddress, u64), (CheatSpan, Felt)>,
     pub global_block_hash: HashMap<u64, (Felt, Vec<ContractAddress>)>,
+    pub transactions: Option<TransactionsState>,
+    pub invariant: Option<InvariantState>,
+    pub snapshots: Vec<Snapshot>,
+    pub expected_calls: Vec<ExpectedCall>,
+    pub storage_recording: StorageRecording,
+    /// Calls made directly by the test which were reverted with `revert_to`, by their indices
+    pub reverted_calls: Vec<Range<usize>>,
+    /// Set from another thread to stop the execution of the test, e.g. when it times out
+    pub interrupted: Arc<AtomicBool>,
 }
 
 impl Default for CheatnetState {
@@ -365,13 +414,26 @@ impl Default for CheatnetState {
             },
             encountered_errors: vec![],
             fuzzer_args: Vec::default(),
+            fuzzer_choices: Vec::default(),
             block_hash_contracts: HashMap::default(),
             global_block_hash: HashMap::default(),
+            transactions: None,
+            invariant: None,
+            snapshots: vec![],
+            expected_calls: vec![],
+            storage_recording: StorageRecording::default(),
+            reverted_calls: vec![],
+            interrupted: Arc::default(),
         }
     }
 }
 
 impl CheatnetState {
+    #[must_use]
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::Relaxed)
+    }
+
     #[must_use]
     pub fn create_cheated_data(&mut self, contract_address: ContractAddress) -> CheatedData {
         let execution_info = self.get_cheated_execution_info_for_contract(contract_address);
@@ -456,6 +518,36 @@ impl CheatnetState {
             .as_value()
     }
 
+    /// Returns the block info with the block-level cheats applied to `address`
+    #[must_use]
+    pub fn get_cheated_block_info(&mut self, address: ContractAddress) -> BlockInfo {
+        let block_info_mock = self
+            .get_cheated_execution_info_for_contract(address)
+            .block_info
+            .clone();
+        let mut block_info = self.block_info.clone();
+
+        if let Some(block_number) = block_info_mock.block_number.as_value() {
+            block_info.block_number = BlockNumber(block_number);
+        }
+        if let Some(block_timestamp) = block_info_mock.block_timestamp.as_value() {
+            block_info.block_timestamp = BlockTimestamp(block_timestamp);
+        }
+        if let Some(sequencer_address) = block_info_mock.sequencer_address.as_value() {
+            block_info.sequencer_address = sequencer_address;
+        }
+        if let Some(gas_prices) = block_info_mock.gas_prices.as_value() {
+            block_info.gas_prices = (&gas_prices)
+                .try_into()
+                .expect("Cheated gas prices should be validated in `cheat_execution_info`");
+        }
+        if let Some(use_kzg_da) = block_info_mock.use_kzg_da.as_value() {
+            block_info.use_kzg_da = use_kzg_da;
+        }
+
+        block_info
+    }
+
     #[must_use]
     pub fn get_cheated_caller_address(
         &mut self,
@@ -473,6 +565,20 @@ impl CheatnetState {
     pub fn update_fuzzer_args(&mut self, arg: String) {
         self.fuzzer_args.push(arg);
     }
+
+    /// Account that calls from the test are sent from, if the test runs in transactional mode
+    #[must_use]
+    pub fn transaction_account(&self) -> Option<TransactionAccount> {
+        self.transactions
+            .as_ref()
+            .and_then(|transactions| transactions.account.clone())
+    }
+
+    pub fn transactions_mut(&mut self) -> &mut TransactionsState {
+        self.transactions
+            .as_mut()
+            .expect("Transactions state is only used in transactional mode")
+    }
 }
 
 impl TraceData {
@@ -526,6 +632,21 @@ impl TraceData {
         last_call.vm_trace = vm_trace;
     }
 
+    pub fn add_storage_access(&mut self, storage_access: StorageAccess) {
+        let current_call = self.current_call_stack.top();
+
+        current_call
+            .borrow_mut()
+            .storage_accesses
+            .push(storage_access);
+    }
+
+    pub fn add_event(&mut self, event: Event) {
+        let current_call = self.current_call_stack.top();
+
+        current_call.borrow_mut().events.push(event);
+    }
+
     pub fn add_deploy_without_constructor_node(&mut self) {
         let current_call = self.current_call_stack.top();
 
@@ -0,0 +1,145 @@
+use crate::cheatcodes::test_environment::TestEnvironment;
+use crate::common::get_contracts;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
+    BlockGasPrices, BlockInfoMockOperations, ExecutionInfoMockOperations, GasPrices, Operation,
+};
+use cheatnet::state::CheatSpan;
+use runtime::starknet::context::SerializableBlockInfo;
+use starknet_api::block::{self, BlockInfo};
+use starknet_types_core::felt::Felt;
+
+fn gas_prices(price: u128) -> BlockGasPrices {
+    let gas_prices = GasPrices {
+        l1_gas_price: price,
+        l1_data_gas_price: price,
+        l2_gas_price: price,
+    };
+
+    BlockGasPrices {
+        eth: gas_prices.clone(),
+        strk: gas_prices,
+    }
+}
+
+fn default_block_info() -> BlockInfo {
+    SerializableBlockInfo::default().into()
+}
+
+#[test]
+fn cheat_block_gas_prices_simple() {
+    let mut test_env = TestEnvironment::new();
+    let contracts_data = get_contracts();
+
+    let class_hash = test_env.declare("HelloStarknet", &contracts_data);
+    let contract_address = test_env.deploy_wrapper(&class_hash, &[]);
+    let other_contract_address = test_env.deploy_wrapper(&class_hash, &[]);
+
+    test_env
+        .cheatnet_state
+        .start_cheat_block_gas_prices(contract_address, gas_prices(123));
+
+    let block_info = test_env
+        .cheatnet_state
+        .get_cheated_block_info(contract_address);
+    assert_eq!(
+        block_info.gas_prices,
+        block::GasPrices::try_from(&gas_prices(123)).unwrap()
+    );
+    assert_eq!(
+        test_env
+            .cheatnet_state
+            .get_cheated_block_info(other_contract_address)
+            .gas_prices,
+        default_block_info().gas_prices
+    );
+
+    test_env
+        .cheatnet_state
+        .stop_cheat_block_gas_prices(contract_address);
+
+    assert_eq!(
+        test_env
+            .cheatnet_state
+            .get_cheated_block_info(contract_address)
+            .gas_prices,
+        default_block_info().gas_prices
+    );
+}
+
+#[test]
+fn cheat_block_gas_prices_global() {
+    let mut test_env = TestEnvironment::new();
+    let contracts_data = get_contracts();
+
+    let class_hash = test_env.declare("HelloStarknet", &contracts_data);
+    let contract_address = test_env.deploy_wrapper(&class_hash, &[]);
+
+    test_env
+        .cheatnet_state
+        .start_cheat_block_gas_prices_global(gas_prices(123));
+    test_env.cheatnet_state.start_cheat_use_kzg_da_global(false);
+
+    let block_info = test_env
+        .cheatnet_state
+        .get_cheated_block_info(contract_address);
+    assert_eq!(
+        block_info.gas_prices,
+        block::GasPrices::try_from(&gas_prices(123)).unwrap()
+    );
+    assert!(!block_info.use_kzg_da);
+
+    test_env.cheatnet_state.stop_cheat_block_gas_prices_global();
+    test_env.cheatnet_state.stop_cheat_use_kzg_da_global();
+
+    let block_info = test_env
+        .cheatnet_state
+        .get_cheated_block_info(contract_address);
+    assert_eq!(block_info.gas_prices, default_block_info().gas_prices);
+    assert!(block_info.use_kzg_da);
+}
+
+#[test]
+fn cheat_block_gas_prices_with_span() {
+    let mut test_env = TestEnvironment::new();
+    let contracts_data = get_contracts();
+
+    let class_hash = test_env.declare("HelloStarknet", &contracts_data);
+    let contract_address = test_env.deploy_wrapper(&class_hash, &[]);
+
+    test_env.cheatnet_state.cheat_block_gas_prices(
+        contract_address,
+        gas_prices(123),
+        CheatSpan::TargetCalls(1),
+    );
+
+    assert_eq!(
+        test_env
+            .cheatnet_state
+            .get_cheated_block_info(contract_address)
+            .gas_prices,
+        block::GasPrices::try_from(&gas_prices(123)).unwrap()
+    );
+
+    test_env.call_contract(&contract_address, "increase_balance", &[Felt::from(5)]);
+
+    assert_eq!(
+        test_env
+            .cheatnet_state
+            .get_cheated_block_info(contract_address)
+            .gas_prices,
+        default_block_info().gas_prices
+    );
+}
+
+#[test]
+fn zero_gas_prices_are_rejected() {
+    let execution_info = ExecutionInfoMockOperations {
+        block_info: BlockInfoMockOperations {
+            gas_prices: Operation::StartGlobal(gas_prices(0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert!(execution_info.validate().is_err());
+}
@@ -1,5 +1,5 @@
 use super::test_environment::TestEnvironment;
-use crate::common::assertions::ClassHashAssert;
+use crate::common::assertions::{ClassHashAssert, assert_panic};
 use crate::common::state::create_cached_state;
 use crate::common::{call_contract, deploy_wrapper};
 use crate::common::{felt_selector_from_name, recover_data};
@@ -8,8 +8,12 @@ use crate::{
     common::{deploy_contract, get_contracts},
 };
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::declare::declare;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::{
+    CalldataMatcher, MockTarget, MockedResult,
+};
 use cheatnet::state::{CheatSpan, CheatnetState};
 use conversions::IntoConv;
+use conversions::felt::FromShortString;
 use starknet::core::utils::get_selector_from_name;
 use starknet_api::core::ContractAddress;
 use starknet_types_core::felt::Felt;
@@ -792,3 +796,135 @@ fn mock_call_override_span() {
         &[111.into()],
     );
 }
+
+#[test]
+fn mock_call_matching_calldata() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("MockChecker", &[111.into()]);
+    let selector = felt_selector_from_name("get_thing");
+    let target = MockTarget::Contract(contract_address);
+
+    test_env.cheatnet_state.mock_calls(
+        target,
+        selector,
+        CalldataMatcher::Exact(vec![1.into()]),
+        vec![MockedResult::Return(vec![222.into()])],
+        CheatSpan::Indefinite,
+    );
+    test_env.cheatnet_state.mock_calls(
+        target,
+        selector,
+        CalldataMatcher::Prefix(vec![1.into()]),
+        vec![MockedResult::Return(vec![333.into()])],
+        CheatSpan::Indefinite,
+    );
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_thing", &[1.into()]),
+        &[222.into()],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_thing", &[1.into(), 2.into()]),
+        &[333.into()],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_thing", &[]),
+        &[111.into()],
+    );
+
+    test_env.cheatnet_state.stop_mock_calls(target, selector);
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_thing", &[]),
+        &[111.into()],
+    );
+}
+
+#[test]
+fn mock_call_revert() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("MockChecker", &[111.into()]);
+
+    test_env.cheatnet_state.mock_calls(
+        MockTarget::Contract(contract_address),
+        felt_selector_from_name("get_thing"),
+        CalldataMatcher::Any,
+        vec![MockedResult::Revert(vec![
+            Felt::from_short_string("error").unwrap(),
+        ])],
+        CheatSpan::TargetCalls(1),
+    );
+
+    assert_panic(
+        test_env.call_contract(&contract_address, "get_thing", &[]),
+        &[Felt::from_short_string("error").unwrap()],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_thing", &[]),
+        &[111.into()],
+    );
+}
+
+#[test]
+fn mock_call_sequence() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("MockChecker", &[111.into()]);
+
+    test_env.cheatnet_state.mock_calls(
+        MockTarget::Contract(contract_address),
+        felt_selector_from_name("get_thing"),
+        CalldataMatcher::Any,
+        vec![
+            MockedResult::Return(vec![222.into()]),
+            MockedResult::Revert(vec![333.into()]),
+            MockedResult::Return(vec![444.into()]),
+        ],
+        CheatSpan::Indefinite,
+    );
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_thing", &[]),
+        &[222.into()],
+    );
+    assert_panic(
+        test_env.call_contract(&contract_address, "get_thing", &[]),
+        &[333.into()],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_thing", &[]),
+        &[444.into()],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_thing", &[]),
+        &[444.into()],
+    );
+}
+
+#[test]
+fn mock_library_call() {
+    let mut test_env = TestEnvironment::new();
+
+    let contracts_data = get_contracts();
+    let class_hash = test_env.declare("MockChecker", &contracts_data);
+    let lib_call_address = test_env.deploy("MockCheckerLibCall", &[]);
+
+    test_env.cheatnet_state.mock_calls(
+        MockTarget::LibraryCall(class_hash),
+        felt_selector_from_name("get_constant_thing"),
+        CalldataMatcher::Any,
+        vec![MockedResult::Return(vec![123.into()])],
+        CheatSpan::Indefinite,
+    );
+
+    assert_success(
+        test_env.call_contract(
+            &lib_call_address,
+            "get_constant_thing_with_lib_call",
+            &[class_hash.into_()],
+        ),
+        &[123.into()],
+    );
+}
@@ -5,6 +5,7 @@ use starknet_types_core::felt::Felt;
 
 mod test_environment;
 
+mod cheat_block_gas_prices;
 mod cheat_block_hash;
 mod cheat_block_number;
 mod cheat_block_timestamp;
@@ -19,6 +20,7 @@ mod load;
 mod mock_call;
 mod multiple_writes_same_storage;
 mod precalculate_address;
+mod record_storage;
 mod replace_bytecode;
 mod spy_events;
 mod store;
@@ -0,0 +1,84 @@
+use crate::cheatcodes::test_environment::TestEnvironment;
+use crate::cheatcodes::{map_entry_address, variable_address};
+use crate::common::get_contracts;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::record_storage::StorageAccesses;
+use starknet_types_core::felt::Felt;
+
+#[test]
+fn record_storage_reads_and_writes() {
+    let mut test_env = TestEnvironment::new();
+    let contracts_data = get_contracts();
+
+    let class_hash = test_env.declare("HelloStarknet", &contracts_data);
+    let contract_address = test_env.deploy_wrapper(&class_hash, &[]);
+    let other_contract_address = test_env.deploy_wrapper(&class_hash, &[]);
+
+    test_env.cheatnet_state.start_record_storage();
+
+    test_env.call_contract(&contract_address, "increase_balance", &[Felt::from(420)]);
+    test_env.call_contract(&contract_address, "get_balance", &[]);
+
+    assert_eq!(
+        test_env
+            .cheatnet_state
+            .get_storage_accesses(contract_address),
+        StorageAccesses {
+            reads: vec![variable_address("balance")],
+            writes: vec![variable_address("balance")],
+        }
+    );
+    assert_eq!(
+        test_env
+            .cheatnet_state
+            .get_storage_accesses(other_contract_address),
+        StorageAccesses::default()
+    );
+}
+
+#[test]
+fn record_storage_only_while_active() {
+    let mut test_env = TestEnvironment::new();
+    let contracts_data = get_contracts();
+
+    let class_hash = test_env.declare("MapSimpleValueSimpleKey", &contracts_data);
+    let contract_address = test_env.deploy_wrapper(&class_hash, &[]);
+
+    test_env.call_contract(
+        &contract_address,
+        "insert",
+        &[Felt::from(1), Felt::from(10)],
+    );
+
+    test_env.cheatnet_state.start_record_storage();
+    test_env.call_contract(
+        &contract_address,
+        "insert",
+        &[Felt::from(2), Felt::from(20)],
+    );
+    test_env.cheatnet_state.stop_record_storage();
+
+    test_env.call_contract(
+        &contract_address,
+        "insert",
+        &[Felt::from(3), Felt::from(30)],
+    );
+
+    assert_eq!(
+        test_env
+            .cheatnet_state
+            .get_storage_accesses(contract_address),
+        StorageAccesses {
+            reads: vec![],
+            writes: vec![map_entry_address("values", &[Felt::from(2)])],
+        }
+    );
+
+    // Starting the recording again discards accesses recorded before
+    test_env.cheatnet_state.start_record_storage();
+    assert_eq!(
+        test_env
+            .cheatnet_state
+            .get_storage_accesses(contract_address),
+        StorageAccesses::default()
+    );
+}
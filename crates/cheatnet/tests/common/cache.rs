@@ -1,10 +1,17 @@
+use camino::Utf8Path;
+use cheatnet::forking::cache::save_fork_caches;
+use flate2::read::MultiGzDecoder;
 use glob::glob;
 use serde_json::{Map, Value};
 use std::fs;
+use std::fs::File;
+use std::io::BufReader;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+/// Reads the cache file, merging entries of all its records into a single object
 pub fn read_cache(file_pattern: &str) -> Map<String, Value> {
+    save_fork_caches(Utf8Path::new(file_pattern).parent().unwrap());
     let mut cache_files = glob(file_pattern).unwrap().filter_map(Result::ok);
 
     let cache_file = match (cache_files.next(), cache_files.next()) {
@@ -13,15 +20,35 @@ pub fn read_cache(file_pattern: &str) -> Map<String, Value> {
         _ => panic!("Multiple matching cache files found"),
     };
 
-    let cache_content = fs::read_to_string(cache_file).expect("Could not read cache");
-    let parsed_cache_content: Value =
-        serde_json::from_str(&cache_content).expect("Could not parse cache");
-    parsed_cache_content
+    let file = File::open(cache_file).expect("Could not read cache");
+    let records = serde_json::Deserializer::from_reader(MultiGzDecoder::new(BufReader::new(file)))
+        .into_iter::<Value>();
+
+    let mut cache_content = Value::Object(Map::new());
+    for record in records {
+        let record = record.expect("Could not parse cache");
+        if let Some(entries) = record.get("entries") {
+            merge(&mut cache_content, entries.clone());
+        }
+    }
+    cache_content
         .as_object()
         .expect("Parsed cache is not an object")
         .clone()
 }
 
+fn merge(target: &mut Value, other: Value) {
+    match (target, other) {
+        (Value::Object(target), Value::Object(other)) => {
+            for (key, value) in other {
+                merge(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (target, Value::Null) if !target.is_null() => {}
+        (target, other) => *target = other,
+    }
+}
+
 pub fn purge_cache(directory: &str) {
     fs::remove_dir_all(PathBuf::from_str(directory).expect("Could not parse cache path"))
         .expect("Could not remove cache directory");
@@ -24,7 +24,14 @@ pub fn create_fork_cached_state_at(
     CachedState::new(ExtendedStateReader {
         dict_state_reader: build_testing_state(),
         fork_state_reader: Some(
-            ForkStateReader::new(node_url, BlockNumber(block_number), cache_dir.into()).unwrap(),
+            ForkStateReader::new(
+                node_url,
+                BlockNumber(block_number),
+                cache_dir.into(),
+                false,
+                false,
+            )
+            .unwrap(),
         ),
     })
 }
@@ -3,11 +3,16 @@ use crate::common::cache::{purge_cache, read_cache};
 use crate::common::state::{create_fork_cached_state, create_fork_cached_state_at};
 use crate::common::{call_contract, deploy_contract, deploy_wrapper, felt_selector_from_name};
 use blockifier::state::cached_state::CachedState;
+use blockifier::state::errors::StateError;
+use blockifier::state::state_api::StateReader;
 use cairo_vm::vm::errors::hint_errors::HintError;
 use camino::Utf8Path;
 use cheatnet::constants::build_testing_state;
-use cheatnet::forking::cache::cache_version;
+use cheatnet::forking::cache::{
+    export_fork_caches, import_fork_caches, list_fork_caches, save_fork_caches,
+};
 use cheatnet::forking::state::ForkStateReader;
+use cheatnet::forking::state_dump::state_dump_block_number;
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::CheatcodeError;
 use cheatnet::state::{BlockInfoReader, CheatnetState, ExtendedStateReader};
 use conversions::IntoConv;
@@ -16,10 +21,14 @@ use conversions::string::TryFromHexStr;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 use runtime::EnhancedHintError;
 use serde_json::Value;
+use shared::test_utils::node_url::node_rpc_url;
 use starknet_api::block::BlockNumber;
 use starknet_api::core::ContractAddress;
+use starknet_api::state::StorageKey;
 use starknet_types_core::felt::Felt;
+use std::fs;
 use tempfile::TempDir;
+use url::Url;
 
 #[test]
 fn fork_simple() {
@@ -404,13 +413,7 @@ fn using_specified_block_nb_is_cached() {
 
     let assert_cache = || {
         // Assertions
-        let cache = read_cache(
-            cache_dir
-                .path()
-                .join(format!("*v{}.json", cache_version()))
-                .to_str()
-                .unwrap(),
-        );
+        let cache = read_cache(cache_dir.path().join("*.fork_cache").to_str().unwrap());
         assert_eq!(
             cache["storage_at"].as_object().unwrap()
                 ["0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9"]
@@ -484,13 +487,7 @@ fn test_cache_merging() {
 
     let assert_cache = || {
         // Assertions
-        let cache = read_cache(
-            cache_dir
-                .path()
-                .join(format!("*v{}.json", cache_version()))
-                .to_str()
-                .unwrap(),
-        );
+        let cache = read_cache(cache_dir.path().join("*.fork_cache").to_str().unwrap());
 
         let contract_1_class_hash =
             "0x6a7eb29ee38b0a0b198e39ed6ad458d2e460264b463351a0acfc05822d61550";
@@ -597,13 +594,7 @@ fn test_cached_block_info_merging() {
 
     let assert_cached_block_info = |is_block_info_cached: bool| {
         // Assertions
-        let cache = read_cache(
-            cache_dir
-                .path()
-                .join(format!("*v{}.json", cache_version()))
-                .to_str()
-                .unwrap(),
-        );
+        let cache = read_cache(cache_dir.path().join("*.fork_cache").to_str().unwrap());
         if is_block_info_cached {
             assert_eq!(
                 cache["block_info"].as_object().unwrap()["block_number"]
@@ -646,6 +637,8 @@ fn test_calling_nonexistent_url() {
                 nonexistent_url,
                 BlockNumber(1),
                 Utf8Path::from_path(temp_dir.path()).unwrap(),
+                false,
+                false,
             )
             .unwrap(),
         ),
@@ -672,3 +665,357 @@ fn test_calling_nonexistent_url() {
         "Unable to reach the node. Check your internet connection and node url",
     );
 }
+
+#[test]
+fn offline_fork_is_served_from_cache() {
+    let cache_dir = TempDir::new().unwrap();
+    let cache_dir_path = Utf8Path::from_path(cache_dir.path()).unwrap();
+    let contract_address = ContractAddress::try_from_hex_str(
+        "0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9",
+    )
+    .unwrap();
+    let selector = felt_selector_from_name("get_balance");
+
+    // Warm the cache
+    {
+        let mut cached_state = create_fork_cached_state_at(53_669, cache_dir_path.as_str());
+        let _ = cached_state.state.get_block_info().unwrap();
+        let output = call_contract(
+            &mut cached_state,
+            &mut CheatnetState::default(),
+            &contract_address,
+            selector,
+            &[],
+        );
+        assert_success(output, &[Felt::from(0)]);
+    }
+    save_fork_caches(cache_dir_path);
+
+    let fork_state_reader = ForkStateReader::new(
+        node_rpc_url(),
+        BlockNumber(53_669),
+        cache_dir_path,
+        true,
+        false,
+    )
+    .unwrap();
+    let mut cached_state = CachedState::new(ExtendedStateReader {
+        dict_state_reader: build_testing_state(),
+        fork_state_reader: Some(fork_state_reader),
+    });
+
+    let output = call_contract(
+        &mut cached_state,
+        &mut CheatnetState::default(),
+        &contract_address,
+        selector,
+        &[],
+    );
+    assert_success(output, &[Felt::from(0)]);
+
+    let error = cached_state
+        .state
+        .fork_state_reader
+        .as_ref()
+        .unwrap()
+        .get_storage_at(contract_address, StorageKey::try_from(Felt::ONE).unwrap())
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        StateError::StateReadError(msg) if msg == "cache miss: storage key 0x1 of contract 0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9 at block 53669"
+    ));
+
+    purge_cache(cache_dir_path.as_str());
+}
+
+#[test]
+fn offline_fork_without_cache_fails() {
+    let cache_dir = TempDir::new().unwrap();
+
+    let error = ForkStateReader::new(
+        node_rpc_url(),
+        BlockNumber(53_669),
+        Utf8Path::from_path(cache_dir.path()).unwrap(),
+        true,
+        false,
+    )
+    .unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .starts_with("cache miss: no fork cache for url")
+    );
+}
+
+#[test]
+fn offline_fork_with_corrupted_cache_fails() {
+    let url: Url = "http://nonexistent-node-address.com".parse().unwrap();
+    let cache_dir = TempDir::new().unwrap();
+    let cache_dir_path = Utf8Path::from_path(cache_dir.path()).unwrap();
+
+    // Creates an empty cache file
+    ForkStateReader::new(url.clone(), BlockNumber(123), cache_dir_path, false, false).unwrap();
+    for entry in fs::read_dir(cache_dir_path).unwrap() {
+        fs::write(entry.unwrap().path(), "corrupted").unwrap();
+    }
+
+    let error =
+        ForkStateReader::new(url, BlockNumber(123), cache_dir_path, true, false).unwrap_err();
+
+    assert!(error.to_string().contains("is corrupted"));
+}
+
+#[test]
+fn legacy_cache_is_migrated_exported_and_imported() {
+    let url: Url = "http://nonexistent-node-address.com".parse().unwrap();
+    let sanitized_url = url
+        .as_str()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+    let cache_dir = TempDir::new().unwrap();
+    let cache_dir_path = Utf8Path::from_path(cache_dir.path()).unwrap();
+
+    fs::write(
+        cache_dir_path.join(format!("{sanitized_url}_123_v0_39_0.json")),
+        r#"{"cache_version":"0_39_0","storage_at":{"0x1":{"0x2":"0x14d"}},"nonce_at":{},"class_hash_at":{},"compiled_contract_class":{},"block_info":null}"#,
+    )
+    .unwrap();
+
+    let read_storage = |cache_dir: &Utf8Path| {
+        ForkStateReader::new(url.clone(), BlockNumber(123), cache_dir, true, false)
+            .unwrap()
+            .get_storage_at(
+                ContractAddress::try_from_hex_str("0x1").unwrap(),
+                StorageKey::try_from(Felt::TWO).unwrap(),
+            )
+            .unwrap()
+    };
+
+    assert_eq!(read_storage(cache_dir_path), Felt::from(333));
+
+    let cache_files = list_fork_caches(cache_dir_path).unwrap();
+    assert_eq!(cache_files.len(), 1);
+    assert_eq!(cache_files[0].url, Some(url.clone()));
+    assert_eq!(cache_files[0].block_number, BlockNumber(123));
+    assert!(cache_files[0].path.as_str().ends_with("_123.fork_cache"));
+
+    let bundle_dir = TempDir::new().unwrap();
+    let bundle = Utf8Path::from_path(bundle_dir.path())
+        .unwrap()
+        .join("bundle.gz");
+    assert_eq!(export_fork_caches(&cache_files, &bundle).unwrap(), 1);
+
+    let imported_cache_dir = TempDir::new().unwrap();
+    let imported_cache_dir_path = Utf8Path::from_path(imported_cache_dir.path()).unwrap();
+    assert_eq!(
+        import_fork_caches(&bundle, imported_cache_dir_path).unwrap(),
+        1
+    );
+    assert_eq!(read_storage(imported_cache_dir_path), Felt::from(333));
+}
+
+#[test]
+fn corrupted_cache_is_discarded() {
+    let url: Url = "http://nonexistent-node-address.com".parse().unwrap();
+    let sanitized_url = url
+        .as_str()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+    let cache_dir = TempDir::new().unwrap();
+    let cache_dir_path = Utf8Path::from_path(cache_dir.path()).unwrap();
+
+    fs::write(
+        cache_dir_path.join(format!("{sanitized_url}_123.fork_cache")),
+        "not a fork cache",
+    )
+    .unwrap();
+
+    let error = ForkStateReader::new(url, BlockNumber(123), cache_dir_path, true, false)
+        .unwrap()
+        .get_storage_at(
+            ContractAddress::try_from_hex_str("0x1").unwrap(),
+            StorageKey::try_from(Felt::TWO).unwrap(),
+        )
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        StateError::StateReadError(msg) if msg == "cache miss: storage key 0x2 of contract 0x1 at block 123"
+    ));
+}
+
+#[test]
+fn fork_from_state_dump() {
+    let dump_dir = TempDir::new().unwrap();
+    let dump_path = dump_dir.path().join("state.json");
+    fs::write(
+        &dump_path,
+        r#"{"format_version": 1, "block_number": 123, "state": {"storage_at": {"0x1": {"0x2": "0x14d"}}}}"#,
+    )
+    .unwrap();
+    let url = Url::from_file_path(&dump_path).unwrap();
+    let cache_dir = Utf8Path::from_path(dump_dir.path()).unwrap();
+
+    assert_eq!(state_dump_block_number(&url).unwrap(), BlockNumber(123));
+
+    let fork_state_reader =
+        ForkStateReader::new(url.clone(), BlockNumber(123), cache_dir, false, false).unwrap();
+    let contract_address = ContractAddress::try_from_hex_str("0x1").unwrap();
+
+    let value = fork_state_reader
+        .get_storage_at(contract_address, StorageKey::try_from(Felt::TWO).unwrap())
+        .unwrap();
+    assert_eq!(value, Felt::from(333));
+
+    let missing_value = fork_state_reader
+        .get_storage_at(contract_address, StorageKey::try_from(Felt::THREE).unwrap())
+        .unwrap();
+    assert_eq!(missing_value, Felt::ZERO);
+
+    assert!(ForkStateReader::new(url, BlockNumber(124), cache_dir, false, false).is_err());
+}
+
+#[test]
+fn fork_from_partial_state_dump() {
+    let dump_dir = TempDir::new().unwrap();
+    let dump_path = dump_dir.path().join("state.json");
+    fs::write(
+        &dump_path,
+        r#"{"format_version": 1, "block_number": 123, "partial": true, "state": {"storage_at": {"0x1": {"0x2": "0x14d"}}}}"#,
+    )
+    .unwrap();
+    let url = Url::from_file_path(&dump_path).unwrap();
+    let cache_dir = Utf8Path::from_path(dump_dir.path()).unwrap();
+
+    let fork_state_reader =
+        ForkStateReader::new(url, BlockNumber(123), cache_dir, false, false).unwrap();
+    let contract_address = ContractAddress::try_from_hex_str("0x1").unwrap();
+
+    let value = fork_state_reader
+        .get_storage_at(contract_address, StorageKey::try_from(Felt::TWO).unwrap())
+        .unwrap();
+    assert_eq!(value, Felt::from(333));
+
+    let missing_storage = fork_state_reader
+        .get_storage_at(contract_address, StorageKey::try_from(Felt::THREE).unwrap())
+        .unwrap_err();
+    assert!(missing_storage.to_string().contains("not in state dump"));
+
+    let missing_class_hash = fork_state_reader
+        .get_class_hash_at(ContractAddress::try_from_hex_str("0x5").unwrap())
+        .unwrap_err();
+    assert!(missing_class_hash.to_string().contains("not in state dump"));
+}
+
+#[test]
+fn only_entries_read_by_tests_are_saved_when_prefetching() {
+    let cache_dir = TempDir::new().unwrap();
+    let cache_dir_path = Utf8Path::from_path(cache_dir.path()).unwrap();
+    let contract_address = ContractAddress::try_from_hex_str(
+        "0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9",
+    )
+    .unwrap();
+    let key = StorageKey::try_from(
+        Felt::from_hex("0x206f38f7e4f15e87567361213c28f235cccdaa1d7fd34c9db1dfe9489c6a091")
+            .unwrap(),
+    )
+    .unwrap();
+
+    // Previous run at an older block
+    {
+        let mut cached_state = create_fork_cached_state_at(53_669, cache_dir_path.as_str());
+        let output = call_contract(
+            &mut cached_state,
+            &mut CheatnetState::default(),
+            &contract_address,
+            felt_selector_from_name("get_balance"),
+            &[],
+        );
+        assert_success(output, &[Felt::from(0)]);
+    }
+    save_fork_caches(cache_dir_path);
+
+    {
+        let fork_state_reader = ForkStateReader::new(
+            node_rpc_url(),
+            BlockNumber(53_670),
+            cache_dir_path,
+            false,
+            true,
+        )
+        .unwrap();
+        let value = fork_state_reader
+            .get_storage_at(contract_address, key)
+            .unwrap();
+        assert_eq!(value, Felt::ZERO);
+    }
+
+    // Only entries read by the test are saved, not all prefetched ones
+    let cache = read_cache(
+        cache_dir
+            .path()
+            .join("*_53670.fork_cache")
+            .to_str()
+            .unwrap(),
+    );
+    assert_eq!(
+        cache["storage_at"].as_object().unwrap()
+            ["0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9"]
+            .as_object()
+            .unwrap()["0x206f38f7e4f15e87567361213c28f235cccdaa1d7fd34c9db1dfe9489c6a091"],
+        "0x0"
+    );
+    assert!(
+        cache
+            .get("class_hash_at")
+            .is_none_or(|class_hashes| class_hashes.as_object().unwrap().is_empty())
+    );
+}
+
+#[test]
+fn fork_cache_is_shared_and_saved_once() {
+    let cache_dir = TempDir::new().unwrap();
+    let cache_dir_path = Utf8Path::from_path(cache_dir.path()).unwrap();
+    let contract_address = ContractAddress::try_from_hex_str(
+        "0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9",
+    )
+    .unwrap();
+    let key = StorageKey::try_from(Felt::ONE).unwrap();
+    let cache_file_size = || list_fork_caches(cache_dir_path).unwrap()[0].size;
+    let new_reader = || {
+        ForkStateReader::new(
+            node_rpc_url(),
+            BlockNumber(53_669),
+            cache_dir_path,
+            false,
+            false,
+        )
+        .unwrap()
+    };
+
+    // Entries fetched by the first reader are written to the file only once caches are saved,
+    // the second reader uses the same cache instead of loading it from the file again
+    let first_reader = new_reader();
+    assert_eq!(
+        first_reader.get_storage_at(contract_address, key).unwrap(),
+        Felt::ZERO
+    );
+    drop(first_reader);
+    assert_eq!(cache_file_size(), 0);
+
+    let second_reader = new_reader();
+    assert_eq!(
+        second_reader.get_storage_at(contract_address, key).unwrap(),
+        Felt::ZERO
+    );
+    drop(second_reader);
+    assert_eq!(cache_file_size(), 0);
+
+    let cache = read_cache(cache_dir.path().join("*.fork_cache").to_str().unwrap());
+    assert_eq!(
+        cache["storage_at"].as_object().unwrap()
+            ["0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9"]
+            .as_object()
+            .unwrap()["0x1"],
+        "0x0"
+    );
+}
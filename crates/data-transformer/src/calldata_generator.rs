@@ -0,0 +1,218 @@
+use crate::reverse_transformer::{split_generic, tuple_elements};
+use anyhow::{Result, bail, ensure};
+use num_bigint::BigInt;
+use starknet::core::types::contract::{AbiEntry, AbiFunction};
+use starknet_types_core::felt::Felt;
+
+/// Limits nesting of resolved types, so that recursive types are rejected
+const MAX_NESTING: usize = 16;
+/// Maximal number of elements in generated arrays and spans
+const MAX_SEQUENCE_LENGTH: u32 = 8;
+/// Maximal number of bytes in generated byte arrays
+const MAX_BYTE_ARRAY_LENGTH: u32 = 31;
+
+/// Type of a function argument resolved from the ABI, describing how its values are serialized
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgumentType {
+    Felt,
+    /// Felt-based value with at most the given number of bits (e.g. `u8`, `bool`, `EthAddress`)
+    Unsigned(u32),
+    /// Signed integer with the given number of bits
+    Signed(u32),
+    ContractAddress,
+    ByteArray,
+    /// Array or span of elements of the given type
+    Sequence(Box<ArgumentType>),
+    /// Tuple or struct, serialized as its elements one by one
+    Tuple(Vec<ArgumentType>),
+    /// Enum, serialized as the index of the variant followed by its value
+    Enum(Vec<ArgumentType>),
+}
+
+/// Resolves types of inputs of the function, fails if values of any of them can't be generated
+pub fn resolve_input_types(function: &AbiFunction, abi: &[AbiEntry]) -> Result<Vec<ArgumentType>> {
+    function
+        .inputs
+        .iter()
+        .map(|input| resolve_type(&input.r#type, abi, 0))
+        .collect()
+}
+
+fn resolve_type(type_str: &str, abi: &[AbiEntry], nesting: usize) -> Result<ArgumentType> {
+    let type_str = type_str.trim();
+    ensure!(
+        nesting <= MAX_NESTING,
+        r#"Type "{type_str}" is nested too deeply"#
+    );
+
+    if let Some(elements) = tuple_elements(type_str) {
+        return elements
+            .into_iter()
+            .map(|element| resolve_type(element, abi, nesting + 1))
+            .collect::<Result<_>>()
+            .map(ArgumentType::Tuple);
+    }
+
+    match split_generic(type_str) {
+        Some(("core::array::Array" | "core::array::Span", generic)) => {
+            return resolve_type(generic, abi, nesting + 1)
+                .map(|element| ArgumentType::Sequence(Box::new(element)));
+        }
+        Some(("core::zeroable::NonZero", generic)) => return resolve_type(generic, abi, nesting),
+        Some(_) => {}
+        None => {
+            if let Some(core_type) = type_str.strip_prefix("core::") {
+                if let Some(argument_type) = resolve_core_primitive(core_type) {
+                    return Ok(argument_type);
+                }
+            }
+        }
+    }
+
+    for entry in abi {
+        match entry {
+            AbiEntry::Struct(abi_struct) if abi_struct.name == type_str => {
+                return abi_struct
+                    .members
+                    .iter()
+                    .map(|member| resolve_type(&member.r#type, abi, nesting + 1))
+                    .collect::<Result<_>>()
+                    .map(ArgumentType::Tuple);
+            }
+            AbiEntry::Enum(abi_enum) if abi_enum.name == type_str => {
+                ensure!(
+                    !abi_enum.variants.is_empty(),
+                    r#"Enum "{type_str}" has no variants"#
+                );
+
+                return abi_enum
+                    .variants
+                    .iter()
+                    .map(|variant| resolve_type(&variant.r#type, abi, nesting + 1))
+                    .collect::<Result<_>>()
+                    .map(ArgumentType::Enum);
+            }
+            _ => {}
+        }
+    }
+
+    bail!(r#"Type "{type_str}" not found in ABI"#)
+}
+
+fn resolve_core_primitive(core_type: &str) -> Option<ArgumentType> {
+    let type_name = core_type
+        .split("::")
+        .last()
+        .expect("split always returns at least one element");
+
+    let u128_limbs = |count| ArgumentType::Tuple(vec![ArgumentType::Unsigned(128); count]);
+
+    let argument_type = match type_name {
+        "felt252" | "ClassHash" | "StorageAddress" => ArgumentType::Felt,
+        "bool" => ArgumentType::Unsigned(1),
+        "u8" => ArgumentType::Unsigned(8),
+        "u16" => ArgumentType::Unsigned(16),
+        "u32" | "usize" => ArgumentType::Unsigned(32),
+        "u64" => ArgumentType::Unsigned(64),
+        "u96" => ArgumentType::Unsigned(96),
+        "u128" => ArgumentType::Unsigned(128),
+        "u256" => u128_limbs(2),
+        "u384" => ArgumentType::Tuple(vec![ArgumentType::Unsigned(96); 4]),
+        "u512" => u128_limbs(4),
+        "i8" => ArgumentType::Signed(8),
+        "i16" => ArgumentType::Signed(16),
+        "i32" => ArgumentType::Signed(32),
+        "i64" => ArgumentType::Signed(64),
+        "i128" => ArgumentType::Signed(128),
+        "EthAddress" => ArgumentType::Unsigned(160),
+        "bytes31" => ArgumentType::Unsigned(248),
+        "ContractAddress" => ArgumentType::ContractAddress,
+        "ByteArray" => ArgumentType::ByteArray,
+        _ => return None,
+    };
+
+    Some(argument_type)
+}
+
+impl ArgumentType {
+    /// Appends a serialized value of this type to `calldata`.
+    ///
+    /// Every primitive value is obtained from `choose`, which returns a value from the inclusive range
+    /// it is given. Contract addresses are picked from `addresses`, if there are any.
+    pub fn generate(
+        &self,
+        choose: &mut impl FnMut(BigInt, BigInt) -> Result<BigInt>,
+        addresses: &[Felt],
+        calldata: &mut Vec<Felt>,
+    ) -> Result<()> {
+        match self {
+            ArgumentType::Felt => {
+                calldata.push(Felt::from(choose(BigInt::ZERO, Felt::MAX.to_bigint())?));
+            }
+            ArgumentType::Unsigned(bits) => {
+                let max = (BigInt::from(1) << bits) - 1;
+                calldata.push(Felt::from(choose(BigInt::ZERO, max)?));
+            }
+            ArgumentType::Signed(bits) => {
+                let bound = BigInt::from(1) << (bits - 1);
+                // Negative values are serialized as P + x, which `Felt::from` takes care of
+                calldata.push(Felt::from(choose(-bound.clone(), bound - 1)?));
+            }
+            ArgumentType::ContractAddress => {
+                if addresses.is_empty() {
+                    ArgumentType::Felt.generate(choose, addresses, calldata)?;
+                } else {
+                    let index = choose_index(choose, addresses.len())?;
+                    calldata.push(addresses[index]);
+                }
+            }
+            ArgumentType::ByteArray => {
+                let length = choose(BigInt::ZERO, BigInt::from(MAX_BYTE_ARRAY_LENGTH))?;
+
+                // Short byte arrays are stored in the pending word only
+                let mut pending_word = BigInt::ZERO;
+                let mut byte = BigInt::ZERO;
+                while byte < length {
+                    // Printable ASCII characters, so that the value is readable when displayed
+                    let character = choose(BigInt::from(0x20), BigInt::from(0x7e))?;
+                    pending_word = (pending_word << 8) + character;
+                    byte += 1;
+                }
+
+                calldata.extend([Felt::ZERO, Felt::from(pending_word), Felt::from(length)]);
+            }
+            ArgumentType::Sequence(element) => {
+                let length = choose(BigInt::ZERO, BigInt::from(MAX_SEQUENCE_LENGTH))?;
+                calldata.push(Felt::from(length.clone()));
+
+                let mut index = BigInt::ZERO;
+                while index < length {
+                    element.generate(choose, addresses, calldata)?;
+                    index += 1;
+                }
+            }
+            ArgumentType::Tuple(elements) => {
+                for element in elements {
+                    element.generate(choose, addresses, calldata)?;
+                }
+            }
+            ArgumentType::Enum(variants) => {
+                let index = choose_index(choose, variants.len())?;
+                calldata.push(Felt::from(index));
+                variants[index].generate(choose, addresses, calldata)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Chooses an index into a non-empty collection of `length` elements
+fn choose_index(
+    choose: &mut impl FnMut(BigInt, BigInt) -> Result<BigInt>,
+    length: usize,
+) -> Result<usize> {
+    let index = choose(BigInt::ZERO, BigInt::from(length - 1))?;
+
+    Ok(usize::try_from(index)?)
+}
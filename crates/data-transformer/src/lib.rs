@@ -1,7 +1,14 @@
 pub mod cairo_types;
 mod calldata;
+mod calldata_generator;
+pub mod reverse_transformer;
 mod sierra_abi;
 mod transformer;
 
 pub use calldata::Calldata;
-pub use transformer::transform;
+pub use calldata_generator::{ArgumentType, resolve_input_types};
+pub use reverse_transformer::{
+    reverse_transform_abi_event, reverse_transform_event, reverse_transform_function_output,
+    reverse_transform_input, reverse_transform_output,
+};
+pub use transformer::{find_function, transform};
@@ -0,0 +1,129 @@
+use super::transform::{ReverseTransformer, short_name};
+use super::types::{Struct, StructField};
+use anyhow::{Context, Result, bail};
+use starknet::core::types::contract::{
+    AbiEntry, AbiEvent, AbiEventEnum, AbiEventStruct, EventFieldKind, TypedAbiEvent,
+};
+use starknet::core::utils::get_selector_from_name;
+use starknet_types_core::felt::Felt;
+
+/// Finds the event matching `keys` among events from the ABI and decodes its members
+pub(super) fn decode_event(keys: &[Felt], data: &[Felt], abi: &[AbiEntry]) -> Result<Struct> {
+    let events: Vec<&TypedAbiEvent> = abi
+        .iter()
+        .filter_map(|entry| match entry {
+            AbiEntry::Event(AbiEvent::Typed(event)) => Some(event),
+            _ => None,
+        })
+        .collect();
+
+    // Contract's `Event` enum is the only one not used as a variant of another event enum
+    let root_enums = events.iter().filter_map(|event| match event {
+        TypedAbiEvent::Enum(abi_enum)
+            if !events
+                .iter()
+                .any(|other| is_variant_of(&abi_enum.name, other)) =>
+        {
+            Some(abi_enum)
+        }
+        _ => None,
+    });
+
+    for root_enum in root_enums {
+        if let Some((event, keys_consumed)) = match_enum(root_enum, keys, &events) {
+            return decode_struct(event, &keys[keys_consumed..], data, abi);
+        }
+    }
+
+    let selector = keys.first().context("Event has no keys")?;
+    bail!("Event with selector {selector:#x} not found in ABI of the contract")
+}
+
+fn event_name(event: &TypedAbiEvent) -> &str {
+    match event {
+        TypedAbiEvent::Struct(abi_struct) => &abi_struct.name,
+        TypedAbiEvent::Enum(abi_enum) => &abi_enum.name,
+    }
+}
+
+fn is_variant_of(name: &str, event: &TypedAbiEvent) -> bool {
+    match event {
+        TypedAbiEvent::Enum(abi_enum) => abi_enum
+            .variants
+            .iter()
+            .any(|variant| variant.r#type == name),
+        TypedAbiEvent::Struct(_) => false,
+    }
+}
+
+/// Returns the event struct selected by `keys` and the number of keys used to select it.
+/// `#[nested]` variants put their selector in keys, while `#[flat]` ones are transparent.
+fn match_enum<'a>(
+    abi_enum: &'a AbiEventEnum,
+    keys: &[Felt],
+    events: &[&'a TypedAbiEvent],
+) -> Option<(&'a AbiEventStruct, usize)> {
+    abi_enum.variants.iter().find_map(|variant| {
+        let inner = events
+            .iter()
+            .copied()
+            .find(|event| event_name(event) == variant.r#type)?;
+
+        match (&variant.kind, inner) {
+            (EventFieldKind::Flat, TypedAbiEvent::Enum(inner_enum)) => {
+                match_enum(inner_enum, keys, events)
+            }
+            (EventFieldKind::Nested, _) => {
+                let selector = get_selector_from_name(&variant.name).ok()?;
+                if keys.first() != Some(&selector) {
+                    return None;
+                }
+
+                match inner {
+                    TypedAbiEvent::Struct(event) => Some((event, 1)),
+                    TypedAbiEvent::Enum(inner_enum) => match_enum(inner_enum, &keys[1..], events)
+                        .map(|(event, keys_consumed)| (event, keys_consumed + 1)),
+                }
+            }
+            _ => None,
+        }
+    })
+}
+
+fn decode_struct(
+    event: &AbiEventStruct,
+    keys: &[Felt],
+    data: &[Felt],
+    abi: &[AbiEntry],
+) -> Result<Struct> {
+    let mut keys_transformer = ReverseTransformer::new(keys, abi);
+    let mut data_transformer = ReverseTransformer::new(data, abi);
+
+    let fields = event
+        .members
+        .iter()
+        .map(|member| {
+            let transformer = match member.kind {
+                EventFieldKind::Key => &mut keys_transformer,
+                EventFieldKind::Data => &mut data_transformer,
+                EventFieldKind::Nested | EventFieldKind::Flat => {
+                    bail!("Nested members of events are not supported")
+                }
+            };
+
+            Ok(StructField {
+                name: member.name.clone(),
+                value: transformer.parse_type(&member.r#type)?,
+            })
+        })
+        .collect::<Result<_>>()
+        .with_context(|| format!(r#"Error while decoding event "{}""#, event.name))?;
+
+    keys_transformer.ensure_all_read()?;
+    data_transformer.ensure_all_read()?;
+
+    Ok(Struct {
+        name: short_name(&event.name),
+        fields,
+    })
+}
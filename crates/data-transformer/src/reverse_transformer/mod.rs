@@ -0,0 +1,91 @@
+mod event;
+mod transform;
+mod types;
+
+use crate::transformer::{extract_abi, find_function};
+use anyhow::{Context, Result};
+use event::decode_event;
+use starknet::core::types::ContractClass;
+use starknet::core::types::contract::{AbiEntry, AbiFunction};
+use starknet_types_core::felt::Felt;
+use transform::ReverseTransformer;
+
+pub(crate) use transform::{split_generic, tuple_elements};
+pub use types::{Enum, Primitive, Struct, StructField, Type};
+
+/// Decode values returned by the function with `function_selector`.
+/// Each returned value can be displayed as a Cairo-like expression
+pub fn reverse_transform_output(
+    output: &[Felt],
+    class_definition: ContractClass,
+    function_selector: &Felt,
+) -> Result<Vec<Type>> {
+    let abi = extract_abi(class_definition)?;
+    let function = find_function(&abi, function_selector)?;
+
+    reverse_transform_function_output(output, &abi, &function)
+}
+
+/// Decode values returned by the `function` from the `abi`.
+/// Each returned value can be displayed as a Cairo-like expression
+pub fn reverse_transform_function_output(
+    output: &[Felt],
+    abi: &[AbiEntry],
+    function: &AbiFunction,
+) -> Result<Vec<Type>> {
+    let mut transformer = ReverseTransformer::new(output, abi);
+
+    let values: Vec<Type> = function
+        .outputs
+        .iter()
+        .map(|parameter| transformer.parse_type(&parameter.r#type))
+        .collect::<Result<_>>()
+        .context("Error while decoding returned values")?;
+
+    transformer.ensure_all_read()?;
+
+    Ok(values)
+}
+
+/// Decode arguments passed to the `function` from the `abi`.
+/// Each argument can be displayed as a Cairo-like expression
+pub fn reverse_transform_input(
+    input: &[Felt],
+    abi: &[AbiEntry],
+    function: &AbiFunction,
+) -> Result<Vec<Type>> {
+    let mut transformer = ReverseTransformer::new(input, abi);
+
+    let values: Vec<Type> = function
+        .inputs
+        .iter()
+        .map(|parameter| transformer.parse_type(&parameter.r#type))
+        .collect::<Result<_>>()
+        .context("Error while decoding arguments")?;
+
+    transformer.ensure_all_read()?;
+
+    Ok(values)
+}
+
+/// Decode event emitted by a contract, looking it up in the ABI by its keys.
+/// Returned event can be displayed as a Cairo-like struct expression
+pub fn reverse_transform_event(
+    keys: &[Felt],
+    data: &[Felt],
+    class_definition: ContractClass,
+) -> Result<Struct> {
+    let abi = extract_abi(class_definition)?;
+
+    reverse_transform_abi_event(keys, data, &abi)
+}
+
+/// Decode event emitted by a contract with the `abi`, looking it up by its keys.
+/// Returned event can be displayed as a Cairo-like struct expression
+pub fn reverse_transform_abi_event(
+    keys: &[Felt],
+    data: &[Felt],
+    abi: &[AbiEntry],
+) -> Result<Struct> {
+    decode_event(keys, data, abi)
+}
@@ -0,0 +1,330 @@
+use super::types::{Enum, Primitive, Struct, StructField, Type};
+use anyhow::{Context, Result, bail, ensure};
+use conversions::byte_array::ByteArray;
+use conversions::serde::deserialize::BufferReader;
+use conversions::serde::serialize::SerializeToFeltVec;
+use num_bigint::{BigInt, BigUint};
+use starknet::core::types::contract::{AbiEntry, AbiEnum, AbiStruct};
+use starknet_types_core::felt::Felt;
+
+/// Reads felts one by one and turns them into values of types described in the ABI
+pub(super) struct ReverseTransformer<'a> {
+    abi: &'a [AbiEntry],
+    felts: &'a [Felt],
+    position: usize,
+}
+
+impl<'a> ReverseTransformer<'a> {
+    pub(super) fn new(felts: &'a [Felt], abi: &'a [AbiEntry]) -> Self {
+        Self {
+            abi,
+            felts,
+            position: 0,
+        }
+    }
+
+    pub(super) fn ensure_all_read(&self) -> Result<()> {
+        ensure!(
+            self.position == self.felts.len(),
+            "Too many values to decode: decoded {} out of {} felts",
+            self.position,
+            self.felts.len()
+        );
+        Ok(())
+    }
+
+    pub(super) fn parse_type(&mut self, type_str: &str) -> Result<Type> {
+        let type_str = type_str.trim();
+
+        if let Some(elements) = tuple_elements(type_str) {
+            return elements
+                .into_iter()
+                .map(|element| self.parse_type(element))
+                .collect::<Result<_>>()
+                .map(Type::Tuple);
+        }
+
+        match split_generic(type_str) {
+            Some(("core::array::Array", generic)) => {
+                return self.parse_sequence(generic).map(Type::Array);
+            }
+            Some(("core::array::Span", generic)) => {
+                return self.parse_sequence(generic).map(Type::Span);
+            }
+            Some(("core::zeroable::NonZero", generic)) => return self.parse_type(generic),
+            // `u96` and other bounded integers are `BoundedInt`s in the ABI
+            Some(("core::internal::bounded_int::BoundedInt", bounds)) => {
+                return self.read_bounded_int(bounds).map(Type::Primitive);
+            }
+            Some(_) => {}
+            None => {
+                if let Some(core_type) = type_str.strip_prefix("core::") {
+                    if let Some(primitive) = self.parse_core_primitive(core_type)? {
+                        return Ok(Type::Primitive(primitive));
+                    }
+                }
+            }
+        }
+
+        self.parse_struct_or_enum(type_str)
+    }
+
+    fn read_felt(&mut self) -> Result<Felt> {
+        let felt = self
+            .felts
+            .get(self.position)
+            .copied()
+            .context("Not enough values to decode")?;
+        self.position += 1;
+
+        Ok(felt)
+    }
+
+    fn parse_sequence(&mut self, element_type: &str) -> Result<Vec<Type>> {
+        let length: usize = self
+            .read_felt()?
+            .try_into()
+            .context("Failed to decode length of an array")?;
+
+        (0..length).map(|_| self.parse_type(element_type)).collect()
+    }
+
+    fn parse_core_primitive(&mut self, core_type: &str) -> Result<Option<Primitive>> {
+        let type_name = core_type
+            .split("::")
+            .last()
+            .expect("split always returns at least one element");
+
+        let primitive = match type_name {
+            "felt252" => Primitive::Felt(self.read_felt()?),
+            "bool" => match self.read_felt()? {
+                felt if felt == Felt::ZERO => Primitive::Bool(false),
+                felt if felt == Felt::ONE => Primitive::Bool(true),
+                felt => bail!("Invalid value for bool: {felt:#x}"),
+            },
+            "u8" => self.read_unsigned(8, type_name)?,
+            "u16" => self.read_unsigned(16, type_name)?,
+            "u32" | "usize" => self.read_unsigned(32, type_name)?,
+            "u64" => self.read_unsigned(64, type_name)?,
+            "u96" => self.read_unsigned(96, type_name)?,
+            "u128" => self.read_unsigned(128, type_name)?,
+            "u256" => self.read_limbs(&[128, 128], type_name)?,
+            "u384" => self.read_limbs(&[96, 96, 96, 96], type_name)?,
+            "u512" => self.read_limbs(&[128, 128, 128, 128], type_name)?,
+            "i8" => self.read_signed(8, type_name)?,
+            "i16" => self.read_signed(16, type_name)?,
+            "i32" => self.read_signed(32, type_name)?,
+            "i64" => self.read_signed(64, type_name)?,
+            "i128" => self.read_signed(128, type_name)?,
+            "ContractAddress" | "ClassHash" | "EthAddress" | "StorageAddress" | "bytes31" => {
+                Primitive::Wrapped(self.read_felt()?, type_name.to_string())
+            }
+            "ByteArray" => Primitive::ByteArray(self.read_byte_array()?.to_string()),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(primitive))
+    }
+
+    fn read_unsigned_value(&mut self, bits: u32, type_name: &str) -> Result<BigUint> {
+        let value = self.read_felt()?.to_biguint();
+
+        ensure!(
+            value.bits() <= u64::from(bits),
+            "Value {value} is out of range for {type_name}"
+        );
+
+        Ok(value)
+    }
+
+    fn read_unsigned(&mut self, bits: u32, type_name: &str) -> Result<Primitive> {
+        let value = self.read_unsigned_value(bits, type_name)?;
+
+        Ok(Primitive::Unsigned(value, type_name.to_string()))
+    }
+
+    /// Reads an unsigned integer serialized as limbs, starting with the least significant one
+    fn read_limbs(&mut self, limb_bits: &[u32], type_name: &str) -> Result<Primitive> {
+        let mut value = BigUint::default();
+        let mut shift = 0;
+
+        for &bits in limb_bits {
+            value += self.read_unsigned_value(bits, type_name)? << shift;
+            shift += bits;
+        }
+
+        Ok(Primitive::Unsigned(value, type_name.to_string()))
+    }
+
+    fn read_signed(&mut self, bits: u32, type_name: &str) -> Result<Primitive> {
+        let value = felt_to_signed(self.read_felt()?);
+
+        let bound = BigInt::from(1) << (bits - 1);
+        ensure!(
+            -bound.clone() <= value && value < bound,
+            "Value {value} is out of range for {type_name}"
+        );
+
+        Ok(Primitive::Signed(value, type_name.to_string()))
+    }
+
+    /// Reads a `BoundedInt` with inclusive `bounds`, e.g. `0, 79228162514264337593543950335`
+    fn read_bounded_int(&mut self, bounds: &str) -> Result<Primitive> {
+        let (min, max) = bounds
+            .split_once(',')
+            .and_then(|(min, max)| {
+                Some((
+                    min.trim().parse::<BigInt>().ok()?,
+                    max.trim().parse::<BigInt>().ok()?,
+                ))
+            })
+            .with_context(|| format!("Invalid bounds of BoundedInt: {bounds}"))?;
+        let value = felt_to_signed(self.read_felt()?);
+
+        ensure!(
+            min <= value && value <= max,
+            "Value {value} is out of range for BoundedInt<{min}, {max}>"
+        );
+
+        let u96_max = (BigInt::from(1) << 96) - 1;
+        match value.to_biguint() {
+            Some(value) if min == BigInt::from(0) && max == u96_max => {
+                Ok(Primitive::Unsigned(value, "u96".to_string()))
+            }
+            _ => Ok(Primitive::BoundedInt(value)),
+        }
+    }
+
+    fn read_byte_array(&mut self) -> Result<ByteArray> {
+        let byte_array: ByteArray = BufferReader::new(&self.felts[self.position..])
+            .read()
+            .context("Failed to decode ByteArray")?;
+
+        // Serializing it back is the simplest way to learn how many felts have been read
+        self.position += byte_array.serialize_to_vec().len();
+
+        Ok(byte_array)
+    }
+
+    fn parse_struct_or_enum(&mut self, type_str: &str) -> Result<Type> {
+        let abi = self.abi;
+
+        for entry in abi {
+            match entry {
+                AbiEntry::Struct(abi_struct) if abi_struct.name == type_str => {
+                    return self.parse_struct(abi_struct).map(Type::Struct);
+                }
+                AbiEntry::Enum(abi_enum) if abi_enum.name == type_str => {
+                    return self.parse_enum(abi_enum).map(Type::Enum);
+                }
+                _ => {}
+            }
+        }
+
+        bail!(r#"Type "{type_str}" not found in ABI"#)
+    }
+
+    fn parse_struct(&mut self, abi_struct: &AbiStruct) -> Result<Struct> {
+        let fields = abi_struct
+            .members
+            .iter()
+            .map(|member| {
+                Ok(StructField {
+                    name: member.name.clone(),
+                    value: self.parse_type(&member.r#type)?,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Struct {
+            name: short_name(&abi_struct.name),
+            fields,
+        })
+    }
+
+    fn parse_enum(&mut self, abi_enum: &AbiEnum) -> Result<Enum> {
+        let position: usize = self
+            .read_felt()?
+            .try_into()
+            .with_context(|| format!(r#"Failed to decode variant of enum "{}""#, abi_enum.name))?;
+
+        let variant = abi_enum.variants.get(position).with_context(|| {
+            format!(
+                r#"Variant at position {position} not found in enum "{}""#,
+                abi_enum.name
+            )
+        })?;
+
+        let value = if variant.r#type == "()" {
+            None
+        } else {
+            Some(Box::new(self.parse_type(&variant.r#type)?))
+        };
+
+        Ok(Enum {
+            name: short_name(&abi_enum.name),
+            variant: variant.name.clone(),
+            value,
+        })
+    }
+}
+
+/// Name of the item without its module path and generic arguments
+/// (e.g. `core::option::Option::<core::integer::u8>` becomes `Option`)
+pub(super) fn short_name(type_str: &str) -> String {
+    let without_generics = split_generic(type_str).map_or(type_str, |(base, _)| base);
+
+    without_generics
+        .split("::")
+        .last()
+        .expect("split always returns at least one element")
+        .to_string()
+}
+
+/// Splits `path::Type::<Generic>` into `path::Type` and `Generic`
+/// Negative value x is serialized as P + x, where P is the STARK prime number
+fn felt_to_signed(felt: Felt) -> BigInt {
+    let prime = Felt::MAX.to_bigint() + 1;
+    let value = felt.to_bigint();
+
+    if value > &prime / 2 {
+        value - prime
+    } else {
+        value
+    }
+}
+
+pub(crate) fn split_generic(type_str: &str) -> Option<(&str, &str)> {
+    let start = type_str.find("::<")?;
+    let generic = type_str.get(start + 3..)?.strip_suffix('>')?;
+
+    Some((&type_str[..start], generic))
+}
+
+/// Splits `(A, B, C)` into its top-level elements
+pub(crate) fn tuple_elements(type_str: &str) -> Option<Vec<&str>> {
+    let inner = type_str.strip_prefix('(')?.strip_suffix(')')?;
+
+    let mut elements = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (index, character) in inner.char_indices() {
+        match character {
+            '(' | '<' | '[' => depth += 1,
+            ')' | '>' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                elements.push(&inner[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = inner[start..].trim();
+    if !last.is_empty() {
+        elements.push(last);
+    }
+
+    Some(elements)
+}
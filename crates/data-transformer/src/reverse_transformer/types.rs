@@ -0,0 +1,108 @@
+use itertools::Itertools;
+use num_bigint::{BigInt, BigUint};
+use starknet_types_core::felt::Felt;
+use std::fmt;
+
+/// Decoded value of a Cairo type, displayed as a Cairo-like expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    Primitive(Primitive),
+    Tuple(Vec<Type>),
+    Struct(Struct),
+    Enum(Enum),
+    Array(Vec<Type>),
+    Span(Vec<Type>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Primitive {
+    Felt(Felt),
+    Bool(bool),
+    /// Unsigned integer along with the name of its type (e.g. `u8`, `u256`)
+    Unsigned(BigUint, String),
+    /// Signed integer along with the name of its type (e.g. `i8`, `i128`)
+    Signed(BigInt, String),
+    /// Value of a `BoundedInt` other than `u96`, which has no literal syntax
+    BoundedInt(BigInt),
+    /// Felt-based type along with its name (e.g. `ContractAddress`, `ClassHash`)
+    Wrapped(Felt, String),
+    ByteArray(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Struct {
+    pub name: String,
+    pub fields: Vec<StructField>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructField {
+    pub name: String,
+    pub value: Type,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enum {
+    pub name: String,
+    pub variant: String,
+    pub value: Option<Box<Type>>,
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Primitive(value) => write!(f, "{value}"),
+            Type::Tuple(values) => {
+                // Single element tuple requires a trailing comma to be distinguishable from parentheses
+                if values.len() == 1 {
+                    write!(f, "({},)", values[0])
+                } else {
+                    write!(f, "({})", values.iter().join(", "))
+                }
+            }
+            Type::Struct(value) => write!(f, "{value}"),
+            Type::Enum(value) => write!(f, "{value}"),
+            Type::Array(values) => write!(f, "array![{}]", values.iter().join(", ")),
+            Type::Span(values) => write!(f, "array![{}].span()", values.iter().join(", ")),
+        }
+    }
+}
+
+impl fmt::Display for Primitive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Primitive::Felt(value) => write!(f, "{value:#x}"),
+            Primitive::Bool(value) => write!(f, "{value}"),
+            Primitive::Unsigned(value, type_name) => write!(f, "{value}_{type_name}"),
+            Primitive::Signed(value, type_name) => write!(f, "{value}_{type_name}"),
+            Primitive::BoundedInt(value) => write!(f, "{value}"),
+            Primitive::Wrapped(value, type_name) => write!(f, "{type_name}({value:#x})"),
+            Primitive::ByteArray(value) => write!(f, "{value:?}"),
+        }
+    }
+}
+
+impl fmt::Display for Struct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| format!("{}: {}", field.name, field.value))
+            .join(", ");
+
+        if fields.is_empty() {
+            write!(f, "{} {{}}", self.name)
+        } else {
+            write!(f, "{} {{ {fields} }}", self.name)
+        }
+    }
+}
+
+impl fmt::Display for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{}::{}({value})", self.name, self.variant),
+            None => write!(f, "{}::{}", self.name, self.variant),
+        }
+    }
+}
@@ -15,31 +15,41 @@ pub fn transform(
     class_definition: ContractClass,
     function_selector: &Felt,
 ) -> Result<Vec<Felt>> {
+    if let ContractClass::Legacy(_) = class_definition {
+        bail!("Transformation of arguments is not available for Cairo Zero contracts")
+    }
+
+    let abi = extract_abi(class_definition)?;
+
+    let function = find_function(&abi, function_selector)?;
+
+    let db = SimpleParserDatabase::default();
+
+    let calldata = split_expressions(calldata, &db)?;
+
+    process(calldata, &function, &abi, &db).context("Error while processing Cairo-like calldata")
+}
+
+/// Deserialize ABI of a Sierra contract class, fails for Cairo Zero contracts
+pub(crate) fn extract_abi(class_definition: ContractClass) -> Result<Vec<AbiEntry>> {
     let sierra_class = match class_definition {
         ContractClass::Sierra(class) => class,
-        ContractClass::Legacy(_) => {
-            bail!("Transformation of arguments is not available for Cairo Zero contracts")
-        }
+        ContractClass::Legacy(_) => bail!("ABI of Cairo Zero contracts is not supported"),
     };
 
-    let abi: Vec<AbiEntry> = serde_json::from_str(sierra_class.abi.as_str())
-        .context("Couldn't deserialize ABI received from chain")?;
-
-    let selector_function_map = map_selectors_to_functions(&abi);
+    serde_json::from_str(sierra_class.abi.as_str())
+        .context("Couldn't deserialize ABI received from chain")
+}
 
-    let function = selector_function_map
-        .get(function_selector)
+/// Find the function (or constructor) with `function_selector` in the `abi`
+pub fn find_function(abi: &[AbiEntry], function_selector: &Felt) -> Result<AbiFunction> {
+    map_selectors_to_functions(abi)
+        .remove(function_selector)
         .with_context(|| {
             format!(
                 r#"Function with selector "{function_selector}" not found in ABI of the contract"#
             )
-        })?;
-
-    let db = SimpleParserDatabase::default();
-
-    let calldata = split_expressions(calldata, &db)?;
-
-    process(calldata, function, &abi, &db).context("Error while processing Cairo-like calldata")
+        })
 }
 
 fn split_expressions(input: &str, db: &SimpleParserDatabase) -> Result<Vec<Expr>> {
@@ -0,0 +1,177 @@
+use data_transformer::{ArgumentType, resolve_input_types, reverse_transform_input};
+use indoc::indoc;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use starknet::core::types::contract::{AbiEntry, AbiFunction};
+use starknet_types_core::felt::Felt;
+
+const ABI: &str = indoc!(
+    r#"
+    [
+      {
+        "type": "struct",
+        "name": "core::integer::u256",
+        "members": [
+          { "name": "low", "type": "core::integer::u128" },
+          { "name": "high", "type": "core::integer::u128" }
+        ]
+      },
+      {
+        "type": "struct",
+        "name": "data_transformer::Position",
+        "members": [
+          { "name": "x", "type": "core::integer::u32" },
+          { "name": "y", "type": "core::integer::i32" }
+        ]
+      },
+      {
+        "type": "enum",
+        "name": "core::option::Option::<core::integer::u8>",
+        "variants": [
+          { "name": "Some", "type": "core::integer::u8" },
+          { "name": "None", "type": "()" }
+        ]
+      },
+      {
+        "type": "enum",
+        "name": "data_transformer::List",
+        "variants": [
+          { "name": "Empty", "type": "()" },
+          { "name": "Node", "type": "(core::felt252, data_transformer::List)" }
+        ]
+      },
+      {
+        "type": "function",
+        "name": "supported_fn",
+        "inputs": [
+          { "name": "amount", "type": "core::integer::u256" },
+          { "name": "position", "type": "data_transformer::Position" },
+          { "name": "option", "type": "core::option::Option::<core::integer::u8>" },
+          { "name": "flag", "type": "core::bool" },
+          { "name": "name", "type": "core::byte_array::ByteArray" },
+          { "name": "recipients", "type": "core::array::Span::<core::starknet::contract_address::ContractAddress>" }
+        ],
+        "outputs": [],
+        "state_mutability": "external"
+      },
+      {
+        "type": "function",
+        "name": "recursive_fn",
+        "inputs": [
+          { "name": "list", "type": "data_transformer::List" }
+        ],
+        "outputs": [],
+        "state_mutability": "external"
+      },
+      {
+        "type": "function",
+        "name": "unknown_type_fn",
+        "inputs": [
+          { "name": "value", "type": "data_transformer::Unknown" }
+        ],
+        "outputs": [],
+        "state_mutability": "external"
+      }
+    ]
+    "#
+);
+
+fn abi() -> Vec<AbiEntry> {
+    serde_json::from_str(ABI).unwrap()
+}
+
+fn function(abi: &[AbiEntry], name: &str) -> AbiFunction {
+    abi.iter()
+        .find_map(|entry| match entry {
+            AbiEntry::Function(function) if function.name == name => Some(function.clone()),
+            _ => None,
+        })
+        .unwrap()
+}
+
+fn generate_and_decode(choose: impl Fn(BigInt, BigInt) -> BigInt) -> String {
+    let abi = abi();
+    let function = function(&abi, "supported_fn");
+    let types = resolve_input_types(&function, &abi).unwrap();
+
+    let addresses = [Felt::from(0x123), Felt::from(0x456)];
+    let mut calldata = vec![];
+    for argument_type in &types {
+        argument_type
+            .generate(
+                &mut |min, max| Ok(choose(min, max)),
+                &addresses,
+                &mut calldata,
+            )
+            .unwrap();
+    }
+
+    reverse_transform_input(&calldata, &abi, &function)
+        .unwrap()
+        .iter()
+        .join(", ")
+}
+
+#[test]
+fn resolves_input_types() {
+    let abi = abi();
+    let types = resolve_input_types(&function(&abi, "supported_fn"), &abi).unwrap();
+
+    assert_eq!(
+        types,
+        vec![
+            ArgumentType::Tuple(vec![
+                ArgumentType::Unsigned(128),
+                ArgumentType::Unsigned(128)
+            ]),
+            ArgumentType::Tuple(vec![ArgumentType::Unsigned(32), ArgumentType::Signed(32)]),
+            ArgumentType::Enum(vec![ArgumentType::Unsigned(8), ArgumentType::Tuple(vec![])]),
+            ArgumentType::Unsigned(1),
+            ArgumentType::ByteArray,
+            ArgumentType::Sequence(Box::new(ArgumentType::ContractAddress)),
+        ]
+    );
+}
+
+#[test]
+fn generates_minimal_values() {
+    let arguments = generate_and_decode(|min, _| min);
+
+    assert_eq!(
+        arguments,
+        r#"0_u256, Position { x: 0_u32, y: -2147483648_i32 }, Option::Some(0_u8), false, "", array![].span()"#
+    );
+}
+
+#[test]
+fn generates_maximal_values() {
+    let arguments = generate_and_decode(|_, max| max);
+
+    assert_eq!(
+        arguments,
+        format!(
+            r#"115792089237316195423570985008687907853269984665640564039457584007913129639935_u256, Position {{ x: 4294967295_u32, y: 2147483647_i32 }}, Option::None, true, "{}", array![{}].span()"#,
+            "~".repeat(31),
+            ["ContractAddress(0x456)"; 8].join(", ")
+        )
+    );
+}
+
+#[test]
+fn fails_for_recursive_type() {
+    let abi = abi();
+    let error = resolve_input_types(&function(&abi, "recursive_fn"), &abi).unwrap_err();
+
+    assert!(error.to_string().contains("is nested too deeply"));
+}
+
+#[test]
+fn fails_for_unknown_type() {
+    let abi = abi();
+    let error = resolve_input_types(&function(&abi, "unknown_type_fn"), &abi).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        r#"Type "data_transformer::Unknown" not found in ABI"#
+    );
+}
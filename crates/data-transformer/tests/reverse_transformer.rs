@@ -0,0 +1,256 @@
+use data_transformer::{reverse_transform_event, reverse_transform_output};
+use indoc::indoc;
+use starknet::core::types::{ContractClass, EntryPointsByType, FlattenedSierraClass};
+use starknet::core::utils::get_selector_from_name;
+use starknet_types_core::felt::Felt;
+use test_case::test_case;
+
+const ABI: &str = indoc!(
+    r#"
+    [
+      {
+        "type": "struct",
+        "name": "core::integer::u256",
+        "members": [
+          { "name": "low", "type": "core::integer::u128" },
+          { "name": "high", "type": "core::integer::u128" }
+        ]
+      },
+      {
+        "type": "struct",
+        "name": "data_transformer::Position",
+        "members": [
+          { "name": "x", "type": "core::integer::u32" },
+          { "name": "y", "type": "core::integer::i32" }
+        ]
+      },
+      {
+        "type": "enum",
+        "name": "core::option::Option::<core::integer::u8>",
+        "variants": [
+          { "name": "Some", "type": "core::integer::u8" },
+          { "name": "None", "type": "()" }
+        ]
+      },
+      {
+        "type": "function",
+        "name": "u256_fn",
+        "inputs": [],
+        "outputs": [{ "type": "core::integer::u256" }],
+        "state_mutability": "view"
+      },
+      {
+        "type": "function",
+        "name": "struct_fn",
+        "inputs": [],
+        "outputs": [{ "type": "data_transformer::Position" }],
+        "state_mutability": "view"
+      },
+      {
+        "type": "function",
+        "name": "option_fn",
+        "inputs": [],
+        "outputs": [{ "type": "core::option::Option::<core::integer::u8>" }],
+        "state_mutability": "view"
+      },
+      {
+        "type": "function",
+        "name": "byte_array_fn",
+        "inputs": [],
+        "outputs": [{ "type": "core::byte_array::ByteArray" }],
+        "state_mutability": "view"
+      },
+      {
+        "type": "function",
+        "name": "array_fn",
+        "inputs": [],
+        "outputs": [{ "type": "core::array::Span::<core::starknet::contract_address::ContractAddress>" }],
+        "state_mutability": "view"
+      },
+      {
+        "type": "function",
+        "name": "tuple_fn",
+        "inputs": [],
+        "outputs": [{ "type": "(core::felt252, core::bool, core::array::Array::<core::integer::u64>)" }],
+        "state_mutability": "view"
+      },
+      {
+        "type": "function",
+        "name": "u96_fn",
+        "inputs": [],
+        "outputs": [{ "type": "core::internal::bounded_int::BoundedInt::<0, 79228162514264337593543950335>" }],
+        "state_mutability": "view"
+      },
+      {
+        "type": "function",
+        "name": "bounded_int_fn",
+        "inputs": [],
+        "outputs": [{ "type": "core::internal::bounded_int::BoundedInt::<-10, 10>" }],
+        "state_mutability": "view"
+      },
+      {
+        "type": "function",
+        "name": "no_output_fn",
+        "inputs": [],
+        "outputs": [],
+        "state_mutability": "view"
+      },
+      {
+        "type": "event",
+        "name": "data_transformer::Transfer",
+        "kind": "struct",
+        "members": [
+          { "name": "from", "type": "core::starknet::contract_address::ContractAddress", "kind": "key" },
+          { "name": "to", "type": "core::starknet::contract_address::ContractAddress", "kind": "key" },
+          { "name": "amount", "type": "core::integer::u256", "kind": "data" }
+        ]
+      },
+      {
+        "type": "event",
+        "name": "data_transformer::component::Paused",
+        "kind": "struct",
+        "members": [
+          { "name": "account", "type": "core::starknet::contract_address::ContractAddress", "kind": "data" }
+        ]
+      },
+      {
+        "type": "event",
+        "name": "data_transformer::component::Event",
+        "kind": "enum",
+        "variants": [
+          { "name": "Paused", "type": "data_transformer::component::Paused", "kind": "nested" }
+        ]
+      },
+      {
+        "type": "event",
+        "name": "data_transformer::Event",
+        "kind": "enum",
+        "variants": [
+          { "name": "Transfer", "type": "data_transformer::Transfer", "kind": "nested" },
+          { "name": "ComponentEvent", "type": "data_transformer::component::Event", "kind": "flat" }
+        ]
+      }
+    ]
+    "#
+);
+
+fn contract_class() -> ContractClass {
+    ContractClass::Sierra(FlattenedSierraClass {
+        sierra_program: vec![],
+        contract_class_version: String::from("0.1.0"),
+        entry_points_by_type: EntryPointsByType {
+            constructor: vec![],
+            external: vec![],
+            l1_handler: vec![],
+        },
+        abi: ABI.to_string(),
+    })
+}
+
+#[test_case("u256_fn", &[Felt::from(0x2137), Felt::ZERO], &["8503_u256"]; "u256")]
+#[test_case(
+    "u256_fn",
+    &[Felt::from(u128::MAX), Felt::from(u128::MAX)],
+    &["115792089237316195423570985008687907853269984665640564039457584007913129639935_u256"];
+    "u256 max"
+)]
+#[test_case("struct_fn", &[Felt::from(1), Felt::from(-5)], &["Position { x: 1_u32, y: -5_i32 }"]; "struct")]
+#[test_case("option_fn", &[Felt::ZERO, Felt::from(7)], &["Option::Some(7_u8)"]; "enum with value")]
+#[test_case("option_fn", &[Felt::ONE], &["Option::None"]; "enum without value")]
+#[test_case(
+    "byte_array_fn",
+    &[Felt::ZERO, Felt::from_hex_unchecked("0x616263"), Felt::from(3)],
+    &[r#""abc""#];
+    "byte array"
+)]
+#[test_case(
+    "array_fn",
+    &[Felt::TWO, Felt::from(0x123), Felt::from(0x456)],
+    &["array![ContractAddress(0x123), ContractAddress(0x456)].span()"];
+    "span"
+)]
+#[test_case(
+    "tuple_fn",
+    &[Felt::from(0x64), Felt::ONE, Felt::ONE, Felt::from(5)],
+    &["(0x64, true, array![5_u64])"];
+    "tuple"
+)]
+#[test_case("u96_fn", &[Felt::from(0x2137)], &["8503_u96"]; "u96")]
+#[test_case("bounded_int_fn", &[Felt::from(-7)], &["-7"]; "bounded int")]
+#[test_case("no_output_fn", &[], &[]; "no output")]
+fn test_reverse_transform_output(function: &str, output: &[Felt], expected: &[&str]) {
+    let result: Vec<String> = reverse_transform_output(
+        output,
+        contract_class(),
+        &get_selector_from_name(function).unwrap(),
+    )
+    .unwrap()
+    .iter()
+    .map(ToString::to_string)
+    .collect();
+
+    assert_eq!(result, expected);
+}
+
+#[test_case("u256_fn", &[Felt::ONE], "Not enough values to decode"; "not enough values")]
+#[test_case("option_fn", &[Felt::ONE, Felt::ONE], "Too many values to decode: decoded 1 out of 2 felts"; "too many values")]
+#[test_case("option_fn", &[Felt::ZERO, Felt::from(256)], "Value 256 is out of range for u8"; "out of range")]
+#[test_case("option_fn", &[Felt::from(5)], r#"Variant at position 5 not found in enum "core::option::Option::<core::integer::u8>""#; "invalid variant")]
+#[test_case("bounded_int_fn", &[Felt::from(11)], "Value 11 is out of range for BoundedInt<-10, 10>"; "bounded int out of range")]
+#[test_case("nonexistent_fn", &[], "not found in ABI of the contract"; "function not found")]
+fn test_reverse_transform_output_fails(function: &str, output: &[Felt], expected_error: &str) {
+    let result = reverse_transform_output(
+        output,
+        contract_class(),
+        &get_selector_from_name(function).unwrap(),
+    );
+
+    let error = result.unwrap_err();
+    assert!(
+        error
+            .chain()
+            .any(|err| err.to_string().contains(expected_error)),
+        "{expected_error:?}\nnot found in\n{error:#?}"
+    );
+}
+
+#[test_case(
+    &["Transfer", "0x1", "0x2"],
+    &["0x64", "0x0"],
+    "Transfer { from: ContractAddress(0x1), to: ContractAddress(0x2), amount: 100_u256 }";
+    "struct event"
+)]
+#[test_case(&["Paused"], &["0x123"], "Paused { account: ContractAddress(0x123) }"; "flat component event")]
+fn test_reverse_transform_event(keys: &[&str], data: &[&str], expected: &str) {
+    let result = reverse_transform_event(&event_felts(keys), &event_felts(data), contract_class())
+        .unwrap()
+        .to_string();
+
+    assert_eq!(result, expected);
+}
+
+#[test_case(&["Approval"], &[], "not found in ABI of the contract"; "unknown event")]
+#[test_case(&[], &[], "Event has no keys"; "no keys")]
+#[test_case(&["Paused"], &[], "Not enough values to decode"; "not enough data")]
+#[test_case(&["Paused", "0x1"], &["0x123"], "Too many values to decode: decoded 0 out of 1 felts"; "too many keys")]
+fn test_reverse_transform_event_fails(keys: &[&str], data: &[&str], expected_error: &str) {
+    let result = reverse_transform_event(&event_felts(keys), &event_felts(data), contract_class());
+
+    let error = result.unwrap_err();
+    assert!(
+        error
+            .chain()
+            .any(|err| err.to_string().contains(expected_error)),
+        "{expected_error:?}\nnot found in\n{error:#?}"
+    );
+}
+
+/// Converts hex values to felts and event names to their selectors
+fn event_felts(values: &[&str]) -> Vec<Felt> {
+    values
+        .iter()
+        .map(|value| {
+            Felt::from_hex(value).unwrap_or_else(|_| get_selector_from_name(value).unwrap())
+        })
+        .collect()
+}
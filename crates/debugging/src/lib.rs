@@ -2,8 +2,8 @@
 //!
 //! Currently, the only option that this crate gives is displaying pretty traces.
 //! The entry point for that is the [`Trace`] struct that implements the [`Display`](std::fmt::Display)
-//! which allows for pretty printing of traces.
+//! which allows for pretty printing of traces, with the amount of details controlled by [`TraceVerbosity`].
 mod trace;
 mod tree;
 
-pub use trace::types::Trace;
+pub use trace::types::{Trace, TraceVerbosity};
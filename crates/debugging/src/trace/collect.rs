@@ -1,69 +1,220 @@
 use crate::Trace;
 use crate::trace::types::{
-    CallerAddress, ContractName, ContractTrace, Selector, StorageAddress, TestName, TraceInfo,
+    CallData, CallResult, CallerAddress, ContractName, ContractTrace, Data, Event, Selector,
+    StorageAccess, StorageAddress, StorageRead, StorageWrite, TestName, TraceInfo, TraceVerbosity,
+};
+use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
+    CallFailure, CallResult as CheatnetCallResult,
 };
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
-use cheatnet::state::{CallTrace, CallTraceNode};
+use cheatnet::state::{
+    CallTrace, CallTraceNode, StorageAccess as CheatnetStorageAccess, StorageAccessKind,
+};
+use data_transformer::{
+    find_function, reverse_transform_abi_event, reverse_transform_function_output,
+    reverse_transform_input,
+};
+use starknet::core::types::contract::{AbiEntry, AbiFunction};
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
-pub fn trace(call_trace: &CallTrace, contracts_data: &ContractsData, test_name: String) -> Trace {
+pub fn trace(
+    call_trace: &CallTrace,
+    contracts_data: &ContractsData,
+    verbosity: TraceVerbosity,
+    test_name: String,
+) -> Trace {
+    let mut collector = Collector {
+        contracts_data,
+        verbosity,
+        abis: HashMap::new(),
+    };
+
     Trace {
         test_name: TestName(test_name),
-        nested_calls: nested_calls(call_trace, contracts_data),
+        nested_calls: collector.nested_calls(call_trace),
     }
 }
 
-fn contract_trace(
-    call_trace: &Rc<RefCell<CallTrace>>,
-    contracts_data: &ContractsData,
-) -> ContractTrace {
-    let call_trace = call_trace.borrow();
-    let nested_calls = nested_calls(&call_trace, contracts_data);
-
-    let trace_info = TraceInfo {
-        contract_name: contract_name(&call_trace, contracts_data),
-        entry_point_type: call_trace.entry_point.entry_point_type,
-        calldata: call_trace.entry_point.calldata.clone(),
-        storage_address: StorageAddress(call_trace.entry_point.storage_address),
-        caller_address: CallerAddress(call_trace.entry_point.caller_address),
-        call_type: call_trace.entry_point.call_type,
-        nested_calls,
-        call_result: call_trace.result.clone(),
-    };
+struct Collector<'a> {
+    contracts_data: &'a ContractsData,
+    verbosity: TraceVerbosity,
+    /// ABIs of contracts by their names, `None` if the ABI is not available
+    abis: HashMap<String, Option<Vec<AbiEntry>>>,
+}
 
-    ContractTrace {
-        selector: selector(&call_trace, contracts_data),
-        trace_info,
+impl Collector<'_> {
+    fn contract_trace(&mut self, call_trace: &Rc<RefCell<CallTrace>>) -> ContractTrace {
+        let call_trace = call_trace.borrow();
+        let nested_calls = self.nested_calls(&call_trace);
+
+        let contract_name = contract_name(&call_trace, self.contracts_data);
+        let entry_point = &call_trace.entry_point;
+
+        let standard = self.verbosity >= TraceVerbosity::Standard;
+        let detailed = self.verbosity >= TraceVerbosity::Detailed;
+
+        let (calldata, call_result, storage_accesses, events) = if standard {
+            let (calldata, call_result) = self.decode(&contract_name, &call_trace);
+            let storage_accesses = call_trace
+                .storage_accesses
+                .iter()
+                .map(storage_access)
+                .collect();
+            let events = self.decode_events(&contract_name, &call_trace);
+            (Some(calldata), Some(call_result), storage_accesses, events)
+        } else {
+            (None, None, vec![], vec![])
+        };
+
+        let trace_info = TraceInfo {
+            contract_name,
+            entry_point_type: detailed.then_some(entry_point.entry_point_type),
+            calldata,
+            storage_address: detailed.then_some(StorageAddress(entry_point.storage_address)),
+            caller_address: detailed.then_some(CallerAddress(entry_point.caller_address)),
+            call_type: detailed.then_some(entry_point.call_type),
+            nested_calls,
+            call_result,
+            storage_accesses,
+            events,
+        };
+
+        ContractTrace {
+            selector: selector(&call_trace, self.contracts_data),
+            trace_info,
+        }
+    }
+
+    fn nested_calls(&mut self, call_trace: &CallTrace) -> Vec<ContractTrace> {
+        call_trace
+            .nested_calls
+            .iter()
+            .filter_map(CallTraceNode::extract_entry_point_call)
+            .map(|call_trace| self.contract_trace(call_trace))
+            .collect()
+    }
+
+    /// Decodes calldata and returned values with the ABI of the called contract,
+    /// falling back to raw felts if the ABI is not available or the values don't match it
+    fn decode(
+        &mut self,
+        contract_name: &ContractName,
+        call_trace: &CallTrace,
+    ) -> (CallData, CallResult) {
+        let calldata = &call_trace.entry_point.calldata.0;
+        let abi_function = self.abi_function(contract_name, call_trace);
+
+        let decoded_calldata = abi_function
+            .as_ref()
+            .and_then(|(abi, function)| reverse_transform_input(calldata, abi, function).ok())
+            .map_or_else(|| Data::Raw(calldata.to_vec()), Data::Decoded);
+
+        let call_result = match &call_trace.result {
+            CheatnetCallResult::Success { ret_data } => CallResult::Success(
+                abi_function
+                    .as_ref()
+                    .and_then(|(abi, function)| {
+                        reverse_transform_function_output(ret_data, abi, function).ok()
+                    })
+                    .map_or_else(|| Data::Raw(ret_data.clone()), Data::Decoded),
+            ),
+            CheatnetCallResult::Failure(CallFailure::Panic { panic_data }) => {
+                CallResult::Panic(panic_data.clone())
+            }
+            CheatnetCallResult::Failure(CallFailure::Error { msg }) => {
+                CallResult::Error(msg.to_string())
+            }
+        };
+
+        (CallData(decoded_calldata), call_result)
+    }
+
+    /// Decodes events with the ABI of the called contract, falling back to raw keys and data
+    fn decode_events(
+        &mut self,
+        contract_name: &ContractName,
+        call_trace: &CallTrace,
+    ) -> Vec<Event> {
+        let abi = self.abi(contract_name);
+
+        call_trace
+            .events
+            .iter()
+            .map(|event| {
+                abi.and_then(|abi| reverse_transform_abi_event(&event.keys, &event.data, abi).ok())
+                    .map_or_else(
+                        || Event::Raw {
+                            keys: event.keys.clone(),
+                            data: event.data.clone(),
+                        },
+                        Event::Decoded,
+                    )
+            })
+            .collect()
+    }
+
+    fn abi(&mut self, contract_name: &ContractName) -> Option<&[AbiEntry]> {
+        let contracts_data = self.contracts_data;
+        self.abis
+            .entry(contract_name.0.clone())
+            .or_insert_with(|| contracts_data.get_abi(&contract_name.0))
+            .as_deref()
+    }
+
+    fn abi_function(
+        &mut self,
+        contract_name: &ContractName,
+        call_trace: &CallTrace,
+    ) -> Option<(&[AbiEntry], AbiFunction)> {
+        let abi = self.abi(contract_name)?;
+        let function = find_function(abi, &call_trace.entry_point.entry_point_selector.0).ok()?;
+
+        Some((abi, function))
     }
 }
 
-fn nested_calls(call_trace: &CallTrace, contracts_data: &ContractsData) -> Vec<ContractTrace> {
-    call_trace
-        .nested_calls
-        .iter()
-        .filter_map(CallTraceNode::extract_entry_point_call)
-        .map(|call_trace| contract_trace(call_trace, contracts_data))
-        .collect()
+fn storage_access(storage_access: &CheatnetStorageAccess) -> StorageAccess {
+    match storage_access.kind {
+        StorageAccessKind::Read => StorageAccess::Read(StorageRead {
+            key: storage_access.key,
+            value: storage_access.old_value,
+        }),
+        StorageAccessKind::Write => StorageAccess::Write(StorageWrite {
+            key: storage_access.key,
+            old_value: storage_access.old_value,
+            new_value: storage_access.new_value,
+        }),
+    }
 }
 
+/// Name of the called contract, or its class hash if the contract is not part of the package,
+/// e.g. when it is called on a fork
 fn contract_name(call_trace: &CallTrace, contracts_data: &ContractsData) -> ContractName {
+    let class_hash = call_trace
+        .entry_point
+        .class_hash
+        .expect("class_hash should be set in `fn execute_call_entry_point` in cheatnet");
+
     contracts_data
-        .get_contract_name(
-            &call_trace
-                .entry_point
-                .class_hash
-                .expect("class_hash should be set in `fn execute_call_entry_point` in cheatnet"),
-        )
+        .get_contract_name(&class_hash)
         .cloned()
-        .map(ContractName)
-        .expect("contract name should be present in `ContractsData`")
+        .map_or_else(
+            || ContractName(format!("{:#x}", class_hash.0)),
+            ContractName,
+        )
 }
 
+/// Name of the called function, or its selector if the contract is not part of the package
 fn selector(call_trace: &CallTrace, contracts_data: &ContractsData) -> Selector {
+    let entry_point_selector = call_trace.entry_point.entry_point_selector;
+
     contracts_data
-        .get_function_name(&call_trace.entry_point.entry_point_selector)
+        .get_function_name(&entry_point_selector)
         .cloned()
-        .map(Selector)
-        .expect("selector should be present in `ContractsData`")
+        .map_or_else(
+            || Selector(format!("{:#x}", entry_point_selector.0)),
+            Selector,
+        )
 }
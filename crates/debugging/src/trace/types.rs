@@ -1,15 +1,29 @@
 use crate::trace::collect;
 use crate::tree::TreeSerialize;
 use blockifier::execution::entry_point::CallType;
-use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::CallResult;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use cheatnet::state::CallTrace;
+use clap::ValueEnum;
+use data_transformer::reverse_transformer::{Struct, Type};
+use serde::Deserialize;
 use starknet_api::contract_class::EntryPointType;
 use starknet_api::core::ContractAddress;
-use starknet_api::transaction::fields::Calldata;
+use starknet_types_core::felt::Felt;
 use std::fmt;
 use std::fmt::Display;
 
+/// Amount of information about every call displayed in a [`Trace`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum TraceVerbosity {
+    /// Called contracts and functions
+    Minimal,
+    /// Called contracts and functions with decoded arguments and results, storage accesses and emitted events
+    Standard,
+    /// All information about the calls, including caller and storage addresses and call types
+    Detailed,
+}
+
 #[derive(Debug, Clone)]
 pub struct Trace {
     pub test_name: TestName,
@@ -22,16 +36,21 @@ pub struct ContractTrace {
     pub trace_info: TraceInfo,
 }
 
+/// Information about a call, fields which are `None` are not displayed at the chosen [`TraceVerbosity`]
 #[derive(Debug, Clone)]
 pub struct TraceInfo {
     pub contract_name: ContractName,
-    pub entry_point_type: EntryPointType,
-    pub calldata: Calldata,
-    pub storage_address: StorageAddress,
-    pub caller_address: CallerAddress,
-    pub call_type: CallType,
+    pub entry_point_type: Option<EntryPointType>,
+    pub calldata: Option<CallData>,
+    pub storage_address: Option<StorageAddress>,
+    pub caller_address: Option<CallerAddress>,
+    pub call_type: Option<CallType>,
     pub nested_calls: Vec<ContractTrace>,
-    pub call_result: CallResult,
+    pub call_result: Option<CallResult>,
+    /// Empty below [`TraceVerbosity::Standard`]
+    pub storage_accesses: Vec<StorageAccess>,
+    /// Empty below [`TraceVerbosity::Standard`]
+    pub events: Vec<Event>,
 }
 
 #[derive(Debug, Clone)]
@@ -49,11 +68,64 @@ pub struct StorageAddress(pub ContractAddress);
 #[derive(Debug, Clone)]
 pub struct CallerAddress(pub ContractAddress);
 
+/// Values passed to or returned from a call
+#[derive(Debug, Clone)]
+pub enum Data {
+    /// Values decoded with the ABI of the called contract
+    Decoded(Vec<Type>),
+    /// Raw felts, used when the ABI of the called contract is not available or doesn't match the values
+    Raw(Vec<Felt>),
+}
+
+#[derive(Debug, Clone)]
+pub struct CallData(pub Data);
+
+#[derive(Debug, Clone)]
+pub enum CallResult {
+    Success(Data),
+    Panic(Vec<Felt>),
+    Error(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum StorageAccess {
+    Read(StorageRead),
+    Write(StorageWrite),
+}
+
+#[derive(Debug, Clone)]
+pub struct StorageRead {
+    pub key: Felt,
+    pub value: Felt,
+}
+
+#[derive(Debug, Clone)]
+pub struct StorageWrite {
+    pub key: Felt,
+    pub old_value: Felt,
+    pub new_value: Felt,
+}
+
+/// Event emitted by a call
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// Event decoded with the ABI of the called contract
+    Decoded(Struct),
+    /// Raw keys and data, used when the ABI of the called contract is not available or doesn't match the event
+    Raw { keys: Vec<Felt>, data: Vec<Felt> },
+}
+
 impl Trace {
-    /// Creates a new [`Trace`] from a given `cheatnet` [`CallTrace`], [`ContractsData`] and a test name.
+    /// Creates a new [`Trace`] from a given `cheatnet` [`CallTrace`], [`ContractsData`], a test name
+    /// and [`TraceVerbosity`] deciding which information about calls is collected.
     #[must_use]
-    pub fn new(call_trace: &CallTrace, contracts_data: &ContractsData, test_name: String) -> Self {
-        collect::trace(call_trace, contracts_data, test_name)
+    pub fn new(
+        call_trace: &CallTrace,
+        contracts_data: &ContractsData,
+        verbosity: TraceVerbosity,
+        test_name: String,
+    ) -> Self {
+        collect::trace(call_trace, contracts_data, verbosity, test_name)
     }
 }
 
@@ -62,3 +134,15 @@ impl Display for Trace {
         write!(f, "{}", self.serialize())
     }
 }
+
+impl Display for Data {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Data::Decoded(values) => {
+                let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+                write!(f, "({})", values.join(", "))
+            }
+            Data::Raw(felts) => write!(f, "{felts:?}"),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::trace::types::{ContractTrace, Trace, TraceInfo};
+use crate::trace::types::{ContractTrace, StorageAccess, Trace, TraceInfo};
 use crate::tree::building::node::Node;
 
 /// Trait for adding a type to a tree.
@@ -28,12 +28,21 @@ impl AsTreeNode for ContractTrace {
 impl AsTreeNode for TraceInfo {
     fn as_tree_node(&self, parent: &mut Node) {
         parent.leaf(&self.contract_name);
-        parent.leaf(&self.entry_point_type);
-        parent.leaf(&self.calldata);
-        parent.leaf(&self.storage_address);
-        parent.leaf(&self.caller_address);
-        parent.leaf(&self.call_type);
-        parent.leaf(&self.call_result);
+        parent.leaf_optional(self.entry_point_type.as_ref());
+        parent.leaf_optional(self.calldata.as_ref());
+        parent.leaf_optional(self.storage_address.as_ref());
+        parent.leaf_optional(self.caller_address.as_ref());
+        parent.leaf_optional(self.call_type.as_ref());
+        parent.leaf_optional(self.call_result.as_ref());
+        for storage_access in &self.storage_accesses {
+            match storage_access {
+                StorageAccess::Read(storage_read) => parent.leaf(storage_read),
+                StorageAccess::Write(storage_write) => parent.leaf(storage_write),
+            }
+        }
+        for event in &self.events {
+            parent.leaf(event);
+        }
         for nested_call in &self.nested_calls {
             parent.as_tree_node(nested_call);
         }
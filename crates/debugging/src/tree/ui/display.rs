@@ -1,10 +1,9 @@
-use crate::trace::types::{CallerAddress, ContractName, Selector, StorageAddress, TestName};
-use blockifier::execution::entry_point::CallType;
-use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
-    CallFailure, CallResult,
+use crate::trace::types::{
+    CallData, CallResult, CallerAddress, ContractName, Event, Selector, StorageAddress,
+    StorageRead, StorageWrite, TestName,
 };
+use blockifier::execution::entry_point::CallType;
 use starknet_api::contract_class::EntryPointType;
-use starknet_api::transaction::fields::Calldata;
 use starknet_types_core::felt::Felt;
 use std::fmt::Debug;
 
@@ -50,10 +49,10 @@ impl NodeDisplay for EntryPointType {
     }
 }
 
-impl NodeDisplay for Calldata {
+impl NodeDisplay for CallData {
     const TAG: &'static str = "calldata";
     fn string_pretty(&self) -> String {
-        string_debug(&self.0)
+        self.0.to_string()
     }
 }
 
@@ -82,17 +81,48 @@ impl NodeDisplay for CallResult {
     const TAG: &'static str = "call result";
     fn string_pretty(&self) -> String {
         match self {
-            CallResult::Success { ret_data } => {
-                format!("success: {ret_data:?}")
+            CallResult::Success(ret_data) => {
+                format!("success: {ret_data}")
+            }
+            CallResult::Panic(panic_data) => {
+                format!("panic: {panic_data:?}")
+            }
+            CallResult::Error(msg) => {
+                format!("error: {msg}")
             }
-            CallResult::Failure(call_failure) => match call_failure {
-                CallFailure::Panic { panic_data } => {
-                    format!("panic: {panic_data:?}")
-                }
-                CallFailure::Error { msg } => {
-                    format!("error: {msg}")
-                }
-            },
+        }
+    }
+}
+
+impl NodeDisplay for StorageRead {
+    const TAG: &'static str = "storage read";
+    fn string_pretty(&self) -> String {
+        format!(
+            "key: {}, value: {}",
+            string_hex(self.key),
+            string_hex(self.value)
+        )
+    }
+}
+
+impl NodeDisplay for StorageWrite {
+    const TAG: &'static str = "storage write";
+    fn string_pretty(&self) -> String {
+        format!(
+            "key: {}, old value: {}, new value: {}",
+            string_hex(self.key),
+            string_hex(self.old_value),
+            string_hex(self.new_value)
+        )
+    }
+}
+
+impl NodeDisplay for Event {
+    const TAG: &'static str = "event";
+    fn string_pretty(&self) -> String {
+        match self {
+            Event::Decoded(event) => event.to_string(),
+            Event::Raw { keys, data } => format!("keys: {keys:?}, data: {data:?}"),
         }
     }
 }
@@ -23,33 +23,43 @@ const BACKTRACE_ENV: &str = "SNFORGE_BACKTRACE";
 
 pub fn add_backtrace_footer(
     message: String,
-    contracts_data: &ContractsData,
     encountered_errors: &[EncounteredError],
+    backtrace: Option<&Result<String>>,
 ) -> String {
     if encountered_errors.is_empty() {
         return message;
     }
 
-    let is_backtrace_enabled = env::var(BACKTRACE_ENV).is_ok_and(|value| value == "1");
-    if !is_backtrace_enabled {
-        return format!(
+    match backtrace {
+        None => format!(
             "{message}\nnote: run with `{BACKTRACE_ENV}=1` environment variable to display a backtrace",
-        );
+        ),
+        Some(Ok(backtraces)) => format!("{message}\n{backtraces}"),
+        Some(Err(err)) => format!("{message}\nfailed to create backtrace: {err}"),
+    }
+}
+
+/// Returns backtraces of the encountered errors,
+/// or `None` if there are no errors or displaying backtraces is disabled
+pub fn get_backtrace(
+    contracts_data: &ContractsData,
+    encountered_errors: &[EncounteredError],
+) -> Option<Result<String>> {
+    let is_backtrace_enabled = env::var(BACKTRACE_ENV).is_ok_and(|value| value == "1");
+    if encountered_errors.is_empty() || !is_backtrace_enabled {
+        return None;
     }
 
-    BacktraceContractRepository::new(contracts_data, encountered_errors)
-        .map(|repository| {
+    Some(
+        BacktraceContractRepository::new(contracts_data, encountered_errors).map(|repository| {
             encountered_errors
                 .iter()
                 .filter_map(|error| repository.get_backtrace(error.pc, error.class_hash))
                 .map(|backtrace| backtrace.to_string())
                 .collect::<Vec<_>>()
                 .join("\n")
-        })
-        .map_or_else(
-            |err| format!("{message}\nfailed to create backtrace: {err}"),
-            |backtraces| format!("{message}\n{backtraces}"),
-        )
+        }),
+    )
 }
 
 struct ContractBacktraceData {
@@ -2,11 +2,13 @@ use blockifier::execution::contract_class::TrackedResource;
 use camino::Utf8PathBuf;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use clap::ValueEnum;
+use debugging::TraceVerbosity;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::ffi::OsString;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Debug, PartialEq)]
 pub struct ForgeConfig {
@@ -14,7 +16,7 @@ pub struct ForgeConfig {
     pub output_config: Arc<OutputConfig>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct TestRunnerConfig {
     pub exit_first: bool,
     pub fuzzer_runs: NonZeroU32,
@@ -22,9 +24,20 @@ pub struct TestRunnerConfig {
     pub max_n_steps: Option<u32>,
     pub is_vm_trace_needed: bool,
     pub cache_dir: Utf8PathBuf,
+    /// Forks are resolved only from the fork cache in `cache_dir`, without connecting to the network
+    pub offline: bool,
+    /// Storage read by previous runs of forks at other blocks is fetched in the background
+    pub prefetch_fork_storage: bool,
     pub contracts_data: ContractsData,
     pub environment_variables: HashMap<String, String>,
     pub tracked_resource: ForgeTrackedResource,
+    pub transactional: bool,
+    /// Time limit of a single test run, unless set for the test with `#[timeout]`
+    pub timeout: Option<Duration>,
+    /// Verbosity of call traces printed for tests, `None` if they are not printed
+    pub trace_verbosity: Option<TraceVerbosity>,
+    /// Print call traces of passed tests too, not only of failed ones
+    pub trace_passed_tests: bool,
 }
 
 #[derive(Debug, PartialEq)]
@@ -84,9 +97,13 @@ pub struct RuntimeConfig<'a> {
     pub max_n_steps: Option<u32>,
     pub is_vm_trace_needed: bool,
     pub cache_dir: &'a Utf8PathBuf,
+    pub offline: bool,
+    pub prefetch_fork_storage: bool,
     pub contracts_data: &'a ContractsData,
     pub environment_variables: &'a HashMap<String, String>,
     pub tracked_resource: &'a ForgeTrackedResource,
+    pub transactional: bool,
+    pub timeout: Option<Duration>,
 }
 
 impl<'a> RuntimeConfig<'a> {
@@ -96,9 +113,13 @@ impl<'a> RuntimeConfig<'a> {
             max_n_steps: value.max_n_steps,
             is_vm_trace_needed: value.is_vm_trace_needed,
             cache_dir: &value.cache_dir,
+            offline: value.offline,
+            prefetch_fork_storage: value.prefetch_fork_storage,
             contracts_data: &value.contracts_data,
             environment_variables: &value.environment_variables,
             tracked_resource: &value.tracked_resource,
+            transactional: value.transactional,
+            timeout: value.timeout,
         }
     }
 }
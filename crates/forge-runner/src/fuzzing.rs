@@ -0,0 +1,2 @@
+pub(crate) mod corpus;
+pub(crate) mod shrinking;
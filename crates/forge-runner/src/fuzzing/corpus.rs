@@ -0,0 +1,75 @@
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::str::FromStr;
+
+const CORPUS_DIR: &str = "fuzzer_corpus";
+
+/// Failing inputs of a fuzz test saved in the cache directory,
+/// which are replayed before generating new ones in subsequent runs
+#[derive(Debug)]
+pub(crate) struct FuzzerCorpus {
+    path: Utf8PathBuf,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CorpusFile {
+    /// Values returned by the `generate_arg` cheatcode in failing runs, in order
+    counterexamples: Vec<Vec<String>>,
+}
+
+impl FuzzerCorpus {
+    pub(crate) fn new(cache_dir: &Utf8Path, test_name: &str) -> Self {
+        let file_name = sanitize_filename::sanitize(test_name.replace("::", "_"));
+
+        Self {
+            path: cache_dir.join(CORPUS_DIR).join(format!("{file_name}.json")),
+        }
+    }
+
+    pub(crate) fn load(&self) -> Result<Vec<Vec<BigInt>>> {
+        if !self.path.exists() {
+            return Ok(vec![]);
+        }
+
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read fuzzer corpus from {}", self.path))?;
+        let corpus: CorpusFile = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse fuzzer corpus from {}", self.path))?;
+
+        corpus
+            .counterexamples
+            .into_iter()
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|value| BigInt::from_str(value))
+                    .collect::<Result<_, _>>()
+                    .with_context(|| format!("Invalid value in fuzzer corpus {}", self.path))
+            })
+            .collect()
+    }
+
+    /// Replaces saved counterexamples with the given ones, removes the corpus file if there are none
+    pub(crate) fn save(&self, counterexamples: &[Vec<BigInt>]) -> Result<()> {
+        if counterexamples.is_empty() {
+            if self.path.exists() {
+                fs::remove_file(&self.path)?;
+            }
+            return Ok(());
+        }
+
+        let corpus = CorpusFile {
+            counterexamples: counterexamples
+                .iter()
+                .map(|values| values.iter().map(ToString::to_string).collect())
+                .collect(),
+        };
+
+        fs::create_dir_all(self.path.parent().unwrap())?;
+        fs::write(&self.path, serde_json::to_string_pretty(&corpus)?)
+            .with_context(|| format!("Failed to save fuzzer corpus to {}", self.path))
+    }
+}
@@ -0,0 +1,215 @@
+use crate::running::FuzzRunResult;
+use crate::test_case_summary::TestCaseSummary;
+use num_bigint::BigInt;
+use num_traits::Signed;
+
+/// Limits the number of test runs spent on shrinking a single failing input
+const MAX_SHRINK_RUNS: usize = 1000;
+
+/// Minimizes values generated for a failing fuzz test run.
+///
+/// Each value returned by the `generate_arg` cheatcode is moved towards the simplest value in its range
+/// (see [`FuzzerChoice::simplest_value`](cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::FuzzerChoice::simplest_value))
+/// with a binary search, as long as the test still fails.
+/// Since all `Fuzzable` types are built from such values, this shrinks arguments of any of them,
+/// e.g. numbers towards zero and byte arrays towards shorter ones.
+///
+/// `run` executes the test with replayed values, it returns `None` if the execution was interrupted.
+pub(crate) fn shrink(
+    failing: FuzzRunResult,
+    mut run: impl FnMut(Vec<BigInt>) -> Option<FuzzRunResult>,
+) -> FuzzRunResult {
+    let mut shrinker = Shrinker {
+        failing,
+        runs_left: MAX_SHRINK_RUNS,
+    };
+
+    loop {
+        let values_before = shrinker.values();
+
+        let mut index = 0;
+        while index < shrinker.failing.choices.len() {
+            if shrinker.shrink_choice(index, &mut run).is_none() {
+                return shrinker.failing;
+            }
+            index += 1;
+        }
+
+        // Shrinking a value can make other ones shrinkable, e.g. when the test fails for `a < b`
+        if shrinker.values() == values_before {
+            return shrinker.failing;
+        }
+    }
+}
+
+struct Shrinker {
+    failing: FuzzRunResult,
+    runs_left: usize,
+}
+
+enum Outcome {
+    Failed,
+    Passed,
+}
+
+impl Shrinker {
+    fn values(&self) -> Vec<BigInt> {
+        self.failing
+            .choices
+            .iter()
+            .map(|choice| choice.value.clone())
+            .collect()
+    }
+
+    /// Returns `None` if shrinking should be stopped
+    fn shrink_choice(
+        &mut self,
+        index: usize,
+        run: &mut impl FnMut(Vec<BigInt>) -> Option<FuzzRunResult>,
+    ) -> Option<()> {
+        let target = self.failing.choices[index].simplest_value();
+        if self.failing.choices[index].value == target {
+            return Some(());
+        }
+
+        if let Outcome::Failed = self.try_value(index, target.clone(), run)? {
+            return Some(());
+        }
+
+        // Value closest to the target for which the test passed
+        let mut passing = target;
+        while let Some(choice) = self.failing.choices.get(index) {
+            let distance = &choice.value - &passing;
+            if distance.abs() <= BigInt::from(1) {
+                break;
+            }
+
+            let candidate = &passing + distance / 2;
+            if let Outcome::Passed = self.try_value(index, candidate.clone(), run)? {
+                passing = candidate;
+            }
+        }
+
+        Some(())
+    }
+
+    fn try_value(
+        &mut self,
+        index: usize,
+        value: BigInt,
+        run: &mut impl FnMut(Vec<BigInt>) -> Option<FuzzRunResult>,
+    ) -> Option<Outcome> {
+        if self.runs_left == 0 {
+            return None;
+        }
+        self.runs_left -= 1;
+
+        let mut values = self.values();
+        values[index] = value;
+
+        let result = run(values)?;
+        match result.summary {
+            TestCaseSummary::Failed { .. } => {
+                self.failing = result;
+                Some(Outcome::Failed)
+            }
+            TestCaseSummary::Skipped {} => None,
+            _ => Some(Outcome::Passed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::FuzzerChoice;
+    use std::time::Duration;
+
+    fn run_result(values: &[BigInt], ranges: &[(i128, i128)], failed: bool) -> FuzzRunResult {
+        let summary = if failed {
+            TestCaseSummary::Failed {
+                name: "test".to_string(),
+                msg: None,
+                backtrace: None,
+                debugging_trace: None,
+                arguments: vec![],
+                fuzzer_args: values.iter().map(ToString::to_string).collect(),
+                test_statistics: (),
+                elapsed: Duration::ZERO,
+                created_forks: vec![],
+            }
+        } else {
+            // Only failures matter for shrinking
+            TestCaseSummary::Ignored {
+                name: "test".to_string(),
+            }
+        };
+
+        FuzzRunResult {
+            summary,
+            choices: values
+                .iter()
+                .zip(ranges)
+                .map(|(value, (min, max))| FuzzerChoice {
+                    min: BigInt::from(*min),
+                    max: BigInt::from(*max),
+                    value: value.clone(),
+                })
+                .collect(),
+            timed_out: false,
+        }
+    }
+
+    fn shrink_with(
+        values: &[i128],
+        ranges: &[(i128, i128)],
+        fails: impl Fn(&[BigInt]) -> bool,
+    ) -> Vec<BigInt> {
+        let values: Vec<_> = values.iter().copied().map(BigInt::from).collect();
+        let failing = run_result(&values, ranges, true);
+
+        shrink(failing, |values| {
+            Some(run_result(&values, ranges, fails(&values)))
+        })
+        .choices
+        .into_iter()
+        .map(|choice| choice.value)
+        .collect()
+    }
+
+    #[test]
+    fn shrinks_towards_zero() {
+        let result = shrink_with(
+            &[1_000_000, -5_000],
+            &[(0, i128::MAX), (-10_000, 10_000)],
+            |values| values[0] > BigInt::from(100),
+        );
+
+        assert_eq!(result, vec![BigInt::from(101), BigInt::from(0)]);
+    }
+
+    #[test]
+    fn shrinks_negative_values() {
+        let result = shrink_with(&[-1_000_000], &[(i128::MIN, i128::MAX)], |values| {
+            values[0] < BigInt::from(-42)
+        });
+
+        assert_eq!(result, vec![BigInt::from(-43)]);
+    }
+
+    #[test]
+    fn shrinks_towards_range_bound() {
+        let result = shrink_with(&[900], &[(500, 1000)], |_| true);
+
+        assert_eq!(result, vec![BigInt::from(500)]);
+    }
+
+    #[test]
+    fn shrinks_dependent_values() {
+        let result = shrink_with(&[700, 900], &[(0, 1000), (0, 1000)], |values| {
+            values[0] < values[1]
+        });
+
+        assert_eq!(result, vec![BigInt::from(0), BigInt::from(1)]);
+    }
+}
@@ -7,7 +7,7 @@ use blockifier::fee::resources::{
     ArchivalDataResources, ComputationResources, MessageResources, StarknetResources,
     StateResources, TransactionResources,
 };
-use blockifier::state::cached_state::CachedState;
+use blockifier::state::cached_state::{CachedState, StateChangesCountForFee};
 use blockifier::state::errors::StateError;
 use blockifier::transaction::objects::HasRelatedFeeType;
 use blockifier::utils::u64_from_usize;
@@ -15,13 +15,15 @@ use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::Use
 use cheatnet::runtime_extensions::forge_config_extension::config::RawAvailableGasConfig;
 use cheatnet::state::ExtendedStateReader;
 use shared::print::print_as_warning;
+use starknet_api::block::BlockInfo;
 use starknet_api::execution_resources::{GasAmount, GasVector};
 use starknet_api::transaction::EventContent;
 use starknet_api::transaction::fields::GasVectorComputationMode;
 
 pub fn calculate_used_gas(
     transaction_context: &TransactionContext,
-    state: &mut CachedState<ExtendedStateReader>,
+    block_info: &BlockInfo,
+    states: &mut [CachedState<ExtendedStateReader>],
     resources: UsedResources,
 ) -> Result<GasVector, StateError> {
     let versioned_constants = transaction_context.block_context.versioned_constants();
@@ -31,7 +33,7 @@ pub fn calculate_used_gas(
         &resources.l1_handler_payload_lengths,
     );
 
-    let state_resources = get_state_resources(transaction_context, state)?;
+    let state_resources = get_state_resources(transaction_context, states)?;
 
     let archival_data_resources = get_archival_data_resources(resources.events);
 
@@ -52,10 +54,9 @@ pub fn calculate_used_gas(
         computation: computation_resources,
     };
 
-    let use_kzg_da = transaction_context.block_context.block_info().use_kzg_da;
     Ok(transaction_resources.to_gas_vector(
         versioned_constants,
-        use_kzg_da,
+        block_info.use_kzg_da,
         &GasVectorComputationMode::All,
     ))
 }
@@ -115,26 +116,42 @@ fn get_messages_resources(
     }
 }
 
+// State changes of all forks are counted, as if each of them was a separate part of the state
 fn get_state_resources(
     transaction_context: &TransactionContext,
-    state: &mut CachedState<ExtendedStateReader>,
+    states: &mut [CachedState<ExtendedStateReader>],
 ) -> Result<StateResources, StateError> {
-    let mut state_changes = state.get_actual_state_changes()?;
-    // compiled_class_hash_updates is used only for keeping track of declares
-    // which we don't want to include in gas cost
-    state_changes.state_maps.compiled_class_hashes.clear();
-    state_changes.state_maps.declared_contracts.clear();
-
-    let state_changes_count = state_changes.count_for_fee_charge(
-        None,
-        transaction_context
-            .block_context
-            .chain_info()
-            .fee_token_address(&transaction_context.tx_info.fee_type()),
-    );
+    let mut state_changes_for_fee = StateChangesCountForFee::default();
+
+    for state in states {
+        let mut state_changes = state.get_actual_state_changes()?;
+        // compiled_class_hash_updates is used only for keeping track of declares
+        // which we don't want to include in gas cost
+        state_changes.state_maps.compiled_class_hashes.clear();
+        state_changes.state_maps.declared_contracts.clear();
+
+        let StateChangesCountForFee {
+            state_changes_count,
+            n_allocated_keys,
+        } = state_changes.count_for_fee_charge(
+            None,
+            transaction_context
+                .block_context
+                .chain_info()
+                .fee_token_address(&transaction_context.tx_info.fee_type()),
+        );
+
+        let total_count = &mut state_changes_for_fee.state_changes_count;
+        total_count.n_storage_updates += state_changes_count.n_storage_updates;
+        total_count.n_class_hash_updates += state_changes_count.n_class_hash_updates;
+        total_count.n_compiled_class_hash_updates +=
+            state_changes_count.n_compiled_class_hash_updates;
+        total_count.n_modified_contracts += state_changes_count.n_modified_contracts;
+        state_changes_for_fee.n_allocated_keys += n_allocated_keys;
+    }
 
     Ok(StateResources {
-        state_changes_for_fee: state_changes_count,
+        state_changes_for_fee,
     })
 }
 
@@ -148,6 +165,8 @@ pub fn check_available_gas(
             arguments,
             gas_info,
             debugging_trace,
+            elapsed,
+            created_forks,
             ..
         } if available_gas.is_some_and(|available_gas| match available_gas {
             RawAvailableGasConfig::MaxGas(gas) => {
@@ -176,10 +195,13 @@ pub fn check_available_gas(
                     "\n\tTest cost exceeded the available gas. Consumed l1_gas: ~{}, l1_data_gas: ~{}, l2_gas: ~{}",
                     gas_info.l1_gas, gas_info.l1_data_gas, gas_info.l2_gas
                 )),
+                backtrace: None,
                 arguments,
                 fuzzer_args: Vec::default(),
                 test_statistics: (),
                 debugging_trace,
+                elapsed,
+                created_forks,
             }
         }
         _ => summary,
@@ -1,12 +1,18 @@
 use crate::coverage_api::run_coverage;
 use crate::forge_config::{ExecutionDataToSave, ForgeConfig, TestRunnerConfig};
-use crate::running::{run_fuzz_test, run_test};
+use crate::fuzzing::corpus::FuzzerCorpus;
+use crate::fuzzing::shrinking::shrink;
+use crate::running::{run_fuzz_test, run_fuzz_test_case, run_test};
 use crate::test_case_summary::TestCaseSummary;
 use anyhow::{Result, anyhow};
 use build_trace_data::save_trace_data;
 use cairo_lang_sierra::program::{ConcreteTypeLongId, Function, TypeDeclaration};
 use camino::Utf8PathBuf;
-use cheatnet::runtime_extensions::forge_config_extension::config::RawFuzzerConfig;
+use cheatnet::runtime_extensions::forge_config_extension::config::{
+    RawFuzzerConfig, RawInvariantConfig,
+};
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::snapshot::Snapshot;
+use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::FuzzerArgsSource;
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
 use package_tests::with_config_resolved::TestCaseWithResolvedConfig;
@@ -18,6 +24,7 @@ use shared::spinner::Spinner;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use test_case_summary::{AnyTestCaseSummary, Fuzzing};
 use tokio::sync::mpsc::{Sender, channel};
 use tokio::task::JoinHandle;
@@ -33,6 +40,7 @@ pub mod test_case_summary;
 pub mod test_target_summary;
 
 mod backtrace;
+mod fuzzing;
 mod gas;
 pub mod printing;
 pub mod running;
@@ -102,15 +110,17 @@ pub fn run_for_test_case(
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     forge_config: Arc<ForgeConfig>,
     versioned_program_path: Arc<Utf8PathBuf>,
+    setup_state: Option<Arc<Snapshot>>,
     send: Sender<()>,
 ) -> JoinHandle<Result<AnyTestCaseSummary>> {
-    if case.config.fuzzer_config.is_none() {
+    if case.config.fuzzer_config.is_none() && case.config.invariant_config.is_none() {
         tokio::task::spawn(async move {
             let res = run_test(
                 case,
                 casm_program,
                 forge_config.test_runner_config.clone(),
                 versioned_program_path,
+                setup_state,
                 send,
             )
             .await?;
@@ -123,6 +133,7 @@ pub fn run_for_test_case(
                 casm_program,
                 forge_config.test_runner_config.clone(),
                 versioned_program_path,
+                setup_state,
                 send,
             )
             .await??;
@@ -131,11 +142,13 @@ pub fn run_for_test_case(
     }
 }
 
+#[expect(clippy::too_many_lines)]
 fn run_with_fuzzing(
     case: Arc<TestCaseWithResolvedConfig>,
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     test_runner_config: Arc<TestRunnerConfig>,
     versioned_program_path: Arc<Utf8PathBuf>,
+    setup_state: Option<Arc<Snapshot>>,
     send: Sender<()>,
 ) -> JoinHandle<Result<TestCaseSummary<Fuzzing>>> {
     tokio::task::spawn(async move {
@@ -143,45 +156,161 @@ fn run_with_fuzzing(
             return Ok(TestCaseSummary::Skipped {});
         }
 
+        let start = Instant::now();
         let (fuzzing_send, mut fuzzing_rec) = channel(1);
 
-        let (fuzzer_runs, fuzzer_seed) = match case.config.fuzzer_config {
-            Some(RawFuzzerConfig { runs, seed }) => (
-                runs.unwrap_or(test_runner_config.fuzzer_runs),
-                seed.unwrap_or(test_runner_config.fuzzer_seed),
-            ),
-            _ => (
-                test_runner_config.fuzzer_runs,
-                test_runner_config.fuzzer_seed,
-            ),
-        };
+        // Invariant tests are run like fuzz tests, every run makes a different sequence of calls
+        let (fuzzer_runs, fuzzer_seed) =
+            match (&case.config.invariant_config, &case.config.fuzzer_config) {
+                (Some(RawInvariantConfig { runs, seed, .. }), _)
+                | (None, Some(RawFuzzerConfig { runs, seed })) => (
+                    runs.unwrap_or(test_runner_config.fuzzer_runs),
+                    seed.unwrap_or(test_runner_config.fuzzer_seed),
+                ),
+                (None, None) => (
+                    test_runner_config.fuzzer_runs,
+                    test_runner_config.fuzzer_seed,
+                ),
+            };
 
-        let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(fuzzer_seed)));
+        let corpus = FuzzerCorpus::new(&test_runner_config.cache_dir, &case.name);
+        let mut counterexamples = corpus.load()?;
 
-        let mut tasks = FuturesUnordered::new();
+        let mut results = vec![];
+        let mut failing = None;
+        let mut timed_out = None;
 
-        for _ in 1..=fuzzer_runs.get() {
-            tasks.push(run_fuzz_test(
+        // Replay inputs that failed in previous runs first, as they are most likely to fail again
+        while !counterexamples.is_empty() && failing.is_none() && timed_out.is_none() {
+            let values = counterexamples.remove(0);
+            let result = run_fuzz_test(
                 case.clone(),
                 casm_program.clone(),
                 test_runner_config.clone(),
                 versioned_program_path.clone(),
+                setup_state.clone(),
                 send.clone(),
                 fuzzing_send.clone(),
-                rng.clone(),
-            ));
+                FuzzerArgsSource::Replay(values),
+            )
+            .await?;
+
+            match result.summary {
+                TestCaseSummary::Skipped {} => return Ok(TestCaseSummary::Skipped {}),
+                _ if result.timed_out => timed_out = Some(result.summary),
+                TestCaseSummary::Failed { .. } => failing = Some(result),
+                _ => results.push(result.summary),
+            }
         }
+        let replayed_runs = results.len();
 
-        let mut results = vec![];
-        while let Some(task) = tasks.next().await {
-            let result = task?;
+        if failing.is_none() && timed_out.is_none() {
+            let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(fuzzer_seed)));
 
-            results.push(result.clone());
+            let mut tasks = FuturesUnordered::new();
 
-            if let TestCaseSummary::Failed { .. } = result {
-                fuzzing_rec.close();
-                break;
+            for _ in 1..=fuzzer_runs.get() {
+                tasks.push(run_fuzz_test(
+                    case.clone(),
+                    casm_program.clone(),
+                    test_runner_config.clone(),
+                    versioned_program_path.clone(),
+                    setup_state.clone(),
+                    send.clone(),
+                    fuzzing_send.clone(),
+                    FuzzerArgsSource::Random(rng.clone()),
+                ));
             }
+
+            while let Some(task) = tasks.next().await {
+                let result = task?;
+
+                if result.timed_out {
+                    fuzzing_rec.close();
+                    timed_out = Some(result.summary);
+                    break;
+                }
+
+                if let TestCaseSummary::Failed { .. } = result.summary {
+                    fuzzing_rec.close();
+                    failing = Some(result);
+                    break;
+                }
+
+                results.push(result.summary);
+            }
+        }
+
+        let is_timed_out = timed_out.is_some();
+        if let Some(timed_out) = timed_out {
+            // Shrinking would run the test until the timeout again for every candidate,
+            // so timed out runs are neither shrunk nor saved in the corpus
+            results.push(timed_out);
+        } else if let Some(failing) = failing {
+            let shrunk = {
+                let case = case.clone();
+                let test_runner_config = test_runner_config.clone();
+                let send = send.clone();
+
+                tokio::task::spawn_blocking(move || {
+                    let shrunk = shrink(failing, |values| {
+                        // Stop shrinking if the execution of tests was interrupted
+                        (!send.is_closed())
+                            .then(|| {
+                                run_fuzz_test_case(
+                                    &case,
+                                    &casm_program,
+                                    &test_runner_config,
+                                    &versioned_program_path,
+                                    setup_state.as_deref(),
+                                    FuzzerArgsSource::Replay(values),
+                                    false,
+                                )
+                            })
+                            // A candidate which timed out fails differently than the shrunk run
+                            .filter(|result| !result.timed_out)
+                    });
+
+                    if test_runner_config.trace_verbosity.is_none() {
+                        return shrunk;
+                    }
+                    // Only the trace of the reported run is printed, so it is built by replaying it
+                    let values = shrunk
+                        .choices
+                        .iter()
+                        .map(|choice| choice.value.clone())
+                        .collect();
+                    let replayed = run_fuzz_test_case(
+                        &case,
+                        &casm_program,
+                        &test_runner_config,
+                        &versioned_program_path,
+                        setup_state.as_deref(),
+                        FuzzerArgsSource::Replay(values),
+                        true,
+                    );
+                    if !replayed.timed_out
+                        && matches!(replayed.summary, TestCaseSummary::Failed { .. })
+                    {
+                        replayed
+                    } else {
+                        shrunk
+                    }
+                })
+                .await?
+            };
+
+            let values = shrunk
+                .choices
+                .iter()
+                .map(|choice| choice.value.clone())
+                .collect();
+            counterexamples.insert(0, values);
+            results.push(shrunk.summary);
+        }
+
+        if !is_timed_out {
+            corpus.save(&counterexamples)?;
         }
 
         let runs = u32::try_from(
@@ -193,10 +322,13 @@ fn run_with_fuzzing(
                         TestCaseSummary::Passed { .. } | TestCaseSummary::Failed { .. }
                     )
                 })
-                .count(),
+                .count()
+                // Replayed results which neither passed nor failed are not counted above
+                .saturating_sub(replayed_runs),
         )?;
 
-        let fuzzing_run_summary: TestCaseSummary<Fuzzing> = TestCaseSummary::from(results);
+        let fuzzing_run_summary: TestCaseSummary<Fuzzing> =
+            TestCaseSummary::from(results, start.elapsed());
 
         if let TestCaseSummary::Passed { .. } = fuzzing_run_summary {
             // Because we execute tests parallel, it's possible to
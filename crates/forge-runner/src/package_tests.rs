@@ -30,6 +30,18 @@ pub struct TestTarget<C> {
     pub sierra_program_path: Arc<Utf8PathBuf>,
     pub casm_program: Arc<AssembledProgramWithDebugInfo>,
     pub test_cases: Vec<TestCase<C>>,
+    pub setup_functions: Vec<SetupFunction>,
+}
+
+impl<C> TestTarget<C> {
+    /// `#[setup]` function that applies to the test, defined in the innermost module containing the test
+    #[must_use]
+    pub fn setup_function_for(&self, test_name: &str) -> Option<&SetupFunction> {
+        self.setup_functions
+            .iter()
+            .filter(|setup| setup.applies_to(test_name))
+            .max_by_key(|setup| setup.module().len())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -38,3 +50,27 @@ pub struct TestCase<C> {
     pub name: String,
     pub config: C,
 }
+
+/// Function marked with `#[setup]`, run once before tests of its module
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetupFunction {
+    pub test_details: TestDetails,
+    pub name: String,
+}
+
+impl SetupFunction {
+    /// Module the setup function is defined in
+    #[must_use]
+    pub fn module(&self) -> &str {
+        self.name.rsplit_once("::").map_or("", |(module, _)| module)
+    }
+
+    /// Whether the setup function applies to a test, that is
+    /// the test is defined in the same module as the setup function or in one of its submodules
+    #[must_use]
+    pub fn applies_to(&self, test_name: &str) -> bool {
+        test_name
+            .strip_prefix(self.module())
+            .is_some_and(|rest| rest.starts_with("::"))
+    }
+}
@@ -1,9 +1,10 @@
 use super::{TestCase, TestTarget};
 use crate::expected_result::ExpectedTestResult;
 use cheatnet::runtime_extensions::forge_config_extension::config::{
-    RawAvailableGasConfig, RawFuzzerConfig,
+    BlockId, RawAvailableGasConfig, RawFuzzerConfig, RawInvariantConfig,
 };
 use starknet_api::block::BlockNumber;
+use std::time::Duration;
 use url::Url;
 
 pub type TestTargetWithResolvedConfig = TestTarget<TestCaseResolvedConfig>;
@@ -13,6 +14,8 @@ pub type TestCaseWithResolvedConfig = TestCase<TestCaseResolvedConfig>;
 #[derive(Debug, Clone, PartialEq)]
 pub struct ResolvedForkConfig {
     pub url: Url,
+    /// Block the fork was configured with
+    pub block_id: BlockId,
     pub block_number: BlockNumber,
 }
 
@@ -26,4 +29,7 @@ pub struct TestCaseResolvedConfig {
     pub expected_result: ExpectedTestResult,
     pub fork_config: Option<ResolvedForkConfig>,
     pub fuzzer_config: Option<RawFuzzerConfig>,
+    pub invariant_config: Option<RawInvariantConfig>,
+    pub transactional: bool,
+    pub timeout: Option<Duration>,
 }
@@ -2,7 +2,6 @@ use crate::forge_config::ForgeTrackedResource;
 use crate::test_case_summary::{AnyTestCaseSummary, FuzzingStatistics, TestCaseSummary};
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
 use console::style;
-
 pub fn print_test_result(
     any_test_result: &AnyTestCaseSummary,
     print_detailed_resources: bool,
@@ -29,7 +28,12 @@ pub fn print_test_result(
                 fuzzer_args,
                 test_statistics: FuzzingStatistics { runs },
                 ..
-            } => Some(format!(" (runs: {runs}, arguments: {fuzzer_args:?})")),
+            } => Some(if fuzzer_args.is_empty() {
+                // Invariant tests have no arguments, their call sequences are a part of the message
+                format!(" (runs: {runs})")
+            } else {
+                format!(" (runs: {runs}, arguments: {fuzzer_args:?})")
+            }),
             _ => None,
         };
     }
@@ -52,8 +56,13 @@ pub fn print_test_result(
         _ => String::new(),
     };
 
+    let elapsed = any_test_result
+        .elapsed()
+        .map(|elapsed| format!(" (time: {:.2}s)", elapsed.as_secs_f64()))
+        .unwrap_or_default();
+
     println!(
-        "{result_header} {result_name}{fuzzer_report}{gas_usage}{used_resources}{result_msg}\n\n{result_debug_trace}"
+        "{result_header} {result_name}{fuzzer_report}{gas_usage}{elapsed}{used_resources}{result_msg}\n\n{result_debug_trace}"
     );
 }
 
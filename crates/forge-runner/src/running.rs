@@ -1,7 +1,11 @@
-use crate::backtrace::add_backtrace_footer;
+use crate::backtrace::{add_backtrace_footer, get_backtrace};
+use crate::expected_result::ExpectedTestResult;
 use crate::forge_config::{RuntimeConfig, TestRunnerConfig};
 use crate::gas::calculate_used_gas;
-use crate::package_tests::with_config_resolved::{ResolvedForkConfig, TestCaseWithResolvedConfig};
+use crate::package_tests::SetupFunction;
+use crate::package_tests::with_config_resolved::{
+    ResolvedForkConfig, TestCaseResolvedConfig, TestCaseWithResolvedConfig,
+};
 use crate::test_case_summary::{Single, TestCaseSummary};
 use anyhow::{Result, ensure};
 use blockifier::execution::contract_class::TrackedResource;
@@ -26,11 +30,19 @@ use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
 use camino::{Utf8Path, Utf8PathBuf};
 use casm::{get_assembled_program, run_assembled_program};
 use cheatnet::constants as cheatnet_constants;
+use cheatnet::forking::multi_fork::{CreatedFork, MultiForkState};
 use cheatnet::forking::state::ForkStateReader;
+use cheatnet::forking::state_dump::is_state_dump_url;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::CallToBlockifierExtension;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
+use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::transaction::TransactionsState;
 use cheatnet::runtime_extensions::cheatable_starknet_runtime_extension::CheatableStarknetRuntimeExtension;
-use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use cheatnet::runtime_extensions::forge_config_extension::config::{BlockId, BlockTag};
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::snapshot::Snapshot;
+use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::{
+    FuzzerArgsSource, FuzzerChoice,
+};
+use cheatnet::runtime_extensions::forge_runtime_extension::invariant::InvariantState;
 use cheatnet::runtime_extensions::forge_runtime_extension::{
     ForgeExtension, ForgeRuntime, add_resources_to_top_call, get_all_used_resources,
     update_top_call_l1_resources, update_top_call_resources, update_top_call_vm_trace,
@@ -38,9 +50,10 @@ use cheatnet::runtime_extensions::forge_runtime_extension::{
 use cheatnet::state::{
     BlockInfoReader, CallTrace, CheatnetState, EncounteredError, ExtendedStateReader,
 };
+use conversions::string::TryFromHexStr;
 use entry_code::create_entry_code;
 use hints::{hints_by_representation, hints_to_params};
-use rand::prelude::StdRng;
+use runtime::starknet::constants::TEST_ADDRESS;
 use runtime::starknet::context::{build_context, set_max_steps};
 use runtime::{ExtendedRuntime, StarknetRuntime};
 use starknet_api::execution_resources::GasVector;
@@ -48,8 +61,10 @@ use starknet_types_core::felt::Felt;
 use std::cell::RefCell;
 use std::default::Default;
 use std::marker::PhantomData;
+use std::num::NonZeroU32;
 use std::rc::Rc;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::Sender;
 use tokio::task::JoinHandle;
 use universal_sierra_compiler_api::AssembledProgramWithDebugInfo;
@@ -59,18 +74,24 @@ pub mod config_run;
 mod entry_code;
 mod hints;
 mod syscall_handler;
+mod timeout;
 pub mod with_config;
 
 use crate::running::syscall_handler::build_syscall_handler;
+use crate::running::timeout::Watchdog;
 pub use syscall_handler::has_segment_arena;
 pub use syscall_handler::syscall_handler_offset;
 
+/// Number of calls made in every sequence of an invariant test, unless set in `#[invariant]`
+const DEFAULT_INVARIANT_DEPTH: u32 = 20;
+
 #[must_use]
 pub fn run_test(
     case: Arc<TestCaseWithResolvedConfig>,
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     test_runner_config: Arc<TestRunnerConfig>,
     versioned_program_path: Arc<Utf8PathBuf>,
+    setup_state: Option<Arc<Snapshot>>,
     send: Sender<()>,
 ) -> JoinHandle<TestCaseSummary<Single>> {
     tokio::task::spawn_blocking(move || {
@@ -80,12 +101,16 @@ pub fn run_test(
         if send.is_closed() {
             return TestCaseSummary::Skipped {};
         }
+        let start = Instant::now();
         let run_result = run_test_case(
             &case,
             &casm_program,
             &RuntimeConfig::from(&test_runner_config),
             None,
+            setup_state.as_deref(),
+            false,
         );
+        let elapsed = start.elapsed();
 
         // TODO: code below is added to fix snforge tests
         // remove it after improve exit-first tests
@@ -98,51 +123,177 @@ pub fn run_test(
             run_result,
             &case,
             vec![],
-            &test_runner_config.contracts_data,
+            &test_runner_config,
             &versioned_program_path,
+            elapsed,
+            true,
         )
     })
 }
 
+/// Result of a single fuzz test run together with the values generated for its arguments
+#[derive(Debug, Clone)]
+pub(crate) struct FuzzRunResult {
+    pub(crate) summary: TestCaseSummary<Single>,
+    pub(crate) choices: Vec<FuzzerChoice>,
+    /// Whether the run was interrupted because it exceeded the timeout
+    pub(crate) timed_out: bool,
+}
+
+#[expect(clippy::too_many_arguments)]
 pub(crate) fn run_fuzz_test(
     case: Arc<TestCaseWithResolvedConfig>,
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     test_runner_config: Arc<TestRunnerConfig>,
     versioned_program_path: Arc<Utf8PathBuf>,
+    setup_state: Option<Arc<Snapshot>>,
     send: Sender<()>,
     fuzzing_send: Sender<()>,
-    rng: Arc<Mutex<StdRng>>,
-) -> JoinHandle<TestCaseSummary<Single>> {
+    fuzzer_args_source: FuzzerArgsSource,
+) -> JoinHandle<FuzzRunResult> {
     tokio::task::spawn_blocking(move || {
         // Due to the inability of spawn_blocking to be abruptly cancelled,
         // a channel is used to receive information indicating
         // that the execution of the task is no longer necessary.
         if send.is_closed() | fuzzing_send.is_closed() {
-            return TestCaseSummary::Skipped {};
+            return FuzzRunResult {
+                summary: TestCaseSummary::Skipped {},
+                choices: vec![],
+                timed_out: false,
+            };
         }
 
-        let run_result = run_test_case(
+        let result = run_fuzz_test_case(
             &case,
             &casm_program,
-            &Arc::new(RuntimeConfig::from(&test_runner_config)),
-            Some(rng),
+            &test_runner_config,
+            &versioned_program_path,
+            setup_state.as_deref(),
+            fuzzer_args_source,
+            false,
         );
 
         // TODO: code below is added to fix snforge tests
         // remove it after improve exit-first tests
         // issue #1043
         if send.is_closed() {
-            return TestCaseSummary::Skipped {};
+            return FuzzRunResult {
+                summary: TestCaseSummary::Skipped {},
+                choices: vec![],
+                timed_out: false,
+            };
         }
 
+        result
+    })
+}
+
+/// Runs a single input of a fuzz test. The call trace is added to the summary only `with_trace`,
+/// so that it is not built for every run, but only for the one reported.
+pub(crate) fn run_fuzz_test_case(
+    case: &TestCaseWithResolvedConfig,
+    casm_program: &AssembledProgramWithDebugInfo,
+    test_runner_config: &TestRunnerConfig,
+    versioned_program_path: &Utf8Path,
+    setup_state: Option<&Snapshot>,
+    fuzzer_args_source: FuzzerArgsSource,
+    with_trace: bool,
+) -> FuzzRunResult {
+    let start = Instant::now();
+    let run_result = run_test_case(
+        case,
+        casm_program,
+        &RuntimeConfig::from(test_runner_config),
+        Some(fuzzer_args_source),
+        setup_state,
+        false,
+    );
+    let elapsed = start.elapsed();
+
+    let (choices, invariant_calls, timed_out) = run_result
+        .as_ref()
+        .map(|result_with_info| {
+            (
+                result_with_info.fuzzer_choices.clone(),
+                result_with_info.invariant_calls.clone(),
+                result_with_info.timed_out.is_some(),
+            )
+        })
+        .unwrap_or_default();
+
+    let summary = add_call_sequence(
         extract_test_case_summary(
             run_result,
-            &case,
+            case,
             vec![],
-            &test_runner_config.contracts_data,
-            &versioned_program_path,
-        )
-    })
+            test_runner_config,
+            versioned_program_path,
+            elapsed,
+            with_trace,
+        ),
+        &invariant_calls,
+    );
+
+    FuzzRunResult {
+        summary,
+        choices,
+        timed_out,
+    }
+}
+
+/// Fails a passed test if call expectations set by it with `expect_call` were not met
+fn check_call_expectations(
+    summary: TestCaseSummary<Single>,
+    unmet_call_expectations: &[String],
+    fuzzer_args: Vec<String>,
+) -> TestCaseSummary<Single> {
+    match summary {
+        TestCaseSummary::Passed {
+            name,
+            arguments,
+            elapsed,
+            created_forks,
+            ..
+        } if !unmet_call_expectations.is_empty() => {
+            let mut msg = String::from("\n    Call expectations were not met:\n");
+            for unmet_call_expectation in unmet_call_expectations {
+                for line in unmet_call_expectation.lines() {
+                    msg.push_str(&format!("    {line}\n"));
+                }
+            }
+
+            TestCaseSummary::Failed {
+                name,
+                msg: Some(msg),
+                backtrace: None,
+                arguments,
+                fuzzer_args,
+                test_statistics: (),
+                debugging_trace: None,
+                elapsed,
+                created_forks,
+            }
+        }
+        _ => summary,
+    }
+}
+
+/// Appends calls made by a failed invariant test to its message, so that the failure can be reproduced
+fn add_call_sequence(
+    mut summary: TestCaseSummary<Single>,
+    calls: &[String],
+) -> TestCaseSummary<Single> {
+    if let TestCaseSummary::Failed { msg, .. } = &mut summary {
+        if !calls.is_empty() {
+            let msg = msg.get_or_insert_with(String::new);
+            msg.push_str("\n    Call sequence:\n");
+            for (index, call) in calls.iter().enumerate() {
+                msg.push_str(&format!("      {}. {call}\n", index + 1));
+            }
+        }
+    }
+
+    summary
 }
 
 pub struct RunResultWithInfo {
@@ -152,6 +303,75 @@ pub struct RunResultWithInfo {
     pub(crate) used_resources: UsedResources,
     pub(crate) encountered_errors: Vec<EncounteredError>,
     pub(crate) fuzzer_args: Vec<String>,
+    pub(crate) fuzzer_choices: Vec<FuzzerChoice>,
+    pub(crate) invariant_calls: Vec<String>,
+    /// Messages describing call expectations set by the test which were not met
+    pub(crate) unmet_call_expectations: Vec<String>,
+    /// State left by the test, saved only if requested
+    pub(crate) final_state: Option<Snapshot>,
+    /// Timeout after which the run was interrupted, if it was
+    pub(crate) timed_out: Option<Duration>,
+    /// Forks created by the test with `create_fork` cheatcode
+    pub(crate) created_forks: Vec<CreatedFork>,
+}
+
+/// Runs a `#[setup]` function, returning the state it left or the message describing its failure
+#[must_use]
+pub fn run_setup(
+    setup: SetupFunction,
+    casm_program: Arc<AssembledProgramWithDebugInfo>,
+    test_runner_config: Arc<TestRunnerConfig>,
+    versioned_program_path: Arc<Utf8PathBuf>,
+) -> JoinHandle<Result<Arc<Snapshot>, String>> {
+    tokio::task::spawn_blocking(move || {
+        let case = TestCaseWithResolvedConfig {
+            name: setup.name,
+            test_details: setup.test_details,
+            config: TestCaseResolvedConfig {
+                available_gas: None,
+                ignored: false,
+                expected_result: ExpectedTestResult::Success,
+                fork_config: None,
+                fuzzer_config: None,
+                invariant_config: None,
+                transactional: false,
+                timeout: None,
+            },
+        };
+
+        let start = Instant::now();
+        let mut run_result = run_test_case(
+            &case,
+            &casm_program,
+            &RuntimeConfig::from(&test_runner_config),
+            None,
+            None,
+            true,
+        );
+        let elapsed = start.elapsed();
+        let final_state = run_result
+            .as_mut()
+            .ok()
+            .and_then(|result_with_info| result_with_info.final_state.take());
+
+        match extract_test_case_summary(
+            run_result,
+            &case,
+            vec![],
+            &test_runner_config,
+            &versioned_program_path,
+            elapsed,
+            false,
+        ) {
+            TestCaseSummary::Passed { .. } => Ok(Arc::new(
+                final_state.expect("Final state should be saved for setup functions"),
+            )),
+            TestCaseSummary::Failed { msg, .. } => Err(msg.unwrap_or_default()),
+            TestCaseSummary::Ignored { .. } | TestCaseSummary::Skipped { .. } => {
+                unreachable!("Setup functions are neither ignored nor skipped")
+            }
+        }
+    })
 }
 
 #[expect(clippy::too_many_lines)]
@@ -159,7 +379,9 @@ pub fn run_test_case(
     case: &TestCaseWithResolvedConfig,
     casm_program: &AssembledProgramWithDebugInfo,
     runtime_config: &RuntimeConfig,
-    fuzzer_rng: Option<Arc<Mutex<StdRng>>>,
+    fuzzer_args_source: Option<FuzzerArgsSource>,
+    setup_state: Option<&Snapshot>,
+    save_final_state: bool,
 ) -> Result<RunResultWithInfo> {
     ensure!(
         case.config
@@ -180,9 +402,15 @@ pub fn run_test_case(
         dict_state_reader: cheatnet_constants::build_testing_state(),
         fork_state_reader: get_fork_state_reader(
             runtime_config.cache_dir,
+            runtime_config.offline,
+            runtime_config.prefetch_fork_storage,
             case.config.fork_config.as_ref(),
         )?,
     };
+    // State left by the setup function is not counted as changes made by the test
+    if let Some(setup_state) = setup_state {
+        setup_state.write_to_state_reader(&mut state_reader.dict_state_reader);
+    }
     let block_info = state_reader.get_block_info()?;
     let chain_id = state_reader.get_chain_id()?;
     let tracked_resource = TrackedResource::from(runtime_config.tracked_resource);
@@ -192,9 +420,19 @@ pub fn run_test_case(
     if let Some(max_n_steps) = runtime_config.max_n_steps {
         set_max_steps(&mut context, max_n_steps);
     }
-    let mut cached_state = CachedState::new(state_reader);
+    let mut forked_state = MultiForkState::new(
+        CachedState::new(state_reader),
+        runtime_config.cache_dir,
+        runtime_config.offline,
+        runtime_config.prefetch_fork_storage,
+    );
+    // Only the default fork is saved in the state left by setup functions
+    if save_final_state {
+        forked_state.disable_forking();
+    }
+    let forks = forked_state.clone();
     let syscall_handler = build_syscall_handler(
-        &mut cached_state,
+        &mut forked_state,
         &string_to_hint,
         &mut context,
         &case.test_details.parameter_types,
@@ -205,11 +443,28 @@ pub fn run_test_case(
         block_info,
         ..Default::default()
     };
+    let timeout = case.config.timeout.or(runtime_config.timeout);
+    let _watchdog =
+        timeout.map(|timeout| Watchdog::start(timeout, cheatnet_state.interrupted.clone()));
     cheatnet_state.trace_data.is_vm_trace_needed = runtime_config.is_vm_trace_needed;
+    if case.config.transactional || runtime_config.transactional {
+        cheatnet_state.transactions = Some(TransactionsState::default());
+    }
+    cheatnet_state.invariant = case.config.invariant_config.as_ref().map(|config| {
+        InvariantState::new(
+            config
+                .depth
+                .map_or(DEFAULT_INVARIANT_DEPTH, NonZeroU32::get),
+        )
+    });
+    if let Some(setup_state) = setup_state {
+        cheatnet_state.start_from_setup(setup_state);
+    }
 
     let cheatable_runtime = ExtendedRuntime {
         extension: CheatableStarknetRuntimeExtension {
             cheatnet_state: &mut cheatnet_state,
+            pending_storage_access: None,
         },
         extended_runtime: StarknetRuntime {
             hint_handler: syscall_handler,
@@ -231,7 +486,8 @@ pub fn run_test_case(
     let forge_extension = ForgeExtension {
         environment_variables: runtime_config.environment_variables,
         contracts_data: runtime_config.contracts_data,
-        fuzzer_rng,
+        fuzzer_args_source,
+        forks,
     };
 
     let mut forge_runtime = ExtendedRuntime {
@@ -277,6 +533,16 @@ pub fn run_test_case(
             }
             Err(err) => Err(err),
         };
+    // Interrupted runs fail on unfinished execution, a run which has just finished is not timed out
+    let timed_out = timeout.filter(|_| {
+        run_result.is_err()
+            && forge_runtime
+                .extended_runtime
+                .extended_runtime
+                .extension
+                .cheatnet_state
+                .is_interrupted()
+    });
 
     let encountered_errors = forge_runtime
         .extended_runtime
@@ -298,13 +564,61 @@ pub fn run_test_case(
         .cheatnet_state
         .fuzzer_args
         .clone();
+    let fuzzer_choices = forge_runtime
+        .extended_runtime
+        .extended_runtime
+        .extension
+        .cheatnet_state
+        .fuzzer_choices
+        .clone();
+
+    let invariant_calls = forge_runtime
+        .extended_runtime
+        .extended_runtime
+        .extension
+        .cheatnet_state
+        .invariant
+        .as_ref()
+        .map(|invariant| invariant.calls.clone())
+        .unwrap_or_default();
+
+    let unmet_call_expectations = forge_runtime
+        .extended_runtime
+        .extended_runtime
+        .extension
+        .cheatnet_state
+        .unmet_call_expectations();
+
+    let final_state = if save_final_state {
+        Some(
+            forge_runtime
+                .extended_runtime
+                .extended_runtime
+                .extension
+                .cheatnet_state
+                .take_snapshot(&forge_runtime.extension.forks)?,
+        )
+    } else {
+        None
+    };
+
+    // Gas prices and `use_kzg_da` may be cheated for the test itself, e.g. globally
+    let block_info = forge_runtime
+        .extended_runtime
+        .extended_runtime
+        .extension
+        .cheatnet_state
+        .get_cheated_block_info(TryFromHexStr::try_from_hex_str(TEST_ADDRESS).unwrap());
+
+    let created_forks = forge_runtime.extension.forks.created_forks();
 
     let transaction_context = get_context(&forge_runtime).tx_context.clone();
     let used_resources =
         get_all_used_resources(forge_runtime, &transaction_context, tracked_resource);
     let gas = calculate_used_gas(
         &transaction_context,
-        &mut cached_state,
+        &block_info,
+        &mut forked_state.states(),
         used_resources.clone(),
     )?;
 
@@ -321,6 +635,12 @@ pub fn run_test_case(
         call_trace: call_trace_ref,
         encountered_errors,
         fuzzer_args,
+        fuzzer_choices,
+        invariant_calls,
+        unmet_call_expectations,
+        final_state,
+        timed_out,
+        created_forks,
     })
 }
 
@@ -378,24 +698,47 @@ fn extract_test_case_summary(
     run_result: Result<RunResultWithInfo>,
     case: &TestCaseWithResolvedConfig,
     args: Vec<Felt>,
-    contracts_data: &ContractsData,
+    test_runner_config: &TestRunnerConfig,
     versioned_program_path: &Utf8Path,
+    elapsed: Duration,
+    with_trace: bool,
 ) -> TestCaseSummary<Single> {
+    let contracts_data = &test_runner_config.contracts_data;
+    let add_trace = |summary: TestCaseSummary<Single>, call_trace: &CallTrace| {
+        if with_trace {
+            summary.with_debugging_trace(call_trace, test_runner_config)
+        } else {
+            summary
+        }
+    };
     match run_result {
+        Ok(RunResultWithInfo {
+            timed_out: Some(timeout),
+            ..
+        }) => TestCaseSummary::timed_out(case, timeout),
         Ok(result_with_info) => {
             match result_with_info.run_result {
-                Ok(run_result) => TestCaseSummary::from_run_result_and_info(
-                    run_result,
-                    case,
-                    args,
-                    result_with_info.fuzzer_args,
-                    result_with_info.gas_used,
-                    result_with_info.used_resources,
-                    &result_with_info.call_trace,
-                    &result_with_info.encountered_errors,
-                    contracts_data,
-                    versioned_program_path,
-                ),
+                Ok(run_result) => add_trace(
+                    check_call_expectations(
+                        TestCaseSummary::from_run_result_and_info(
+                            run_result,
+                            case,
+                            args,
+                            result_with_info.fuzzer_args.clone(),
+                            result_with_info.gas_used,
+                            result_with_info.used_resources,
+                            &result_with_info.call_trace,
+                            &result_with_info.encountered_errors,
+                            contracts_data,
+                            versioned_program_path,
+                            elapsed,
+                        ),
+                        &result_with_info.unmet_call_expectations,
+                        result_with_info.fuzzer_args,
+                    ),
+                    &result_with_info.call_trace.borrow(),
+                )
+                .with_created_forks(result_with_info.created_forks),
                 // CairoRunError comes from VirtualMachineError which may come from HintException that originates in TestExecutionSyscallHandler
                 Err(error) => {
                     let mut message = format!(
@@ -409,26 +752,25 @@ fn extract_test_case_summary(
                                 "\n    Suggestion: Consider using the flag `--max-n-steps` to increase allowed limit of steps",
                             );
                     }
-                    TestCaseSummary::Failed {
+                    let backtrace =
+                        get_backtrace(contracts_data, &result_with_info.encountered_errors);
+                    let summary = TestCaseSummary::Failed {
                         name: case.name.clone(),
-                        msg: Some(message).map(|msg| {
-                            add_backtrace_footer(
-                                msg,
-                                contracts_data,
-                                &result_with_info.encountered_errors,
-                            )
-                        }),
+                        msg: Some(add_backtrace_footer(
+                            message,
+                            &result_with_info.encountered_errors,
+                            backtrace.as_ref(),
+                        )),
+                        backtrace: backtrace.and_then(Result::ok),
                         arguments: args,
                         fuzzer_args: result_with_info.fuzzer_args,
                         test_statistics: (),
-                        debugging_trace: cfg!(feature = "debugging").then(|| {
-                            debugging::Trace::new(
-                                &result_with_info.call_trace.borrow(),
-                                contracts_data,
-                                case.name.clone(),
-                            )
-                        }),
-                    }
+                        debugging_trace: None,
+                        elapsed,
+                        created_forks: vec![],
+                    };
+                    add_trace(summary, &result_with_info.call_trace.borrow())
+                        .with_created_forks(result_with_info.created_forks)
                 }
             }
         }
@@ -437,23 +779,39 @@ fn extract_test_case_summary(
         Err(error) => TestCaseSummary::Failed {
             name: case.name.clone(),
             msg: Some(error.to_string()),
+            backtrace: None,
             arguments: args,
             fuzzer_args: Vec::default(),
             test_statistics: (),
             debugging_trace: None,
+            elapsed,
+            created_forks: vec![],
         },
     }
 }
 
 fn get_fork_state_reader(
     cache_dir: &Utf8Path,
+    offline: bool,
+    prefetch: bool,
     fork_config: Option<&ResolvedForkConfig>,
 ) -> Result<Option<ForkStateReader>> {
     fork_config
         .as_ref()
-        .map(|ResolvedForkConfig { url, block_number }| {
-            ForkStateReader::new(url.clone(), *block_number, cache_dir)
-        })
+        .map(
+            |ResolvedForkConfig {
+                 url,
+                 block_id,
+                 block_number,
+             }| {
+                // A state dump has no pending block, every tag refers to the block of the dump
+                if *block_id == BlockId::BlockTag(BlockTag::Pending) && !is_state_dump_url(url) {
+                    Ok(ForkStateReader::new_pending(url.clone(), *block_number))
+                } else {
+                    ForkStateReader::new(url.clone(), *block_number, cache_dir, offline, prefetch)
+                }
+            },
+        )
         .transpose()
 }
 
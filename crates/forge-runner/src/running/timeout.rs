@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Interrupts a run which does not finish within the timeout.
+/// The run finishes by dropping the watchdog, which stops its thread.
+pub(crate) struct Watchdog {
+    _finished: mpsc::Sender<()>,
+}
+
+impl Watchdog {
+    /// Sets `interrupted` once `timeout` passes, the VM checks it before every step
+    pub(crate) fn start(timeout: Duration, interrupted: Arc<AtomicBool>) -> Self {
+        let (finished, wait_for_finish) = mpsc::channel::<()>();
+        thread::spawn(move || {
+            if wait_for_finish.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout) {
+                interrupted.store(true, Ordering::Relaxed);
+            }
+        });
+
+        Self {
+            _finished: finished,
+        }
+    }
+}
@@ -1,13 +1,13 @@
 use crate::{
     forge_config::ForgeTrackedResource,
     package_tests::{
-        TestDetails,
+        SetupFunction, TestDetails,
         raw::TestTargetRaw,
         with_config::{TestCaseWithConfig, TestTargetWithConfig},
     },
     running::config_run::run_config_pass,
 };
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, bail};
 use cairo_lang_sierra::{
     extensions::core::{CoreLibfunc, CoreType},
     ids::ConcreteTypeId,
@@ -60,6 +60,10 @@ pub fn test_target_with_config(
         .as_ref()
         .and_then(|info| info.executables.get("snforge_internal_test_executable"))
         .unwrap_or(&default_executables);
+    let setup_executables = debug_info
+        .as_ref()
+        .and_then(|info| info.executables.get("snforge_internal_setup_executable"))
+        .unwrap_or(&default_executables);
 
     let test_cases = executables
         .par_iter()
@@ -78,12 +82,35 @@ pub fn test_target_with_config(
         })
         .collect::<Result<_>>()?;
 
+    let setup_functions: Vec<_> = setup_executables
+        .iter()
+        .map(|setup| SetupFunction {
+            test_details: build_test_details(funcs[&setup.id], &type_declarations, &type_size_map),
+            name: setup.debug_name.clone().unwrap().into(),
+        })
+        .collect();
+
+    for (i, setup) in setup_functions.iter().enumerate() {
+        if let Some(other) = setup_functions[..i]
+            .iter()
+            .find(|other| other.module() == setup.module())
+        {
+            bail!(
+                "Module {} has more than one #[setup] function: {} and {}",
+                setup.module(),
+                other.name,
+                setup.name
+            );
+        }
+    }
+
     Ok(TestTargetWithConfig {
         tests_location: test_target_raw.tests_location,
         test_cases,
         sierra_program: test_target_raw.sierra_program,
         sierra_program_path: test_target_raw.sierra_program_path.into(),
         casm_program,
+        setup_functions,
     })
 }
 
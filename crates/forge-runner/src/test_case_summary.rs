@@ -1,12 +1,14 @@
-use crate::backtrace::add_backtrace_footer;
+use crate::backtrace::{add_backtrace_footer, get_backtrace};
 use crate::build_trace_data::build_profiler_call_trace;
 use crate::expected_result::{ExpectedPanicValue, ExpectedTestResult};
+use crate::forge_config::TestRunnerConfig;
 use crate::gas::check_available_gas;
 use crate::package_tests::with_config_resolved::TestCaseWithResolvedConfig;
 use cairo_annotations::trace_data::VersionedCallTrace as VersionedProfilerCallTrace;
 use cairo_lang_runner::short_string::as_cairo_short_string;
 use cairo_lang_runner::{RunResult, RunResultValue};
 use camino::Utf8Path;
+use cheatnet::forking::multi_fork::CreatedFork;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use cheatnet::state::{CallTrace as InternalCallTrace, EncounteredError};
@@ -19,6 +21,7 @@ use std::cell::RefCell;
 use std::fmt;
 use std::option::Option;
 use std::rc::Rc;
+use std::time::Duration;
 
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct GasStatistics {
@@ -147,6 +150,10 @@ pub enum TestCaseSummary<T: TestType> {
         test_statistics: <T as TestType>::TestStatistics,
         /// Test trace data
         trace_data: <T as TestType>::TraceData,
+        /// Wall-clock time of running the test case, including all runs of fuzz tests
+        elapsed: Duration,
+        /// Forks created by the test case with `create_fork` cheatcode
+        created_forks: Vec<CreatedFork>,
     },
     /// Test case failed
     Failed {
@@ -154,6 +161,8 @@ pub enum TestCaseSummary<T: TestType> {
         name: String,
         /// Message returned by the test case run
         msg: Option<String>,
+        /// Backtrace of the errors encountered during the test case run
+        backtrace: Option<String>,
         /// Trace of the test case run
         debugging_trace: Option<debugging::Trace>,
         /// Arguments used in the test case run
@@ -162,6 +171,10 @@ pub enum TestCaseSummary<T: TestType> {
         fuzzer_args: Vec<String>,
         /// Statistics of the test run
         test_statistics: <T as TestType>::TestStatistics,
+        /// Wall-clock time of running the test case, including all runs of fuzz tests
+        elapsed: Duration,
+        /// Forks created by the test case with `create_fork` cheatcode
+        created_forks: Vec<CreatedFork>,
     },
     /// Test case ignored due to `#[ignored]` attribute or `--ignored` flag
     Ignored {
@@ -211,11 +224,31 @@ impl<T: TestType> TestCaseSummary<T> {
             _ => None,
         }
     }
+
+    #[must_use]
+    pub fn elapsed(&self) -> Option<Duration> {
+        match self {
+            TestCaseSummary::Passed { elapsed, .. } | TestCaseSummary::Failed { elapsed, .. } => {
+                Some(*elapsed)
+            }
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn created_forks(&self) -> &[CreatedFork] {
+        match self {
+            TestCaseSummary::Passed { created_forks, .. }
+            | TestCaseSummary::Failed { created_forks, .. } => created_forks,
+            _ => &[],
+        }
+    }
 }
 
 impl TestCaseSummary<Fuzzing> {
+    /// Combines results of all runs of a fuzz test, which took `elapsed` time in total
     #[must_use]
-    pub fn from(results: Vec<TestCaseSummary<Single>>) -> Self {
+    pub fn from(results: Vec<TestCaseSummary<Single>>, elapsed: Duration) -> Self {
         let last: TestCaseSummary<Single> = results
             .iter()
             .last()
@@ -232,6 +265,8 @@ impl TestCaseSummary<Fuzzing> {
                 test_statistics: (),
                 trace_data: _,
                 debugging_trace,
+                elapsed: _,
+                created_forks,
             } => {
                 let runs = results.len();
                 let gas_usages: Vec<GasVector> = results
@@ -251,24 +286,32 @@ impl TestCaseSummary<Fuzzing> {
                     test_statistics: FuzzingStatistics { runs },
                     trace_data: (),
                     debugging_trace,
+                    elapsed,
+                    created_forks,
                 }
             }
             TestCaseSummary::Failed {
                 name,
                 msg,
+                backtrace,
                 arguments,
                 fuzzer_args,
                 debugging_trace,
                 test_statistics: (),
+                elapsed: _,
+                created_forks,
             } => TestCaseSummary::Failed {
                 name,
                 msg,
+                backtrace,
                 arguments,
                 fuzzer_args,
                 test_statistics: FuzzingStatistics {
                     runs: results.len(),
                 },
                 debugging_trace,
+                elapsed,
+                created_forks,
             },
             TestCaseSummary::Ignored { name } => TestCaseSummary::Ignored { name: name.clone() },
             TestCaseSummary::Skipped {} => TestCaseSummary::Skipped {},
@@ -277,6 +320,93 @@ impl TestCaseSummary<Fuzzing> {
 }
 
 impl TestCaseSummary<Single> {
+    /// Adds the call trace of the test to the summary, if it should be printed
+    #[must_use]
+    pub(crate) fn with_debugging_trace(
+        mut self,
+        call_trace: &InternalCallTrace,
+        test_runner_config: &TestRunnerConfig,
+    ) -> Self {
+        let Some(verbosity) = test_runner_config.trace_verbosity else {
+            return self;
+        };
+        let trace = |name: &str| {
+            debugging::Trace::new(
+                call_trace,
+                &test_runner_config.contracts_data,
+                verbosity,
+                name.to_string(),
+            )
+        };
+
+        match &mut self {
+            TestCaseSummary::Passed {
+                name,
+                debugging_trace,
+                ..
+            } if test_runner_config.trace_passed_tests => *debugging_trace = Some(trace(name)),
+            TestCaseSummary::Failed {
+                name,
+                debugging_trace,
+                ..
+            } => *debugging_trace = Some(trace(name)),
+            _ => {}
+        }
+
+        self
+    }
+
+    /// Adds forks created by the test to the summary
+    #[must_use]
+    pub(crate) fn with_created_forks(mut self, forks: Vec<CreatedFork>) -> Self {
+        if let TestCaseSummary::Passed { created_forks, .. }
+        | TestCaseSummary::Failed { created_forks, .. } = &mut self
+        {
+            *created_forks = forks;
+        }
+
+        self
+    }
+
+    /// Summary of a test case run that did not finish within `timeout`
+    #[must_use]
+    pub(crate) fn timed_out(test_case: &TestCaseWithResolvedConfig, timeout: Duration) -> Self {
+        TestCaseSummary::Failed {
+            name: test_case.name.clone(),
+            msg: Some(format!(
+                "\n    Test timed out after {}s\n\n    Suggestion: Consider increasing the limit with `#[timeout]` attribute or `timeout` in [tool.snforge]\n",
+                timeout.as_secs()
+            )),
+            backtrace: None,
+            arguments: vec![],
+            fuzzer_args: vec![],
+            test_statistics: (),
+            debugging_trace: None,
+            elapsed: timeout,
+            created_forks: vec![],
+        }
+    }
+
+    /// Summary of a test case that was not run, because the `#[setup]` function of its module failed
+    #[must_use]
+    pub fn setup_failed(
+        test_case: &TestCaseWithResolvedConfig,
+        setup_name: &str,
+        msg: &str,
+    ) -> Self {
+        TestCaseSummary::Failed {
+            name: test_case.name.clone(),
+            msg: Some(format!("\n    Setup function {setup_name} failed\n{msg}")),
+            backtrace: None,
+            arguments: vec![],
+            fuzzer_args: vec![],
+            test_statistics: (),
+            debugging_trace: None,
+            elapsed: Duration::ZERO,
+            created_forks: vec![],
+        }
+    }
+
     #[must_use]
     #[expect(clippy::too_many_arguments)]
     pub(crate) fn from_run_result_and_info(
@@ -290,13 +420,13 @@ impl TestCaseSummary<Single> {
         encountered_errors: &[EncounteredError],
         contracts_data: &ContractsData,
         versioned_program_path: &Utf8Path,
+        elapsed: Duration,
     ) -> Self {
         let name = test_case.name.clone();
+        let backtrace = get_backtrace(contracts_data, encountered_errors);
         let msg = extract_result_data(&run_result, &test_case.config.expected_result)
-            .map(|msg| add_backtrace_footer(msg, contracts_data, encountered_errors));
-
-        let debugging_trace = cfg!(feature = "debugging")
-            .then(|| debugging::Trace::new(&call_trace.borrow(), contracts_data, name.clone()));
+            .map(|msg| add_backtrace_footer(msg, encountered_errors, backtrace.as_ref()));
+        let backtrace = backtrace.and_then(Result::ok);
 
         match run_result.value {
             RunResultValue::Success(_) => match &test_case.config.expected_result {
@@ -313,37 +443,48 @@ impl TestCaseSummary<Single> {
                             contracts_data,
                             versioned_program_path,
                         )),
-                        debugging_trace,
+                        debugging_trace: None,
+                        elapsed,
+                        created_forks: vec![],
                     };
                     check_available_gas(test_case.config.available_gas, summary)
                 }
                 ExpectedTestResult::Panics(_) => TestCaseSummary::Failed {
                     name,
                     msg,
+                    backtrace,
                     arguments,
                     fuzzer_args,
                     test_statistics: (),
-                    debugging_trace,
+                    debugging_trace: None,
+                    elapsed,
+                    created_forks: vec![],
                 },
             },
             RunResultValue::Panic(value) => match &test_case.config.expected_result {
                 ExpectedTestResult::Success => TestCaseSummary::Failed {
                     name,
                     msg,
+                    backtrace,
                     arguments,
                     fuzzer_args,
                     test_statistics: (),
-                    debugging_trace,
+                    debugging_trace: None,
+                    elapsed,
+                    created_forks: vec![],
                 },
                 ExpectedTestResult::Panics(panic_expectation) => match panic_expectation {
                     ExpectedPanicValue::Exact(expected) if !is_matching(&value, expected) => {
                         TestCaseSummary::Failed {
                             name,
                             msg,
+                            backtrace,
                             arguments,
                             fuzzer_args,
                             test_statistics: (),
-                            debugging_trace,
+                            debugging_trace: None,
+                            elapsed,
+                            created_forks: vec![],
                         }
                     }
                     _ => TestCaseSummary::Passed {
@@ -358,7 +499,9 @@ impl TestCaseSummary<Single> {
                             contracts_data,
                             versioned_program_path,
                         )),
-                        debugging_trace,
+                        debugging_trace: None,
+                        elapsed,
+                        created_forks: vec![],
                     },
                 },
             },
@@ -462,6 +605,22 @@ impl AnyTestCaseSummary {
         }
     }
 
+    #[must_use]
+    pub fn elapsed(&self) -> Option<Duration> {
+        match self {
+            AnyTestCaseSummary::Fuzzing(case) => case.elapsed(),
+            AnyTestCaseSummary::Single(case) => case.elapsed(),
+        }
+    }
+
+    #[must_use]
+    pub fn created_forks(&self) -> &[CreatedFork] {
+        match self {
+            AnyTestCaseSummary::Fuzzing(case) => case.created_forks(),
+            AnyTestCaseSummary::Single(case) => case.created_forks(),
+        }
+    }
+
     #[must_use]
     pub fn is_passed(&self) -> bool {
         matches!(
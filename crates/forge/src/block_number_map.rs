@@ -1,27 +1,68 @@
-use anyhow::{Result, anyhow};
-use conversions::{IntoConv, string::IntoHexStr};
-use starknet::{
-    core::types::{BlockId, MaybePendingBlockWithTxHashes},
-    providers::{JsonRpcClient, Provider, jsonrpc::HttpTransport},
+use anyhow::{Result, bail, ensure};
+use camino::Utf8PathBuf;
+use cheatnet::forking::block_number::{
+    fetch_block_number_for_block_id, fetch_block_number_for_hash, fetch_latest_block_number,
 };
+use cheatnet::forking::cache::latest_cached_block_number;
+use cheatnet::forking::multi_fork::CreatedFork;
+use cheatnet::forking::state_dump::{is_state_dump_url, state_dump_block_number};
+use cheatnet::runtime_extensions::forge_config_extension::config::{
+    BlockId as ForkBlockId, BlockTag,
+};
+use conversions::string::IntoHexStr;
 use starknet_api::block::BlockNumber;
 use starknet_types_core::felt::Felt;
 use std::collections::HashMap;
-use tokio::runtime::Handle;
 use url::Url;
 
 #[derive(Default)]
 pub struct BlockNumberMap {
     url_to_latest_block_number: HashMap<Url, BlockNumber>,
     url_and_hash_to_block_number: HashMap<(Url, Felt), BlockNumber>,
+    // Blocks of forks configured with other tags than `latest` or with a timestamp
+    url_and_block_id_to_block_number: HashMap<(Url, ForkBlockId), BlockNumber>,
+    offline_cache_dir: Option<Utf8PathBuf>,
 }
 
 impl BlockNumberMap {
+    /// Creates a map resolving block numbers only from fork cache files in `cache_dir`
+    #[must_use]
+    pub fn offline(cache_dir: Utf8PathBuf) -> Self {
+        Self {
+            offline_cache_dir: Some(cache_dir),
+            ..Default::default()
+        }
+    }
+
+    /// Resolves the number of the block `block_id` of the fork at `url`
+    pub async fn get_block_number(
+        &mut self,
+        url: Url,
+        block_id: &ForkBlockId,
+    ) -> Result<BlockNumber> {
+        match block_id {
+            ForkBlockId::BlockNumber(block_number) => Ok(BlockNumber(*block_number)),
+            ForkBlockId::BlockHash(hash) => self.get_block_number_for_hash(url, *hash).await,
+            ForkBlockId::BlockTag(BlockTag::Latest) => self.get_latest_block_number(url).await,
+            ForkBlockId::BlockTag(_) | ForkBlockId::BlockTimestamp(_) => {
+                self.get_block_number_for_block_id(url, block_id).await
+            }
+        }
+    }
+
     pub async fn get_latest_block_number(&mut self, url: Url) -> Result<BlockNumber> {
+        // The block of a state dump is read from the file instead of a node
+        if is_state_dump_url(&url) {
+            return state_dump_block_number(&url);
+        }
+
         let block_number = if let Some(block_number) = self.url_to_latest_block_number.get(&url) {
             *block_number
         } else {
-            let latest_block_number = fetch_latest_block_number(url.clone()).await?;
+            let latest_block_number = match &self.offline_cache_dir {
+                Some(cache_dir) => latest_cached_block_number(&url, cache_dir)?,
+                None => fetch_latest_block_number(url.clone()).await?,
+            };
 
             self.url_to_latest_block_number
                 .insert(url, latest_block_number);
@@ -38,6 +79,17 @@ impl BlockNumberMap {
         {
             *block_number
         } else {
+            if is_state_dump_url(&url) {
+                bail!(
+                    "Fork from the state dump {url} cannot be configured with a block hash, use `block_number` or `block_tag: latest`"
+                );
+            }
+            if self.offline_cache_dir.is_some() {
+                bail!(
+                    "cache miss: block with hash 0x{} cannot be resolved in offline mode, use `block_number` in the fork configuration",
+                    hash.into_hex_string()
+                );
+            }
             let block_number = fetch_block_number_for_hash(url.clone(), hash).await?;
 
             self.url_and_hash_to_block_number
@@ -49,34 +101,77 @@ impl BlockNumberMap {
         Ok(block_number)
     }
 
-    #[must_use]
-    pub fn get_url_to_latest_block_number(&self) -> &HashMap<Url, BlockNumber> {
-        &self.url_to_latest_block_number
-    }
-}
+    async fn get_block_number_for_block_id(
+        &mut self,
+        url: Url,
+        block_id: &ForkBlockId,
+    ) -> Result<BlockNumber> {
+        // A state dump has a single block, which every tag refers to
+        if is_state_dump_url(&url) {
+            ensure!(
+                matches!(block_id, ForkBlockId::BlockTag(_)),
+                "Fork from the state dump {url} cannot be configured with `{block_id}`, use `block_number` or `block_tag`"
+            );
+            return state_dump_block_number(&url);
+        }
 
-async fn fetch_latest_block_number(url: Url) -> Result<BlockNumber> {
-    let client = JsonRpcClient::new(HttpTransport::new(url));
+        let key = (url, block_id.clone());
+        if let Some(block_number) = self.url_and_block_id_to_block_number.get(&key) {
+            return Ok(*block_number);
+        }
 
-    Ok(Handle::current()
-        .spawn(async move { client.block_number().await })
-        .await?
-        .map(BlockNumber)?)
-}
+        if self.offline_cache_dir.is_some() {
+            bail!(
+                "cache miss: `{block_id}` cannot be resolved in offline mode, use `block_number` in the fork configuration"
+            );
+        }
+        let block_number = fetch_block_number_for_block_id(key.0.clone(), block_id).await?;
+
+        self.url_and_block_id_to_block_number
+            .insert(key, block_number);
+
+        Ok(block_number)
+    }
+
+    /// Records the block of a fork created by a test with `create_fork`,
+    /// so that it is printed together with the blocks of forks configured with `#[fork]`
+    pub fn record_created_fork(&mut self, fork: &CreatedFork) {
+        let CreatedFork {
+            url,
+            block_id,
+            block_number,
+            ..
+        } = fork.clone();
 
-async fn fetch_block_number_for_hash(url: Url, block_hash: Felt) -> Result<BlockNumber> {
-    let client = JsonRpcClient::new(HttpTransport::new(url));
+        match block_id {
+            ForkBlockId::BlockNumber(_) => {}
+            ForkBlockId::BlockHash(hash) => {
+                self.url_and_hash_to_block_number
+                    .entry((url, hash))
+                    .or_insert(block_number);
+            }
+            ForkBlockId::BlockTag(BlockTag::Latest) => {
+                self.url_to_latest_block_number
+                    .entry(url)
+                    .or_insert(block_number);
+            }
+            ForkBlockId::BlockTag(_) | ForkBlockId::BlockTimestamp(_) => {
+                self.url_and_block_id_to_block_number
+                    .entry((url, block_id))
+                    .or_insert(block_number);
+            }
+        }
+    }
 
-    let hash = BlockId::Hash(block_hash.into_());
+    #[must_use]
+    pub fn get_url_to_latest_block_number(&self) -> &HashMap<Url, BlockNumber> {
+        &self.url_to_latest_block_number
+    }
 
-    match Handle::current()
-        .spawn(async move { client.get_block_with_tx_hashes(hash).await })
-        .await?
-    {
-        Ok(MaybePendingBlockWithTxHashes::Block(block)) => Ok(BlockNumber(block.block_number)),
-        _ => Err(anyhow!(
-            "Could not get the block number for block with hash 0x{}",
-            block_hash.into_hex_string()
-        )),
+    #[must_use]
+    pub fn get_url_and_block_id_to_block_number(
+        &self,
+    ) -> &HashMap<(Url, ForkBlockId), BlockNumber> {
+        &self.url_and_block_id_to_block_number
     }
 }
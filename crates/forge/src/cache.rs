@@ -0,0 +1,146 @@
+use crate::{CacheCommand, PruneArgs};
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use cheatnet::forking::cache::{
+    ForkCacheFile, export_fork_caches, import_fork_caches, latest_cached_block_number,
+    list_fork_caches,
+};
+use cheatnet::forking::state_dump::{dump_fork_cache, dump_network_state};
+use forge_runner::CACHE_DIR;
+use scarb_api::{ScarbCommand, metadata::MetadataCommandExt};
+use starknet_api::block::BlockNumber;
+use std::fs;
+use std::time::{Duration, SystemTime};
+use tokio::runtime::Runtime;
+use url::Url;
+
+const SECONDS_IN_DAY: u64 = 24 * 60 * 60;
+
+pub fn cache(command: CacheCommand) -> Result<()> {
+    let scarb_metadata = ScarbCommand::metadata().inherit_stderr().no_deps().run()?;
+    let cache_dir = scarb_metadata.workspace.root.join(CACHE_DIR);
+
+    match command {
+        CacheCommand::Info { url } => info(&cache_dir, url.as_ref()),
+        CacheCommand::Prune(args) => prune(&cache_dir, &args),
+        CacheCommand::Export { path, url } => {
+            let cache_files = fork_caches_for_url(&cache_dir, url.as_ref())?;
+            let exported = export_fork_caches(&cache_files, &path)?;
+            println!("Exported {exported} fork cache(s) to {path}");
+            Ok(())
+        }
+        CacheCommand::Import { path } => {
+            let imported = import_fork_caches(&path, &cache_dir)?;
+            println!("Imported {imported} fork cache(s) from {path}");
+            Ok(())
+        }
+        CacheCommand::Dump {
+            path,
+            url,
+            block_number,
+            complete,
+        } => {
+            let block_number = if complete {
+                Runtime::new()?.block_on(dump_network_state(
+                    &url,
+                    block_number.map(BlockNumber),
+                    &path,
+                ))?
+            } else {
+                let block_number = match block_number {
+                    Some(block_number) => BlockNumber(block_number),
+                    None => latest_cached_block_number(&url, &cache_dir)?,
+                };
+                dump_fork_cache(&url, block_number, &cache_dir, &path)?;
+                block_number
+            };
+            println!(
+                "Saved state dump of {url} at block {} to {path}",
+                block_number.0
+            );
+            Ok(())
+        }
+    }
+}
+
+fn info(cache_dir: &Utf8Path, url: Option<&Url>) -> Result<()> {
+    let cache_files = fork_caches_for_url(cache_dir, url)?;
+    let now = SystemTime::now();
+
+    for cache_file in &cache_files {
+        let fork = match &cache_file.url {
+            Some(url) => format!("{url} at block {}", cache_file.block_number),
+            None => cache_file.path.file_name().unwrap_or_default().to_string(),
+        };
+        let days = now
+            .duration_since(cache_file.modified)
+            .unwrap_or_default()
+            .as_secs()
+            / SECONDS_IN_DAY;
+
+        println!(
+            "{fork}: {}, last used {days} day(s) ago",
+            format_size(cache_file.size)
+        );
+    }
+
+    let total_size = cache_files.iter().map(|cache_file| cache_file.size).sum();
+    println!(
+        "Total: {} fork cache(s), {}",
+        cache_files.len(),
+        format_size(total_size)
+    );
+
+    Ok(())
+}
+
+fn prune(cache_dir: &Utf8Path, args: &PruneArgs) -> Result<()> {
+    let cut_off = args
+        .older_than
+        .map(|days| SystemTime::now() - Duration::from_secs(days * SECONDS_IN_DAY));
+
+    let mut removed = 0;
+    let mut freed_size = 0;
+    for cache_file in fork_caches_for_url(cache_dir, args.url.as_ref())? {
+        if cut_off.is_some_and(|cut_off| cache_file.modified > cut_off) {
+            continue;
+        }
+
+        fs::remove_file(&cache_file.path)
+            .with_context(|| format!("Failed to remove fork cache: {}", cache_file.path))?;
+        removed += 1;
+        freed_size += cache_file.size;
+    }
+
+    println!(
+        "Removed {removed} fork cache(s), freed {}",
+        format_size(freed_size)
+    );
+
+    Ok(())
+}
+
+fn fork_caches_for_url(cache_dir: &Utf8Path, url: Option<&Url>) -> Result<Vec<ForkCacheFile>> {
+    Ok(list_fork_caches(cache_dir)?
+        .into_iter()
+        .filter(|cache_file| url.is_none_or(|url| cache_file.is_for_url(url)))
+        .collect())
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
@@ -1,6 +1,7 @@
 use crate::scarb::config::ForgeConfigFromScarb;
 use camino::Utf8PathBuf;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use debugging::TraceVerbosity;
 use forge_runner::forge_config::{
     ExecutionDataToSave, ForgeConfig, ForgeTrackedResource, OutputConfig, TestRunnerConfig,
 };
@@ -9,6 +10,7 @@ use std::env;
 use std::ffi::OsString;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[expect(clippy::too_many_arguments)]
 #[expect(clippy::fn_params_excessive_bools)]
@@ -22,8 +24,12 @@ pub fn combine_configs(
     coverage: bool,
     max_n_steps: Option<u32>,
     tracked_resource: ForgeTrackedResource,
+    trace_verbosity: Option<TraceVerbosity>,
+    trace_passed_tests: bool,
     contracts_data: ContractsData,
     cache_dir: Utf8PathBuf,
+    offline: bool,
+    prefetch_fork_storage: bool,
     forge_config_from_scarb: &ForgeConfigFromScarb,
     additional_args: &[OsString],
 ) -> ForgeConfig {
@@ -46,8 +52,17 @@ pub fn combine_configs(
             max_n_steps: max_n_steps.or(forge_config_from_scarb.max_n_steps),
             is_vm_trace_needed: execution_data_to_save.is_vm_trace_needed(),
             cache_dir,
+            offline,
+            prefetch_fork_storage: prefetch_fork_storage
+                || forge_config_from_scarb.prefetch_fork_storage,
             contracts_data,
             tracked_resource,
+            transactional: forge_config_from_scarb.transactional,
+            timeout: forge_config_from_scarb
+                .timeout
+                .map(|seconds| Duration::from_secs(seconds.get())),
+            trace_verbosity: trace_verbosity.or(forge_config_from_scarb.trace_verbosity),
+            trace_passed_tests,
             environment_variables: env::vars().collect(),
         }),
         output_config: Arc::new(OutputConfig {
@@ -60,6 +75,7 @@ pub fn combine_configs(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::num::NonZeroU64;
 
     #[test]
     fn fuzzer_default_seed() {
@@ -73,8 +89,12 @@ mod tests {
             false,
             None,
             ForgeTrackedResource::CairoSteps,
+            None,
+            false,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            false,
+            false,
             &ForgeConfigFromScarb::default(),
             &[],
         );
@@ -88,8 +108,12 @@ mod tests {
             false,
             None,
             ForgeTrackedResource::CairoSteps,
+            None,
+            false,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            false,
+            false,
             &ForgeConfigFromScarb::default(),
             &[],
         );
@@ -114,8 +138,12 @@ mod tests {
             false,
             None,
             ForgeTrackedResource::CairoSteps,
+            None,
+            false,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            false,
+            false,
             &ForgeConfigFromScarb::default(),
             &[],
         );
@@ -128,8 +156,14 @@ mod tests {
                     fuzzer_seed: config.test_runner_config.fuzzer_seed,
                     max_n_steps: None,
                     tracked_resource: ForgeTrackedResource::CairoSteps,
+                    transactional: false,
+                    timeout: None,
+                    trace_verbosity: None,
+                    trace_passed_tests: false,
                     is_vm_trace_needed: false,
                     cache_dir: Utf8PathBuf::default(),
+                    offline: false,
+                    prefetch_fork_storage: false,
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -154,6 +188,10 @@ mod tests {
             coverage: true,
             max_n_steps: Some(1_000_000),
             tracked_resource: ForgeTrackedResource::CairoSteps,
+            transactional: true,
+            timeout: Some(NonZeroU64::new(60).unwrap()),
+            trace_verbosity: Some(TraceVerbosity::Standard),
+            prefetch_fork_storage: true,
         };
 
         let config = combine_configs(
@@ -166,8 +204,12 @@ mod tests {
             false,
             None,
             ForgeTrackedResource::CairoSteps,
+            None,
+            false,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            false,
+            false,
             &config_from_scarb,
             &[],
         );
@@ -180,8 +222,14 @@ mod tests {
                     fuzzer_seed: 500,
                     max_n_steps: Some(1_000_000),
                     tracked_resource: ForgeTrackedResource::CairoSteps,
+                    transactional: true,
+                    timeout: Some(Duration::from_secs(60)),
+                    trace_verbosity: Some(TraceVerbosity::Standard),
+                    trace_passed_tests: false,
                     is_vm_trace_needed: true,
                     cache_dir: Utf8PathBuf::default(),
+                    offline: false,
+                    prefetch_fork_storage: true,
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -211,6 +259,10 @@ mod tests {
             coverage: false,
             max_n_steps: Some(1234),
             tracked_resource: ForgeTrackedResource::CairoSteps,
+            transactional: false,
+            timeout: None,
+            trace_verbosity: Some(TraceVerbosity::Minimal),
+            prefetch_fork_storage: true,
         };
         let config = combine_configs(
             true,
@@ -222,8 +274,12 @@ mod tests {
             true,
             Some(1_000_000),
             ForgeTrackedResource::CairoSteps,
+            Some(TraceVerbosity::Detailed),
+            true,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            true,
+            false,
             &config_from_scarb,
             &[],
         );
@@ -237,8 +293,14 @@ mod tests {
                     fuzzer_seed: 32,
                     max_n_steps: Some(1_000_000),
                     tracked_resource: ForgeTrackedResource::CairoSteps,
+                    transactional: false,
+                    timeout: None,
+                    trace_verbosity: Some(TraceVerbosity::Detailed),
+                    trace_passed_tests: true,
                     is_vm_trace_needed: true,
                     cache_dir: Utf8PathBuf::default(),
+                    offline: true,
+                    prefetch_fork_storage: true,
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
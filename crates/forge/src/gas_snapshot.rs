@@ -0,0 +1,406 @@
+use crate::test_report::{GasReport, TestReport};
+use anyhow::{Context, Result, anyhow};
+use camino::Utf8Path;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::fs;
+
+/// File in the workspace root saved with `--gas-snapshot` and checked with `--check-gas-snapshot`
+pub const GAS_SNAPSHOT_FILE: &str = ".gas-snapshot";
+
+/// Fuzzer seed used when saving or checking the snapshot unless another one is set,
+/// so that the mean gas of fuzz tests does not depend on random inputs
+pub const GAS_SNAPSHOT_FUZZER_SEED: u64 = 0;
+
+const RESOURCES: [&str; 3] = ["l1_gas", "l1_data_gas", "l2_gas"];
+
+/// Gas used by passed tests, fuzz tests are represented by the mean of all runs
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GasSnapshot {
+    tests: BTreeMap<String, [u64; 3]>,
+}
+
+/// Change of gas used by a test since the snapshot, for a single resource
+#[derive(Debug, PartialEq, Eq)]
+pub struct GasDiff {
+    pub name: String,
+    pub resource: &'static str,
+    pub snapshot: u64,
+    pub current: u64,
+}
+
+impl GasSnapshot {
+    pub fn load(path: &Utf8Path) -> Result<Self> {
+        let content = fs::read_to_string(path).with_context(|| {
+            format!("Failed to read gas snapshot from {path}, save it first with `--gas-snapshot`")
+        })?;
+
+        content
+            .parse()
+            .with_context(|| format!("Failed to parse gas snapshot from {path}"))
+    }
+
+    pub fn save(&self, path: &Utf8Path) -> Result<()> {
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Failed to write gas snapshot to {path}"))
+    }
+
+    /// Replaces gas of the tests present in `current` and removes tests which no longer exist.
+    /// Other tests, e.g. filtered out ones, are kept unchanged.
+    pub fn update(&mut self, current: GasSnapshot, collected_tests: &HashSet<&str>) {
+        let collected_crates = collected_crates(collected_tests);
+        self.tests
+            .retain(|name, _| !is_missing(name, collected_tests, &collected_crates));
+        self.tests.extend(current.tests);
+    }
+
+    /// Returns tests from `current` which are not in the snapshot
+    #[must_use]
+    pub fn new_tests<'a>(&self, current: &'a GasSnapshot) -> Vec<&'a str> {
+        current
+            .tests
+            .keys()
+            .filter(|name| !self.tests.contains_key(*name))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns tests from the snapshot which no longer exist, i.e. were not collected
+    /// although other tests of their crate were. Tests of other packages are not considered missing.
+    #[must_use]
+    pub fn missing_tests(&self, collected_tests: &HashSet<&str>) -> Vec<&str> {
+        let collected_crates = collected_crates(collected_tests);
+
+        self.tests
+            .keys()
+            .map(String::as_str)
+            .filter(|name| is_missing(name, collected_tests, &collected_crates))
+            .collect()
+    }
+
+    /// Returns changes of gas for tests present both in the snapshot and in `current`.
+    /// Tests added or removed since the snapshot are reported by [`Self::new_tests`] and [`Self::missing_tests`].
+    #[must_use]
+    pub fn diff(&self, current: &GasSnapshot) -> Vec<GasDiff> {
+        let mut diffs = vec![];
+
+        for (name, snapshot_gas) in &self.tests {
+            let Some(current_gas) = current.tests.get(name) else {
+                continue;
+            };
+
+            for ((resource, snapshot), current) in
+                RESOURCES.into_iter().zip(snapshot_gas).zip(current_gas)
+            {
+                if snapshot != current {
+                    diffs.push(GasDiff {
+                        name: name.clone(),
+                        resource,
+                        snapshot: *snapshot,
+                        current: *current,
+                    });
+                }
+            }
+        }
+
+        diffs
+    }
+}
+
+impl From<&TestReport> for GasSnapshot {
+    fn from(report: &TestReport) -> Self {
+        let tests = report
+            .packages
+            .iter()
+            .flat_map(|package| &package.test_targets)
+            .flat_map(|test_target| &test_target.tests)
+            .filter_map(|test| {
+                let gas = match test.gas.as_ref()? {
+                    GasReport::Single {
+                        l1_gas,
+                        l1_data_gas,
+                        l2_gas,
+                    } => [*l1_gas, *l1_data_gas, *l2_gas],
+                    GasReport::Fuzzing {
+                        l1_gas,
+                        l1_data_gas,
+                        l2_gas,
+                    } => [l1_gas.mean, l1_data_gas.mean, l2_gas.mean].map(round_gas),
+                };
+                Some((test.name.clone(), gas))
+            })
+            .collect();
+
+        Self { tests }
+    }
+}
+
+/// Test names start with the name of their crate, e.g. `package_integrationtest::test_a`
+fn crate_name(test_name: &str) -> &str {
+    test_name.split("::").next().unwrap_or(test_name)
+}
+
+fn collected_crates<'a>(collected_tests: &HashSet<&'a str>) -> HashSet<&'a str> {
+    collected_tests
+        .iter()
+        .map(|name| crate_name(name))
+        .collect()
+}
+
+fn is_missing(
+    name: &str,
+    collected_tests: &HashSet<&str>,
+    collected_crates: &HashSet<&str>,
+) -> bool {
+    collected_crates.contains(crate_name(name)) && !collected_tests.contains(name)
+}
+
+#[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn round_gas(mean: f64) -> u64 {
+    // Mean of non-negative integers, which always fits in `u64`
+    mean.round() as u64
+}
+
+impl std::fmt::Display for GasSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, [l1_gas, l1_data_gas, l2_gas]) in &self.tests {
+            writeln!(
+                f,
+                "{name} (l1_gas: {l1_gas}, l1_data_gas: {l1_data_gas}, l2_gas: {l2_gas})"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for GasSnapshot {
+    type Err = anyhow::Error;
+
+    fn from_str(content: &str) -> Result<Self> {
+        let tests = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                parse_line(line).ok_or_else(|| anyhow!("Invalid line {}: {line}", index + 1))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { tests })
+    }
+}
+
+fn parse_line(line: &str) -> Option<(String, [u64; 3])> {
+    let (name, gas) = line.trim().split_once(" (")?;
+    let mut values = gas.strip_suffix(')')?.split(", ");
+
+    let mut gas = [0; 3];
+    for (resource, value) in RESOURCES.into_iter().zip(&mut gas) {
+        *value = values
+            .next()?
+            .strip_prefix(resource)?
+            .strip_prefix(": ")?
+            .parse()
+            .ok()?;
+    }
+
+    values.next().is_none().then(|| (name.to_string(), gas))
+}
+
+impl GasDiff {
+    /// Relative change of gas, in percents
+    #[must_use]
+    #[expect(clippy::cast_precision_loss)]
+    pub fn change(&self) -> f64 {
+        if self.snapshot == 0 {
+            return f64::INFINITY;
+        }
+        (self.current as f64 - self.snapshot as f64) / self.snapshot as f64 * 100.0
+    }
+
+    /// Whether gas increased by more than `tolerance` percents
+    #[must_use]
+    pub fn is_regression(&self, tolerance: f64) -> bool {
+        self.current > self.snapshot && self.change() > tolerance
+    }
+}
+
+/// Formats differences as a table, marking ones exceeding `tolerance`
+#[must_use]
+pub fn format_diffs(diffs: &[GasDiff], tolerance: f64) -> String {
+    let header = ["Test", "Resource", "Snapshot", "Current", "Change"].map(String::from);
+    let rows: Vec<[String; 5]> = diffs
+        .iter()
+        .map(|diff| {
+            let mut change = if diff.snapshot == 0 {
+                "new".to_string()
+            } else {
+                format!("{:+.2}%", diff.change())
+            };
+            if diff.is_regression(tolerance) {
+                change.push_str(" (regression)");
+            }
+            [
+                diff.name.clone(),
+                diff.resource.to_string(),
+                diff.snapshot.to_string(),
+                diff.current.to_string(),
+                change,
+            ]
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|cell| cell.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    let mut write_row = |row: &[String; 5]| {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect();
+        writeln!(table, "| {} |", cells.join(" | ")).unwrap();
+    };
+
+    write_row(&header);
+    write_row(&widths.map(|width| "-".repeat(width)));
+    for row in &rows {
+        write_row(row);
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const SNAPSHOT: &str = indoc! {"
+        package::tests::test_a (l1_gas: 0, l1_data_gas: 96, l2_gas: 40000)
+        package::tests::test_b (l1_gas: 0, l1_data_gas: 0, l2_gas: 1000)
+    "};
+
+    #[test]
+    fn parse_and_format() {
+        let snapshot: GasSnapshot = SNAPSHOT.parse().unwrap();
+
+        assert_eq!(snapshot.tests["package::tests::test_a"], [0, 96, 40000]);
+        assert_eq!(snapshot.to_string(), SNAPSHOT);
+    }
+
+    #[test]
+    fn parse_invalid_line() {
+        let error = "package::tests::test_a (l2_gas: 40000)"
+            .parse::<GasSnapshot>()
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid line 1: package::tests::test_a (l2_gas: 40000)"
+        );
+    }
+
+    #[test]
+    fn update_keeps_other_tests() {
+        let mut snapshot: GasSnapshot = SNAPSHOT.parse().unwrap();
+        let current: GasSnapshot =
+            "package::tests::test_b (l1_gas: 0, l1_data_gas: 0, l2_gas: 900)"
+                .parse()
+                .unwrap();
+        let collected_tests = HashSet::from(["package::tests::test_a", "package::tests::test_b"]);
+
+        snapshot.update(current, &collected_tests);
+
+        assert_eq!(
+            snapshot.to_string(),
+            indoc! {"
+                package::tests::test_a (l1_gas: 0, l1_data_gas: 96, l2_gas: 40000)
+                package::tests::test_b (l1_gas: 0, l1_data_gas: 0, l2_gas: 900)
+            "}
+        );
+    }
+
+    #[test]
+    fn update_removes_missing_tests() {
+        let mut snapshot: GasSnapshot = indoc! {"
+            other_package::tests::test_a (l1_gas: 0, l1_data_gas: 0, l2_gas: 500)
+            package::tests::test_a (l1_gas: 0, l1_data_gas: 96, l2_gas: 40000)
+            package::tests::test_b (l1_gas: 0, l1_data_gas: 0, l2_gas: 1000)
+        "}
+        .parse()
+        .unwrap();
+        let current: GasSnapshot =
+            "package::tests::test_c (l1_gas: 0, l1_data_gas: 0, l2_gas: 5000)"
+                .parse()
+                .unwrap();
+        let collected_tests = HashSet::from(["package::tests::test_a", "package::tests::test_c"]);
+
+        assert_eq!(
+            snapshot.missing_tests(&collected_tests),
+            vec!["package::tests::test_b"]
+        );
+        assert_eq!(snapshot.new_tests(&current), vec!["package::tests::test_c"]);
+
+        snapshot.update(current, &collected_tests);
+
+        assert_eq!(
+            snapshot.to_string(),
+            indoc! {"
+                other_package::tests::test_a (l1_gas: 0, l1_data_gas: 0, l2_gas: 500)
+                package::tests::test_a (l1_gas: 0, l1_data_gas: 96, l2_gas: 40000)
+                package::tests::test_c (l1_gas: 0, l1_data_gas: 0, l2_gas: 5000)
+            "}
+        );
+    }
+
+    #[test]
+    fn diff_with_tolerance() {
+        let snapshot: GasSnapshot = SNAPSHOT.parse().unwrap();
+        let current: GasSnapshot = indoc! {"
+            package::tests::test_a (l1_gas: 0, l1_data_gas: 96, l2_gas: 41000)
+            package::tests::test_b (l1_gas: 0, l1_data_gas: 0, l2_gas: 900)
+            package::tests::test_c (l1_gas: 0, l1_data_gas: 0, l2_gas: 5000)
+        "}
+        .parse()
+        .unwrap();
+
+        let diffs = snapshot.diff(&current);
+
+        assert_eq!(
+            diffs,
+            vec![
+                GasDiff {
+                    name: "package::tests::test_a".to_string(),
+                    resource: "l2_gas",
+                    snapshot: 40000,
+                    current: 41000,
+                },
+                GasDiff {
+                    name: "package::tests::test_b".to_string(),
+                    resource: "l2_gas",
+                    snapshot: 1000,
+                    current: 900,
+                },
+            ]
+        );
+        assert!(diffs[0].is_regression(2.0));
+        assert!(!diffs[0].is_regression(2.5));
+        assert!(!diffs[1].is_regression(0.0));
+
+        assert_eq!(
+            format_diffs(&diffs, 2.0),
+            indoc! {"
+                | Test                   | Resource | Snapshot | Current | Change              |
+                | ---------------------- | -------- | -------- | ------- | ------------------- |
+                | package::tests::test_a | l2_gas   | 40000    | 41000   | +2.50% (regression) |
+                | package::tests::test_b | l2_gas   | 1000     | 900     | -10.00%             |
+            "}
+        );
+    }
+}
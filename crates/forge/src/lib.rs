@@ -3,6 +3,7 @@ use anyhow::Result;
 use anyhow::anyhow;
 use camino::Utf8PathBuf;
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use debugging::TraceVerbosity;
 use derive_more::Display;
 use forge_runner::CACHE_DIR;
 use forge_runner::forge_config::ForgeTrackedResource;
@@ -16,13 +17,17 @@ use std::cell::RefCell;
 use std::ffi::OsString;
 use std::process::Command;
 use std::{fs, num::NonZeroU32, thread::available_parallelism};
+use test_report::ReportTarget;
 use tokio::runtime::Builder;
 use universal_sierra_compiler_api::UniversalSierraCompilerCommand;
+use url::Url;
 
 pub mod block_number_map;
+mod cache;
 mod clean;
 mod combine_configs;
 mod compatibility_check;
+pub mod gas_snapshot;
 mod init;
 mod new;
 pub mod pretty_printing;
@@ -30,6 +35,7 @@ pub mod run_tests;
 pub mod scarb;
 pub mod shared_cache;
 pub mod test_filter;
+pub mod test_report;
 mod warn;
 
 pub const CAIRO_EDITION: &str = "2024_07";
@@ -102,6 +108,11 @@ enum ForgeSubcommand {
     },
     /// Clean Forge cache directory
     CleanCache {},
+    /// Inspect and manage fork caches in the `.snfoundry_cache` directory
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
     /// Check if all `snforge` requirements are installed
     CheckRequirements,
     /// Generate completion script
@@ -128,6 +139,58 @@ pub enum CleanComponent {
     All,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum CacheCommand {
+    /// Print fork caches with their size and time of the last use
+    Info {
+        /// Show only caches of forks with this RPC url
+        #[arg(long)]
+        url: Option<Url>,
+    },
+    /// Remove fork caches
+    Prune(PruneArgs),
+    /// Write fork caches into a single bundle file
+    Export {
+        /// Path of the bundle file
+        path: Utf8PathBuf,
+        /// Export only caches of forks with this RPC url
+        #[arg(long)]
+        url: Option<Url>,
+    },
+    /// Merge fork caches from a bundle file created with `snforge cache export`
+    Import {
+        /// Path of the bundle file
+        path: Utf8PathBuf,
+    },
+    /// Write the fork cache of a single fork as a state dump, which can be used as a fork source
+    Dump {
+        /// Path of the state dump file
+        path: Utf8PathBuf,
+        /// RPC url of the fork
+        #[arg(long)]
+        url: Url,
+        /// Block number of the fork, defaults to the latest cached block
+        /// (or the latest block of the network with `--complete`)
+        #[arg(long)]
+        block_number: Option<u64>,
+        /// Write the whole state of the network instead of its fork cache, built from state updates
+        /// of all blocks. Meant for local networks, like `starknet-devnet` loaded from its state dump
+        #[arg(long)]
+        complete: bool,
+    },
+}
+
+#[derive(Parser, Debug)]
+#[group(required = true, multiple = true)]
+pub struct PruneArgs {
+    /// Remove caches of forks with this RPC url
+    #[arg(long)]
+    pub url: Option<Url>,
+    /// Remove caches not used for more than the given number of days
+    #[arg(long, value_name = "DAYS")]
+    pub older_than: Option<u64>,
+}
+
 #[derive(ValueEnum, Debug, Clone)]
 enum ColorOption {
     Auto,
@@ -189,10 +252,40 @@ pub struct TestArgs {
     #[arg(long, conflicts_with = "build_profile")]
     coverage: bool,
 
+    /// Save a report of the test run in the given format to the given path, e.g. `--report junit=target/junit.xml`.
+    /// Possible formats: junit, json. Can be passed multiple times
+    #[arg(long, value_name = "FORMAT=PATH")]
+    report: Vec<ReportTarget>,
+
+    /// Save gas used by passed tests to the `.gas-snapshot` file in the workspace root
+    #[arg(long, conflicts_with = "check_gas_snapshot")]
+    gas_snapshot: bool,
+
+    /// Compare gas used by passed tests with the `.gas-snapshot` file and fail if it increased by more than `--gas-snapshot-tolerance`
+    #[arg(long)]
+    check_gas_snapshot: bool,
+
+    /// Increase of gas usage, in percents, allowed by `--check-gas-snapshot`
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        default_value_t = 0.0,
+        requires = "check_gas_snapshot"
+    )]
+    gas_snapshot_tolerance: f64,
+
     /// Number of maximum steps during a single test. For fuzz tests this value is applied to each subtest separately.
     #[arg(long)]
     max_n_steps: Option<u32>,
 
+    /// Resolve forks only from the fork cache in `.snfoundry_cache`, without connecting to the network
+    #[arg(long)]
+    offline: bool,
+
+    /// Fetch storage read by previous runs of forks of the same url in the background when a fork is created
+    #[arg(long, conflicts_with = "offline")]
+    prefetch_fork_storage: bool,
+
     /// Specify features to enable
     #[command(flatten)]
     pub features: FeaturesSpec,
@@ -205,6 +298,10 @@ pub struct TestArgs {
     #[arg(long, value_enum, default_value_t)]
     tracked_resource: ForgeTrackedResource,
 
+    /// Print call traces of failed tests (and of all tests selected with a test filter) with the given amount of details
+    #[arg(long, value_enum)]
+    trace_verbosity: Option<TraceVerbosity>,
+
     /// Additional arguments for cairo-coverage or cairo-profiler
     #[arg(last = true)]
     additional_args: Vec<OsString>,
@@ -275,6 +372,10 @@ pub fn main_execution() -> Result<ExitStatus> {
 
             Ok(ExitStatus::Success)
         }
+        ForgeSubcommand::Cache { command } => {
+            cache::cache(command)?;
+            Ok(ExitStatus::Success)
+        }
         ForgeSubcommand::Test { args } => {
             check_requirements(false, args.tracked_resource)?;
             let cores = if let Ok(available_cores) = available_parallelism() {
@@ -289,7 +390,10 @@ pub fn main_execution() -> Result<ExitStatus> {
                 .enable_all()
                 .build()?;
 
-            rt.block_on(run_for_workspace(args))
+            let result = rt.block_on(run_for_workspace(args));
+            // Timed out tests can't be interrupted, don't wait for them to finish
+            rt.shutdown_background();
+            result
         }
         ForgeSubcommand::CheckRequirements => {
             check_requirements(true, ForgeTrackedResource::default())?;
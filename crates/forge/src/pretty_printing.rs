@@ -1,9 +1,11 @@
+use crate::gas_snapshot::{GasDiff, format_diffs};
 use anyhow::Error;
+use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
 use console::style;
 use forge_runner::package_tests::TestTargetLocation;
 use forge_runner::{test_case_summary::AnyTestCaseSummary, test_target_summary::TestTargetSummary};
 use starknet_api::block::BlockNumber;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use url::Url;
 
 pub fn print_error_message(error: &Error) {
@@ -74,11 +76,61 @@ pub fn print_failures(all_failed_tests: &[AnyTestCaseSummary]) {
 }
 
 #[expect(clippy::implicit_hasher)]
-pub fn print_latest_blocks_numbers(url_to_latest_block_number_map: &HashMap<Url, BlockNumber>) {
-    if !url_to_latest_block_number_map.is_empty() {
+pub fn print_resolved_blocks_numbers(
+    url_to_latest_block_number_map: &HashMap<Url, BlockNumber>,
+    url_and_block_id_to_block_number_map: &HashMap<(Url, BlockId), BlockNumber>,
+) {
+    if !url_to_latest_block_number_map.is_empty()
+        || !url_and_block_id_to_block_number_map.is_empty()
+    {
         println!();
     }
     for (url, latest_block_number) in url_to_latest_block_number_map {
         println!("Latest block number = {latest_block_number} for url = {url}");
     }
+    for ((url, block_id), block_number) in url_and_block_id_to_block_number_map {
+        println!("Block number = {block_number} for {block_id} and url = {url}");
+    }
+}
+
+pub(crate) fn print_gas_snapshot_diffs(
+    diffs: &[GasDiff],
+    new_tests: &[&str],
+    missing_tests: &[&str],
+    tolerance: f64,
+) {
+    if diffs.is_empty() && new_tests.is_empty() && missing_tests.is_empty() {
+        println!("\nGas usage matches the snapshot");
+        return;
+    }
+
+    if !diffs.is_empty() {
+        println!("\n{}", style("Gas snapshot differences:").bold());
+        print!("{}", format_diffs(diffs, tolerance));
+    }
+    if !new_tests.is_empty() {
+        println!("\n{}", style("Tests not in the snapshot:").bold());
+        for name in new_tests {
+            println!("    {name}");
+        }
+    }
+    if !missing_tests.is_empty() {
+        println!("\n{}", style("Tests removed since the snapshot:").bold());
+        for name in missing_tests {
+            println!("    {name}");
+        }
+    }
+
+    let regressed_tests: HashSet<_> = diffs
+        .iter()
+        .filter(|diff| diff.is_regression(tolerance))
+        .map(|diff| &diff.name)
+        .collect();
+    if !regressed_tests.is_empty() {
+        let error_tag = style("ERROR").red();
+        println!(
+            "\n[{error_tag}] Gas usage of {} test(s) increased by more than {tolerance}%",
+            regressed_tests.len()
+        );
+    }
 }
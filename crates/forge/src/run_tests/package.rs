@@ -6,6 +6,7 @@ use crate::{
     TestArgs,
     block_number_map::BlockNumberMap,
     combine_configs::combine_configs,
+    gas_snapshot::GAS_SNAPSHOT_FUZZER_SEED,
     pretty_printing,
     scarb::{
         config::{ForgeConfigFromScarb, ForkTarget},
@@ -13,6 +14,7 @@ use crate::{
     },
     shared_cache::FailedTestsCache,
     test_filter::{NameFilter, TestsFilter},
+    test_report::{PackageReport, TestReport},
     warn::{
         warn_if_available_gas_used_with_incompatible_scarb_version,
         warn_if_incompatible_rpc_version,
@@ -23,7 +25,7 @@ use camino::{Utf8Path, Utf8PathBuf};
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use configuration::load_package_config;
 use forge_runner::{
-    forge_config::ForgeConfig,
+    forge_config::{ForgeConfig, TestRunnerConfig},
     package_tests::{raw::TestTargetRaw, with_config_resolved::TestTargetWithResolvedConfig},
     running::with_config::test_target_with_config,
     test_case_summary::AnyTestCaseSummary,
@@ -31,7 +33,9 @@ use forge_runner::{
 };
 use scarb_api::get_contracts_artifacts_and_source_sierra_paths;
 use scarb_metadata::{Metadata, PackageMetadata};
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 pub struct RunForPackageArgs {
     pub test_targets: Vec<TestTargetRaw>,
@@ -39,6 +43,8 @@ pub struct RunForPackageArgs {
     pub forge_config: Arc<ForgeConfig>,
     pub fork_targets: Vec<ForkTarget>,
     pub package_name: String,
+    /// Root of the package, against which paths of state dumps are resolved
+    pub package_root: Utf8PathBuf,
 }
 
 impl RunForPackageArgs {
@@ -66,15 +72,19 @@ impl RunForPackageArgs {
         let forge_config = Arc::new(combine_configs(
             args.exit_first,
             args.fuzzer_runs,
-            args.fuzzer_seed,
+            fuzzer_seed(args, &forge_config_from_scarb),
             args.detailed_resources,
             args.save_trace_data,
             args.build_profile,
             args.coverage,
             args.max_n_steps,
             args.tracked_resource,
+            args.trace_verbosity,
+            args.exact,
             contracts_data,
             cache_dir.clone(),
+            args.offline,
+            args.prefetch_fork_storage,
             &forge_config_from_scarb,
             &args.additional_args,
         ));
@@ -92,15 +102,29 @@ impl RunForPackageArgs {
             test_targets: raw_test_targets,
             forge_config,
             tests_filter: test_filter,
-            fork_targets: forge_config_from_scarb.fork,
+            fork_targets: forge_config_from_scarb
+                .fork
+                .into_iter()
+                .map(|fork_target| fork_target.resolve(&package.root))
+                .collect::<Result<_>>()?,
             package_name: package.name,
+            package_root: package.root,
         })
     }
 }
 
+/// Seed of the fuzzer set with `--fuzzer-seed` or in Scarb.toml. When saving or checking
+/// the gas snapshot it defaults to a fixed one, so that gas of fuzz tests is reproducible.
+fn fuzzer_seed(args: &TestArgs, forge_config_from_scarb: &ForgeConfigFromScarb) -> Option<u64> {
+    args.fuzzer_seed
+        .or(forge_config_from_scarb.fuzzer_seed)
+        .or((args.gas_snapshot || args.check_gas_snapshot).then_some(GAS_SNAPSHOT_FUZZER_SEED))
+}
+
 async fn test_package_with_config_resolved(
     test_targets: Vec<TestTargetRaw>,
     fork_targets: &[ForkTarget],
+    package_root: &Utf8Path,
     block_number_map: &mut BlockNumberMap,
     forge_config: &ForgeConfig,
 ) -> Result<Vec<TestTargetWithResolvedConfig>> {
@@ -112,7 +136,8 @@ async fn test_package_with_config_resolved(
             &forge_config.test_runner_config.tracked_resource,
         )?;
 
-        let test_target = resolve_config(test_target, fork_targets, block_number_map).await?;
+        let test_target =
+            resolve_config(test_target, fork_targets, package_root, block_number_map).await?;
 
         test_targets_with_resolved_config.push(test_target);
     }
@@ -131,17 +156,25 @@ pub async fn run_for_package(
         tests_filter,
         fork_targets,
         package_name,
+        package_root,
     }: RunForPackageArgs,
     block_number_map: &mut BlockNumberMap,
+    test_report: &mut TestReport,
 ) -> Result<Vec<TestTargetSummary>> {
     let mut test_targets = test_package_with_config_resolved(
         test_targets,
         &fork_targets,
+        &package_root,
         block_number_map,
         &forge_config,
     )
     .await?;
     let all_tests = sum_test_cases(&test_targets);
+    let collected_tests = test_targets
+        .iter()
+        .flat_map(|test_target| &test_target.test_cases)
+        .map(|test_case| test_case.name.clone())
+        .collect();
 
     for test_target in &mut test_targets {
         tests_filter.filter_tests(&mut test_target.test_cases)?;
@@ -153,7 +186,24 @@ pub async fn run_for_package(
     let not_filtered = sum_test_cases(&test_targets);
     pretty_printing::print_collected_tests_count(not_filtered, &package_name);
 
+    // Traces of passed tests are printed only for a single selected test, e.g. with `--exact`
+    let forge_config = if matches!(tests_filter.name_filter, NameFilter::Match(_))
+        && not_filtered == 1
+        && !forge_config.test_runner_config.trace_passed_tests
+    {
+        Arc::new(ForgeConfig {
+            test_runner_config: Arc::new(TestRunnerConfig {
+                trace_passed_tests: true,
+                ..(*forge_config.test_runner_config).clone()
+            }),
+            output_config: forge_config.output_config.clone(),
+        })
+    } else {
+        forge_config
+    };
+
     let mut summaries = vec![];
+    let mut package_report = PackageReport::new(package_name, collected_tests);
 
     for test_target in test_targets {
         pretty_printing::print_running_tests(
@@ -162,14 +212,40 @@ pub async fn run_for_package(
         );
 
         let forge_config = forge_config.clone();
+        let tests_location = test_target.tests_location;
+        let fork_configs: HashMap<_, _> = test_target
+            .test_cases
+            .iter()
+            .filter_map(|case| {
+                case.config
+                    .fork_config
+                    .clone()
+                    .map(|fork_config| (case.name.clone(), fork_config))
+            })
+            .collect();
+        let start = Instant::now();
 
         let summary = run_for_test_target(test_target, forge_config, &tests_filter).await?;
 
         match summary {
             TestTargetRunResult::Ok(summary) => {
+                package_report.add_test_target(
+                    tests_location,
+                    &summary,
+                    &fork_configs,
+                    start.elapsed(),
+                );
+                record_created_forks(&summary, block_number_map);
                 summaries.push(summary);
             }
             TestTargetRunResult::Interrupted(summary) => {
+                package_report.add_test_target(
+                    tests_location,
+                    &summary,
+                    &fork_configs,
+                    start.elapsed(),
+                );
+                record_created_forks(&summary, block_number_map);
                 summaries.push(summary);
                 // Handle scenario for --exit-first flag.
                 // Because snforge runs test crates one by one synchronously.
@@ -179,6 +255,8 @@ pub async fn run_for_package(
         }
     }
 
+    test_report.packages.push(package_report);
+
     // TODO(#2574): Bring back "filtered out" number in tests summary when running with `--exact` flag
     if let NameFilter::ExactMatch(_) = tests_filter.name_filter {
         pretty_printing::print_test_summary(&summaries, None);
@@ -201,3 +279,15 @@ pub async fn run_for_package(
 
     Ok(summaries)
 }
+
+/// Records blocks of forks created by tests with `create_fork`,
+/// so that they are printed together with the ones configured with `#[fork]`
+fn record_created_forks(summary: &TestTargetSummary, block_number_map: &mut BlockNumberMap) {
+    for fork in summary
+        .test_case_summaries
+        .iter()
+        .flat_map(AnyTestCaseSummary::created_forks)
+    {
+        block_number_map.record_created_fork(fork);
+    }
+}
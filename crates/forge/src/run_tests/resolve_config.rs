@@ -1,7 +1,9 @@
 use crate::{block_number_map::BlockNumberMap, scarb::config::ForkTarget};
 use anyhow::{Result, anyhow};
+use camino::Utf8Path;
+use cheatnet::forking::state_dump::state_dump_url;
 use cheatnet::runtime_extensions::forge_config_extension::config::{
-    BlockId, InlineForkConfig, OverriddenForkConfig, RawForkConfig,
+    BlockId, BlockTag, InlineForkConfig, OverriddenForkConfig, RawForkConfig,
 };
 use conversions::byte_array::ByteArray;
 use forge_runner::package_tests::{
@@ -11,11 +13,11 @@ use forge_runner::package_tests::{
         TestTargetWithResolvedConfig,
     },
 };
-use starknet_api::block::BlockNumber;
 
 pub async fn resolve_config(
     test_target: TestTargetWithConfig,
     fork_targets: &[ForkTarget],
+    package_root: &Utf8Path,
     block_number_map: &mut BlockNumberMap,
 ) -> Result<TestTargetWithResolvedConfig> {
     let mut test_cases = Vec::with_capacity(test_target.test_cases.len());
@@ -32,9 +34,13 @@ pub async fn resolve_config(
                     case.config.fork_config,
                     block_number_map,
                     fork_targets,
+                    package_root,
                 )
                 .await?,
                 fuzzer_config: case.config.fuzzer_config,
+                invariant_config: case.config.invariant_config,
+                transactional: case.config.transactional,
+                timeout: case.config.timeout,
             },
         });
     }
@@ -45,6 +51,7 @@ pub async fn resolve_config(
         sierra_program_path: test_target.sierra_program_path,
         casm_program: test_target.casm_program,
         test_cases,
+        setup_functions: test_target.setup_functions,
     })
 }
 
@@ -52,30 +59,26 @@ async fn resolve_fork_config(
     fork_config: Option<RawForkConfig>,
     block_number_map: &mut BlockNumberMap,
     fork_targets: &[ForkTarget],
+    package_root: &Utf8Path,
 ) -> Result<Option<ResolvedForkConfig>> {
     let Some(fc) = fork_config else {
         return Ok(None);
     };
 
-    let raw_fork_params = replace_id_with_params(fc, fork_targets)?;
+    let raw_fork_params = replace_id_with_params(fc, fork_targets, package_root)?;
 
     let url = raw_fork_params.url;
+    let block_id = raw_fork_params.block;
 
-    let block_number = match raw_fork_params.block {
-        BlockId::BlockNumber(block_number) => BlockNumber(block_number),
-        BlockId::BlockHash(hash) => {
-            block_number_map
-                .get_block_number_for_hash(url.clone(), hash)
-                .await?
-        }
-        BlockId::BlockTag => {
-            block_number_map
-                .get_latest_block_number(url.clone())
-                .await?
-        }
-    };
+    let block_number = block_number_map
+        .get_block_number(url.clone(), &block_id)
+        .await?;
 
-    Ok(Some(ResolvedForkConfig { url, block_number }))
+    Ok(Some(ResolvedForkConfig {
+        url,
+        block_id,
+        block_number,
+    }))
 }
 
 fn get_fork_target_from_runner_config<'a>(
@@ -94,6 +97,7 @@ fn get_fork_target_from_runner_config<'a>(
 fn replace_id_with_params(
     raw_fork_config: RawForkConfig,
     fork_targets: &[ForkTarget],
+    package_root: &Utf8Path,
 ) -> Result<InlineForkConfig> {
     match raw_fork_config {
         RawForkConfig::Inline(raw_fork_params) => Ok(raw_fork_params),
@@ -116,6 +120,10 @@ fn replace_id_with_params(
 
             Ok(InlineForkConfig { url, block })
         }
+        RawForkConfig::StateFile(path) => Ok(InlineForkConfig {
+            url: state_dump_url(Utf8Path::new(&path.to_string()), package_root)?,
+            block: BlockId::BlockTag(BlockTag::Latest),
+        }),
     }
 }
 
@@ -163,6 +171,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: Some(RawForkConfig::Named("non_existent".into())),
                     fuzzer_config: None,
+                    invariant_config: None,
+                    transactional: false,
+                    timeout: None,
                 },
                 test_details: TestDetails {
                     sierra_entry_point_statement_idx: 100,
@@ -178,6 +189,7 @@ mod tests {
                 },
             }],
             tests_location: TestTargetLocation::Lib,
+            setup_functions: vec![],
         };
 
         assert!(
@@ -188,6 +200,7 @@ mod tests {
                     url: Url::parse("https://not_taken.com").expect("Should be valid url"),
                     block_id: BlockId::BlockNumber(120),
                 }],
+                Utf8Path::new("."),
                 &mut BlockNumberMap::default()
             )
             .await
@@ -6,10 +6,12 @@ use forge_runner::{
     package_tests::with_config_resolved::TestTargetWithResolvedConfig,
     printing::print_test_result,
     run_for_test_case,
+    running::run_setup,
     test_case_summary::{AnyTestCaseSummary, TestCaseSummary},
     test_target_summary::TestTargetSummary,
 };
 use futures::{StreamExt, stream::FuturesUnordered};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::mpsc::channel;
 
@@ -34,10 +36,30 @@ pub async fn run_for_test_target(
     // a channel is used to signal the task that test processing is no longer necessary.
     let (send, mut rec) = channel(1);
 
-    for case in tests.test_cases {
+    // Each `#[setup]` function is run once and its state is shared by all tests of its module.
+    // Setups of all modules are spawned upfront, so that they run concurrently.
+    let mut setup_handles = HashMap::new();
+    for case in &tests.test_cases {
+        if !tests_filter.should_be_run(case) {
+            continue;
+        }
+        if let Some(setup) = tests.setup_function_for(&case.name) {
+            setup_handles.entry(setup.name.clone()).or_insert_with(|| {
+                run_setup(
+                    setup.clone(),
+                    casm_program.clone(),
+                    forge_config.test_runner_config.clone(),
+                    tests.sierra_program_path.clone(),
+                )
+            });
+        }
+    }
+    let mut setup_states = HashMap::new();
+
+    for case in &tests.test_cases {
         let case_name = case.name.clone();
 
-        if !tests_filter.should_be_run(&case) {
+        if !tests_filter.should_be_run(case) {
             tasks.push(tokio::task::spawn(async {
                 // TODO TestCaseType should also be encoded in the test case definition
                 Ok(AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
@@ -47,13 +69,34 @@ pub async fn run_for_test_target(
             continue;
         };
 
-        let case = Arc::new(case);
+        let setup_state = match tests.setup_function_for(&case.name) {
+            Some(setup) => {
+                if let Some(setup_handle) = setup_handles.remove(&setup.name) {
+                    setup_states.insert(setup.name.clone(), setup_handle.await?);
+                }
+
+                match &setup_states[&setup.name] {
+                    Ok(setup_state) => Some(setup_state.clone()),
+                    Err(msg) => {
+                        let summary = TestCaseSummary::setup_failed(case, &setup.name, msg);
+                        tasks.push(tokio::task::spawn(async {
+                            Ok(AnyTestCaseSummary::Single(summary))
+                        }));
+                        continue;
+                    }
+                }
+            }
+            None => None,
+        };
+
+        let case = Arc::new(case.clone());
 
         tasks.push(run_for_test_case(
             case,
             casm_program.clone(),
             forge_config.clone(),
             tests.sierra_program_path.clone(),
+            setup_state,
             send.clone(),
         ));
     }
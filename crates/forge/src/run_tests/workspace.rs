@@ -1,10 +1,18 @@
 use super::package::RunForPackageArgs;
 use crate::{
-    ColorOption, ExitStatus, TestArgs, block_number_map::BlockNumberMap, pretty_printing,
-    run_tests::package::run_for_package, scarb::build_artifacts_with_scarb,
-    shared_cache::FailedTestsCache, warn::warn_if_snforge_std_not_compatible,
+    ColorOption, ExitStatus, TestArgs,
+    block_number_map::BlockNumberMap,
+    gas_snapshot::{GAS_SNAPSHOT_FILE, GasSnapshot},
+    pretty_printing,
+    run_tests::package::run_for_package,
+    scarb::build_artifacts_with_scarb,
+    shared_cache::FailedTestsCache,
+    test_report::TestReport,
+    warn::warn_if_snforge_std_not_compatible,
 };
 use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use cheatnet::forking::cache::save_fork_caches;
 use forge_runner::{CACHE_DIR, test_target_summary::TestTargetSummary};
 use forge_runner::{
     coverage_api::can_coverage_be_generated,
@@ -29,6 +37,8 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
     }
 
     let scarb_metadata = ScarbCommand::metadata().inherit_stderr().run()?;
+    // Report paths are relative to the directory `snforge` was run in
+    let current_dir = Utf8PathBuf::try_from(env::current_dir()?)?;
 
     if args.coverage {
         can_coverage_be_generated(&scarb_metadata)?;
@@ -62,11 +72,22 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
         args.no_optimization,
     )?;
 
-    let mut block_number_map = BlockNumberMap::default();
     let mut all_failed_tests = vec![];
+    let mut test_report = TestReport::default();
 
     let workspace_root = &scarb_metadata.workspace.root;
     let cache_dir = workspace_root.join(CACHE_DIR);
+    let mut block_number_map = if args.offline {
+        BlockNumberMap::offline(cache_dir.clone())
+    } else {
+        BlockNumberMap::default()
+    };
+    let gas_snapshot_path = workspace_root.join(GAS_SNAPSHOT_FILE);
+    // Loaded before running tests to fail early if the snapshot is missing
+    let gas_snapshot = args
+        .check_gas_snapshot
+        .then(|| GasSnapshot::load(&gas_snapshot_path))
+        .transpose()?;
 
     for package in packages {
         env::set_current_dir(&package.root)?;
@@ -79,21 +100,60 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
             &artifacts_dir_path,
         )?;
 
-        let tests_file_summaries = run_for_package(args, &mut block_number_map).await?;
+        let tests_file_summaries =
+            run_for_package(args, &mut block_number_map, &mut test_report).await?;
 
         all_failed_tests.extend(extract_failed_tests(tests_file_summaries));
     }
+    // Entries fetched from forks by all tests are appended to their caches once
+    save_fork_caches(&cache_dir);
 
     FailedTestsCache::new(&cache_dir).save_failed_tests(&all_failed_tests)?;
 
-    pretty_printing::print_latest_blocks_numbers(block_number_map.get_url_to_latest_block_number());
+    for report_target in &args.report {
+        test_report.save(report_target.format, &current_dir.join(&report_target.path))?;
+    }
+
+    if args.gas_snapshot {
+        // Tests which were not run, e.g. filtered out, keep their gas from the previous snapshot
+        let mut snapshot = if gas_snapshot_path.exists() {
+            GasSnapshot::load(&gas_snapshot_path)?
+        } else {
+            GasSnapshot::default()
+        };
+        snapshot.update(
+            GasSnapshot::from(&test_report),
+            &test_report.collected_tests(),
+        );
+        snapshot.save(&gas_snapshot_path)?;
+    }
+
+    let mut has_gas_regressions = false;
+    if let Some(gas_snapshot) = gas_snapshot {
+        let current = GasSnapshot::from(&test_report);
+        let diffs = gas_snapshot.diff(&current);
+        pretty_printing::print_gas_snapshot_diffs(
+            &diffs,
+            &gas_snapshot.new_tests(&current),
+            &gas_snapshot.missing_tests(&test_report.collected_tests()),
+            args.gas_snapshot_tolerance,
+        );
+        has_gas_regressions = diffs
+            .iter()
+            .any(|diff| diff.is_regression(args.gas_snapshot_tolerance));
+    }
+
+    pretty_printing::print_resolved_blocks_numbers(
+        block_number_map.get_url_to_latest_block_number(),
+        block_number_map.get_url_and_block_id_to_block_number(),
+    );
     pretty_printing::print_failures(&all_failed_tests);
 
     if args.exact {
         unset_forge_test_filter();
     }
 
-    Ok(if all_failed_tests.is_empty() {
+    Ok(if all_failed_tests.is_empty() && !has_gas_regressions {
         ExitStatus::Success
     } else {
         ExitStatus::Failure
@@ -117,11 +117,11 @@ pub fn load_test_artifacts(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::scarb::config::ForkTarget;
+    use crate::scarb::config::RawForkTarget;
     use assert_fs::TempDir;
     use assert_fs::fixture::{FileWriteStr, PathChild, PathCopy};
     use camino::Utf8PathBuf;
-    use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
+    use cheatnet::runtime_extensions::forge_config_extension::config::{BlockId, BlockTag};
     use configuration::load_package_config;
     use forge_runner::forge_config::ForgeTrackedResource;
     use indoc::{formatdoc, indoc};
@@ -206,25 +206,29 @@ mod tests {
             ForgeConfigFromScarb {
                 exit_first: false,
                 fork: vec![
-                    ForkTarget {
+                    RawForkTarget {
                         name: "FIRST_FORK_NAME".to_string(),
-                        url: "http://some.rpc.url".parse().expect("Should be valid url"),
-                        block_id: BlockId::BlockNumber(1),
+                        url: Some("http://some.rpc.url".parse().expect("Should be valid url")),
+                        block_id: Some(BlockId::BlockNumber(1)),
+                        state_file: None,
                     },
-                    ForkTarget {
+                    RawForkTarget {
                         name: "SECOND_FORK_NAME".to_string(),
-                        url: "http://some.rpc.url".parse().expect("Should be valid url"),
-                        block_id: BlockId::BlockHash(0xa.into()),
+                        url: Some("http://some.rpc.url".parse().expect("Should be valid url")),
+                        block_id: Some(BlockId::BlockHash(0xa.into())),
+                        state_file: None,
                     },
-                    ForkTarget {
+                    RawForkTarget {
                         name: "THIRD_FORK_NAME".to_string(),
-                        url: "http://some.rpc.url".parse().expect("Should be valid url"),
-                        block_id: BlockId::BlockHash(10.into()),
+                        url: Some("http://some.rpc.url".parse().expect("Should be valid url")),
+                        block_id: Some(BlockId::BlockHash(10.into())),
+                        state_file: None,
                     },
-                    ForkTarget {
+                    RawForkTarget {
                         name: "FOURTH_FORK_NAME".to_string(),
-                        url: "http://some.rpc.url".parse().expect("Should be valid url"),
-                        block_id: BlockId::BlockTag,
+                        url: Some("http://some.rpc.url".parse().expect("Should be valid url")),
+                        block_id: Some(BlockId::BlockTag(BlockTag::Latest)),
+                        state_file: None,
                     },
                 ],
                 fuzzer_runs: None,
@@ -235,6 +239,10 @@ mod tests {
                 save_trace_data: false,
                 build_profile: false,
                 coverage: false,
+                transactional: false,
+                timeout: None,
+                trace_verbosity: None,
+                prefetch_fork_storage: false,
             }
         );
     }
@@ -449,7 +457,10 @@ mod tests {
             &scarb_metadata.workspace.members[0],
         )
         .unwrap();
-        assert_eq!(forge_config.fork[0].block_id, BlockId::BlockTag);
+        assert_eq!(
+            forge_config.fork[0].block_id,
+            Some(BlockId::BlockTag(BlockTag::Latest))
+        );
     }
 
     #[test]
@@ -489,12 +500,15 @@ mod tests {
             config,
             ForgeConfigFromScarb {
                 exit_first: false,
-                fork: vec![ForkTarget {
+                fork: vec![RawForkTarget {
                     name: "ENV_URL_FORK".to_string(),
-                    url: "http://some.rpc.url_from_env"
-                        .parse()
-                        .expect("Should be valid url"),
-                    block_id: BlockId::BlockNumber(1),
+                    url: Some(
+                        "http://some.rpc.url_from_env"
+                            .parse()
+                            .expect("Should be valid url")
+                    ),
+                    block_id: Some(BlockId::BlockNumber(1)),
+                    state_file: None,
                 }],
                 fuzzer_runs: None,
                 fuzzer_seed: None,
@@ -504,6 +518,10 @@ mod tests {
                 save_trace_data: false,
                 build_profile: false,
                 coverage: false,
+                transactional: false,
+                timeout: None,
+                trace_verbosity: None,
+                prefetch_fork_storage: false,
             }
         );
     }
@@ -1,8 +1,14 @@
-use anyhow::Result;
-use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
+use anyhow::{Result, anyhow};
+use camino::{Utf8Path, Utf8PathBuf};
+use cheatnet::forking::state_dump::state_dump_url;
+use cheatnet::runtime_extensions::forge_config_extension::config::{BlockId, BlockTag};
+use debugging::TraceVerbosity;
 use forge_runner::forge_config::ForgeTrackedResource;
 use serde::{Deserialize, Deserializer};
-use std::{collections::HashSet, num::NonZeroU32};
+use std::{
+    collections::HashSet,
+    num::{NonZeroU32, NonZeroU64},
+};
 use url::Url;
 
 pub const SCARB_MANIFEST_TEMPLATE_CONTENT: &str = r#"
@@ -12,6 +18,10 @@ pub const SCARB_MANIFEST_TEMPLATE_CONTENT: &str = r#"
 # exit_first = true                                          # Stop tests execution immediately upon the first failure
 # fuzzer_runs = 1234                                         # Number of runs of the random fuzzer
 # fuzzer_seed = 1111                                         # Seed for the random fuzzer
+# transactional = true                                       # Execute calls from tests as transactions
+# timeout = 60                                               # Fail tests (or fuzz test runs) running longer than this many seconds
+# trace_verbosity = "standard"                               # Print call traces of failed tests with this amount of details
+# prefetch_fork_storage = true                               # Prefetch fork storage read by previous runs in the background
 
 # [[tool.snforge.fork]]                                      # Used for fork testing
 # name = "SOME_NAME"                                         # Fork name
@@ -28,6 +38,15 @@ pub const SCARB_MANIFEST_TEMPLATE_CONTENT: &str = r#"
 # url = "http://your.third.rpc.url"
 # block_id.hash = "0x123"                                    # Block to fork from (block hash)
 
+# [[tool.snforge.fork]]
+# name = "SOME_FOURTH_NAME"
+# url = "http://your.fourth.rpc.url"
+# block_id.timestamp = "1700000000"                          # Fork from the last block created at or before this Unix timestamp
+
+# [[tool.snforge.fork]]
+# name = "SOME_STATE_DUMP_NAME"
+# state_file = "fixtures/state.json"                         # State dump to fork from instead of an RPC provider
+
 # [profile.dev.cairo]                                        # Configure Cairo compiler
 # unstable-add-statements-code-locations-debug-info = true   # Should be used if you want to use coverage
 # unstable-add-statements-functions-debug-info = true        # Should be used if you want to use coverage/profiler
@@ -61,27 +80,78 @@ pub struct ForgeConfigFromScarb {
     pub coverage: bool,
     /// Fork configuration profiles
     #[serde(default, deserialize_with = "validate_forks")]
-    pub fork: Vec<ForkTarget>,
+    pub fork: Vec<RawForkTarget>,
     /// Limit of steps
     pub max_n_steps: Option<u32>,
     /// Set tracked resource
     #[serde(default)]
     pub tracked_resource: ForgeTrackedResource,
+    /// Execute calls from tests as transactions sent from an account
+    #[serde(default)]
+    pub transactional: bool,
+    /// Time limit of a single test run, in seconds
+    pub timeout: Option<NonZeroU64>,
+    /// Verbosity of call traces printed for failed tests
+    pub trace_verbosity: Option<TraceVerbosity>,
+    /// Prefetch storage read by previous runs of forks in the background
+    #[serde(default)]
+    pub prefetch_fork_storage: bool,
 }
 
-#[derive(Debug, PartialEq, Clone, Deserialize)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct ForkTarget {
     pub name: String,
     pub url: Url,
     pub block_id: BlockId,
 }
 
-fn validate_forks<'de, D>(deserializer: D) -> Result<Vec<ForkTarget>, D::Error>
+/// Fork defined in `Scarb.toml`, before its state file is resolved against the package root
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+pub struct RawForkTarget {
+    pub name: String,
+    pub url: Option<Url>,
+    pub block_id: Option<BlockId>,
+    /// Path of a state dump file, relative to the package root
+    pub state_file: Option<Utf8PathBuf>,
+}
+
+impl RawForkTarget {
+    pub fn resolve(self, package_root: &Utf8Path) -> Result<ForkTarget> {
+        let RawForkTarget {
+            name,
+            url,
+            block_id,
+            state_file,
+        } = self;
+
+        match (url, state_file) {
+            (Some(url), None) => {
+                let block_id = block_id
+                    .ok_or_else(|| anyhow!("Fork {name} is missing the `block_id` field"))?;
+                Ok(ForkTarget {
+                    name,
+                    url,
+                    block_id,
+                })
+            }
+            (None, Some(state_file)) => Ok(ForkTarget {
+                url: state_dump_url(&state_file, package_root)?,
+                block_id: block_id.unwrap_or(BlockId::BlockTag(BlockTag::Latest)),
+                name,
+            }),
+            _ => Err(anyhow!(
+                "Fork {name} has to define exactly one of `url` and `state_file`"
+            )),
+        }
+    }
+}
+
+fn validate_forks<'de, D>(deserializer: D) -> Result<Vec<RawForkTarget>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    // deserialize to Vec<ForkTarget>
-    let fork_targets = Vec::<ForkTarget>::deserialize(deserializer)?;
+    // deserialize to Vec<RawForkTarget>
+    let fork_targets = Vec::<RawForkTarget>::deserialize(deserializer)?;
 
     let names: Vec<_> = fork_targets.iter().map(|fork| &fork.name).collect();
     let removed_duplicated_names: HashSet<_> = names.iter().collect();
@@ -100,6 +170,14 @@ mod tests {
     use test_case::test_case;
     use url::Url;
 
+    fn package_root() -> Utf8PathBuf {
+        Utf8PathBuf::try_from(std::env::temp_dir()).unwrap()
+    }
+
+    fn parse_fork_target(json_str: &str) -> Result<ForkTarget> {
+        serde_json::from_str::<RawForkTarget>(json_str)?.resolve(&package_root())
+    }
+
     #[test]
     fn test_fork_target_new_valid_number() {
         let name = "TestFork";
@@ -116,7 +194,7 @@ mod tests {
         })
         .to_string();
 
-        let fork_target = serde_json::from_str::<ForkTarget>(&json_str).unwrap();
+        let fork_target = parse_fork_target(&json_str).unwrap();
 
         assert_eq!(fork_target.name, name);
         assert_eq!(fork_target.url, Url::parse(url).unwrap());
@@ -141,7 +219,7 @@ mod tests {
         })
         .to_string();
 
-        let fork_target = serde_json::from_str::<ForkTarget>(&json_str).unwrap();
+        let fork_target = parse_fork_target(&json_str).unwrap();
 
         assert_eq!(fork_target.name, name);
         assert_eq!(fork_target.url, Url::parse(url).unwrap());
@@ -166,17 +244,28 @@ mod tests {
         })
         .to_string();
 
-        let fork_target = serde_json::from_str::<ForkTarget>(&json_str).unwrap();
+        let fork_target = parse_fork_target(&json_str).unwrap();
 
         assert_eq!(fork_target.name, name);
         assert_eq!(fork_target.url, Url::parse(url).unwrap());
-        if let BlockId::BlockTag = fork_target.block_id {
+        if let BlockId::BlockTag(BlockTag::Latest) = fork_target.block_id {
             // Expected variant
         } else {
             panic!("Expected BlockId::BlockTag");
         }
     }
 
+    #[test_case(
+        &json!({
+            "name": "TestFork",
+            "url": "http://example.com",
+            "block_id": {
+                "tag": "finalized"
+            }
+        }),
+        "block_id.tag can only be equal to latest, pending or l1_accepted";
+        "invalid tag"
+    )]
     #[test_case(
         &json!({
             "name": "TestFork",
@@ -210,10 +299,78 @@ mod tests {
         "Failed to create Felt from string";
         "invalid hash"
     )]
+    #[test_case(
+        &json!({
+            "name": "TestFork",
+            "url": "http://example.com",
+            "state_file": "state.json"
+        }),
+        "has to define exactly one of `url` and `state_file`";
+        "url and state file"
+    )]
+    #[test_case(
+        &json!({
+            "name": "TestFork",
+            "url": "http://example.com"
+        }),
+        "missing the `block_id` field";
+        "missing block id"
+    )]
     fn test_fork_target_invalid_cases(input: &serde_json::Value, expected_error: &str) {
         let json_str = input.to_string();
-        let result = serde_json::from_str::<ForkTarget>(&json_str);
+        let result = parse_fork_target(&json_str);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains(expected_error));
     }
+
+    #[test_case("pending", BlockTag::Pending)]
+    #[test_case("l1_accepted", BlockTag::L1Accepted)]
+    fn test_fork_target_new_valid_other_tags(tag: &str, expected: BlockTag) {
+        let json_str = json!({
+            "name": "TestFork",
+            "url": "http://example.com",
+            "block_id": {
+                "tag": tag
+            }
+        })
+        .to_string();
+
+        let fork_target = parse_fork_target(&json_str).unwrap();
+
+        assert_eq!(fork_target.block_id, BlockId::BlockTag(expected));
+    }
+
+    #[test]
+    fn test_fork_target_new_valid_timestamp() {
+        let json_str = json!({
+            "name": "TestFork",
+            "url": "http://example.com",
+            "block_id": {
+                "timestamp": "1700000000"
+            }
+        })
+        .to_string();
+
+        let fork_target = parse_fork_target(&json_str).unwrap();
+
+        assert_eq!(fork_target.block_id, BlockId::BlockTimestamp(1_700_000_000));
+    }
+
+    #[test]
+    fn test_fork_target_new_valid_state_file() {
+        let json_str = json!({
+            "name": "TestFork",
+            "state_file": "fixtures/state.json"
+        })
+        .to_string();
+
+        let fork_target = parse_fork_target(&json_str).unwrap();
+
+        assert_eq!(fork_target.name, "TestFork");
+        assert_eq!(
+            fork_target.url,
+            Url::from_file_path(package_root().join("fixtures").join("state.json")).unwrap()
+        );
+        assert_eq!(fork_target.block_id, BlockId::BlockTag(BlockTag::Latest));
+    }
 }
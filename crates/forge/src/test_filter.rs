@@ -185,6 +185,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -197,6 +200,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -209,6 +215,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -221,10 +230,14 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
             ],
             tests_location: TestTargetLocation::Lib,
+            setup_functions: vec![],
         };
 
         let tests_filter = TestsFilter::from_flags(
@@ -252,6 +265,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
                     fuzzer_config: None,
+                    invariant_config: None,
+                    transactional: false,
+                    timeout: None,
                 },
             },]
         );
@@ -280,6 +296,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
                     fuzzer_config: None,
+                    invariant_config: None,
+                    transactional: false,
+                    timeout: None,
                 },
             },]
         );
@@ -309,6 +328,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -321,6 +343,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -333,6 +358,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -345,6 +373,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
             ]
@@ -389,6 +420,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -401,6 +435,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -413,6 +450,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -425,6 +465,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
             ]
@@ -445,6 +488,7 @@ mod tests {
             ),
             test_cases: vec![],
             tests_location: TestTargetLocation::Lib,
+            setup_functions: vec![],
         };
 
         let tests_filter = TestsFilter::from_flags(
@@ -500,6 +544,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -512,6 +559,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -524,6 +574,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -536,10 +589,14 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
             ],
             tests_location: TestTargetLocation::Tests,
+            setup_functions: vec![],
         };
 
         let tests_filter = TestsFilter::from_flags(
@@ -594,6 +651,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
                     fuzzer_config: None,
+                    invariant_config: None,
+                    transactional: false,
+                    timeout: None,
                 },
             },]
         );
@@ -622,6 +682,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
                     fuzzer_config: None,
+                    invariant_config: None,
+                    transactional: false,
+                    timeout: None,
                 },
             },]
         );
@@ -664,6 +727,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
                     fuzzer_config: None,
+                    invariant_config: None,
+                    transactional: false,
+                    timeout: None,
                 },
             },]
         );
@@ -692,6 +758,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -704,6 +773,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -716,6 +788,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -728,10 +803,14 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
             ],
             tests_location: TestTargetLocation::Tests,
+            setup_functions: vec![],
         };
 
         let tests_filter =
@@ -752,6 +831,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -764,6 +846,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
             ]
@@ -794,6 +879,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -806,6 +894,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -818,6 +909,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -830,10 +924,14 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
             ],
             tests_location: TestTargetLocation::Tests,
+            setup_functions: vec![],
         };
 
         let tests_filter =
@@ -854,6 +952,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -866,6 +967,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -878,6 +982,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -890,6 +997,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
+                        transactional: false,
+                        timeout: None,
                     },
                 },
             ]
@@ -0,0 +1,653 @@
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use cheatnet::forking::multi_fork::CreatedFork;
+use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
+use clap::ValueEnum;
+use forge_runner::package_tests::TestTargetLocation;
+use forge_runner::package_tests::with_config_resolved::ResolvedForkConfig;
+use forge_runner::test_case_summary::{
+    AnyTestCaseSummary, FuzzingStatistics, GasStatistics, GasStatisticsComponent, TestCaseSummary,
+};
+use forge_runner::test_target_summary::TestTargetSummary;
+use serde::Serialize;
+use starknet_api::execution_resources::GasVector;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::fs;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// JUnit XML report
+    Junit,
+    /// JSON report
+    Json,
+}
+
+/// Report requested with `--report <FORMAT>=<PATH>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportTarget {
+    pub format: ReportFormat,
+    pub path: Utf8PathBuf,
+}
+
+impl FromStr for ReportTarget {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (format, path) = value
+            .split_once('=')
+            .ok_or_else(|| format!("expected `<FORMAT>=<PATH>`, got `{value}`"))?;
+
+        let format = ReportFormat::from_str(format, true).map_err(|_| {
+            format!("unknown report format `{format}`, possible values: junit, json")
+        })?;
+
+        if path.is_empty() {
+            return Err(format!("missing path for the `{value}` report"));
+        }
+
+        Ok(Self {
+            format,
+            path: Utf8PathBuf::from(path),
+        })
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct TestReport {
+    pub packages: Vec<PackageReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PackageReport {
+    pub name: String,
+    pub test_targets: Vec<TestTargetReport>,
+    /// Names of all tests of the package, including ones filtered out
+    #[serde(skip)]
+    pub collected_tests: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TestTargetReport {
+    pub location: &'static str,
+    /// Duration of running the whole test target, in seconds
+    pub duration: f64,
+    pub tests: Vec<TestCaseReport>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TestCaseReport {
+    pub name: String,
+    pub status: TestStatus,
+    /// Duration of running the test case, in seconds, `None` for ignored tests
+    pub duration: Option<f64>,
+    pub message: Option<String>,
+    pub backtrace: Option<String>,
+    pub gas: Option<GasReport>,
+    pub fuzzing: Option<FuzzingReport>,
+    pub fork: Option<ForkReport>,
+    /// Forks created by the test with `create_fork` cheatcode
+    pub created_forks: Vec<CreatedForkReport>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum GasReport {
+    Single {
+        l1_gas: u64,
+        l1_data_gas: u64,
+        l2_gas: u64,
+    },
+    Fuzzing {
+        l1_gas: GasStatisticsReport,
+        l1_data_gas: GasStatisticsReport,
+        l2_gas: GasStatisticsReport,
+    },
+}
+
+#[derive(Debug, Serialize)]
+pub struct GasStatisticsReport {
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub std_deviation: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FuzzingReport {
+    pub runs: usize,
+    /// Arguments of the failing run
+    pub arguments: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ForkReport {
+    pub url: String,
+    /// Block the fork was configured with, e.g. a tag or a timestamp
+    pub block_id: BlockId,
+    /// Number of the block the fork was resolved to
+    pub block_number: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreatedForkReport {
+    pub name: String,
+    #[serde(flatten)]
+    pub fork: ForkReport,
+}
+
+impl From<&GasVector> for GasReport {
+    fn from(gas: &GasVector) -> Self {
+        GasReport::Single {
+            l1_gas: gas.l1_gas.0,
+            l1_data_gas: gas.l1_data_gas.0,
+            l2_gas: gas.l2_gas.0,
+        }
+    }
+}
+
+impl From<&GasStatistics> for GasReport {
+    fn from(gas: &GasStatistics) -> Self {
+        GasReport::Fuzzing {
+            l1_gas: (&gas.l1_gas).into(),
+            l1_data_gas: (&gas.l1_data_gas).into(),
+            l2_gas: (&gas.l2_gas).into(),
+        }
+    }
+}
+
+impl From<&GasStatisticsComponent> for GasStatisticsReport {
+    fn from(component: &GasStatisticsComponent) -> Self {
+        Self {
+            min: component.min,
+            max: component.max,
+            mean: component.mean,
+            std_deviation: component.std_deviation,
+        }
+    }
+}
+
+impl From<&ResolvedForkConfig> for ForkReport {
+    fn from(fork_config: &ResolvedForkConfig) -> Self {
+        Self {
+            url: fork_config.url.to_string(),
+            block_id: fork_config.block_id.clone(),
+            block_number: fork_config.block_number.0,
+        }
+    }
+}
+
+impl From<&CreatedFork> for CreatedForkReport {
+    fn from(fork: &CreatedFork) -> Self {
+        Self {
+            name: fork.name.clone(),
+            fork: ForkReport {
+                url: fork.url.to_string(),
+                block_id: fork.block_id.clone(),
+                block_number: fork.block_number.0,
+            },
+        }
+    }
+}
+
+impl PackageReport {
+    #[must_use]
+    pub fn new(name: String, collected_tests: Vec<String>) -> Self {
+        Self {
+            name,
+            test_targets: vec![],
+            collected_tests,
+        }
+    }
+
+    #[expect(clippy::implicit_hasher)]
+    pub fn add_test_target(
+        &mut self,
+        location: TestTargetLocation,
+        summary: &TestTargetSummary,
+        fork_configs: &HashMap<String, ResolvedForkConfig>,
+        duration: Duration,
+    ) {
+        let location = match location {
+            TestTargetLocation::Lib => "src",
+            TestTargetLocation::Tests => "tests",
+        };
+
+        let tests = summary
+            .test_case_summaries
+            .iter()
+            .filter_map(|summary| {
+                TestCaseReport::new(summary, |name| fork_configs.get(name).map(Into::into))
+            })
+            .collect();
+
+        self.test_targets.push(TestTargetReport {
+            location,
+            duration: duration.as_secs_f64(),
+            tests,
+        });
+    }
+}
+
+impl TestCaseReport {
+    /// Returns `None` for test cases that were skipped
+    fn new(
+        summary: &AnyTestCaseSummary,
+        fork: impl FnOnce(&str) -> Option<ForkReport>,
+    ) -> Option<Self> {
+        let name = summary.name()?.to_string();
+
+        let status = if summary.is_passed() {
+            TestStatus::Passed
+        } else if summary.is_failed() {
+            TestStatus::Failed
+        } else {
+            TestStatus::Ignored
+        };
+
+        let backtrace = match summary {
+            AnyTestCaseSummary::Single(TestCaseSummary::Failed { backtrace, .. })
+            | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed { backtrace, .. }) => {
+                backtrace.clone()
+            }
+            _ => None,
+        };
+
+        let gas = match summary {
+            AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, .. }) => {
+                Some(gas_info.into())
+            }
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { gas_info, .. }) => {
+                Some(gas_info.into())
+            }
+            _ => None,
+        };
+
+        let fuzzing = match summary {
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+                test_statistics: FuzzingStatistics { runs },
+                ..
+            }) => Some(FuzzingReport {
+                runs: *runs,
+                arguments: None,
+            }),
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed {
+                test_statistics: FuzzingStatistics { runs },
+                fuzzer_args,
+                ..
+            }) => Some(FuzzingReport {
+                runs: *runs,
+                arguments: Some(fuzzer_args.clone()),
+            }),
+            _ => None,
+        };
+
+        Some(Self {
+            fork: fork(&name),
+            created_forks: summary.created_forks().iter().map(Into::into).collect(),
+            name,
+            status,
+            duration: summary.elapsed().map(|elapsed| elapsed.as_secs_f64()),
+            message: summary.msg().map(|msg| msg.trim().to_string()),
+            backtrace,
+            gas,
+            fuzzing,
+        })
+    }
+}
+
+impl TestReport {
+    /// Names of all tests of the run packages, including ones filtered out
+    #[must_use]
+    pub fn collected_tests(&self) -> HashSet<&str> {
+        self.packages
+            .iter()
+            .flat_map(|package| &package.collected_tests)
+            .map(String::as_str)
+            .collect()
+    }
+
+    pub fn save(&self, format: ReportFormat, path: &Utf8Path) -> Result<()> {
+        let content = match format {
+            ReportFormat::Junit => self.to_junit_xml(),
+            ReportFormat::Json => serde_json::to_string_pretty(self)?,
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {parent}"))?;
+        }
+
+        fs::write(path, content).with_context(|| format!("Failed to write test report to {path}"))
+    }
+
+    fn to_junit_xml(&self) -> String {
+        let all_tests = || {
+            self.packages
+                .iter()
+                .flat_map(|package| &package.test_targets)
+                .flat_map(|test_target| &test_target.tests)
+        };
+        let duration: f64 = self
+            .packages
+            .iter()
+            .flat_map(|package| &package.test_targets)
+            .map(|test_target| test_target.duration)
+            .sum();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        writeln!(
+            xml,
+            r#"<testsuites name="snforge" tests="{}" failures="{}" skipped="{}" time="{duration:.3}">"#,
+            all_tests().count(),
+            count_with_status(all_tests(), TestStatus::Failed),
+            count_with_status(all_tests(), TestStatus::Ignored),
+        )
+        .unwrap();
+
+        for package in &self.packages {
+            for test_target in &package.test_targets {
+                write_junit_test_suite(&mut xml, &package.name, test_target);
+            }
+        }
+
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+fn count_with_status<'a>(
+    tests: impl Iterator<Item = &'a TestCaseReport>,
+    status: TestStatus,
+) -> usize {
+    tests.filter(|test| test.status == status).count()
+}
+
+fn write_junit_test_suite(xml: &mut String, package_name: &str, test_target: &TestTargetReport) {
+    writeln!(
+        xml,
+        r#"  <testsuite name="{}" tests="{}" failures="{}" skipped="{}" time="{:.3}">"#,
+        escape_xml(&format!("{package_name}::{}", test_target.location)),
+        test_target.tests.len(),
+        count_with_status(test_target.tests.iter(), TestStatus::Failed),
+        count_with_status(test_target.tests.iter(), TestStatus::Ignored),
+        test_target.duration,
+    )
+    .unwrap();
+
+    for test in &test_target.tests {
+        let (classname, name) = test.name.rsplit_once("::").unwrap_or(("", &test.name));
+        let time = test
+            .duration
+            .map(|duration| format!(r#" time="{duration:.3}""#))
+            .unwrap_or_default();
+        writeln!(
+            xml,
+            r#"    <testcase name="{}" classname="{}"{time}>"#,
+            escape_xml(name),
+            escape_xml(classname),
+        )
+        .unwrap();
+
+        let properties = test.junit_properties();
+        if !properties.is_empty() {
+            xml.push_str("      <properties>\n");
+            for (name, value) in properties {
+                writeln!(
+                    xml,
+                    r#"        <property name="{name}" value="{}"/>"#,
+                    escape_xml(&value)
+                )
+                .unwrap();
+            }
+            xml.push_str("      </properties>\n");
+        }
+
+        match test.status {
+            TestStatus::Passed => {}
+            TestStatus::Failed => {
+                let message = test.message.as_deref().unwrap_or_default();
+                writeln!(
+                    xml,
+                    r#"      <failure message="{}">{}</failure>"#,
+                    escape_xml(message.lines().next().unwrap_or_default()),
+                    escape_xml(message),
+                )
+                .unwrap();
+            }
+            TestStatus::Ignored => xml.push_str("      <skipped/>\n"),
+        }
+
+        if let Some(backtrace) = &test.backtrace {
+            writeln!(
+                xml,
+                "      <system-err>{}</system-err>",
+                escape_xml(backtrace)
+            )
+            .unwrap();
+        }
+
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n");
+}
+
+impl TestCaseReport {
+    fn junit_properties(&self) -> Vec<(&'static str, String)> {
+        let mut properties = vec![];
+
+        match &self.gas {
+            Some(GasReport::Single {
+                l1_gas,
+                l1_data_gas,
+                l2_gas,
+            }) => {
+                properties.push(("l1_gas", l1_gas.to_string()));
+                properties.push(("l1_data_gas", l1_data_gas.to_string()));
+                properties.push(("l2_gas", l2_gas.to_string()));
+            }
+            Some(GasReport::Fuzzing {
+                l1_gas,
+                l1_data_gas,
+                l2_gas,
+            }) => {
+                properties.push(("l1_gas_mean", format!("{:.2}", l1_gas.mean)));
+                properties.push(("l1_data_gas_mean", format!("{:.2}", l1_data_gas.mean)));
+                properties.push(("l2_gas_mean", format!("{:.2}", l2_gas.mean)));
+            }
+            None => {}
+        }
+
+        if let Some(fuzzing) = &self.fuzzing {
+            properties.push(("fuzzer_runs", fuzzing.runs.to_string()));
+            if let Some(arguments) = &fuzzing.arguments {
+                properties.push(("fuzzer_arguments", arguments.join(", ")));
+            }
+        }
+
+        if let Some(fork) = &self.fork {
+            properties.push(("fork_url", fork.url.clone()));
+            properties.push(("fork_block_id", fork.block_id.to_string()));
+            properties.push(("fork_block_number", fork.block_number.to_string()));
+        }
+        for CreatedForkReport { name, fork } in &self.created_forks {
+            properties.push((
+                "created_fork",
+                format!(
+                    "{name}: url = {}, {}, block_number = {}",
+                    fork.url, fork.block_id, fork.block_number
+                ),
+            ));
+        }
+
+        properties
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for char in value.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Characters not allowed in XML 1.0, e.g. colour codes
+            char if char.is_control() && !matches!(char, '\n' | '\r' | '\t') => {}
+            char => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cheatnet::runtime_extensions::forge_config_extension::config::BlockTag;
+
+    #[test]
+    fn parse_report_target() {
+        assert_eq!(
+            "junit=reports/junit.xml".parse::<ReportTarget>().unwrap(),
+            ReportTarget {
+                format: ReportFormat::Junit,
+                path: Utf8PathBuf::from("reports/junit.xml"),
+            }
+        );
+        assert_eq!(
+            "JSON=report.json".parse::<ReportTarget>().unwrap(),
+            ReportTarget {
+                format: ReportFormat::Json,
+                path: Utf8PathBuf::from("report.json"),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_invalid_report_target() {
+        assert_eq!(
+            "junit".parse::<ReportTarget>().unwrap_err(),
+            "expected `<FORMAT>=<PATH>`, got `junit`"
+        );
+        assert_eq!(
+            "xml=report.xml".parse::<ReportTarget>().unwrap_err(),
+            "unknown report format `xml`, possible values: junit, json"
+        );
+        assert_eq!(
+            "json=".parse::<ReportTarget>().unwrap_err(),
+            "missing path for the `json=` report"
+        );
+    }
+
+    #[test]
+    fn junit_xml() {
+        let report = TestReport {
+            packages: vec![PackageReport {
+                name: "package".to_string(),
+                test_targets: vec![TestTargetReport {
+                    location: "tests",
+                    duration: 1.5,
+                    tests: vec![
+                        TestCaseReport {
+                            name: "package_integrationtest::test::passing".to_string(),
+                            status: TestStatus::Passed,
+                            duration: Some(0.25),
+                            message: None,
+                            backtrace: None,
+                            gas: Some(GasReport::Single {
+                                l1_gas: 0,
+                                l1_data_gas: 96,
+                                l2_gas: 40000,
+                            }),
+                            fuzzing: None,
+                            fork: Some(ForkReport {
+                                url: "http://127.0.0.1:5055/rpc".to_string(),
+                                block_id: BlockId::BlockTimestamp(1_700_000_000),
+                                block_number: 123,
+                            }),
+                            created_forks: vec![CreatedForkReport {
+                                name: "other".to_string(),
+                                fork: ForkReport {
+                                    url: "http://127.0.0.1:5055/rpc".to_string(),
+                                    block_id: BlockId::BlockTag(BlockTag::Latest),
+                                    block_number: 456,
+                                },
+                            }],
+                        },
+                        TestCaseReport {
+                            name: "package_integrationtest::test::failing".to_string(),
+                            status: TestStatus::Failed,
+                            duration: Some(1.0),
+                            message: Some("0x6661696c ('fail')\n<message>".to_string()),
+                            backtrace: None,
+                            gas: None,
+                            fuzzing: Some(FuzzingReport {
+                                runs: 3,
+                                arguments: Some(vec!["1".to_string(), "2".to_string()]),
+                            }),
+                            fork: None,
+                            created_forks: vec![],
+                        },
+                        TestCaseReport {
+                            name: "package_integrationtest::test::ignored".to_string(),
+                            status: TestStatus::Ignored,
+                            duration: None,
+                            message: None,
+                            backtrace: None,
+                            gas: None,
+                            fuzzing: None,
+                            fork: None,
+                            created_forks: vec![],
+                        },
+                    ],
+                }],
+                collected_tests: vec![],
+            }],
+        };
+
+        assert_eq!(
+            report.to_junit_xml(),
+            indoc::indoc! {r#"
+                <?xml version="1.0" encoding="UTF-8"?>
+                <testsuites name="snforge" tests="3" failures="1" skipped="1" time="1.500">
+                  <testsuite name="package::tests" tests="3" failures="1" skipped="1" time="1.500">
+                    <testcase name="passing" classname="package_integrationtest::test" time="0.250">
+                      <properties>
+                        <property name="l1_gas" value="0"/>
+                        <property name="l1_data_gas" value="96"/>
+                        <property name="l2_gas" value="40000"/>
+                        <property name="fork_url" value="http://127.0.0.1:5055/rpc"/>
+                        <property name="fork_block_id" value="block_timestamp: 1700000000"/>
+                        <property name="fork_block_number" value="123"/>
+                        <property name="created_fork" value="other: url = http://127.0.0.1:5055/rpc, block_tag: latest, block_number = 456"/>
+                      </properties>
+                    </testcase>
+                    <testcase name="failing" classname="package_integrationtest::test" time="1.000">
+                      <properties>
+                        <property name="fuzzer_runs" value="3"/>
+                        <property name="fuzzer_arguments" value="1, 2"/>
+                      </properties>
+                      <failure message="0x6661696c (&apos;fail&apos;)">0x6661696c (&apos;fail&apos;)
+                &lt;message&gt;</failure>
+                    </testcase>
+                    <testcase name="ignored" classname="package_integrationtest::test">
+                      <skipped/>
+                    </testcase>
+                  </testsuite>
+                </testsuites>
+            "#}
+        );
+    }
+}
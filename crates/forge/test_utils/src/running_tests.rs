@@ -7,6 +7,7 @@ use forge::{
     run_tests::package::{RunForPackageArgs, run_for_package},
     scarb::load_test_artifacts,
     test_filter::TestsFilter,
+    test_report::TestReport,
 };
 use forge_runner::CACHE_DIR;
 use forge_runner::forge_config::{
@@ -46,10 +47,11 @@ pub fn run_test_case(
     let raw_test_targets =
         load_test_artifacts(&test.path().unwrap().join("target/dev"), package).unwrap();
 
-    rt.block_on(run_for_package(
+    let result = rt.block_on(run_for_package(
         RunForPackageArgs {
             test_targets: raw_test_targets,
             package_name: "test_package".to_string(),
+            package_root: package.root.clone(),
             tests_filter: TestsFilter::from_flags(
                 None,
                 false,
@@ -68,8 +70,14 @@ pub fn run_test_case(
                     cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path())
                         .unwrap()
                         .join(CACHE_DIR),
+                    offline: false,
+                    prefetch_fork_storage: false,
                     contracts_data: ContractsData::try_from(test.contracts().unwrap()).unwrap(),
                     tracked_resource,
+                    transactional: false,
+                    timeout: None,
+                    trace_verbosity: None,
+                    trace_passed_tests: false,
                     environment_variables: test.env().clone(),
                 }),
                 output_config: Arc::new(OutputConfig {
@@ -80,6 +88,10 @@ pub fn run_test_case(
             fork_targets: vec![],
         },
         &mut BlockNumberMap::default(),
-    ))
-    .expect("Runner fail")
+        &mut TestReport::default(),
+    ));
+    // Timed out tests can't be interrupted, don't wait for them to finish
+    rt.shutdown_background();
+
+    result.expect("Runner fail")
 }
@@ -0,0 +1,36 @@
+#[starknet::interface]
+trait IInvariantChecker<TContractState> {
+    fn add(ref self: TContractState, amount: u8);
+    fn get_value(self: @TContractState) -> u32;
+}
+
+#[starknet::contract]
+mod InvariantChecker {
+    #[storage]
+    struct Storage {
+        value: u32,
+        limit: u32,
+    }
+
+    // Values above `limit` are rejected, unless the limit is 0
+    #[constructor]
+    fn constructor(ref self: ContractState, limit: u32) {
+        self.limit.write(limit);
+    }
+
+    #[abi(embed_v0)]
+    impl IInvariantCheckerImpl of super::IInvariantChecker<ContractState> {
+        // Increases the value by the given amount
+        fn add(ref self: ContractState, amount: u8) {
+            let value = self.value.read() + amount.into();
+            let limit = self.limit.read();
+            assert(limit == 0 || value <= limit, 'limit exceeded');
+            self.value.write(value);
+        }
+
+        // Returns the current value
+        fn get_value(self: @ContractState) -> u32 {
+            self.value.read()
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use starknet::account::Call;
+
+#[starknet::interface]
+trait ISimpleAccount<TContractState> {
+    fn __validate__(ref self: TContractState, calls: Array<Call>) -> felt252;
+    fn __execute__(ref self: TContractState, calls: Array<Call>) -> Array<Span<felt252>>;
+    fn get_public_key(self: @TContractState) -> felt252;
+}
+
+#[starknet::contract(account)]
+mod SimpleAccount {
+    use core::ecdsa::check_ecdsa_signature;
+    use starknet::account::Call;
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+    use starknet::{SyscallResultTrait, get_tx_info, syscalls::call_contract_syscall};
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, public_key: felt252) {
+        self.public_key.write(public_key);
+    }
+
+    #[abi(embed_v0)]
+    impl SimpleAccountImpl of super::ISimpleAccount<ContractState> {
+        fn __validate__(ref self: ContractState, calls: Array<Call>) -> felt252 {
+            let tx_info = get_tx_info().unbox();
+            let signature = tx_info.signature;
+            assert(signature.len() == 2, 'Invalid signature length');
+            assert(
+                check_ecdsa_signature(
+                    tx_info.transaction_hash,
+                    self.public_key.read(),
+                    *signature.at(0),
+                    *signature.at(1),
+                ),
+                'Invalid signature',
+            );
+
+            starknet::VALIDATED
+        }
+
+        fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+            let mut results = array![];
+            for call in calls {
+                let result = call_contract_syscall(call.to, call.selector, call.calldata)
+                    .unwrap_syscall();
+                results.append(result);
+            };
+            results
+        }
+
+        fn get_public_key(self: @ContractState) -> felt252 {
+            self.public_key.read()
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use starknet::account::Call;
+
+#[starknet::interface]
+trait IValidatingAccount<TContractState> {
+    fn __validate__(ref self: TContractState, calls: Array<Call>) -> felt252;
+    fn __execute__(ref self: TContractState, calls: Array<Call>) -> Array<Span<felt252>>;
+}
+
+// Accepts transactions signed with `['valid']` and emits an event in `__validate__`.
+// Transactions signed with `['block_hash']` use a syscall which is not allowed in validation.
+#[starknet::contract(account)]
+mod ValidatingAccount {
+    use starknet::account::Call;
+    use starknet::syscalls::{call_contract_syscall, get_block_hash_syscall};
+    use starknet::{SyscallResultTrait, get_tx_info};
+
+    #[storage]
+    struct Storage {}
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        Validated: Validated,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Validated {}
+
+    #[abi(embed_v0)]
+    impl ValidatingAccountImpl of super::IValidatingAccount<ContractState> {
+        fn __validate__(ref self: ContractState, calls: Array<Call>) -> felt252 {
+            self.emit(Validated {});
+
+            let signature = get_tx_info().unbox().signature;
+            if *signature.at(0) == 'block_hash' {
+                get_block_hash_syscall(0).unwrap_syscall();
+            }
+            assert(*signature.at(0) == 'valid', 'Invalid signature');
+
+            starknet::VALIDATED
+        }
+
+        fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+            let mut results = array![];
+            for call in calls {
+                let result = call_contract_syscall(call.to, call.selector, call.calldata)
+                    .unwrap_syscall();
+                results.append(result);
+            };
+            results
+        }
+    }
+}
@@ -1,12 +1,29 @@
 use super::common::runner::{setup_package, test_runner};
-use indoc::formatdoc;
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::{formatdoc, indoc};
 use shared::test_utils::output_assert::assert_stdout_contains;
+use std::fs;
+
+const CONTRACT_A: &str = "0x5ab84515ea91a99261961ba72888d663606612958a363e8538302a27398bc29";
+const CONTRACT_B: &str = "0x634cf632813aca745d024ee244aab954461a7341b610b103fa7569bf1e14a5e";
+const CONTRACT_C: &str = "0x38767c97f072a291507aa962d6d92b04ae7b4e01c406717f5485b7a86fbdde7";
 
 #[test]
-fn debugging_trace() {
+fn debugging_trace_detailed() {
     let temp = setup_package("debugging");
 
-    let output = test_runner(&temp).assert().code(1);
+    let output = test_runner(&temp)
+        .arg("test_debugging_trace")
+        .arg("--trace-verbosity")
+        .arg("detailed")
+        .assert()
+        .code(1);
+
+    // Traces of passed tests are printed only if a single test is selected
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(!stdout.contains(
+        "[test name] trace_info_integrationtest::test_trace::test_debugging_trace_success"
+    ));
 
     assert_stdout_contains(
         output,
@@ -16,7 +33,7 @@ fn debugging_trace() {
 
         Collected 2 test(s) from trace_info package
         Running 2 test(s) from tests/
-        [FAIL] trace_info_integrationtest::test_trace::test_debugging_trace_fail
+        [FAIL] trace_info_integrationtest::test_trace::test_debugging_trace_fail (time: [..]s)
 
         Failure data:
             (0x1, 0x2, 0x3, 0x4, 0x5)
@@ -24,75 +41,213 @@ fn debugging_trace() {
         note: run with `SNFORGE_BACKTRACE=1` environment variable to display a backtrace
         {debugging_trace_fail}
 
-        [PASS] trace_info_integrationtest::test_trace::test_debugging_trace_success (l1_gas: ~0, l1_data_gas: ~288, l2_gas: ~1440000)
+        [PASS] trace_info_integrationtest::test_trace::test_debugging_trace_success (l1_gas: ~0, l1_data_gas: ~288, l2_gas: ~1440000) (time: [..]s)
+
+        Running 0 test(s) from src/
+        Tests: 1 passed, 1 failed, 0 skipped, 0 ignored, 0 filtered out
+
+        Failures:
+            trace_info_integrationtest::test_trace::test_debugging_trace_fail
+        ",
+        debugging_trace_fail = detailed_trace_message("fail")},
+    );
+}
+
+#[test]
+fn debugging_trace_of_selected_passed_test() {
+    let temp = setup_package("debugging");
+
+    let output = test_runner(&temp)
+        .arg("trace_info_integrationtest::test_trace::test_debugging_trace_success")
+        .arg("--exact")
+        .arg("--trace-verbosity")
+        .arg("detailed")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+        [PASS] trace_info_integrationtest::test_trace::test_debugging_trace_success (l1_gas: ~0, l1_data_gas: ~288, l2_gas: ~1440000) (time: [..]s)
 
         Success data:
             (0x1, 0x2, 0x3, 0x4, 0x5)
 
         note: run with `SNFORGE_BACKTRACE=1` environment variable to display a backtrace
         {debugging_trace_pass}
+        ",
+        debugging_trace_pass = detailed_trace_message("success")},
+    );
+}
 
-        Running 0 test(s) from src/
-        Tests: 1 passed, 1 failed, 0 skipped, 0 ignored, 0 filtered out
+#[test]
+fn debugging_trace_minimal_only_failed() {
+    let temp = setup_package("debugging");
 
-        Failures:
-            trace_info_integrationtest::test_trace::test_debugging_trace_fail
+    let output = test_runner(&temp)
+        .arg("--trace-verbosity")
+        .arg("minimal")
+        .assert()
+        .code(1);
+
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(!stdout.contains(
+        "[test name] trace_info_integrationtest::test_trace::test_debugging_trace_success"
+    ));
+
+    assert_stdout_contains(output, minimal_trace_message("fail"));
+}
+
+#[test]
+fn debugging_trace_verbosity_from_scarb_toml() {
+    let temp = setup_package("debugging");
+    let manifest = fs::read_to_string(temp.child("Scarb.toml")).unwrap();
+    temp.child("Scarb.toml")
+        .write_str(&formatdoc! {r#"
+            {manifest}
+            [tool.snforge]
+            trace_verbosity = "standard"
+        "#})
+        .unwrap();
+
+    let output = test_runner(&temp).assert().code(1);
+
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+        [test name] trace_info_integrationtest::test_trace::test_debugging_trace_fail
+        ├─ [selector] execute_calls
+        │  ├─ [contract name] SimpleContract
+        │  ├─ [calldata] {calldata}
+        │  ├─ [call result] success: ()
+        [..]
+        └─ [selector] fail
+           ├─ [contract name] SimpleContract
+           ├─ [calldata] (array![0x1, 0x2, 0x3, 0x4, 0x5])
+           └─ [call result] panic: [0x1, 0x2, 0x3, 0x4, 0x5]
         ",
-        debugging_trace_fail = debugging_trace_message("fail"),
-        debugging_trace_pass = debugging_trace_message("success")},
+        calldata = execute_calls_calldata()},
+    );
+}
+
+#[test]
+fn debugging_trace_storage_accesses_and_events() {
+    let temp = setup_package("erc20_package");
+
+    let output = test_runner(&temp)
+        .arg("complex")
+        .arg("--trace-verbosity")
+        .arg("standard")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [test name] erc20_package_integrationtest::test_complex::complex
+        [..][selector] transfer
+        [..][calldata] (ContractAddress(0x7b), 2_u256)
+        [..][storage read] key: [..], value: 0xa
+        [..][storage write] key: [..], old value: 0xa, new value: 0x8
+        [..][storage write] key: [..], old value: 0x0, new value: 0x2
+        [..][event] Transfer { from: ContractAddress([..]), to: ContractAddress(0x7b), value: 2_u256 }
+        "},
     );
 }
 
-fn debugging_trace_message(test_name: &str) -> String {
+#[test]
+fn no_debugging_trace_by_default() {
+    let temp = setup_package("debugging");
+
+    let output = test_runner(&temp).assert().code(1);
+
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(!stdout.contains("[test name]"));
+}
+
+fn empty_call() -> String {
+    format!(
+        "RecursiveCall {{ contract_address: ContractAddress({CONTRACT_C}), payload: array![] }}"
+    )
+}
+
+fn execute_calls_calldata() -> String {
+    let empty_call = empty_call();
+    format!(
+        "(array![RecursiveCall {{ contract_address: ContractAddress({CONTRACT_B}), payload: array![{empty_call}, {empty_call}] }}, {empty_call}])"
+    )
+}
+
+fn minimal_trace_message(test_name: &str) -> String {
+    formatdoc! {r"
+        [test name] trace_info_integrationtest::test_trace::test_debugging_trace_{test_name}
+        ├─ [selector] execute_calls
+        │  ├─ [contract name] SimpleContract
+        │  ├─ [selector] execute_calls
+        │  │  ├─ [contract name] SimpleContract
+        │  │  ├─ [selector] execute_calls
+        │  │  │  └─ [contract name] SimpleContract
+        │  │  └─ [selector] execute_calls
+        │  │     └─ [contract name] SimpleContract
+        │  └─ [selector] execute_calls
+        │     └─ [contract name] SimpleContract
+        └─ [selector] fail
+           └─ [contract name] SimpleContract
+        "}
+}
+
+fn detailed_trace_message(test_name: &str) -> String {
     formatdoc! {r"
         [test name] trace_info_integrationtest::test_trace::test_debugging_trace_{test_name}
         ├─ [selector] execute_calls
         │  ├─ [contract name] SimpleContract
         │  ├─ [entry point type] External
-        │  ├─ [calldata] [0x2, 0x634cf632813aca745d024ee244aab954461a7341b610b103fa7569bf1e14a5e, 0x2, 0x38767c97f072a291507aa962d6d92b04ae7b4e01c406717f5485b7a86fbdde7, 0x0, 0x38767c97f072a291507aa962d6d92b04ae7b4e01c406717f5485b7a86fbdde7, 0x0, 0x38767c97f072a291507aa962d6d92b04ae7b4e01c406717f5485b7a86fbdde7, 0x0]
-        │  ├─ [storage address] 0x5ab84515ea91a99261961ba72888d663606612958a363e8538302a27398bc29
+        │  ├─ [calldata] {calldata}
+        │  ├─ [storage address] {CONTRACT_A}
         │  ├─ [caller address] 0x1724987234973219347210837402
         │  ├─ [call type] Call
-        │  ├─ [call result] success: []
+        │  ├─ [call result] success: ()
         │  ├─ [selector] execute_calls
         │  │  ├─ [contract name] SimpleContract
         │  │  ├─ [entry point type] External
-        │  │  ├─ [calldata] [0x2, 0x38767c97f072a291507aa962d6d92b04ae7b4e01c406717f5485b7a86fbdde7, 0x0, 0x38767c97f072a291507aa962d6d92b04ae7b4e01c406717f5485b7a86fbdde7, 0x0]
-        │  │  ├─ [storage address] 0x634cf632813aca745d024ee244aab954461a7341b610b103fa7569bf1e14a5e
-        │  │  ├─ [caller address] 0x5ab84515ea91a99261961ba72888d663606612958a363e8538302a27398bc29
+        │  │  ├─ [calldata] (array![{empty_call}, {empty_call}])
+        │  │  ├─ [storage address] {CONTRACT_B}
+        │  │  ├─ [caller address] {CONTRACT_A}
         │  │  ├─ [call type] Call
-        │  │  ├─ [call result] success: []
+        │  │  ├─ [call result] success: ()
         │  │  ├─ [selector] execute_calls
         │  │  │  ├─ [contract name] SimpleContract
         │  │  │  ├─ [entry point type] External
-        │  │  │  ├─ [calldata] [0x0]
-        │  │  │  ├─ [storage address] 0x38767c97f072a291507aa962d6d92b04ae7b4e01c406717f5485b7a86fbdde7
-        │  │  │  ├─ [caller address] 0x634cf632813aca745d024ee244aab954461a7341b610b103fa7569bf1e14a5e
+        │  │  │  ├─ [calldata] (array![])
+        │  │  │  ├─ [storage address] {CONTRACT_C}
+        │  │  │  ├─ [caller address] {CONTRACT_B}
         │  │  │  ├─ [call type] Call
-        │  │  │  └─ [call result] success: []
+        │  │  │  └─ [call result] success: ()
         │  │  └─ [selector] execute_calls
         │  │     ├─ [contract name] SimpleContract
         │  │     ├─ [entry point type] External
-        │  │     ├─ [calldata] [0x0]
-        │  │     ├─ [storage address] 0x38767c97f072a291507aa962d6d92b04ae7b4e01c406717f5485b7a86fbdde7
-        │  │     ├─ [caller address] 0x634cf632813aca745d024ee244aab954461a7341b610b103fa7569bf1e14a5e
+        │  │     ├─ [calldata] (array![])
+        │  │     ├─ [storage address] {CONTRACT_C}
+        │  │     ├─ [caller address] {CONTRACT_B}
         │  │     ├─ [call type] Call
-        │  │     └─ [call result] success: []
+        │  │     └─ [call result] success: ()
         │  └─ [selector] execute_calls
         │     ├─ [contract name] SimpleContract
         │     ├─ [entry point type] External
-        │     ├─ [calldata] [0x0]
-        │     ├─ [storage address] 0x38767c97f072a291507aa962d6d92b04ae7b4e01c406717f5485b7a86fbdde7
-        │     ├─ [caller address] 0x5ab84515ea91a99261961ba72888d663606612958a363e8538302a27398bc29
+        │     ├─ [calldata] (array![])
+        │     ├─ [storage address] {CONTRACT_C}
+        │     ├─ [caller address] {CONTRACT_A}
         │     ├─ [call type] Call
-        │     └─ [call result] success: []
+        │     └─ [call result] success: ()
         └─ [selector] fail
            ├─ [contract name] SimpleContract
            ├─ [entry point type] External
-           ├─ [calldata] [0x5, 0x1, 0x2, 0x3, 0x4, 0x5]
-           ├─ [storage address] 0x5ab84515ea91a99261961ba72888d663606612958a363e8538302a27398bc29
+           ├─ [calldata] (array![0x1, 0x2, 0x3, 0x4, 0x5])
+           ├─ [storage address] {CONTRACT_A}
            ├─ [caller address] 0x1724987234973219347210837402
            ├─ [call type] Call
            └─ [call result] panic: [0x1, 0x2, 0x3, 0x4, 0x5]
-        "}
+        ",
+    calldata = execute_calls_calldata(),
+    empty_call = empty_call()}
 }
@@ -73,7 +73,7 @@ fn should_print_warning() {
                 Collected 1 test(s) from empty package
                 Running 0 test(s) from src/
                 Running 1 test(s) from tests/
-                [FAIL] empty_integrationtest::test::t1
+                [FAIL] empty_integrationtest::test::t1 (time: [..]s)
 
                 Failure[..]
                 Tests: 0 passed, 1 failed, 0 skipped, 0 ignored, 0 filtered out
@@ -127,10 +127,10 @@ fn should_dedup_urls() {
                 Collected 2 test(s) from empty package
                 Running 0 test(s) from src/
                 Running 2 test(s) from tests/
-                [FAIL] empty_integrationtest::test::t1
+                [FAIL] empty_integrationtest::test::t1 (time: [..]s)
 
                 Failure[..]
-                [FAIL] empty_integrationtest::test::t2
+                [FAIL] empty_integrationtest::test::t2 (time: [..]s)
 
                 Failure[..]
                 Tests: 0 passed, 2 failed, 0 skipped, 0 ignored, 0 filtered out
@@ -188,10 +188,10 @@ fn should_print_foreach() {
                 Collected 2 test(s) from empty package
                 Running 0 test(s) from src/
                 Running 2 test(s) from tests/
-                [FAIL] empty_integrationtest::test::t1
+                [FAIL] empty_integrationtest::test::t1 (time: [..]s)
 
                 Failure[..]
-                [FAIL] empty_integrationtest::test::t2
+                [FAIL] empty_integrationtest::test::t2 (time: [..]s)
 
                 Failure[..]
                 Tests: 0 passed, 2 failed, 0 skipped, 0 ignored, 0 filtered out
@@ -1,10 +1,13 @@
 use super::common::runner::{
     BASE_FILE_PATTERNS, runner, setup_package_with_file_patterns, test_runner,
 };
+use assert_fs::fixture::{FileWriteStr, PathChild};
 use forge_runner::CACHE_DIR;
 use indoc::{formatdoc, indoc};
+use serde_json::{Value, json};
 use shared::test_utils::node_url::node_rpc_url;
 use shared::test_utils::output_assert::assert_stdout_contains;
+use std::fs;
 
 #[test]
 fn without_cache() {
@@ -33,6 +36,30 @@ fn without_cache() {
     );
 }
 
+#[test]
+fn trace_of_forked_contract() {
+    let temp = setup_package_with_file_patterns("forking", BASE_FILE_PATTERNS);
+
+    let output = test_runner(&temp)
+        .args(["--exact", "forking::tests::test_fork_simple"])
+        .args(["--trace-verbosity", "standard"])
+        .assert()
+        .code(0);
+
+    // The forked contract is not part of the package, so its class hash and selectors are printed
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [PASS] forking::tests::test_fork_simple [..]
+        [..]
+        [test name] forking::tests::test_fork_simple
+        ├─ [selector] 0x[..]
+        │  ├─ [contract name] 0x[..]
+        │  ├─ [calldata] []
+        "},
+    );
+}
+
 #[test]
 /// The cache file at `forking/$CACHE_DIR` was modified to have different value stored
 /// that this from the real network. We use it to verify that values from cache are actually used.
@@ -59,7 +86,7 @@ fn with_cache() {
 
         Collected 1 test(s) from forking package
         Running 1 test(s) from src/
-        [FAIL] forking::tests::test_fork_simple
+        [FAIL] forking::tests::test_fork_simple (time: [..]s)
 
         Failure data:
             0x42616c616e63652073686f756c642062652030 ('Balance should be 0')
@@ -130,3 +157,212 @@ fn printing_latest_block_number() {
         "},
     );
 }
+
+#[test]
+fn printing_and_reporting_resolved_block_ids() {
+    let temp = setup_package_with_file_patterns("forking", BASE_FILE_PATTERNS);
+    let node_rpc_url = node_rpc_url();
+
+    temp.child("tests/block_ids.cairo")
+        .write_str(&formatdoc! {r#"
+            #[test]
+            #[fork(url: "{node_rpc_url}", block_timestamp: 1711645884)]
+            fn fork_block_timestamp() {{
+                assert(starknet::get_block_info().unbox().block_number == 54060, 'Wrong block number');
+            }}
+
+            #[test]
+            #[fork(url: "{node_rpc_url}", block_tag: pending)]
+            fn fork_block_tag_pending() {{
+                assert(starknet::get_block_info().unbox().block_number > 54060, 'Wrong block number');
+            }}
+        "#})
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg("block_ids")
+        .args(["--report", "json=report.json"])
+        .assert()
+        .code(0);
+
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+        [..]
+        [PASS] forking_integrationtest::block_ids::fork_block_timestamp [..]
+        [PASS] forking_integrationtest::block_ids::fork_block_tag_pending [..]
+        Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, [..] filtered out
+
+        Block number = 54060 for block_timestamp: 1711645884 and url = {node_rpc_url}
+        Block number = [..] for block_tag: pending and url = {node_rpc_url}
+        "},
+    );
+
+    let report: Value =
+        serde_json::from_str(&fs::read_to_string(temp.child("report.json")).unwrap()).unwrap();
+    let tests: Vec<&Value> = report["packages"][0]["test_targets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|test_target| test_target["tests"].as_array().unwrap())
+        .collect();
+    let find_fork = |name: &str| {
+        &tests
+            .iter()
+            .find(|test| test["name"] == name)
+            .unwrap_or_else(|| panic!("{name} not found in the report"))["fork"]
+    };
+
+    let fork = find_fork("forking_integrationtest::block_ids::fork_block_timestamp");
+    assert_eq!(fork["url"], node_rpc_url.as_str());
+    assert_eq!(fork["block_id"], json!({ "timestamp": "1711645884" }));
+    assert_eq!(fork["block_number"], 54060);
+
+    let fork = find_fork("forking_integrationtest::block_ids::fork_block_tag_pending");
+    assert_eq!(fork["block_id"], json!({ "tag": "pending" }));
+    assert!(fork["block_number"].as_u64().unwrap() > 54060);
+}
+
+#[test]
+fn cache_export_prune_and_import() {
+    let temp = setup_package_with_file_patterns(
+        "forking",
+        &[BASE_FILE_PATTERNS, &[&format!("{CACHE_DIR}/*.json")]].concat(),
+    );
+    let node_rpc_url = node_rpc_url();
+
+    // Converts the cache to the current format
+    test_runner(&temp)
+        .args(["--exact", "forking::tests::test_fork_simple"])
+        .assert()
+        .code(1);
+
+    let output = runner(&temp).args(["cache", "info"]).assert().success();
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+        {node_rpc_url} at block 54060: [..], last used 0 day(s) ago
+        Total: 1 fork cache(s), [..]
+        "},
+    );
+
+    let output = runner(&temp)
+        .args(["cache", "export", "bundle.gz"])
+        .assert()
+        .success();
+    assert_stdout_contains(output, "Exported 1 fork cache(s) to bundle.gz");
+
+    let output = runner(&temp)
+        .args(["cache", "prune", "--url", node_rpc_url.as_str()])
+        .assert()
+        .success();
+    assert_stdout_contains(output, "Removed 1 fork cache(s), freed [..]");
+
+    let output = runner(&temp)
+        .args(["cache", "import", "bundle.gz"])
+        .assert()
+        .success();
+    assert_stdout_contains(output, "Imported 1 fork cache(s) from bundle.gz");
+
+    // Fabricated value from the imported cache is used
+    let output = test_runner(&temp)
+        .args(["--exact", "forking::tests::test_fork_simple"])
+        .assert()
+        .code(1);
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]
+        [FAIL] forking::tests::test_fork_simple (time: [..]s)
+
+        Failure data:
+            0x42616c616e63652073686f756c642062652030 ('Balance should be 0')
+        [..]
+        "},
+    );
+}
+
+#[test]
+fn forking_from_state_dump() {
+    let temp = setup_package_with_file_patterns("forking", BASE_FILE_PATTERNS);
+    let node_rpc_url = node_rpc_url();
+
+    test_runner(&temp)
+        .args(["--exact", "forking::tests::test_fork_simple"])
+        .assert()
+        .code(0);
+
+    let output = runner(&temp)
+        .args([
+            "cache",
+            "dump",
+            "state.json",
+            "--url",
+            node_rpc_url.as_str(),
+        ])
+        .assert()
+        .success();
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+        Saved state dump of {node_rpc_url} at block 54060 to state.json
+        "},
+    );
+
+    let manifest = fs::read_to_string(temp.child("Scarb.toml")).unwrap();
+    temp.child("Scarb.toml")
+        .write_str(&formatdoc! {r#"
+            {manifest}
+            [[tool.snforge.fork]]
+            name = "STATE_DUMP"
+            state_file = "state.json"
+        "#})
+        .unwrap();
+    temp.child("tests/state_dump.cairo")
+        .write_str(indoc! {r#"
+            use starknet::ContractAddress;
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            fn dispatcher() -> IHelloStarknetDispatcher {
+                let contract_address: ContractAddress =
+                    0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9
+                    .try_into()
+                    .unwrap();
+                IHelloStarknetDispatcher { contract_address }
+            }
+
+            #[test]
+            #[fork(state_file: "state.json")]
+            fn state_file_in_attribute() {
+                assert(dispatcher().get_balance() == 0, 'Balance should be 0');
+            }
+
+            #[test]
+            #[fork("STATE_DUMP")]
+            fn state_file_in_scarb_toml() {
+                assert(dispatcher().get_balance() == 0, 'Balance should be 0');
+            }
+        "#})
+        .unwrap();
+
+    // State files are resolved against the package root, not the directory snforge is run from
+    let output = test_runner(&temp)
+        .current_dir(temp.child("src").path())
+        .arg("state_dump")
+        .assert()
+        .code(0);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]
+        [PASS] forking_integrationtest::state_dump::state_file_in_attribute (time: [..]s)
+        [PASS] forking_integrationtest::state_dump::state_file_in_scarb_toml (time: [..]s)
+        Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, [..] filtered out
+        "},
+    );
+}
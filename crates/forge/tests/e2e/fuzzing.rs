@@ -22,7 +22,7 @@ fn fuzzing() {
         [PASS] fuzzing::tests::fuzzed_argument (runs: 256, [..]
         [PASS] fuzzing::tests::fuzzed_both_arguments (runs: 256, [..]
         [PASS] fuzzing::tests::passing [..]
-        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]])
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]]) (time: [..]s)
 
         Failure data:
             0x726573756c74203d3d2061202b2062 ('result == a + b')
@@ -67,7 +67,7 @@ fn fuzzing_set_runs() {
         [PASS] fuzzing::tests::fuzzed_argument (runs: 10, [..]
         [PASS] fuzzing::tests::fuzzed_both_arguments (runs: 10, [..]
         [PASS] fuzzing::tests::passing [..]
-        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]])
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]]) (time: [..]s)
 
         Failure data:
             0x726573756c74203d3d2061202b2062 ('result == a + b')
@@ -112,7 +112,7 @@ fn fuzzing_set_seed() {
         [PASS] fuzzing::tests::fuzzed_argument (runs: 256, [..]
         [PASS] fuzzing::tests::fuzzed_both_arguments (runs: 256, [..]
         [PASS] fuzzing::tests::passing [..]
-        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]])
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]]) (time: [..]s)
 
         Failure data:
             0x726573756c74203d3d2061202b2062 ('result == a + b')
@@ -191,7 +191,7 @@ fn fuzzing_exit_first() {
 
         Collected 2 test(s) from fuzzing package
         Running 2 test(s) from tests/
-        [FAIL] fuzzing_integrationtest::exit_first_fuzz::exit_first_fails_test (runs: 1, arguments: [[..]])
+        [FAIL] fuzzing_integrationtest::exit_first_fuzz::exit_first_fails_test (runs: 1, arguments: [[..]]) (time: [..]s)
 
         Failure data:
             0x32202b2062203d3d2032202b2062 ('2 + b == 2 + b')
@@ -223,7 +223,7 @@ fn fuzzing_exit_first_single_fail() {
 
         Collected 2 test(s) from fuzzing package
         Running 2 test(s) from tests/
-        [FAIL] fuzzing_integrationtest::exit_first_single_fail::exit_first_fails_test
+        [FAIL] fuzzing_integrationtest::exit_first_single_fail::exit_first_fails_test (time: [..]s)
 
         Failure data:
             0x32202b2062203d3d2032202b2062 ('2 + b == 2 + b')
@@ -255,9 +255,9 @@ fn fuzzing_multiple_attributes() {
         Collected 4 test(s) from fuzzing package
         Running 4 test(s) from tests/
         [IGNORE] fuzzing_integrationtest::multiple_attributes::ignored
-        [PASS] fuzzing_integrationtest::multiple_attributes::with_should_panic (runs: 256, [..])
-        [PASS] fuzzing_integrationtest::multiple_attributes::with_available_gas (runs: 50, [..])
-        [PASS] fuzzing_integrationtest::multiple_attributes::with_both (runs: 300, [..])
+        [PASS] fuzzing_integrationtest::multiple_attributes::with_should_panic (runs: 256, [..]) (time: [..]s)
+        [PASS] fuzzing_integrationtest::multiple_attributes::with_available_gas (runs: 50, [..]) (time: [..]s)
+        [PASS] fuzzing_integrationtest::multiple_attributes::with_both (runs: 300, [..]) (time: [..]s)
         Tests: 3 passed, 0 failed, 0 skipped, 1 ignored, 20 filtered out
         "},
     );
@@ -278,12 +278,12 @@ fn generate_arg_cheatcode() {
 
         Collected 2 test(s) from fuzzing package
         Running 2 test(s) from tests/
-        [FAIL] fuzzing_integrationtest::generate_arg::generate_arg_incorrect_range
+        [FAIL] fuzzing_integrationtest::generate_arg::generate_arg_incorrect_range (time: [..]s)
 
         Failure data:
             "`generate_arg` cheatcode: `min_value` must be <= `max_value`, provided values after deserialization: 101 and 100"
 
-        [PASS] fuzzing_integrationtest::generate_arg::use_generate_arg_outside_fuzzer (l1_gas: ~0, l1_data_gas: ~0, l2_gas: ~40000)
+        [PASS] fuzzing_integrationtest::generate_arg::use_generate_arg_outside_fuzzer (l1_gas: ~0, l1_data_gas: ~0, l2_gas: ~40000) (time: [..]s)
         Tests: 1 passed, 1 failed, 0 skipped, 0 ignored, 22 filtered out
         "#},
     );
@@ -318,3 +318,46 @@ fn no_fuzzer_attribute() {
         "},
     );
 }
+
+#[test]
+fn failing_arguments_are_saved_in_corpus() {
+    let temp = setup_package("fuzzing");
+
+    let output = test_runner(&temp)
+        .args(["fuzzing::tests::failing_fuzz", "--exact"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r#"
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: ["0", "0"]) (time: [..]s)
+        "#},
+    );
+
+    let corpus = temp.child(".snfoundry_cache/fuzzer_corpus/fuzzing_tests_failing_fuzz.json");
+    let corpus: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(corpus.path()).unwrap()).unwrap();
+    assert_eq!(
+        corpus,
+        serde_json::json!({ "counterexamples": [["0", "0"]] })
+    );
+
+    // Saved arguments are replayed before random ones
+    let output = test_runner(&temp)
+        .args([
+            "fuzzing::tests::failing_fuzz",
+            "--exact",
+            "--fuzzer-seed",
+            "1",
+        ])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r#"
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: ["0", "0"]) (time: [..]s)
+        "#},
+    );
+}
@@ -0,0 +1,226 @@
+use super::common::runner::{setup_package, test_runner};
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use std::fs;
+
+const TEST_SIMPLE: &str = "simple_package_integrationtest::test_simple::test_simple";
+
+#[test]
+fn saves_snapshot() {
+    let temp = setup_package("simple_package");
+
+    test_runner(&temp).arg("--gas-snapshot").assert().code(1);
+
+    let snapshot = fs::read_to_string(temp.child(".gas-snapshot")).unwrap();
+    let lines: Vec<&str> = snapshot.lines().collect();
+
+    let mut sorted_lines = lines.clone();
+    sorted_lines.sort_unstable();
+    assert_eq!(lines, sorted_lines);
+
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.starts_with(&format!("{TEST_SIMPLE} (l1_gas: ")))
+    );
+    // Failed and ignored tests don't use gas
+    assert!(!snapshot.contains("test_failing"));
+    assert!(!snapshot.contains("ignored_test"));
+}
+
+#[test]
+fn saving_filtered_tests_keeps_other_tests() {
+    let temp = setup_package("simple_package");
+
+    temp.child(".gas-snapshot")
+        .write_str(&format!(
+            "{TEST_SIMPLE} (l1_gas: 0, l1_data_gas: 0, l2_gas: 1)\n{TEST_SIMPLE}2 (l1_gas: 0, l1_data_gas: 0, l2_gas: 7)\n"
+        ))
+        .unwrap();
+
+    test_runner(&temp)
+        .arg(TEST_SIMPLE)
+        .arg("--exact")
+        .arg("--gas-snapshot")
+        .assert()
+        .success();
+
+    let snapshot = fs::read_to_string(temp.child(".gas-snapshot")).unwrap();
+    let lines: Vec<&str> = snapshot.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with(&format!("{TEST_SIMPLE} (l1_gas: ")));
+    assert!(!lines[0].ends_with("l2_gas: 1)"));
+    assert_eq!(
+        lines[1],
+        format!("{TEST_SIMPLE}2 (l1_gas: 0, l1_data_gas: 0, l2_gas: 7)")
+    );
+}
+
+#[test]
+fn saving_removes_missing_tests() {
+    let temp = setup_package("simple_package");
+
+    temp.child(".gas-snapshot")
+        .write_str(indoc! {"
+            other_package::tests::test_a (l1_gas: 0, l1_data_gas: 0, l2_gas: 5)
+            simple_package_integrationtest::test_simple::removed (l1_gas: 0, l1_data_gas: 0, l2_gas: 7)
+        "})
+        .unwrap();
+
+    test_runner(&temp)
+        .arg(TEST_SIMPLE)
+        .arg("--exact")
+        .arg("--gas-snapshot")
+        .assert()
+        .success();
+
+    let snapshot = fs::read_to_string(temp.child(".gas-snapshot")).unwrap();
+    let lines: Vec<&str> = snapshot.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        lines[0],
+        "other_package::tests::test_a (l1_gas: 0, l1_data_gas: 0, l2_gas: 5)"
+    );
+    assert!(lines[1].starts_with(&format!("{TEST_SIMPLE} (l1_gas: ")));
+}
+
+#[test]
+fn check_matching_snapshot() {
+    let temp = setup_package("simple_package");
+
+    test_runner(&temp)
+        .arg(TEST_SIMPLE)
+        .arg("--exact")
+        .arg("--gas-snapshot")
+        .assert()
+        .success();
+
+    let output = test_runner(&temp)
+        .arg(TEST_SIMPLE)
+        .arg("--exact")
+        .arg("--check-gas-snapshot")
+        .assert()
+        .success();
+
+    assert_stdout_contains(output, "Gas usage matches the snapshot");
+}
+
+#[test]
+fn check_matching_snapshot_of_fuzz_test() {
+    let temp = setup_package("fuzzing");
+    let fuzz_test = "fuzzing::tests::fuzzed_while_loop";
+
+    test_runner(&temp)
+        .args([fuzz_test, "--exact", "--gas-snapshot"])
+        .assert()
+        .success();
+
+    // Fuzz tests run with a fixed seed, so their mean gas is the same in every run
+    let output = test_runner(&temp)
+        .args([fuzz_test, "--exact", "--check-gas-snapshot"])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            Fuzzer seed: 0
+
+            Gas usage matches the snapshot
+        "},
+    );
+}
+
+#[test]
+fn check_snapshot_with_regression() {
+    let temp = setup_package("simple_package");
+
+    temp.child(".gas-snapshot")
+        .write_str(&format!(
+            "{TEST_SIMPLE} (l1_gas: 0, l1_data_gas: 0, l2_gas: 1)\n"
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg(TEST_SIMPLE)
+        .arg("--exact")
+        .arg("--check-gas-snapshot")
+        .arg("--gas-snapshot-tolerance")
+        .arg("5")
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            Gas snapshot differences:
+            | Test [..] | Resource | Snapshot | Current | Change [..] |
+            | simple_package_integrationtest::test_simple::test_simple | l2_gas   | 1        | [..] | +[..]% (regression) |
+
+            [ERROR] Gas usage of 1 test(s) increased by more than 5%
+        "},
+    );
+}
+
+#[test]
+fn check_snapshot_with_new_and_missing_tests() {
+    let temp = setup_package("simple_package");
+
+    temp.child(".gas-snapshot")
+        .write_str(
+            "simple_package_integrationtest::test_simple::removed (l1_gas: 0, l1_data_gas: 0, l2_gas: 7)\n",
+        )
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg(TEST_SIMPLE)
+        .arg("--exact")
+        .arg("--check-gas-snapshot")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            Tests not in the snapshot:
+                simple_package_integrationtest::test_simple::test_simple
+
+            Tests removed since the snapshot:
+                simple_package_integrationtest::test_simple::removed
+        "},
+    );
+}
+
+#[test]
+fn check_missing_snapshot() {
+    let temp = setup_package("simple_package");
+
+    let output = test_runner(&temp)
+        .arg("--check-gas-snapshot")
+        .assert()
+        .code(2);
+
+    assert_stdout_contains(
+        output,
+        "[ERROR] Failed to read gas snapshot from [..].gas-snapshot, save it first with `--gas-snapshot`[..]",
+    );
+}
+
+#[test]
+fn tolerance_requires_check() {
+    let temp = setup_package("simple_package");
+
+    let output = test_runner(&temp)
+        .arg("--gas-snapshot-tolerance")
+        .arg("5")
+        .assert()
+        .code(2);
+
+    assert_stderr_contains(
+        output,
+        "error: the following required arguments were not provided:\n  --check-gas-snapshot",
+    );
+}
@@ -23,22 +23,22 @@ fn file_reading() {
         Collected 11 test(s) from file_reading package
         Running 0 test(s) from src/
         Running 11 test(s) from tests/
-        [FAIL] file_reading_integrationtest::test::json_non_existent
+        [FAIL] file_reading_integrationtest::test::json_non_existent (time: [..]s)
         
         Failure data:
             "{}"
         
-        [FAIL] file_reading_integrationtest::test::invalid_json
+        [FAIL] file_reading_integrationtest::test::invalid_json (time: [..]s)
         
         Failure data:
             "Parse JSON error: invalid type: integer `231232`, expected a map at line 1 column 6 , in file data/json/invalid.json"
         
-        [FAIL] file_reading_integrationtest::test::non_existent
+        [FAIL] file_reading_integrationtest::test::non_existent (time: [..]s)
         
         Failure data:
             "{}"
         
-        [FAIL] file_reading_integrationtest::test::non_ascii
+        [FAIL] file_reading_integrationtest::test::non_ascii (time: [..]s)
         
         Failure data:
             "Failed to parse data/non_ascii.txt file"
@@ -46,12 +46,12 @@ fn file_reading() {
         [PASS] file_reading_integrationtest::test::valid_content_and_same_content_no_matter_newlines [..]
         [PASS] file_reading_integrationtest::test::serialization [..]
         [PASS] file_reading_integrationtest::test::json_with_array [..]
-        [FAIL] file_reading_integrationtest::test::negative_number
+        [FAIL] file_reading_integrationtest::test::negative_number (time: [..]s)
             "Failed to parse data/negative_number.txt file"
         
         Failure data:
         
-        [FAIL] file_reading_integrationtest::test::valid_content_different_folder
+        [FAIL] file_reading_integrationtest::test::valid_content_different_folder (time: [..]s)
         
         Failure data:
             0x756e657870656374656420636f6e74656e74 ('unexpected content')
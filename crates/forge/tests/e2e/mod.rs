@@ -12,7 +12,6 @@ mod contract_artifacts;
 #[cfg(not(target_os = "windows"))]
 // TODO(#2990): Run coverage test on Windows
 mod coverage;
-#[cfg(feature = "debugging")]
 mod debugging;
 mod docs_snippets_validation;
 mod env;
@@ -20,8 +19,10 @@ mod features;
 mod fork_warning;
 mod forking;
 mod fuzzing;
+mod gas_snapshot;
 mod io_operations;
 mod new;
+mod report;
 mod requirements;
 mod running;
 mod steps;
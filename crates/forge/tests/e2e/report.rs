@@ -0,0 +1,99 @@
+use super::common::runner::{setup_package, test_runner};
+use assert_fs::fixture::PathChild;
+use serde_json::Value;
+use shared::test_utils::output_assert::assert_stderr_contains;
+use std::fs;
+
+#[test]
+fn json_report() {
+    let temp = setup_package("simple_package");
+
+    test_runner(&temp)
+        .arg("--report")
+        .arg("json=reports/report.json")
+        .assert()
+        .code(1);
+
+    let report: Value =
+        serde_json::from_str(&fs::read_to_string(temp.child("reports/report.json")).unwrap())
+            .unwrap();
+
+    let package = &report["packages"][0];
+    assert_eq!(package["name"], "simple_package");
+
+    let test_targets = package["test_targets"].as_array().unwrap();
+    assert_eq!(test_targets.len(), 2);
+    assert_eq!(test_targets[0]["location"], "src");
+    assert_eq!(test_targets[1]["location"], "tests");
+    assert!(test_targets[1]["duration"].as_f64().unwrap() > 0.0);
+
+    let tests = test_targets[1]["tests"].as_array().unwrap();
+    let find_test = |name: &str| {
+        tests
+            .iter()
+            .find(|test| test["name"] == name)
+            .unwrap_or_else(|| panic!("{name} not found in the report"))
+    };
+
+    let passed = find_test("simple_package_integrationtest::test_simple::test_simple");
+    assert_eq!(passed["status"], "passed");
+    assert!(passed["gas"]["l2_gas"].is_u64());
+    assert!(passed["duration"].as_f64().unwrap() > 0.0);
+    assert!(passed["fork"].is_null());
+    assert!(passed["created_forks"].as_array().unwrap().is_empty());
+
+    let failed = find_test("simple_package_integrationtest::test_simple::test_failing");
+    assert_eq!(failed["status"], "failed");
+    assert_eq!(
+        failed["message"],
+        "0x6661696c696e6720636865636b ('failing check')"
+    );
+    assert!(failed["gas"].is_null());
+
+    let ignored = find_test("simple_package_integrationtest::ext_function_test::ignored_test");
+    assert_eq!(ignored["status"], "ignored");
+    assert!(ignored["duration"].is_null());
+}
+
+#[test]
+fn junit_report() {
+    let temp = setup_package("simple_package");
+
+    test_runner(&temp)
+        .arg("--report")
+        .arg("junit=junit.xml")
+        .assert()
+        .code(1);
+
+    let report = fs::read_to_string(temp.child("junit.xml")).unwrap();
+
+    assert!(report.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+    assert!(report.contains(r#"<testsuites name="snforge" tests="13" failures="2" skipped="2""#));
+    assert!(
+        report.contains(
+            r#"<testsuite name="simple_package::src" tests="2" failures="0" skipped="1""#
+        )
+    );
+    assert!(report.contains(
+        r#"<testcase name="test_failing" classname="simple_package_integrationtest::test_simple" time="#
+    ));
+    assert!(report.contains(
+        r#"<failure message="0x6661696c696e6720636865636b (&apos;failing check&apos;)">"#
+    ));
+}
+
+#[test]
+fn invalid_report_format() {
+    let temp = setup_package("simple_package");
+
+    let output = test_runner(&temp)
+        .arg("--report")
+        .arg("html=report.html")
+        .assert()
+        .code(2);
+
+    assert_stderr_contains(
+        output,
+        "error: invalid value 'html=report.html' for '--report <FORMAT=PATH>': unknown report format `html`, possible values: junit, json",
+    );
+}
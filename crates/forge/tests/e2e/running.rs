@@ -32,12 +32,12 @@ fn simple_package() {
     [PASS] simple_package_integrationtest::test_simple::test_simple2 [..]
     [PASS] simple_package_integrationtest::test_simple::test_two [..]
     [PASS] simple_package_integrationtest::test_simple::test_two_and_two [..]
-    [FAIL] simple_package_integrationtest::test_simple::test_failing
+    [FAIL] simple_package_integrationtest::test_simple::test_failing (time: [..]s)
 
     Failure data:
         0x6661696c696e6720636865636b ('failing check')
 
-    [FAIL] simple_package_integrationtest::test_simple::test_another_failing
+    [FAIL] simple_package_integrationtest::test_simple::test_another_failing (time: [..]s)
 
     Failure data:
         0x6661696c696e6720636865636b ('failing check')
@@ -103,12 +103,12 @@ fn simple_package_with_git_dependency() {
         [PASS] simple_package_integrationtest::test_simple::test_simple2 [..]
         [PASS] simple_package_integrationtest::test_simple::test_two [..]
         [PASS] simple_package_integrationtest::test_simple::test_two_and_two [..]
-        [FAIL] simple_package_integrationtest::test_simple::test_failing
+        [FAIL] simple_package_integrationtest::test_simple::test_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
 
-        [FAIL] simple_package_integrationtest::test_simple::test_another_failing
+        [FAIL] simple_package_integrationtest::test_simple::test_another_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
@@ -287,7 +287,7 @@ fn with_ignored_flag() {
         Running 1 test(s) from src/
         [PASS] simple_package::tests::ignored_test [..]
         Running 1 test(s) from tests/
-        [FAIL] simple_package_integrationtest::ext_function_test::ignored_test
+        [FAIL] simple_package_integrationtest::ext_function_test::ignored_test (time: [..]s)
 
         Failure data:
             0x6e6f742070617373696e67 ('not passing')
@@ -320,7 +320,7 @@ fn with_include_ignored_flag() {
         Running 11 test(s) from tests/
         [PASS] simple_package_integrationtest::contract::call_and_invoke [..]
         [PASS] simple_package_integrationtest::ext_function_test::test_my_test [..]
-        [FAIL] simple_package_integrationtest::ext_function_test::ignored_test
+        [FAIL] simple_package_integrationtest::ext_function_test::ignored_test (time: [..]s)
 
         Failure data:
             0x6e6f742070617373696e67 ('not passing')
@@ -330,12 +330,12 @@ fn with_include_ignored_flag() {
         [PASS] simple_package_integrationtest::test_simple::test_simple2 [..]
         [PASS] simple_package_integrationtest::test_simple::test_two [..]
         [PASS] simple_package_integrationtest::test_simple::test_two_and_two [..]
-        [FAIL] simple_package_integrationtest::test_simple::test_failing
+        [FAIL] simple_package_integrationtest::test_simple::test_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
 
-        [FAIL] simple_package_integrationtest::test_simple::test_another_failing
+        [FAIL] simple_package_integrationtest::test_simple::test_another_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
@@ -371,7 +371,7 @@ fn with_ignored_flag_and_filter() {
         Collected 1 test(s) from simple_package package
         Running 0 test(s) from src/
         Running 1 test(s) from tests/
-        [FAIL] simple_package_integrationtest::ext_function_test::ignored_test
+        [FAIL] simple_package_integrationtest::ext_function_test::ignored_test (time: [..]s)
 
         Failure data:
             0x6e6f742070617373696e67 ('not passing')
@@ -405,7 +405,7 @@ fn with_include_ignored_flag_and_filter() {
         Running 1 test(s) from src/
         [PASS] simple_package::tests::ignored_test [..]
         Running 1 test(s) from tests/
-        [FAIL] simple_package_integrationtest::ext_function_test::ignored_test
+        [FAIL] simple_package_integrationtest::ext_function_test::ignored_test (time: [..]s)
 
         Failure data:
             0x6e6f742070617373696e67 ('not passing')
@@ -443,12 +443,12 @@ fn with_rerun_failed_flag_without_cache() {
         [PASS] simple_package_integrationtest::test_simple::test_simple2 [..]
         [PASS] simple_package_integrationtest::test_simple::test_two [..]
         [PASS] simple_package_integrationtest::test_simple::test_two_and_two [..]
-        [FAIL] simple_package_integrationtest::test_simple::test_failing
+        [FAIL] simple_package_integrationtest::test_simple::test_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
 
-        [FAIL] simple_package_integrationtest::test_simple::test_another_failing
+        [FAIL] simple_package_integrationtest::test_simple::test_another_failing (time: [..]s)
 
         [PASS] simple_package_integrationtest::without_prefix::five [..]
         Failures:
@@ -484,7 +484,7 @@ fn with_rerun_failed_flag_and_name_filter() {
 
         Collected 1 test(s) from simple_package package
         Running 1 test(s) from tests/
-        [FAIL] simple_package_integrationtest::test_simple::test_another_failing
+        [FAIL] simple_package_integrationtest::test_simple::test_another_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
@@ -515,12 +515,12 @@ fn with_rerun_failed_flag() {
         Collected 2 test(s) from simple_package package
         Running 0 test(s) from src/
         Running 2 test(s) from tests/
-        [FAIL] simple_package_integrationtest::test_simple::test_another_failing
+        [FAIL] simple_package_integrationtest::test_simple::test_another_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
 
-        [FAIL] simple_package_integrationtest::test_simple::test_failing
+        [FAIL] simple_package_integrationtest::test_simple::test_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
@@ -550,36 +550,36 @@ fn with_panic_data_decoding() {
 
         Collected 8 test(s) from panic_decoding package
         Running 8 test(s) from tests/
-        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_panic_decoding2
+        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_panic_decoding2 (time: [..]s)
 
         Failure data:
             0x80
 
-        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_assert
+        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_assert (time: [..]s)
 
         Failure data:
             "assertion failed: `x`."
 
-        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_panic_decoding
+        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_panic_decoding (time: [..]s)
 
         Failure data:
             (0x7b ('{'), 0x616161 ('aaa'), 0x800000000000011000000000000000000000000000000000000000000000000, 0x98, 0x7c ('|'), 0x95)
 
-        [PASS] panic_decoding_integrationtest::test_panic_decoding::test_simple2 (l1_gas: [..], l1_data_gas: [..], l2_gas: [..])
-        [PASS] panic_decoding_integrationtest::test_panic_decoding::test_simple (l1_gas: [..], l1_data_gas: [..], l2_gas: [..])
-        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_assert_eq
+        [PASS] panic_decoding_integrationtest::test_panic_decoding::test_simple2 (l1_gas: [..], l1_data_gas: [..], l2_gas: [..]) (time: [..]s)
+        [PASS] panic_decoding_integrationtest::test_panic_decoding::test_simple (l1_gas: [..], l1_data_gas: [..], l2_gas: [..]) (time: [..]s)
+        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_assert_eq (time: [..]s)
 
         Failure data:
             "assertion `x == y` failed.
             x: 5
             y: 6"
 
-        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_assert_message
+        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_assert_message (time: [..]s)
 
         Failure data:
             "Another identifiable and meaningful error message"
 
-        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_assert_eq_message
+        [FAIL] panic_decoding_integrationtest::test_panic_decoding::test_assert_eq_message (time: [..]s)
 
         Failure data:
             "assertion `x == y` failed: An identifiable and meaningful error message
@@ -637,7 +637,7 @@ fn with_exit_first() {
 
         Collected 2 test(s) from exit_first package
         Running 2 test(s) from tests/
-        [FAIL] exit_first_integrationtest::ext_function_test::simple_test
+        [FAIL] exit_first_integrationtest::ext_function_test::simple_test (time: [..]s)
 
         Failure data:
             0x73696d706c6520636865636b ('simple check')
@@ -665,7 +665,7 @@ fn with_exit_first_flag() {
 
         Collected 2 test(s) from exit_first package
         Running 2 test(s) from tests/
-        [FAIL] exit_first_integrationtest::ext_function_test::simple_test
+        [FAIL] exit_first_integrationtest::ext_function_test::simple_test (time: [..]s)
 
         Failure data:
             0x73696d706c6520636865636b ('simple check')
@@ -690,66 +690,66 @@ fn should_panic() {
         Collected 14 test(s) from should_panic_test package
         Running 0 test(s) from src/
         Running 14 test(s) from tests/
-        [FAIL] should_panic_test_integrationtest::should_panic_test::didnt_expect_panic
+        [FAIL] should_panic_test_integrationtest::should_panic_test::didnt_expect_panic (time: [..]s)
 
         Failure data:
             0x756e65787065637465642070616e6963 ('unexpected panic')
 
-        [FAIL] should_panic_test_integrationtest::should_panic_test::should_panic_expected_contains_error
+        [FAIL] should_panic_test_integrationtest::should_panic_test::should_panic_expected_contains_error (time: [..]s)
 
         Failure data:
             Incorrect panic data
             Actual:    [0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3, 0x0, 0x77696c6c, 0x4] (will)
             Expected:  [0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3, 0x0, 0x546869732077696c6c2070616e6963, 0xf] (This will panic)
 
-        [FAIL] should_panic_test_integrationtest::should_panic_test::should_panic_byte_array_with_felt
+        [FAIL] should_panic_test_integrationtest::should_panic_test::should_panic_byte_array_with_felt (time: [..]s)
 
         Failure data:
             Incorrect panic data
             Actual:    [0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3, 0x0, 0x546869732077696c6c2070616e6963, 0xf] (This will panic)
             Expected:  [0x546869732077696c6c2070616e6963] (This will panic)
 
-        [FAIL] should_panic_test_integrationtest::should_panic_test::expected_panic_but_didnt_with_expected_multiple
+        [FAIL] should_panic_test_integrationtest::should_panic_test::expected_panic_but_didnt_with_expected_multiple (time: [..]s)
 
         Failure data:
             Expected to panic but didn't
             Expected panic data:  [0x70616e6963206d657373616765, 0x7365636f6e64206d657373616765] (panic message, second message)
 
-        [FAIL] should_panic_test_integrationtest::should_panic_test::expected_panic_but_didnt
+        [FAIL] should_panic_test_integrationtest::should_panic_test::expected_panic_but_didnt (time: [..]s)
 
         Failure data:
             Expected to panic but didn't
 
-        [PASS] should_panic_test_integrationtest::should_panic_test::should_panic_no_data (l1_gas: [..], l1_data_gas: [..], l2_gas: [..])
+        [PASS] should_panic_test_integrationtest::should_panic_test::should_panic_no_data (l1_gas: [..], l1_data_gas: [..], l2_gas: [..]) (time: [..]s)
 
         Success data:
             0x0 ('')
 
-        [PASS] should_panic_test_integrationtest::should_panic_test::should_panic_check_data (l1_gas: [..], l1_data_gas: [..], l2_gas: [..])
-        [FAIL] should_panic_test_integrationtest::should_panic_test::should_panic_not_matching_suffix
+        [PASS] should_panic_test_integrationtest::should_panic_test::should_panic_check_data (l1_gas: [..], l1_data_gas: [..], l2_gas: [..]) (time: [..]s)
+        [FAIL] should_panic_test_integrationtest::should_panic_test::should_panic_not_matching_suffix (time: [..]s)
 
         Failure data:
             Incorrect panic data
             Actual:    [0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3, 0x0, 0x546869732077696c6c2070616e6963, 0xf] (This will panic)
             Expected:  [0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3, 0x0, 0x77696c6c2070616e696363, 0xb] (will panicc)
 
-        [PASS] should_panic_test_integrationtest::should_panic_test::should_panic_match_suffix (l1_gas: [..], l1_data_gas: [..], l2_gas: [..])
-        [PASS] should_panic_test_integrationtest::should_panic_test::should_panic_felt_matching (l1_gas: [..], l1_data_gas: [..], l2_gas: [..])
-        [FAIL] should_panic_test_integrationtest::should_panic_test::should_panic_felt_with_byte_array
+        [PASS] should_panic_test_integrationtest::should_panic_test::should_panic_match_suffix (l1_gas: [..], l1_data_gas: [..], l2_gas: [..]) (time: [..]s)
+        [PASS] should_panic_test_integrationtest::should_panic_test::should_panic_felt_matching (l1_gas: [..], l1_data_gas: [..], l2_gas: [..]) (time: [..]s)
+        [FAIL] should_panic_test_integrationtest::should_panic_test::should_panic_felt_with_byte_array (time: [..]s)
 
         Failure data:
             Incorrect panic data
             Actual:    [0x546869732077696c6c2070616e6963] (This will panic)
             Expected:  [0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3, 0x0, 0x546869732077696c6c2070616e6963, 0xf] (This will panic)
 
-        [PASS] should_panic_test_integrationtest::should_panic_test::should_panic_multiple_messages (l1_gas: [..], l1_data_gas: [..], l2_gas: [..])
-        [FAIL] should_panic_test_integrationtest::should_panic_test::expected_panic_but_didnt_with_expected
+        [PASS] should_panic_test_integrationtest::should_panic_test::should_panic_multiple_messages (l1_gas: [..], l1_data_gas: [..], l2_gas: [..]) (time: [..]s)
+        [FAIL] should_panic_test_integrationtest::should_panic_test::expected_panic_but_didnt_with_expected (time: [..]s)
 
         Failure data:
             Expected to panic but didn't
             Expected panic data:  [0x70616e6963206d657373616765] (panic message)
 
-        [FAIL] should_panic_test_integrationtest::should_panic_test::should_panic_with_non_matching_data
+        [FAIL] should_panic_test_integrationtest::should_panic_test::should_panic_with_non_matching_data (time: [..]s)
 
         Failure data:
             Incorrect panic data
@@ -798,7 +798,7 @@ fn incompatible_snforge_std_version_warning() {
         Collected 2 test(s) from steps package
         Running 2 test(s) from src/
         [PASS] steps::tests::steps_less_than_10000000 [..]
-        [FAIL] steps::tests::steps_more_than_10000000
+        [FAIL] steps::tests::steps_more_than_10000000 (time: [..]s)
 
         Failure data:
             Could not reach the end of the program. RunResources has no remaining steps.
@@ -22,13 +22,13 @@ fn should_allow_less_than_default() {
 
                 Collected 2 test(s) from steps package
                 Running 2 test(s) from src/
-                [FAIL] steps::tests::steps_less_than_10000000
+                [FAIL] steps::tests::steps_less_than_10000000 (time: [..]s)
 
                 Failure data:
                     Could not reach the end of the program. RunResources has no remaining steps.
                     Suggestion: Consider using the flag `--max-n-steps` to increase allowed limit of steps
 
-                [FAIL] steps::tests::steps_more_than_10000000
+                [FAIL] steps::tests::steps_more_than_10000000 (time: [..]s)
 
                 Failure data:
                     Could not reach the end of the program. RunResources has no remaining steps.
@@ -84,8 +84,8 @@ fn should_default_to_10m() {
 
             Collected 2 test(s) from steps package
             Running 2 test(s) from src/
-            [PASS] steps::tests::steps_less_than_10000000 (l1_gas: ~[..], l1_data_gas: ~[..], l2_gas: ~[..])
-            [FAIL] steps::tests::steps_more_than_10000000
+            [PASS] steps::tests::steps_less_than_10000000 (l1_gas: ~[..], l1_data_gas: ~[..], l2_gas: ~[..]) (time: [..]s)
+            [FAIL] steps::tests::steps_more_than_10000000 (time: [..]s)
 
             Failure data:
                 Could not reach the end of the program. RunResources has no remaining steps.
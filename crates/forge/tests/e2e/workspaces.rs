@@ -19,12 +19,12 @@ fn root_workspace_without_arguments() {
         Running 1 test(s) from src/
         [PASS] hello_workspaces::tests::test_simple [..]
         Running 2 test(s) from tests/
-        [FAIL] hello_workspaces_integrationtest::test_failing::test_failing
+        [FAIL] hello_workspaces_integrationtest::test_failing::test_failing (time: [..]s)
         
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
         
-        [FAIL] hello_workspaces_integrationtest::test_failing::test_another_failing
+        [FAIL] hello_workspaces_integrationtest::test_failing::test_another_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
@@ -93,7 +93,7 @@ fn root_workspace_specific_package2() {
         [PASS] fibonacci_tests::lib_test [..]
         [PASS] fibonacci_tests::abc::abc_test [..]
         [PASS] fibonacci_tests::abc::efg::efg_test [..]
-        [FAIL] fibonacci_tests::abc::efg::failing_test
+        [FAIL] fibonacci_tests::abc::efg::failing_test (time: [..]s)
         
         Failure data:
             0x0 ('')
@@ -152,12 +152,12 @@ fn root_workspace_specify_root_package() {
         Running 1 test(s) from src/
         [PASS] hello_workspaces::tests::test_simple [..]
         Running 2 test(s) from tests/
-        [FAIL] hello_workspaces_integrationtest::test_failing::test_failing
+        [FAIL] hello_workspaces_integrationtest::test_failing::test_failing (time: [..]s)
         
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
         
-        [FAIL] hello_workspaces_integrationtest::test_failing::test_another_failing
+        [FAIL] hello_workspaces_integrationtest::test_failing::test_another_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
@@ -237,7 +237,7 @@ fn root_workspace_for_entire_workspace() {
         [PASS] fibonacci_tests::lib_test [..]
         [PASS] fibonacci_tests::abc::abc_test [..]
         [PASS] fibonacci_tests::abc::efg::efg_test [..]
-        [FAIL] fibonacci_tests::abc::efg::failing_test
+        [FAIL] fibonacci_tests::abc::efg::failing_test (time: [..]s)
         
         Failure data:
             0x0 ('')
@@ -249,12 +249,12 @@ fn root_workspace_for_entire_workspace() {
         Running 1 test(s) from src/
         [PASS] hello_workspaces::tests::test_simple [..]
         Running 2 test(s) from tests/
-        [FAIL] hello_workspaces_integrationtest::test_failing::test_failing
+        [FAIL] hello_workspaces_integrationtest::test_failing::test_failing (time: [..]s)
         
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
         
-        [FAIL] hello_workspaces_integrationtest::test_failing::test_another_failing
+        [FAIL] hello_workspaces_integrationtest::test_failing::test_another_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
@@ -309,7 +309,7 @@ fn root_workspace_for_entire_workspace_inside_package() {
         [PASS] fibonacci_tests::lib_test [..]
         [PASS] fibonacci_tests::abc::abc_test [..]
         [PASS] fibonacci_tests::abc::efg::efg_test [..]
-        [FAIL] fibonacci_tests::abc::efg::failing_test
+        [FAIL] fibonacci_tests::abc::efg::failing_test (time: [..]s)
         
         Failure data:
             0x0 ('')
@@ -321,12 +321,12 @@ fn root_workspace_for_entire_workspace_inside_package() {
         Running 1 test(s) from src/
         [PASS] hello_workspaces::tests::test_simple [..]
         Running 2 test(s) from tests/
-        [FAIL] hello_workspaces_integrationtest::test_failing::test_failing
+        [FAIL] hello_workspaces_integrationtest::test_failing::test_failing (time: [..]s)
         
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
         
-        [FAIL] hello_workspaces_integrationtest::test_failing::test_another_failing
+        [FAIL] hello_workspaces_integrationtest::test_failing::test_another_failing (time: [..]s)
 
         Failure data:
             0x6661696c696e6720636865636b ('failing check')
@@ -392,7 +392,7 @@ fn virtual_workspace_without_arguments() {
         [PASS] fibonacci2_tests::lib_test [..]
         [PASS] fibonacci2_tests::abc::abc_test [..]
         [PASS] fibonacci2_tests::abc::efg::efg_test [..]
-        [FAIL] fibonacci2_tests::abc::efg::failing_test
+        [FAIL] fibonacci2_tests::abc::efg::failing_test (time: [..]s)
         
         Failure data:
             0x0 ('')
@@ -464,7 +464,7 @@ fn virtual_workspace_specific_package2() {
         [PASS] fibonacci2_tests::lib_test [..]
         [PASS] fibonacci2_tests::abc::abc_test [..]
         [PASS] fibonacci2_tests::abc::efg::efg_test [..]
-        [FAIL] fibonacci2_tests::abc::efg::failing_test
+        [FAIL] fibonacci2_tests::abc::efg::failing_test (time: [..]s)
         
         Failure data:
             0x0 ('')
@@ -555,7 +555,7 @@ fn virtual_workspace_for_entire_workspace() {
         [PASS] fibonacci2_tests::lib_test [..]
         [PASS] fibonacci2_tests::abc::abc_test [..]
         [PASS] fibonacci2_tests::abc::efg::efg_test [..]
-        [FAIL] fibonacci2_tests::abc::efg::failing_test
+        [FAIL] fibonacci2_tests::abc::efg::failing_test (time: [..]s)
         
         Failure data:
             0x0 ('')
@@ -603,7 +603,7 @@ fn virtual_workspace_for_entire_workspace_inside_package() {
         [PASS] fibonacci2_tests::lib_test [..]
         [PASS] fibonacci2_tests::abc::abc_test [..]
         [PASS] fibonacci2_tests::abc::efg::efg_test [..]
-        [FAIL] fibonacci2_tests::abc::efg::failing_test
+        [FAIL] fibonacci2_tests::abc::efg::failing_test (time: [..]s)
         
         Failure data:
             0x0 ('')
@@ -0,0 +1,129 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::{formatdoc, indoc};
+use shared::test_utils::node_url::node_rpc_url;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn deal_with_custom_layout() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::{ContractAddress, contract_address_const};
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, deal_with_layout,
+                Erc20StorageLayout,
+            };
+
+            #[starknet::interface]
+            trait IERC20<TContractState> {
+                fn get_total_supply(self: @TContractState) -> u256;
+                fn balance_of(self: @TContractState, account: ContractAddress) -> u256;
+            }
+
+            fn deploy_token(recipient: ContractAddress) -> IERC20Dispatcher {
+                let contract = declare("ERC20").unwrap().contract_class();
+                let (contract_address, _) = contract
+                    .deploy(@array!['token', 'TKN', 18, 1000, 0, recipient.into()])
+                    .unwrap();
+                IERC20Dispatcher { contract_address }
+            }
+
+            fn layout() -> Erc20StorageLayout {
+                Erc20StorageLayout {
+                    balances: selector!("balances"), total_supply: selector!("total_supply"),
+                }
+            }
+
+            #[test]
+            fn deal_without_adjusting_total_supply() {
+                let holder = contract_address_const::<123>();
+                let token = deploy_token(holder);
+
+                deal_with_layout(token.contract_address, holder, 5000, false, layout());
+
+                assert(token.balance_of(holder) == 5000, 'Wrong balance');
+                assert(token.get_total_supply() == 1000, 'Wrong total supply');
+            }
+
+            #[test]
+            fn deal_lowering_balance_below_total_supply() {
+                let holder = contract_address_const::<123>();
+                let token = deploy_token(holder);
+
+                deal_with_layout(token.contract_address, holder, 5000, false, layout());
+                deal_with_layout(token.contract_address, holder, 0, true, layout());
+
+                assert(token.balance_of(holder) == 0, 'Wrong balance');
+                assert(token.get_total_supply() == 0, 'Wrong total supply');
+            }
+
+            #[test]
+            fn deal_adjusting_total_supply() {
+                let holder = contract_address_const::<123>();
+                let other = contract_address_const::<456>();
+                let token = deploy_token(holder);
+
+                deal_with_layout(token.contract_address, other, 300, true, layout());
+                assert(token.balance_of(other) == 300, 'Wrong balance');
+                assert(token.get_total_supply() == 1300, 'Wrong total supply');
+
+                deal_with_layout(token.contract_address, holder, 200, true, layout());
+                assert(token.balance_of(holder) == 200, 'Wrong balance');
+                assert(token.get_total_supply() == 500, 'Wrong total supply');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "ERC20".to_string(),
+            Path::new("tests/data/contracts/erc20.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn deal_forked_token() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use starknet::{{ContractAddress, contract_address_const}};
+            use snforge_std::deal;
+
+            #[starknet::interface]
+            trait IERC20Camel<TState> {{
+                fn totalSupply(self: @TState) -> u256;
+                fn balanceOf(self: @TState, account: ContractAddress) -> u256;
+            }}
+
+            #[test]
+            #[fork(url: "{}", block_number: 54060)]
+            fn deal_forked_token() {{
+                let contract_address = contract_address_const::<0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7>();
+                let dispatcher = IERC20CamelDispatcher {{ contract_address }};
+                let account = contract_address_const::<0x123>();
+
+                let total_supply = dispatcher.totalSupply();
+                let balance = dispatcher.balanceOf(account);
+
+                deal(contract_address, account, balance + 1000, true);
+
+                assert(dispatcher.balanceOf(account) == balance + 1000, 'Wrong balance');
+                assert(dispatcher.totalSupply() == total_supply + 1000, 'Wrong total supply');
+            }}
+        "#,
+            node_rpc_url()
+        )
+        .as_str()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
@@ -91,3 +91,29 @@ fn fuzzed_while_loop() {
     assert!(gas_info.l2_gas.mean > 0.0);
     assert!(gas_info.l2_gas.std_deviation > 0.0);
 }
+
+#[test]
+fn failing_arguments_are_shrunk() {
+    let test = test_case!(indoc!(
+        r#"
+        #[test]
+        #[fuzzer(runs: 256, seed: 100)]
+        fn failing_arguments_are_shrunk(a: u64, b: i32, c: ByteArray) {
+            assert(a < 1000 || c.len() < 3, 'too big');
+        }
+    "#
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    let test_target_summary = TestCase::find_test_result(&result);
+    let AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed { fuzzer_args, .. }) =
+        &test_target_summary.test_case_summaries[0]
+    else {
+        panic!("Test should fail")
+    };
+
+    assert_eq!(fuzzer_args[0], "1000");
+    assert_eq!(fuzzer_args[1], "0");
+    assert_eq!(fuzzer_args[2], r#""   ""#);
+}
@@ -1,8 +1,9 @@
 use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
 use indoc::indoc;
 use starknet_api::execution_resources::{GasAmount, GasVector};
 use std::path::Path;
-use test_utils::runner::{Contract, assert_gas, assert_passed};
+use test_utils::runner::{Contract, TestCase, assert_gas, assert_passed};
 use test_utils::running_tests::run_test_case;
 use test_utils::test_case;
 
@@ -675,6 +676,44 @@ fn storage_write_from_test_cost_cairo_steps() {
     );
 }
 
+#[test]
+fn storage_write_without_kzg_da_cost_cairo_steps() {
+    let test = test_case!(indoc!(
+        r"
+        use snforge_std::start_cheat_use_kzg_da_global;
+
+        #[starknet::contract]
+        mod Contract {
+            #[storage]
+            struct Storage {
+                balance: felt252,
+            }
+        }
+
+        #[test]
+        fn storage_write_without_kzg_da_cost() {
+            start_cheat_use_kzg_da_global(false);
+
+            let mut state = Contract::contract_state_for_testing();
+            state.balance.write(10);
+        }
+    "
+    ),);
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+    // Without blobs the state diff is published in calldata, so it is paid for in l1_gas
+    let test_target_summary = TestCase::find_test_result(&result);
+    let AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, .. }) =
+        &test_target_summary.test_case_summaries[0]
+    else {
+        panic!()
+    };
+    assert_eq!(gas_info.l1_data_gas, GasAmount(0));
+    assert!(gas_info.l1_gas > GasAmount(0));
+}
+
 #[test]
 fn multiple_storage_writes_cost_cairo_steps() {
     let test = test_case!(
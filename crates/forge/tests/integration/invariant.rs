@@ -0,0 +1,166 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+
+const SETUP: &str = indoc!(
+    r#"
+    use starknet::ContractAddress;
+    use snforge_std::{
+        declare, ContractClassTrait, DeclareResultTrait, target_contract, run_invariants,
+        Invariants,
+    };
+
+    #[starknet::interface]
+    trait IInvariantChecker<TContractState> {
+        fn add(ref self: TContractState, amount: u8);
+        fn get_value(self: @TContractState) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct State {
+        checker: IInvariantCheckerDispatcher,
+    }
+
+    impl StateInvariants of Invariants<State> {
+        fn check_invariants(self: @State) {
+            assert((*self.checker).get_value() <= 200, 'value above 200');
+        }
+    }
+
+    fn deploy_checker(limit: u32) -> IInvariantCheckerDispatcher {
+        let contract = declare("InvariantChecker").unwrap().contract_class();
+        let (contract_address, _) = contract.deploy(@array![limit.into()]).unwrap();
+        IInvariantCheckerDispatcher { contract_address }
+    }
+    "#
+);
+
+fn contract() -> Contract {
+    Contract::from_code_path(
+        "InvariantChecker".to_string(),
+        Path::new("tests/data/contracts/invariant_checker.cairo"),
+    )
+    .unwrap()
+}
+
+#[test]
+fn invariants_hold() {
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r"
+                #[test]
+                #[invariant(runs: 32, depth: 10, seed: 100)]
+                fn invariants_hold() {
+                    let checker = deploy_checker(200);
+                    target_contract(checker.contract_address);
+
+                    run_invariants(State { checker });
+                }
+                "
+            )
+        ),
+        contract()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn broken_invariant_shows_call_sequence() {
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r"
+                #[test]
+                #[invariant(runs: 32, depth: 10, seed: 100)]
+                fn broken_invariant_shows_call_sequence() {
+                    let checker = deploy_checker(0);
+                    target_contract(checker.contract_address);
+
+                    run_invariants(State { checker });
+                }
+                "
+            )
+        ),
+        contract()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "broken_invariant_shows_call_sequence",
+        "value above 200",
+    );
+    assert_case_output_contains(
+        &result,
+        "broken_invariant_shows_call_sequence",
+        "Call sequence:\n      1. InvariantChecker::add(",
+    );
+}
+
+#[test]
+fn run_invariants_without_targets() {
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r"
+                #[test]
+                #[invariant(runs: 1)]
+                fn run_invariants_without_targets() {
+                    let checker = deploy_checker(200);
+
+                    run_invariants(State { checker });
+                }
+                "
+            )
+        ),
+        contract()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "run_invariants_without_targets",
+        "No contracts to call in the invariant test, add them with `target_contract`",
+    );
+}
+
+#[test]
+fn target_contract_outside_invariant_test() {
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r"
+                #[test]
+                fn target_contract_outside_invariant_test() {
+                    let checker = deploy_checker(200);
+                    target_contract(checker.contract_address);
+                }
+                "
+            )
+        ),
+        contract()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "target_contract_outside_invariant_test",
+        "`target_contract` can only be used in invariant tests, mark the test with #[invariant]",
+    );
+}
@@ -208,3 +208,62 @@ fn mock_calls() {
     let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
     assert_passed(&result);
 }
+
+#[test]
+fn mock_calls_matching_calldata_with_reverts_and_sequences() {
+    let test = test_case!(indoc!(
+        r#"
+        use snforge_std::{
+            start_mock_call_when, mock_calls, MockTarget, CalldataMatcher, MockedResult, CheatSpan,
+        };
+
+        #[starknet::interface]
+        trait IOracle<TContractState> {
+            fn get_price(self: @TContractState, asset: felt252) -> felt252;
+        }
+
+        #[test]
+        #[feature("safe_dispatcher")]
+        fn mock_calls_matching_calldata() {
+            let contract_address = 123.try_into().unwrap();
+            let dispatcher = IOracleSafeDispatcher { contract_address };
+
+            start_mock_call_when(
+                contract_address, selector!("get_price"), CalldataMatcher::Exact(array!['ETH']), 3000,
+            );
+            start_mock_call_when(contract_address, selector!("get_price"), CalldataMatcher::Any, 1);
+
+            assert(dispatcher.get_price('ETH').unwrap() == 3000, 'Price should be 3000');
+            assert(dispatcher.get_price('BTC').unwrap() == 1, 'Price should be 1');
+        }
+
+        #[test]
+        #[feature("safe_dispatcher")]
+        fn mock_calls_reverts_and_sequences() {
+            let contract_address = 123.try_into().unwrap();
+            let dispatcher = IOracleSafeDispatcher { contract_address };
+
+            mock_calls(
+                MockTarget::Contract(contract_address),
+                selector!("get_price"),
+                CalldataMatcher::Any,
+                array![MockedResult::Return(array![10]), MockedResult::Revert(array!['oracle down'])],
+                CheatSpan::Indefinite,
+            );
+
+            assert(dispatcher.get_price('ETH').unwrap() == 10, 'Price should be 10');
+            match dispatcher.get_price('ETH') {
+                Result::Ok(_) => panic!("Call should fail"),
+                Result::Err(panic_data) => assert(*panic_data.at(0) == 'oracle down', 'Wrong panic data'),
+            }
+            match dispatcher.get_price('ETH') {
+                Result::Ok(_) => panic!("Call should fail"),
+                Result::Err(panic_data) => assert(*panic_data.at(0) == 'oracle down', 'Wrong panic data'),
+            }
+        }
+    "#
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+    assert_passed(&result);
+}
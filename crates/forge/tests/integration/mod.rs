@@ -7,6 +7,7 @@ mod cheat_caller_address;
 mod cheat_execution_info;
 mod cheat_fork;
 mod cheat_sequencer_address;
+mod deal;
 mod declare;
 mod deploy;
 mod deploy_at;
@@ -17,20 +18,27 @@ mod fuzzing;
 mod gas;
 mod generate_random_felt;
 mod get_class_hash;
+mod invariant;
 mod l1_handler_executor;
 mod message_to_l1;
 mod mock_call;
+mod multi_fork;
 mod precalculate_address;
 mod pure_cairo;
 mod replace_bytecode;
 mod resources;
 mod runtime;
+mod setup;
 mod setup_fork;
 mod should_panic;
 mod signing;
+mod snapshot;
+mod spy_calls;
 mod spy_events;
 mod store_load;
 mod syscalls;
 mod test_state;
+mod timeout;
 mod too_many_events;
 mod trace;
+mod transactional;
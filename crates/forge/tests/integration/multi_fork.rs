@@ -0,0 +1,232 @@
+use cheatnet::runtime_extensions::forge_config_extension::config::{BlockId, BlockTag};
+use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use indoc::{formatdoc, indoc};
+use shared::test_utils::node_url::node_rpc_url;
+use starknet_api::block::BlockNumber;
+use test_utils::runner::{TestCase, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn state_is_separate_for_each_fork() {
+    let test = test_case!(formatdoc!(
+        r#"
+            use starknet::contract_address_const;
+            use snforge_std::{{BlockId, create_fork, get_class_hash, select_fork, select_default_fork}};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {{
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }}
+
+            #[test]
+            fn state_is_separate_for_each_fork() {{
+                create_fork("first", "{url}", BlockId::BlockNumber(54060));
+                create_fork("second", "{url}", BlockId::BlockNumber(54060));
+
+                let dispatcher = IHelloStarknetDispatcher {{
+                    contract_address: contract_address_const::<0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9>()
+                }};
+
+                select_fork("first");
+                dispatcher.increase_balance(100);
+                assert(dispatcher.get_balance() == 100, 'Balance should be 100');
+
+                select_fork("second");
+                assert(dispatcher.get_balance() == 0, 'Balance should be 0');
+
+                select_fork("first");
+                assert(dispatcher.get_balance() == 100, 'Balance should be 100');
+
+                select_default_fork();
+                let class_hash: felt252 = get_class_hash(dispatcher.contract_address).into();
+                assert(class_hash == 0, 'Contract should not exist');
+            }}
+        "#,
+        url = node_rpc_url(),
+    )
+    .as_str());
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn block_info_changes_with_selected_fork() {
+    let test = test_case!(formatdoc!(
+        r#"
+            use snforge_std::{{BlockId, create_fork, select_fork, select_default_fork}};
+
+            #[test]
+            #[fork(url: "{url}", block_number: 54060)]
+            fn block_info_changes_with_selected_fork() {{
+                create_fork("other", "{url}", BlockId::BlockNumber(54070));
+
+                assert(starknet::get_block_info().unbox().block_number == 54060, 'Wrong block number');
+
+                select_fork("other");
+                assert(starknet::get_block_info().unbox().block_number == 54070, 'Wrong block number');
+
+                select_default_fork();
+                assert(starknet::get_block_info().unbox().block_number == 54060, 'Wrong block number');
+            }}
+        "#,
+        url = node_rpc_url(),
+    )
+    .as_str());
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn create_fork_with_block_hash_and_tag() {
+    let test = test_case!(formatdoc!(
+        r#"
+            use snforge_std::{{BlockId, BlockTag, create_fork, select_fork}};
+
+            #[test]
+            fn create_fork_with_block_hash_and_tag() {{
+                create_fork(
+                    "hash",
+                    "{url}",
+                    BlockId::BlockHash(0x06ae121e46f5375f93b00475fb130348ae38148e121f84b0865e17542e9485de),
+                );
+                create_fork("latest", "{url}", BlockId::BlockTag(BlockTag::Latest));
+
+                select_fork("hash");
+                assert(starknet::get_block_info().unbox().block_number == 54060, 'Wrong block number');
+
+                select_fork("latest");
+                assert(starknet::get_block_info().unbox().block_number > 54060, 'Wrong block number');
+            }}
+        "#,
+        url = node_rpc_url(),
+    )
+    .as_str());
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+
+    let created_forks = TestCase::find_test_result(&result).test_case_summaries[0]
+        .created_forks()
+        .to_vec();
+    assert_eq!(created_forks.len(), 2);
+    assert_eq!(created_forks[0].name, "hash");
+    assert_eq!(created_forks[0].block_number, BlockNumber(54060));
+    assert_eq!(created_forks[1].name, "latest");
+    assert_eq!(
+        created_forks[1].block_id,
+        BlockId::BlockTag(BlockTag::Latest)
+    );
+    assert!(created_forks[1].block_number > BlockNumber(54060));
+}
+
+#[test]
+fn gas_includes_writes_on_all_forks() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use starknet::contract_address_const;
+            use snforge_std::{{BlockId, create_fork, select_fork, store}};
+
+            #[test]
+            fn write_and_stay_on_fork() {{
+                create_fork("first", "{url}", BlockId::BlockNumber(54060));
+                create_fork("second", "{url}", BlockId::BlockNumber(54060));
+
+                select_fork("first");
+                store(contract_address_const::<0x123>(), 0x456, array![789].span());
+            }}
+
+            #[test]
+            fn write_and_select_other_fork() {{
+                create_fork("first", "{url}", BlockId::BlockNumber(54060));
+                create_fork("second", "{url}", BlockId::BlockNumber(54060));
+
+                select_fork("first");
+                store(contract_address_const::<0x123>(), 0x456, array![789].span());
+                select_fork("second");
+            }}
+        "#,
+            url = node_rpc_url(),
+        )
+        .as_str()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+
+    let gas_of =
+        |test_case_name: &str| {
+            TestCase::find_test_result(&result)
+                .test_case_summaries
+                .iter()
+                .find_map(|case| match case {
+                    AnyTestCaseSummary::Single(TestCaseSummary::Passed {
+                        name, gas_info, ..
+                    }) if name.ends_with(test_case_name) => Some(*gas_info),
+                    _ => None,
+                })
+                .unwrap()
+        };
+    let gas = gas_of("write_and_stay_on_fork");
+    assert!(gas.l1_data_gas.0 > 0);
+    assert_eq!(gas, gas_of("write_and_select_other_fork"));
+}
+
+#[test]
+fn select_nonexistent_fork() {
+    let test = test_case!(indoc!(
+        r#"
+            use snforge_std::select_fork;
+
+            #[test]
+            fn select_nonexistent_fork() {
+                select_fork("nonexistent");
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "select_nonexistent_fork",
+        "Fork with name = nonexistent does not exist",
+    );
+}
+
+#[test]
+fn create_fork_with_duplicated_name() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use snforge_std::{{BlockId, create_fork}};
+
+            #[test]
+            fn create_fork_with_duplicated_name() {{
+                create_fork("default", "{}", BlockId::BlockNumber(54060));
+            }}
+        "#,
+            node_rpc_url(),
+        )
+        .as_str()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "create_fork_with_duplicated_name",
+        "Fork with name = default already exists",
+    );
+}
@@ -0,0 +1,159 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn setup_state_is_shared_by_tests() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            fn dispatcher() -> IHelloStarknetDispatcher {
+                IHelloStarknetDispatcher { contract_address: 123.try_into().unwrap() }
+            }
+
+            #[setup]
+            fn setup() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                contract.deploy_at(@array![], 123.try_into().unwrap()).unwrap();
+                dispatcher().increase_balance(5);
+            }
+
+            #[test]
+            fn first_test() {
+                assert(dispatcher().get_balance() == 5, 'Balance should be 5');
+                dispatcher().increase_balance(1);
+                assert(dispatcher().get_balance() == 6, 'Balance should be 6');
+            }
+
+            #[test]
+            fn second_test() {
+                assert(dispatcher().get_balance() == 5, 'Balance should be 5');
+                dispatcher().increase_balance(2);
+                assert(dispatcher().get_balance() == 7, 'Balance should be 7');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn setup_cheats_and_declarations_apply_to_tests() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResult, DeclareResultTrait,
+                start_cheat_block_number,
+            };
+
+            #[starknet::interface]
+            trait ICheatBlockNumberChecker<TContractState> {
+                fn get_block_number(ref self: TContractState) -> u64;
+            }
+
+            #[setup]
+            fn setup() {
+                let contract = declare("CheatBlockNumberChecker").unwrap().contract_class();
+                contract.deploy_at(@array![], 123.try_into().unwrap()).unwrap();
+                start_cheat_block_number(123.try_into().unwrap(), 456);
+            }
+
+            #[test]
+            fn cheats_apply_to_tests() {
+                let dispatcher = ICheatBlockNumberCheckerDispatcher {
+                    contract_address: 123.try_into().unwrap(),
+                };
+                assert(dispatcher.get_block_number() == 456, 'Block number should be 456');
+            }
+
+            #[test]
+            fn declarations_apply_to_tests() {
+                match declare("CheatBlockNumberChecker").unwrap() {
+                    DeclareResult::Success(_) => panic!("Class should be declared"),
+                    DeclareResult::AlreadyDeclared(_) => {},
+                }
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "CheatBlockNumberChecker".to_string(),
+            Path::new("tests/data/contracts/cheat_block_number_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn failing_setup_fails_tests() {
+    let test = test_case!(indoc!(
+        r#"
+            #[setup]
+            fn setup() {
+                panic!("Setup panicked");
+            }
+
+            #[test]
+            fn failing_setup_fails_tests() {
+                assert(1 == 1, 'Should not be run');
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "failing_setup_fails_tests", "::setup failed");
+    assert_case_output_contains(&result, "failing_setup_fails_tests", "Setup panicked");
+}
+
+#[test]
+fn creating_fork_in_setup_fails() {
+    let test = test_case!(indoc!(
+        r#"
+            use snforge_std::{BlockId, create_fork};
+
+            #[setup]
+            fn setup() {
+                create_fork("other", "http://127.0.0.1:5055/rpc", BlockId::BlockNumber(54060));
+            }
+
+            #[test]
+            fn creating_fork_in_setup_fails() {
+                assert(1 == 1, 'Should not be run');
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "creating_fork_in_setup_fails",
+        "Forks cannot be created in #[setup] functions",
+    );
+}
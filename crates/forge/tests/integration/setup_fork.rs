@@ -1,4 +1,4 @@
-use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
+use cheatnet::runtime_extensions::forge_config_extension::config::{BlockId, BlockTag};
 use indoc::{formatdoc, indoc};
 use std::num::NonZeroU32;
 use std::path::Path;
@@ -9,6 +9,7 @@ use forge::block_number_map::BlockNumberMap;
 use forge::run_tests::package::run_for_package;
 use forge::scarb::config::ForkTarget;
 use forge::test_filter::TestsFilter;
+use forge::test_report::TestReport;
 use tempfile::tempdir;
 use tokio::runtime::Runtime;
 
@@ -134,6 +135,7 @@ fn fork_aliased_decorator() {
             RunForPackageArgs {
                 test_targets: raw_test_targets,
                 package_name: "test_package".to_string(),
+                package_root: package.root.clone(),
                 tests_filter: TestsFilter::from_flags(
                     None,
                     false,
@@ -152,8 +154,14 @@ fn fork_aliased_decorator() {
                         cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path())
                             .unwrap()
                             .join(CACHE_DIR),
+                        offline: false,
+                        prefetch_fork_storage: false,
                         contracts_data: ContractsData::try_from(test.contracts().unwrap()).unwrap(),
                         tracked_resource: ForgeTrackedResource::CairoSteps,
+                        transactional: false,
+                        timeout: None,
+                        trace_verbosity: None,
+                        trace_passed_tests: false,
                         environment_variables: test.env().clone(),
                     }),
                     output_config: Arc::new(OutputConfig {
@@ -164,10 +172,11 @@ fn fork_aliased_decorator() {
                 fork_targets: vec![ForkTarget {
                     name: "FORK_NAME_FROM_SCARB_TOML".to_string(),
                     url: node_rpc_url().as_str().parse().unwrap(),
-                    block_id: BlockId::BlockTag,
+                    block_id: BlockId::BlockTag(BlockTag::Latest),
                 }],
             },
             &mut BlockNumberMap::default(),
+            &mut TestReport::default(),
         ))
         .expect("Runner fail");
 
@@ -220,6 +229,7 @@ fn fork_aliased_decorator_overrding() {
             RunForPackageArgs {
                 test_targets: raw_test_targets,
                 package_name: "test_package".to_string(),
+                package_root: package.root.clone(),
                 tests_filter: TestsFilter::from_flags(
                     None,
                     false,
@@ -238,8 +248,14 @@ fn fork_aliased_decorator_overrding() {
                         cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path())
                             .unwrap()
                             .join(CACHE_DIR),
+                        offline: false,
+                        prefetch_fork_storage: false,
                         contracts_data: ContractsData::try_from(test.contracts().unwrap()).unwrap(),
                         tracked_resource: ForgeTrackedResource::CairoSteps,
+                        transactional: false,
+                        timeout: None,
+                        trace_verbosity: None,
+                        trace_passed_tests: false,
                         environment_variables: test.env().clone(),
                     }),
                     output_config: Arc::new(OutputConfig {
@@ -254,6 +270,7 @@ fn fork_aliased_decorator_overrding() {
                 }],
             },
             &mut BlockNumberMap::default(),
+            &mut TestReport::default(),
         ))
         .expect("Runner fail");
 
@@ -358,6 +375,29 @@ fn get_block_info_in_forked_block() {
                 assert(block_info.block_number > 54060, block_info.block_number.into());
             }}
 
+            #[test]
+            #[fork(url: "{node_rpc_url}", block_tag: l1_accepted)]
+            fn test_fork_get_block_info_l1_accepted_block() {{
+                let block_info = starknet::get_block_info().unbox();
+                assert(block_info.block_number > 54060, block_info.block_number.into());
+            }}
+
+            #[test]
+            #[fork(url: "{node_rpc_url}", block_tag: pending)]
+            fn test_fork_get_block_info_pending_block() {{
+                let block_info = starknet::get_block_info().unbox();
+                assert(block_info.block_timestamp > 1711645884, block_info.block_timestamp.into());
+                assert(block_info.block_number > 54060, block_info.block_number.into());
+            }}
+
+            #[test]
+            #[fork(url: "{node_rpc_url}", block_timestamp: 1711645884)]
+            fn test_fork_get_block_info_block_timestamp() {{
+                let block_info = starknet::get_block_info().unbox();
+                assert(block_info.block_timestamp == 1711645884, block_info.block_timestamp.into());
+                assert(block_info.block_number == 54060, block_info.block_number.into());
+            }}
+
             #[test]
             #[fork(url: "{node_rpc_url}", block_hash: 0x06ae121e46f5375f93b00475fb130348ae38148e121f84b0865e17542e9485de)]
             fn test_fork_get_block_info_block_hash() {{
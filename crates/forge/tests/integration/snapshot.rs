@@ -0,0 +1,231 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn revert_to_restores_storage_and_deployments() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, get_class_hash, snapshot, revert_to};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn revert_to_restores_storage_and_deployments() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+                dispatcher.increase_balance(5);
+
+                let id = snapshot();
+
+                dispatcher.increase_balance(10);
+                let (other_address, _) = contract.deploy(@array![]).unwrap();
+                assert(dispatcher.get_balance() == 15, 'Balance should be 15');
+
+                revert_to(id);
+                assert(dispatcher.get_balance() == 5, 'Balance should be 5');
+                let class_hash: felt252 = get_class_hash(other_address).into();
+                assert(class_hash == 0, 'Contract should not exist');
+
+                dispatcher.increase_balance(1);
+                assert(dispatcher.get_balance() == 6, 'Balance should be 6');
+
+                revert_to(id);
+                assert(dispatcher.get_balance() == 5, 'Balance should be 5');
+
+                // Deployment counter is restored too, so the same address is used again
+                let (redeployed_address, _) = contract.deploy(@array![]).unwrap();
+                assert(redeployed_address == other_address, 'Addresses should be equal');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn revert_to_restores_declared_classes() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, DeclareResult, snapshot, revert_to};
+
+            #[test]
+            fn revert_to_restores_declared_classes() {
+                let id = snapshot();
+
+                match declare("HelloStarknet").unwrap() {
+                    DeclareResult::Success(_) => {},
+                    DeclareResult::AlreadyDeclared(_) => panic!("Class should not be declared"),
+                }
+
+                revert_to(id);
+
+                match declare("HelloStarknet").unwrap() {
+                    DeclareResult::Success(_) => {},
+                    DeclareResult::AlreadyDeclared(_) => panic!("Class should not be declared"),
+                }
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn revert_to_restores_cheats_mocks_and_spies() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, start_cheat_block_number,
+                start_mock_call, spy_events, EventSpyTrait, snapshot, revert_to,
+            };
+
+            #[starknet::interface]
+            trait ICheatBlockNumberChecker<TContractState> {
+                fn get_block_number(ref self: TContractState) -> u64;
+                fn get_block_number_and_emit_event(ref self: TContractState) -> u64;
+            }
+
+            #[test]
+            fn revert_to_restores_cheats_mocks_and_spies() {
+                let contract = declare("CheatBlockNumberChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = ICheatBlockNumberCheckerDispatcher { contract_address };
+
+                start_cheat_block_number(contract_address, 123);
+                let mut spy = spy_events();
+
+                let id = snapshot();
+
+                start_cheat_block_number(contract_address, 456);
+                dispatcher.get_block_number_and_emit_event();
+                assert(spy.get_events().events.len() == 1, 'Event should be caught');
+                start_mock_call(contract_address, selector!("get_block_number"), 789_u64);
+                assert(dispatcher.get_block_number() == 789, 'Call should be mocked');
+
+                revert_to(id);
+
+                assert(dispatcher.get_block_number() == 123, 'Block number should be 123');
+                assert(spy.get_events().events.len() == 0, 'Event should be reverted');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "CheatBlockNumberChecker".to_string(),
+            Path::new("tests/data/contracts/cheat_block_number_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn revert_to_restores_call_expectations() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, expect_call, ExpectedCallCount,
+                CalldataMatcher, snapshot, revert_to,
+            };
+
+            #[starknet::interface]
+            trait ICheatBlockNumberChecker<TContractState> {
+                fn get_block_number(ref self: TContractState) -> u64;
+            }
+
+            #[test]
+            fn revert_to_restores_call_expectations() {
+                let contract = declare("CheatBlockNumberChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = ICheatBlockNumberCheckerDispatcher { contract_address };
+
+                expect_call(
+                    contract_address,
+                    selector!("get_block_number"),
+                    CalldataMatcher::Any,
+                    ExpectedCallCount::Exactly(1),
+                );
+
+                let id = snapshot();
+
+                expect_call(
+                    contract_address,
+                    selector!("get_block_number"),
+                    CalldataMatcher::Any,
+                    ExpectedCallCount::Exactly(5),
+                );
+                dispatcher.get_block_number();
+                dispatcher.get_block_number();
+
+                revert_to(id);
+
+                dispatcher.get_block_number();
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "CheatBlockNumberChecker".to_string(),
+            Path::new("tests/data/contracts/cheat_block_number_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn revert_to_nonexistent_snapshot() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::{snapshot, revert_to};
+
+            #[test]
+            fn revert_to_nonexistent_snapshot() {
+                let id = snapshot();
+                revert_to(id + 1);
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "revert_to_nonexistent_snapshot",
+        "Snapshot with id = 1 does not exist",
+    );
+}
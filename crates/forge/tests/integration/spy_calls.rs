@@ -0,0 +1,257 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn spy_calls_get_calls() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::ContractAddress;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, spy_calls, CallSpyTrait, Call,
+            };
+
+            #[starknet::interface]
+            trait ITraceInfoProxy<T> {
+                fn with_libcall(self: @T, class_hash: starknet::ClassHash) -> felt252;
+                fn regular_call(self: @T, contract_address: ContractAddress) -> felt252;
+            }
+
+            #[test]
+            fn spy_calls_get_calls() {
+                let checker = declare("TraceInfoChecker").unwrap().contract_class();
+                let (checker_address, _) = checker.deploy(@array![]).unwrap();
+                let proxy = declare("TraceInfoProxy").unwrap().contract_class();
+                let (proxy_address, _) = proxy.deploy(@array![checker_address.into()]).unwrap();
+                let dispatcher = ITraceInfoProxyDispatcher { contract_address: proxy_address };
+
+                let mut spy = spy_calls();
+                dispatcher.regular_call(checker_address);
+                dispatcher.with_libcall(*checker.class_hash);
+
+                let expected = array![
+                    Call {
+                        contract_address: proxy_address,
+                        function_selector: selector!("regular_call"),
+                        calldata: array![checker_address.into()],
+                    },
+                    Call {
+                        contract_address: checker_address,
+                        function_selector: selector!("from_proxy"),
+                        calldata: array![2],
+                    },
+                    Call {
+                        contract_address: proxy_address,
+                        function_selector: selector!("with_libcall"),
+                        calldata: array![(*checker.class_hash).into()],
+                    },
+                ];
+                assert(spy.get_calls() == expected, 'Wrong calls');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "TraceInfoProxy".to_string(),
+            Path::new("tests/data/contracts/trace_info_proxy.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "TraceInfoChecker".to_string(),
+            Path::new("tests/data/contracts/trace_info_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn expect_call_met() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::ContractAddress;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, expect_call, ExpectedCallCount,
+                CalldataMatcher,
+            };
+
+            #[starknet::interface]
+            trait ITraceInfoProxy<T> {
+                fn regular_call(self: @T, contract_address: ContractAddress) -> felt252;
+            }
+
+            #[test]
+            fn expect_call_met() {
+                let checker = declare("TraceInfoChecker").unwrap().contract_class();
+                let (checker_address, _) = checker.deploy(@array![]).unwrap();
+                let proxy = declare("TraceInfoProxy").unwrap().contract_class();
+                let (proxy_address, _) = proxy.deploy(@array![checker_address.into()]).unwrap();
+                let dispatcher = ITraceInfoProxyDispatcher { contract_address: proxy_address };
+
+                // The call made by the constructor of the proxy is not counted
+                expect_call(
+                    checker_address,
+                    selector!("from_proxy"),
+                    CalldataMatcher::Exact(array![2]),
+                    ExpectedCallCount::Exactly(2),
+                );
+                expect_call(
+                    checker_address,
+                    selector!("from_proxy"),
+                    CalldataMatcher::Any,
+                    ExpectedCallCount::AtLeastOnce,
+                );
+                expect_call(
+                    checker_address,
+                    selector!("from_proxy"),
+                    CalldataMatcher::Exact(array![1]),
+                    ExpectedCallCount::Never,
+                );
+                expect_call(
+                    checker_address,
+                    selector!("panic"),
+                    CalldataMatcher::Any,
+                    ExpectedCallCount::Never,
+                );
+
+                dispatcher.regular_call(checker_address);
+                dispatcher.regular_call(checker_address);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "TraceInfoProxy".to_string(),
+            Path::new("tests/data/contracts/trace_info_proxy.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "TraceInfoChecker".to_string(),
+            Path::new("tests/data/contracts/trace_info_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn expect_call_unmet() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::ContractAddress;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, expect_call, ExpectedCallCount,
+                CalldataMatcher,
+            };
+
+            #[starknet::interface]
+            trait ITraceInfoProxy<T> {
+                fn regular_call(self: @T, contract_address: ContractAddress) -> felt252;
+            }
+
+            #[test]
+            fn expect_call_exactly_unmet() {
+                let checker = declare("TraceInfoChecker").unwrap().contract_class();
+                let (checker_address, _) = checker.deploy(@array![]).unwrap();
+                let proxy = declare("TraceInfoProxy").unwrap().contract_class();
+                let (proxy_address, _) = proxy.deploy(@array![checker_address.into()]).unwrap();
+
+                expect_call(
+                    checker_address,
+                    selector!("from_proxy"),
+                    CalldataMatcher::Prefix(array![2]),
+                    ExpectedCallCount::Exactly(2),
+                );
+
+                ITraceInfoProxyDispatcher { contract_address: proxy_address }
+                    .regular_call(checker_address);
+            }
+
+            #[test]
+            fn expect_call_never_unmet() {
+                let checker = declare("TraceInfoChecker").unwrap().contract_class();
+                let (checker_address, _) = checker.deploy(@array![]).unwrap();
+
+                expect_call(
+                    checker_address,
+                    selector!("from_proxy"),
+                    CalldataMatcher::Any,
+                    ExpectedCallCount::Never,
+                );
+
+                let proxy = declare("TraceInfoProxy").unwrap().contract_class();
+                proxy.deploy(@array![checker_address.into()]).unwrap();
+            }
+
+            #[test]
+            fn expect_call_at_least_once_unmet() {
+                let checker = declare("TraceInfoChecker").unwrap().contract_class();
+                let (checker_address, _) = checker.deploy(@array![]).unwrap();
+
+                expect_call(
+                    checker_address,
+                    selector!("panic"),
+                    CalldataMatcher::Any,
+                    ExpectedCallCount::AtLeastOnce,
+                );
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "TraceInfoProxy".to_string(),
+            Path::new("tests/data/contracts/trace_info_proxy.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "TraceInfoChecker".to_string(),
+            Path::new("tests/data/contracts/trace_info_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "expect_call_exactly_unmet",
+        "Call expectations were not met",
+    );
+    assert_case_output_contains(
+        &result,
+        "expect_call_exactly_unmet",
+        "Expected 2 call(s) to function",
+    );
+    assert_case_output_contains(
+        &result,
+        "expect_call_exactly_unmet",
+        "with calldata starting with [0x2], but got 1",
+    );
+    assert_case_output_contains(&result, "expect_call_exactly_unmet", "+ [0x2]");
+    assert_case_output_contains(
+        &result,
+        "expect_call_never_unmet",
+        "Expected no calls to function",
+    );
+    assert_case_output_contains(&result, "expect_call_never_unmet", "+ [0x1]");
+    assert_case_output_contains(
+        &result,
+        "expect_call_at_least_once_unmet",
+        "Expected at least one call to function",
+    );
+    assert_case_output_contains(
+        &result,
+        "expect_call_at_least_once_unmet",
+        "No calls were made to function",
+    );
+}
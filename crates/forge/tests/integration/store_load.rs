@@ -599,3 +599,274 @@ fn fork_store_load() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn record_storage_accesses() {
+    let test = test_utils::test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, store, start_record_storage,
+                stop_record_storage, get_storage_accesses,
+            };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn get_balance(ref self: TContractState) -> felt252;
+                fn increase_balance(ref self: TContractState, amount: felt252);
+            }
+
+            #[test]
+            fn record_storage_accesses() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                start_record_storage();
+                dispatcher.increase_balance(5);
+                stop_record_storage();
+                dispatcher.increase_balance(5);
+
+                let accesses = get_storage_accesses(contract_address);
+                assert(accesses.reads == array![selector!("balance")], 'Wrong reads');
+                assert(accesses.writes == array![selector!("balance")], 'Wrong writes');
+
+                // The recorded slot can be written to directly
+                store(contract_address, *accesses.writes.at(0), array![100].span());
+                assert(dispatcher.get_balance() == 100, 'Wrong balance');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn interact_with_state_by_variable_paths() {
+    let test = test_utils::test_case!(
+        indoc!(
+            r#"
+            use starknet::storage::{
+                StoragePointerReadAccess, StoragePointerWriteAccess, StoragePathEntry,
+                MutableVecTrait, VecTrait,
+            };
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, interact_with_state};
+
+            #[derive(Serde, Drop)]
+            struct NestedStructure {
+                c: felt252,
+            }
+
+            #[derive(Serde, Drop)]
+            struct StoredStructure {
+                a: felt252,
+                b: NestedStructure,
+            }
+
+            #[starknet::interface]
+            trait IStorageTester<TContractState> {
+                fn insert_structure(ref self: TContractState, value: StoredStructure);
+                fn read_felt_to_structure(self: @TContractState, key: felt252) -> StoredStructure;
+                fn read_felt_to_felt(self: @TContractState, key: felt252) -> felt252;
+            }
+
+            // Storage variables of `StorageTester`, and variables of other types
+            #[starknet::contract]
+            mod StorageTesterState {
+                use starknet::storage::{Map, Vec};
+
+                #[derive(Drop, starknet::Store)]
+                pub struct NestedStructure {
+                    pub c: felt252,
+                }
+
+                #[derive(Drop, starknet::Store)]
+                pub struct StoredStructure {
+                    pub a: felt252,
+                    pub b: NestedStructure,
+                }
+
+                #[storage]
+                pub struct Storage {
+                    pub structure: StoredStructure,
+                    pub felt_to_structure: Map<felt252, StoredStructure>,
+                    pub felt_to_felt: Map<felt252, felt252>,
+                    pub numbers: Vec<felt252>,
+                    pub maybe_amount: Option<u256>,
+                }
+            }
+
+            #[test]
+            fn interact_with_state_by_variable_paths() {
+                let contract = declare("StorageTester").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IStorageTesterDispatcher { contract_address };
+
+                interact_with_state(
+                    contract_address,
+                    || {
+                        let mut state = StorageTesterState::contract_state_for_testing();
+                        state.felt_to_structure.entry(1).b.c.write(420);
+                        state.felt_to_felt.entry(2).write(3);
+                        state.numbers.append().write(7);
+                        state.maybe_amount.write(Option::Some(100));
+                    },
+                );
+
+                assert(dispatcher.read_felt_to_structure(1).b.c == 420, 'Wrong nested member');
+                assert(dispatcher.read_felt_to_structure(1).a == 0, 'Wrong member');
+                assert(dispatcher.read_felt_to_felt(2) == 3, 'Wrong map entry');
+
+                dispatcher.insert_structure(StoredStructure { a: 5, b: NestedStructure { c: 6 } });
+
+                let (a, c, number, maybe_amount) = interact_with_state(
+                    contract_address,
+                    || {
+                        let state = StorageTesterState::contract_state_for_testing();
+                        (
+                            state.structure.a.read(),
+                            state.structure.b.c.read(),
+                            state.numbers.at(0).read(),
+                            state.maybe_amount.read(),
+                        )
+                    },
+                );
+                assert(a == 5, 'Wrong member read');
+                assert(c == 6, 'Wrong nested member read');
+                assert(number == 7, 'Wrong vec element read');
+                assert(maybe_amount == Option::Some(100), 'Wrong option read');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "StorageTester".to_string(),
+            Path::new("tests/data/contracts/storage_tester.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn interact_with_state_nested() {
+    let test = test_utils::test_case!(
+        indoc!(
+            r#"
+            use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess, StoragePathEntry};
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, interact_with_state};
+
+            #[starknet::contract]
+            mod StorageTesterState {
+                use starknet::storage::Map;
+
+                #[storage]
+                pub struct Storage {
+                    pub felt_to_felt: Map<felt252, felt252>,
+                }
+            }
+
+            #[test]
+            fn interact_with_state_nested() {
+                let contract = declare("StorageTester").unwrap().contract_class();
+                let (first_address, _) = contract.deploy(@array![]).unwrap();
+                let (second_address, _) = contract.deploy(@array![]).unwrap();
+
+                interact_with_state(
+                    first_address,
+                    || {
+                        let mut state = StorageTesterState::contract_state_for_testing();
+                        state.felt_to_felt.entry(1).write(10);
+
+                        interact_with_state(
+                            second_address,
+                            || {
+                                let mut state = StorageTesterState::contract_state_for_testing();
+                                state.felt_to_felt.entry(1).write(20);
+                            },
+                        );
+
+                        // Storage of the outer contract is accessed again
+                        state.felt_to_felt.entry(2).write(30);
+                    },
+                );
+
+                let first = interact_with_state(
+                    first_address,
+                    || {
+                        let state = StorageTesterState::contract_state_for_testing();
+                        (state.felt_to_felt.entry(1).read(), state.felt_to_felt.entry(2).read())
+                    },
+                );
+                let second = interact_with_state(
+                    second_address,
+                    || {
+                        let state = StorageTesterState::contract_state_for_testing();
+                        (state.felt_to_felt.entry(1).read(), state.felt_to_felt.entry(2).read())
+                    },
+                );
+                assert(first == (10, 30), 'Wrong outer storage');
+                assert(second == (20, 0), 'Wrong inner storage');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "StorageTester".to_string(),
+            Path::new("tests/data/contracts/storage_tester.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn interact_with_state_rejects_calls() {
+    let test = test_utils::test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, interact_with_state};
+
+            #[starknet::interface]
+            trait IStorageTester<TContractState> {
+                fn read_felt_to_felt(self: @TContractState, key: felt252) -> felt252;
+            }
+
+            #[test]
+            fn interact_with_state_rejects_calls() {
+                let contract = declare("StorageTester").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IStorageTesterDispatcher { contract_address };
+
+                interact_with_state(contract_address, || dispatcher.read_felt_to_felt(1));
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "StorageTester".to_string(),
+            Path::new("tests/data/contracts/storage_tester.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "interact_with_state_rejects_calls",
+        "Calls and deployments can't be made inside `interact_with_state`",
+    );
+}
@@ -0,0 +1,93 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+
+#[test]
+fn finishes_within_timeout() {
+    let test = test_utils::test_case!(indoc!(
+        r"
+        #[test]
+        #[timeout(seconds: 60)]
+        fn finishes_within_timeout() {
+            assert(1 + 1 == 2, 'simple check');
+        }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn exceeds_timeout() {
+    let test = test_utils::test_case!(indoc!(
+        r"
+        #[test]
+        #[timeout(seconds: 1)]
+        fn exceeds_timeout() {
+            let mut i: u64 = 0;
+            while i != 100_000_000 {
+                i += 1;
+            };
+        }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "exceeds_timeout", "Test timed out after 1s");
+}
+
+#[test]
+fn fuzz_run_exceeds_timeout() {
+    let test = test_utils::test_case!(indoc!(
+        r"
+        #[test]
+        #[fuzzer(runs: 10, seed: 100)]
+        #[timeout(seconds: 1)]
+        fn fuzz_run_exceeds_timeout(a: u8) {
+            let mut i: u64 = 0;
+            while i != 100_000_000 {
+                i += 1;
+            };
+        }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "fuzz_run_exceeds_timeout",
+        "Test timed out after 1s",
+    );
+}
+
+#[test]
+fn infinite_loop_is_interrupted() {
+    let test = test_utils::test_case!(indoc!(
+        r"
+        #[test]
+        #[timeout(seconds: 1)]
+        fn infinite_loop_is_interrupted() {
+            let mut i: u64 = 0;
+            loop {
+                i = (i + 1) % 1000;
+            }
+        }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "infinite_loop_is_interrupted",
+        "Test timed out after 1s",
+    );
+}
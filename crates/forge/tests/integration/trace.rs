@@ -77,10 +77,14 @@ fn trace_deploy() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![101])
+                                    result: CallResult::Success(array![101]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![])
+                            result: CallResult::Success(array![]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -102,10 +106,14 @@ fn trace_deploy() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![101])
+                                    result: CallResult::Success(array![101]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![])
+                            result: CallResult::Success(array![]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -127,13 +135,19 @@ fn trace_deploy() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![101])
+                                    result: CallResult::Success(array![101]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![])
+                            result: CallResult::Success(array![]),
+                            storage_accesses: array![],
+                            events: array![]
                         }
                     ],
-                    result: CallResult::Success(array![])
+                    result: CallResult::Success(array![]),
+                    storage_accesses: array![],
+                    events: array![]
                 };
 
                 assert(trace == expected_trace, '');
@@ -165,7 +179,7 @@ fn trace_call() {
             r#"
             use core::clone::Clone;
             use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, test_address, test_selector, start_cheat_caller_address};
-            use snforge_std::trace::{CallTrace, CallEntryPoint, CallType, EntryPointType, get_call_trace, CallResult};
+            use snforge_std::trace::{CallTrace, CallEntryPoint, CallType, EntryPointType, get_call_trace, CallResult, StorageAccess, StorageAccessKind};
 
             use starknet::{ContractAddress, ClassHash};
 
@@ -249,10 +263,14 @@ fn trace_call() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![101])
+                                    result: CallResult::Success(array![101]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 },
                             ],
-                            result: CallResult::Success(array![])
+                            result: CallResult::Success(array![]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -274,10 +292,14 @@ fn trace_call() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![102])
+                                    result: CallResult::Success(array![102]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![102])
+                            result: CallResult::Success(array![102]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -299,10 +321,14 @@ fn trace_call() {
                                         call_type: CallType::Delegate,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![103])
+                                    result: CallResult::Success(array![103]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![103])
+                            result: CallResult::Success(array![103]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -324,7 +350,9 @@ fn trace_call() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![142])
+                                    result: CallResult::Success(array![142]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 },
                                 CallTrace {
                                     entry_point: CallEntryPoint {
@@ -336,10 +364,22 @@ fn trace_call() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![])
+                                    result: CallResult::Success(array![]),
+                                    storage_accesses: array![
+                                        StorageAccess {
+                                            kind: StorageAccessKind::Write,
+                                            contract_address: dummy_address,
+                                            key: selector!("balance"),
+                                            old_value: 0,
+                                            new_value: 7,
+                                        }
+                                    ],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![])
+                            result: CallResult::Success(array![]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -351,10 +391,14 @@ fn trace_call() {
                                 call_type: CallType::Call,
                             },
                             nested_calls: array![],
-                            result: CallResult::Success(array![104])
+                            result: CallResult::Success(array![104]),
+                            storage_accesses: array![],
+                            events: array![]
                         }
                     ],
-                    result: CallResult::Success(array![])
+                    result: CallResult::Success(array![]),
+                    storage_accesses: array![],
+                    events: array![]
                 };
 
                 assert(expected == trace, '');
@@ -464,10 +508,14 @@ fn trace_failed_call() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![101])
+                                    result: CallResult::Success(array![101]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 },
                             ],
-                            result: CallResult::Success(array![])
+                            result: CallResult::Success(array![]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -489,10 +537,14 @@ fn trace_failed_call() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Failure(CallFailure::Panic(array![482670963043]))
+                                    result: CallResult::Failure(CallFailure::Panic(array![482670963043])),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Failure(CallFailure::Panic(array![482670963043]))
+                            result: CallResult::Failure(CallFailure::Panic(array![482670963043])),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -504,10 +556,14 @@ fn trace_failed_call() {
                                 call_type: CallType::Call,
                             },
                             nested_calls: array![],
-                            result: CallResult::Failure(CallFailure::Panic(array![482670963043]))
+                            result: CallResult::Failure(CallFailure::Panic(array![482670963043])),
+                            storage_accesses: array![],
+                            events: array![]
                         }
                     ],
-                    result: CallResult::Success(array![])
+                    result: CallResult::Success(array![]),
+                    storage_accesses: array![],
+                    events: array![]
                 };
 
                 assert(expected == trace, '');
@@ -539,7 +595,7 @@ fn trace_library_call_from_test() {
             r#"
             use core::clone::Clone;
             use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, test_address, test_selector};
-            use snforge_std::trace::{CallEntryPoint, CallType, EntryPointType, get_call_trace, CallTrace, CallResult};
+            use snforge_std::trace::{CallEntryPoint, CallType, EntryPointType, get_call_trace, CallTrace, CallResult, StorageAccess, StorageAccessKind};
 
             use starknet::{ContractAddress, ClassHash};
 
@@ -622,10 +678,14 @@ fn trace_library_call_from_test() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![102])
+                                    result: CallResult::Success(array![102]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![102])
+                            result: CallResult::Success(array![102]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -647,10 +707,14 @@ fn trace_library_call_from_test() {
                                         call_type: CallType::Delegate,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![103])
+                                    result: CallResult::Success(array![103]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![103])
+                            result: CallResult::Success(array![103]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -672,7 +736,9 @@ fn trace_library_call_from_test() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![142])
+                                    result: CallResult::Success(array![142]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 },
                                 CallTrace {
                                     entry_point: CallEntryPoint {
@@ -684,10 +750,22 @@ fn trace_library_call_from_test() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![])
+                                    result: CallResult::Success(array![]),
+                                    storage_accesses: array![
+                                        StorageAccess {
+                                            kind: StorageAccessKind::Write,
+                                            contract_address: dummy_address,
+                                            key: selector!("balance"),
+                                            old_value: 0,
+                                            new_value: 7,
+                                        }
+                                    ],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![])
+                            result: CallResult::Success(array![]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -699,10 +777,14 @@ fn trace_library_call_from_test() {
                                 call_type: CallType::Delegate,
                             },
                             nested_calls: array![],
-                            result: CallResult::Success(array![104])
+                            result: CallResult::Success(array![104]),
+                            storage_accesses: array![],
+                            events: array![]
                         }
                     ],
-                    result: CallResult::Success(array![])
+                    result: CallResult::Success(array![]),
+                    storage_accesses: array![],
+                    events: array![]
                 };
 
                 assert(expected == trace, '');
@@ -812,10 +894,14 @@ fn trace_failed_library_call_from_test() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![101])
+                                    result: CallResult::Success(array![101]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 },
                             ],
-                            result: CallResult::Success(array![])
+                            result: CallResult::Success(array![]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -837,10 +923,14 @@ fn trace_failed_library_call_from_test() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Failure(CallFailure::Panic(array![482670963043]))
+                                    result: CallResult::Failure(CallFailure::Panic(array![482670963043])),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Failure(CallFailure::Panic(array![482670963043]))
+                            result: CallResult::Failure(CallFailure::Panic(array![482670963043])),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -852,10 +942,14 @@ fn trace_failed_library_call_from_test() {
                                 call_type: CallType::Call,
                             },
                             nested_calls: array![],
-                            result: CallResult::Failure(CallFailure::Panic(array![482670963043]))
+                            result: CallResult::Failure(CallFailure::Panic(array![482670963043])),
+                            storage_accesses: array![],
+                            events: array![]
                         }
                     ],
-                    result: CallResult::Success(array![])
+                    result: CallResult::Success(array![]),
+                    storage_accesses: array![],
+                    events: array![]
                 };
 
                 assert(expected == trace, '');
@@ -937,10 +1031,14 @@ fn trace_l1_handler() {
                                         call_type: CallType::Call,
                                     },
                                     nested_calls: array![],
-                                    result: CallResult::Success(array![101])
+                                    result: CallResult::Success(array![101]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![])
+                            result: CallResult::Success(array![]),
+                            storage_accesses: array![],
+                            events: array![]
                         },
                         CallTrace {
                             entry_point: CallEntryPoint {
@@ -972,16 +1070,24 @@ fn trace_l1_handler() {
                                                 call_type: CallType::Call,
                                             },
                                             nested_calls: array![],
-                                            result: CallResult::Success(array![102])
+                                            result: CallResult::Success(array![102]),
+                                            storage_accesses: array![],
+                                            events: array![]
                                         }
                                     ],
-                                    result: CallResult::Success(array![102])
+                                    result: CallResult::Success(array![102]),
+                                    storage_accesses: array![],
+                                    events: array![]
                                 }
                             ],
-                            result: CallResult::Success(array![102])
+                            result: CallResult::Success(array![102]),
+                            storage_accesses: array![],
+                            events: array![]
                         }
                     ],
-                    result: CallResult::Success(array![])
+                    result: CallResult::Success(array![]),
+                    storage_accesses: array![],
+                    events: array![]
                 };
 
                 assert(trace == expected_trace, '');
@@ -1004,3 +1110,87 @@ fn trace_l1_handler() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn trace_storage_accesses_and_events() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, Event};
+            use snforge_std::trace::{get_call_trace, StorageAccess, StorageAccessKind};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+            }
+
+            #[starknet::interface]
+            trait ISpyEventsChecker<TContractState> {
+                fn emit_one_event(ref self: TContractState, some_data: felt252);
+            }
+
+            #[test]
+            fn test_storage_accesses_and_events() {
+                let hello = declare("HelloStarknet").unwrap().contract_class();
+                let (hello_address, _) = hello.deploy(@array![]).unwrap();
+                let spy_events_checker = declare("SpyEventsChecker").unwrap().contract_class();
+                let (spy_events_checker_address, _) = spy_events_checker.deploy(@array![]).unwrap();
+
+                IHelloStarknetDispatcher { contract_address: hello_address }.increase_balance(5);
+                ISpyEventsCheckerDispatcher { contract_address: spy_events_checker_address }
+                    .emit_one_event(123);
+
+                let trace = get_call_trace();
+                let increase_balance_call = trace.nested_calls.at(0).clone();
+                let emit_one_event_call = trace.nested_calls.at(1).clone();
+
+                let expected_storage_accesses = array![
+                    StorageAccess {
+                        kind: StorageAccessKind::Read,
+                        contract_address: hello_address,
+                        key: selector!("balance"),
+                        old_value: 0,
+                        new_value: 0,
+                    },
+                    StorageAccess {
+                        kind: StorageAccessKind::Write,
+                        contract_address: hello_address,
+                        key: selector!("balance"),
+                        old_value: 0,
+                        new_value: 5,
+                    },
+                ];
+                assert(
+                    increase_balance_call.storage_accesses == expected_storage_accesses,
+                    'Wrong storage accesses'
+                );
+                assert(increase_balance_call.events == array![], 'Unexpected events');
+
+                let expected_events = array![
+                    (
+                        spy_events_checker_address,
+                        Event { keys: array![selector!("FirstEvent")], data: array![123] }
+                    ),
+                ];
+                assert(emit_one_event_call.storage_accesses == array![], 'Unexpected storage accesses');
+                assert(emit_one_event_call.events == expected_events, 'Wrong events');
+                assert(trace.storage_accesses == array![], 'Test accessed storage');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "SpyEventsChecker".to_string(),
+            Path::new("tests/data/contracts/spy_events_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
@@ -0,0 +1,479 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+
+const SETUP: &str = indoc!(
+    r#"
+    use core::num::traits::Zero;
+    use starknet::ContractAddress;
+    use snforge_std::{
+        declare, ContractClassTrait, DeclareResultTrait, store, load, map_entry_address,
+        set_transaction_account, remove_transaction_account, last_transaction_receipt,
+        TransactionSigner, TransactionExecutionStatus,
+    };
+    use snforge_std::signature::KeyPairTrait;
+    use snforge_std::signature::stark_curve::StarkCurveKeyPairImpl;
+
+    #[starknet::interface]
+    trait IHelloStarknet<TContractState> {
+        fn increase_balance(ref self: TContractState, amount: felt252);
+        fn get_balance(self: @TContractState) -> felt252;
+        fn do_a_panic(self: @TContractState);
+    }
+
+    const SECRET_KEY: felt252 = 0x1234;
+
+    fn strk_balance(address: ContractAddress) -> u256 {
+        let balance = load(
+            0x1001.try_into().unwrap(),
+            map_entry_address(selector!("ERC20_balances"), array![address.into()].span()),
+            2,
+        );
+        u256 { low: (*balance[0]).try_into().unwrap(), high: (*balance[1]).try_into().unwrap() }
+    }
+
+    fn deploy_account(strk: u128) -> ContractAddress {
+        let key_pair = StarkCurveKeyPairImpl::from_secret_key(SECRET_KEY);
+        let contract = declare("SimpleAccount").unwrap().contract_class();
+        let (account, _) = contract.deploy(@array![key_pair.public_key]).unwrap();
+
+        store(
+            0x1001.try_into().unwrap(),
+            map_entry_address(selector!("ERC20_balances"), array![account.into()].span()),
+            array![strk.into(), 0].span(),
+        );
+
+        account
+    }
+
+    fn deploy_hello() -> IHelloStarknetDispatcher {
+        let contract = declare("HelloStarknet").unwrap().contract_class();
+        let (contract_address, _) = contract.deploy(@array![]).unwrap();
+        IHelloStarknetDispatcher { contract_address }
+    }
+    "#
+);
+
+fn contracts() -> [Contract; 2] {
+    [
+        Contract::from_code_path(
+            "SimpleAccount".to_string(),
+            Path::new("tests/data/contracts/simple_account.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap(),
+    ]
+}
+
+#[test]
+fn transaction_is_executed_and_charged() {
+    let [account, hello] = contracts();
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r#"
+                #[test]
+                #[transactional]
+                fn transaction_is_executed_and_charged() {
+                    let account = deploy_account(1000000000000000000000);
+                    let hello = deploy_hello();
+
+                    set_transaction_account(account, TransactionSigner::StarkCurve(SECRET_KEY));
+                    hello.increase_balance(5);
+                    assert(hello.get_balance() == 5, 'balance not increased');
+
+                    let receipt = last_transaction_receipt().unwrap();
+                    assert(receipt.execution_status == TransactionExecutionStatus::Succeeded, 'not succeeded');
+                    assert(receipt.revert_reason.is_none(), 'revert reason set');
+                    assert(receipt.actual_fee.is_non_zero(), 'fee not charged');
+
+                    let first_fee: u256 = 1000000000000000000000 - strk_balance(account);
+                    assert(first_fee.is_non_zero(), 'balance not charged');
+                    assert(strk_balance(0x1000.try_into().unwrap()) == first_fee, 'sequencer not paid');
+
+                    remove_transaction_account();
+                    hello.increase_balance(5);
+                    assert(hello.get_balance() == 10, 'balance not increased');
+                    assert(1000000000000000000000 - strk_balance(account) == first_fee, 'charged directly');
+                }
+                "#
+            )
+        ),
+        account,
+        hello
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::SierraGas);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn reverted_transaction_keeps_state_and_charges_fee() {
+    let [account, hello] = contracts();
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r#"
+                #[starknet::interface]
+                trait IHelloStarknetSafe<TContractState> {
+                    fn do_a_panic(self: @TContractState);
+                }
+
+                #[test]
+                #[transactional]
+                fn reverted_transaction_keeps_state_and_charges_fee() {
+                    let account = deploy_account(1000000000000000000000);
+                    let hello = deploy_hello();
+                    let safe_hello = IHelloStarknetSafeSafeDispatcher {
+                        contract_address: hello.contract_address,
+                    };
+
+                    set_transaction_account(account, TransactionSigner::StarkCurve(SECRET_KEY));
+                    match safe_hello.do_a_panic() {
+                        Result::Ok(_) => panic!("Should have panicked"),
+                        Result::Err(panic_data) => assert(*panic_data[0] == 'PANIC', 'wrong panic data'),
+                    }
+
+                    let receipt = last_transaction_receipt().unwrap();
+                    assert(receipt.execution_status == TransactionExecutionStatus::Reverted, 'not reverted');
+                    assert(receipt.revert_reason.is_some(), 'no revert reason');
+                    assert(receipt.actual_fee.is_non_zero(), 'fee not charged');
+                    assert(strk_balance(account) < 1000000000000000000000, 'balance not charged');
+                }
+                "#
+            )
+        ),
+        account,
+        hello
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::SierraGas);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn transaction_with_invalid_signature_is_rejected() {
+    let [account, hello] = contracts();
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r#"
+                #[starknet::interface]
+                trait IHelloStarknetSafe<TContractState> {
+                    fn increase_balance(ref self: TContractState, amount: felt252);
+                }
+
+                #[test]
+                #[transactional]
+                fn transaction_with_invalid_signature_is_rejected() {
+                    let account = deploy_account(1000000000000000000000);
+                    let hello = deploy_hello();
+                    let safe_hello = IHelloStarknetSafeSafeDispatcher {
+                        contract_address: hello.contract_address,
+                    };
+
+                    set_transaction_account(account, TransactionSigner::Signature(array![1, 2]));
+                    match safe_hello.increase_balance(5) {
+                        Result::Ok(_) => panic!("Should have been rejected"),
+                        Result::Err(panic_data) => assert(*panic_data[0] == 'Invalid signature', 'wrong panic data'),
+                    }
+
+                    assert(last_transaction_receipt().is_none(), 'receipt for rejected');
+                    assert(strk_balance(account) == 1000000000000000000000, 'rejected tx charged');
+
+                    remove_transaction_account();
+                    assert(hello.get_balance() == 0, 'state changed');
+                }
+                "#
+            )
+        ),
+        account,
+        hello
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::SierraGas);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn transaction_fee_uses_cheated_gas_prices() {
+    let [account, hello] = contracts();
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r#"
+                use snforge_std::{
+                    snapshot, revert_to, start_cheat_block_gas_prices, BlockGasPrices, GasPrices,
+                };
+
+                #[test]
+                #[transactional]
+                fn transaction_fee_uses_cheated_gas_prices() {
+                    let account = deploy_account(1000000000000000000000);
+                    let hello = deploy_hello();
+                    set_transaction_account(account, TransactionSigner::StarkCurve(SECRET_KEY));
+
+                    let id = snapshot();
+                    hello.increase_balance(5);
+                    let default_fee = last_transaction_receipt().unwrap().actual_fee;
+                    revert_to(id);
+
+                    let gas_prices = GasPrices {
+                        l1_gas_price: 200000000000,
+                        l1_data_gas_price: 2000000000,
+                        l2_gas_price: 20000000000000,
+                    };
+                    start_cheat_block_gas_prices(account, BlockGasPrices { eth: gas_prices, strk: gas_prices });
+                    hello.increase_balance(5);
+
+                    let receipt = last_transaction_receipt().unwrap();
+                    assert(receipt.actual_fee == default_fee * 2, 'fee not doubled');
+                    assert(1000000000000000000000 - strk_balance(account) == (default_fee * 2).into(), 'balance not charged');
+                }
+                "#
+            )
+        ),
+        account,
+        hello
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::SierraGas);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn transaction_fee_is_paid_to_cheated_sequencer() {
+    let [account, hello] = contracts();
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r#"
+                use snforge_std::start_cheat_sequencer_address;
+
+                #[test]
+                #[transactional]
+                fn transaction_fee_is_paid_to_cheated_sequencer() {
+                    let account = deploy_account(1000000000000000000000);
+                    let hello = deploy_hello();
+                    set_transaction_account(account, TransactionSigner::StarkCurve(SECRET_KEY));
+
+                    start_cheat_sequencer_address(account, 0x2000.try_into().unwrap());
+                    hello.increase_balance(5);
+
+                    let fee: u256 = last_transaction_receipt().unwrap().actual_fee.into();
+                    assert(strk_balance(0x2000.try_into().unwrap()) == fee, 'sequencer not paid');
+                    assert(strk_balance(0x1000.try_into().unwrap()) == 0, 'wrong sequencer paid');
+                }
+                "#
+            )
+        ),
+        account,
+        hello
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::SierraGas);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn transaction_fee_saturates_at_balance() {
+    let [account, hello] = contracts();
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r#"
+                use snforge_std::{start_cheat_block_gas_prices, BlockGasPrices, GasPrices};
+
+                #[starknet::interface]
+                trait IHelloStarknetSafe<TContractState> {
+                    fn increase_balance(ref self: TContractState, amount: felt252);
+                }
+
+                #[test]
+                #[transactional]
+                fn transaction_fee_saturates_at_balance() {
+                    let account = deploy_account(1000000000000000000000);
+                    let hello = deploy_hello();
+                    let safe_hello = IHelloStarknetSafeSafeDispatcher {
+                        contract_address: hello.contract_address,
+                    };
+                    set_transaction_account(account, TransactionSigner::StarkCurve(SECRET_KEY));
+
+                    let gas_prices = GasPrices {
+                        l1_gas_price: 1,
+                        l1_data_gas_price: 1,
+                        l2_gas_price: 0xffffffffffffffffffffffffffffffff,
+                    };
+                    start_cheat_block_gas_prices(account, BlockGasPrices { eth: gas_prices, strk: gas_prices });
+                    assert(safe_hello.increase_balance(5).is_err(), 'Should have failed');
+
+                    let receipt = last_transaction_receipt().unwrap();
+                    assert(receipt.execution_status == TransactionExecutionStatus::Reverted, 'not reverted');
+                    assert(receipt.actual_fee == 1000000000000000000000, 'wrong fee');
+                    assert(strk_balance(account) == 0, 'balance not charged');
+
+                    remove_transaction_account();
+                    assert(hello.get_balance() == 0, 'state not reverted');
+                }
+                "#
+            )
+        ),
+        account,
+        hello
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::SierraGas);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn transaction_account_requires_transactional_mode() {
+    let [account, hello] = contracts();
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{}",
+            indoc!(
+                r#"
+                #[test]
+                fn not_transactional() {
+                    let account = deploy_account(1000000000000000000000);
+                    set_transaction_account(account, TransactionSigner::StarkCurve(SECRET_KEY));
+                }
+                "#
+            )
+        ),
+        account,
+        hello
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::SierraGas);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "not_transactional",
+        "Transactions can only be sent in transactional tests, mark the test with #[transactional] or set `transactional = true` in [tool.snforge]",
+    );
+}
+
+const VALIDATING_ACCOUNT_SETUP: &str = indoc!(
+    r#"
+    use snforge_std::{spy_events, EventSpyTrait};
+
+    fn deploy_validating_account() -> ContractAddress {
+        let contract = declare("ValidatingAccount").unwrap().contract_class();
+        let (account, _) = contract.deploy(@array![]).unwrap();
+
+        store(
+            0x1001.try_into().unwrap(),
+            map_entry_address(selector!("ERC20_balances"), array![account.into()].span()),
+            array![1000000000000000000000, 0].span(),
+        );
+
+        account
+    }
+    "#
+);
+
+fn validating_account() -> Contract {
+    Contract::from_code_path(
+        "ValidatingAccount".to_string(),
+        Path::new("tests/data/contracts/validating_account.cairo"),
+    )
+    .unwrap()
+}
+
+#[test]
+fn rejected_transaction_discards_validate_events() {
+    let [_, hello] = contracts();
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{VALIDATING_ACCOUNT_SETUP}{}",
+            indoc!(
+                r#"
+                #[starknet::interface]
+                trait IHelloStarknetSafe<TContractState> {
+                    fn increase_balance(ref self: TContractState, amount: felt252);
+                }
+
+                #[test]
+                #[transactional]
+                fn rejected_transaction_discards_validate_events() {
+                    let account = deploy_validating_account();
+                    let hello = deploy_hello();
+                    let safe_hello = IHelloStarknetSafeSafeDispatcher {
+                        contract_address: hello.contract_address,
+                    };
+                    let mut spy = spy_events();
+
+                    set_transaction_account(account, TransactionSigner::Signature(array!['invalid']));
+                    assert(safe_hello.increase_balance(5).is_err(), 'Should have been rejected');
+                    assert(spy.get_events().events.len() == 0, 'Events of rejected tx kept');
+
+                    set_transaction_account(account, TransactionSigner::Signature(array!['valid']));
+                    hello.increase_balance(5);
+                    assert(spy.get_events().events.len() == 1, 'Validate event not emitted');
+                }
+                "#
+            )
+        ),
+        validating_account(),
+        hello
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::SierraGas);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn validate_runs_in_validate_mode() {
+    let [_, hello] = contracts();
+    let test = test_utils::test_case!(
+        &format!(
+            "{SETUP}{VALIDATING_ACCOUNT_SETUP}{}",
+            indoc!(
+                r#"
+                #[test]
+                #[transactional]
+                fn validate_runs_in_validate_mode() {
+                    let account = deploy_validating_account();
+                    let hello = deploy_hello();
+
+                    set_transaction_account(account, TransactionSigner::Signature(array!['block_hash']));
+                    hello.increase_balance(5);
+                }
+                "#
+            )
+        ),
+        validating_account(),
+        hello
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::SierraGas);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "validate_runs_in_validate_mode",
+        "Unauthorized syscall get_block_hash in execution mode Validate",
+    );
+}
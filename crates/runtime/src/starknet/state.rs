@@ -13,6 +13,11 @@ use std::collections::HashMap;
 pub struct DictStateReader {
     pub address_to_class_hash: HashMap<ContractAddress, ClassHash>,
     pub class_hash_to_class: HashMap<ClassHash, ContractClass>,
+    pub storage_view: HashMap<(ContractAddress, StorageKey), Felt>,
+    pub address_to_nonce: HashMap<ContractAddress, Nonce>,
+    /// Classes that are already compiled, e.g. declared by a setup function
+    pub class_hash_to_compiled_class: HashMap<ClassHash, RunnableCompiledClass>,
+    pub class_hash_to_compiled_class_hash: HashMap<ClassHash, CompiledClassHash>,
 }
 
 impl StateReader for DictStateReader {
@@ -21,15 +26,25 @@ impl StateReader for DictStateReader {
         contract_address: ContractAddress,
         key: StorageKey,
     ) -> StateResult<Felt> {
-        Err(StateError::StateReadError(format!(
-            "Unable to get storage at address: {contract_address:?} and key: {key:?} from DictStateReader"
-        )))
+        self.storage_view
+            .get(&(contract_address, key))
+            .copied()
+            .ok_or_else(|| {
+                StateError::StateReadError(format!(
+                    "Unable to get storage at address: {contract_address:?} and key: {key:?} from DictStateReader"
+                ))
+            })
     }
 
     fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
-        Err(StateError::StateReadError(format!(
-            "Unable to get nonce at {contract_address:?} from DictStateReader"
-        )))
+        self.address_to_nonce
+            .get(&contract_address)
+            .copied()
+            .ok_or_else(|| {
+                StateError::StateReadError(format!(
+                    "Unable to get nonce at {contract_address:?} from DictStateReader"
+                ))
+            })
     }
 
     fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
@@ -40,6 +55,10 @@ impl StateReader for DictStateReader {
     }
 
     fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        if let Some(compiled_class) = self.class_hash_to_compiled_class.get(&class_hash) {
+            return Ok(compiled_class.clone());
+        }
+
         let contract_class = self.class_hash_to_class.get(&class_hash).cloned();
         match contract_class {
             Some(contract_class) => Ok(contract_class.try_into()?),
@@ -48,8 +67,13 @@ impl StateReader for DictStateReader {
     }
 
     fn get_compiled_class_hash(&self, class_hash: ClassHash) -> StateResult<CompiledClassHash> {
-        Err(StateError::StateReadError(format!(
-            "Unable to get compiled class hash at {class_hash:?} from DictStateReader"
-        )))
+        self.class_hash_to_compiled_class_hash
+            .get(&class_hash)
+            .copied()
+            .ok_or_else(|| {
+                StateError::StateReadError(format!(
+                    "Unable to get compiled class hash at {class_hash:?} from DictStateReader"
+                ))
+            })
     }
 }
@@ -1,8 +1,9 @@
 use crate::starknet_commands::{
     account, account::Account, call::Call, declare::Declare, deploy::Deploy, invoke::Invoke,
-    multicall::Multicall, script::Script, show_config::ShowConfig, tx_status::TxStatus,
+    multicall::Multicall, receipt::Receipt, script::Script, show_config::ShowConfig,
+    tx_status::TxStatus,
 };
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, anyhow};
 use data_transformer::Calldata;
 use sncast::helpers::account::generate_account_name;
 use sncast::response::explorer_link::print_block_explorer_link_if_allowed;
@@ -15,6 +16,7 @@ use camino::Utf8PathBuf;
 use clap::{CommandFactory, Parser, Subcommand};
 use configuration::load_config;
 use shared::auto_completions::{Completion, generate_completions};
+use shared::print::print_as_warning;
 use sncast::helpers::config::{combine_cast_configs, get_global_config_path};
 use sncast::helpers::configuration::CastConfig;
 use sncast::helpers::constants::{DEFAULT_ACCOUNTS_FILE, DEFAULT_MULTICALL_CONTENTS};
@@ -149,6 +151,10 @@ enum Commands {
     /// Get the status of a transaction
     TxStatus(TxStatus),
 
+    /// Get events emitted by a transaction
+    #[command(alias = "events")]
+    Receipt(Receipt),
+
     /// Verify a contract
     Verify(Verify),
 
@@ -344,6 +350,7 @@ async fn run_async_command(
             contract_address,
             function,
             arguments,
+            raw,
             block_id,
             rpc,
         }) => {
@@ -356,7 +363,7 @@ async fn run_async_command(
             let selector = get_selector_from_name(&function)
                 .context("Failed to convert entry point selector to FieldElement")?;
 
-            let calldata = arguments.try_into_calldata(contract_class, &selector)?;
+            let calldata = arguments.try_into_calldata(contract_class.clone(), &selector)?;
 
             let result = starknet_commands::call::call(
                 contract_address,
@@ -368,7 +375,33 @@ async fn run_async_command(
             .await
             .map_err(handle_starknet_command_error);
 
-            print_command_result("call", &result, numbers_format, output_format)?;
+            let is_cairo_zero = matches!(contract_class, ContractClass::Legacy(_));
+
+            let transformed = match &result {
+                Ok(response) if !raw && !is_cairo_zero => {
+                    starknet_commands::call::transform_response(
+                        response.clone(),
+                        contract_class,
+                        &selector,
+                    )
+                    .inspect_err(|err| {
+                        if output_format == OutputFormat::Human {
+                            print_as_warning(&anyhow!(
+                                "Failed to decode the response, printing raw values instead: {err:#}"
+                            ));
+                        }
+                    })
+                    .ok()
+                }
+                _ => None,
+            };
+
+            match transformed {
+                Some(transformed) => {
+                    print_command_result("call", &Ok(transformed), numbers_format, output_format)?;
+                }
+                None => print_command_result("call", &result, numbers_format, output_format)?,
+            }
             Ok(())
         }
 
@@ -453,10 +486,30 @@ async fn run_async_command(
                         config.keystore,
                     )
                     .await?;
-                    let result =
+                    let mut result =
                         starknet_commands::multicall::run::run(run.clone(), &account, wait_config)
                             .await;
 
+                    // Values returned by the calls are known only once the transaction is executed
+                    if let Ok(response) = &mut result {
+                        if wait_config.wait && !run.raw {
+                            response.response_decoded =
+                                starknet_commands::multicall::run::decode_results(
+                                    &provider,
+                                    response.transaction_hash.0,
+                                )
+                                .await
+                                .inspect_err(|err| {
+                                    if output_format == OutputFormat::Human {
+                                        print_as_warning(&anyhow!(
+                                            "Failed to decode values returned by the calls: {err:#}"
+                                        ));
+                                    }
+                                })
+                                .ok();
+                        }
+                    }
+
                     print_command_result("multicall run", &result, numbers_format, output_format)?;
                     print_block_explorer_link_if_allowed(
                         &result,
@@ -623,6 +676,22 @@ async fn run_async_command(
             Ok(())
         }
 
+        Commands::Receipt(receipt) => {
+            let provider = receipt.rpc.get_provider(&config).await?;
+
+            let result = starknet_commands::receipt::get_transaction_events(
+                &provider,
+                receipt.transaction_hash,
+                !receipt.raw,
+            )
+            .await
+            .map(starknet_commands::receipt::build_receipt_response)
+            .map_err(handle_starknet_command_error);
+
+            print_command_result("receipt", &result, numbers_format, output_format)?;
+            Ok(())
+        }
+
         Commands::Verify(verify) => {
             let manifest_path = assert_manifest_path_exists()?;
             let package_metadata = get_package_metadata(&manifest_path, &verify.package)?;
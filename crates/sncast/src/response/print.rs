@@ -36,6 +36,7 @@ where
 pub enum OutputValue {
     String(String),
     Array(Vec<OutputValue>),
+    Object(Vec<(String, OutputValue)>),
 }
 
 impl Serialize for OutputValue {
@@ -46,6 +47,9 @@ impl Serialize for OutputValue {
         match &self {
             OutputValue::String(s) => serializer.serialize_str(s),
             OutputValue::Array(arr) => arr.serialize::<S>(serializer),
+            OutputValue::Object(pairs) => {
+                serializer.collect_map(pairs.iter().map(|(key, value)| (key, value)))
+            }
         }
     }
 }
@@ -62,6 +66,13 @@ impl Display for OutputValue {
                     .join(", ");
                 write!(fmt, "[{arr_as_string}]")
             }
+            OutputValue::Object(pairs) => {
+                let pairs_as_string = pairs
+                    .iter()
+                    .map(|(key, value)| format!("{key}: {value}"))
+                    .join(", ");
+                write!(fmt, "{{{pairs_as_string}}}")
+            }
         }
     }
 }
@@ -76,6 +87,12 @@ impl From<Value> for OutputValue {
             ),
             Value::String(s) => OutputValue::String(s.to_string()),
             Value::Bool(b) => OutputValue::String(b.to_string()),
+            Value::Object(obj) => OutputValue::Object(
+                obj.into_iter()
+                    .filter(|(_, v)| !(matches!(v, Value::Null)))
+                    .map(|(k, v)| (k, v.into()))
+                    .collect(),
+            ),
             s => panic!("{s:?} cannot be auto-serialized to output"),
         }
     }
@@ -104,11 +121,17 @@ impl Format for OutputValue {
                     .collect();
                 OutputValue::Array(formatted_arr)
             }
+            OutputValue::Object(pairs) => OutputValue::Object(
+                pairs
+                    .into_iter()
+                    .map(|(key, value)| (key, value.format_with(numbers)))
+                    .collect(),
+            ),
         }
     }
 }
 
-/// Constrained subset of `serde::json`. No nested maps allowed.
+/// Constrained subset of `serde::json`, with values formatted as [`OutputValue`]
 #[derive(Debug, PartialEq, Eq, Serialize)]
 struct OutputData(Vec<(String, OutputValue)>);
 
@@ -318,4 +341,24 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_nested_object_to_output_value() {
+        let mut json_value = Map::new();
+        json_value.insert(String::from("K"), Value::String(String::from("0x1")));
+        json_value.insert(String::from("K2"), Value::Null);
+
+        let actual: OutputValue = Value::Array(vec![Value::Object(json_value)]).into();
+
+        let expected = OutputValue::Array(vec![OutputValue::Object(vec![(
+            String::from("K"),
+            OutputValue::String(String::from("0x1")),
+        )])]);
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            actual.format_with(NumbersFormat::Decimal).to_string(),
+            "[{K: 1}]"
+        );
+    }
 }
@@ -2,6 +2,7 @@ use super::explorer_link::OutputLink;
 use crate::helpers::block_explorer;
 use crate::helpers::block_explorer::LinkProvider;
 use camino::Utf8PathBuf;
+use conversions::byte_array::ByteArray;
 use conversions::padded_felt::PaddedFelt;
 use conversions::serde::serialize::CairoSerialize;
 use indoc::formatdoc;
@@ -34,12 +35,30 @@ pub struct CallResponse {
 }
 impl CommandResponse for CallResponse {}
 
+#[derive(Serialize, Clone)]
+pub struct TransformedCallResponse {
+    pub response: Vec<Felt>,
+    /// Returned values decoded using the contract ABI
+    pub response_decoded: Vec<String>,
+}
+impl CommandResponse for TransformedCallResponse {}
+
 #[derive(Serialize, Deserialize, CairoSerialize, Clone, Debug, PartialEq)]
 pub struct InvokeResponse {
     pub transaction_hash: PaddedFelt,
 }
 impl CommandResponse for InvokeResponse {}
 
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct MulticallRunResponse {
+    pub transaction_hash: PaddedFelt,
+    /// Values returned by each call, decoded using ABIs of the called contracts.
+    /// Present only if the command waited for the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_decoded: Option<Vec<Vec<String>>>,
+}
+impl CommandResponse for MulticallRunResponse {}
+
 #[derive(Clone, Serialize, Deserialize, CairoSerialize, Debug, PartialEq)]
 pub struct DeployResponse {
     pub contract_address: PaddedFelt,
@@ -157,6 +176,42 @@ pub struct TransactionStatusResponse {
 
 impl CommandResponse for TransactionStatusResponse {}
 
+#[derive(CairoSerialize)]
+pub struct EventResponse {
+    pub from_address: Felt,
+    pub keys: Vec<Felt>,
+    pub data: Vec<Felt>,
+    pub decoded: Option<ByteArray>,
+}
+
+#[derive(CairoSerialize)]
+pub struct GetEventsResponse {
+    pub events: Vec<EventResponse>,
+}
+
+#[derive(Serialize)]
+pub struct ReceiptEventField {
+    pub name: String,
+    pub value: String,
+}
+
+/// Event emitted by a transaction, `name` and `fields` are set only if it was decoded
+#[derive(Serialize)]
+pub struct ReceiptEvent {
+    pub from_address: PaddedFelt,
+    pub name: Option<String>,
+    pub fields: Option<Vec<ReceiptEventField>>,
+    pub keys: Vec<Felt>,
+    pub data: Vec<Felt>,
+}
+
+#[derive(Serialize)]
+pub struct ReceiptResponse {
+    pub events: Vec<ReceiptEvent>,
+}
+
+impl CommandResponse for ReceiptResponse {}
+
 #[derive(Serialize)]
 pub struct VerifyResponse {
     pub message: String,
@@ -175,6 +230,17 @@ impl OutputLink for InvokeResponse {
     }
 }
 
+impl OutputLink for MulticallRunResponse {
+    const TITLE: &'static str = "invocation";
+
+    fn format_links(&self, provider: Box<dyn LinkProvider>) -> String {
+        format!(
+            "transaction: {}",
+            provider.transaction(self.transaction_hash)
+        )
+    }
+}
+
 impl OutputLink for DeployResponse {
     const TITLE: &'static str = "deployment";
 
@@ -1,10 +1,11 @@
 use crate::Arguments;
 use anyhow::Result;
 use clap::Args;
+use data_transformer::reverse_transform_output;
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::errors::StarknetCommandError;
-use sncast::response::structs::CallResponse;
-use starknet::core::types::{BlockId, FunctionCall};
+use sncast::response::structs::{CallResponse, TransformedCallResponse};
+use starknet::core::types::{BlockId, ContractClass, FunctionCall};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::{JsonRpcClient, Provider};
 use starknet_types_core::felt::Felt;
@@ -23,6 +24,10 @@ pub struct Call {
     #[command(flatten)]
     pub arguments: Arguments,
 
+    /// Print returned values as raw felts, without decoding them using the contract ABI
+    #[arg(long)]
+    pub raw: bool,
+
     /// Block identifier on which call should be performed.
     /// Possible values: pending, latest, block hash (0x prefixed string)
     /// and block number (u64)
@@ -52,3 +57,17 @@ pub async fn call(
         Err(error) => Err(StarknetCommandError::ProviderError(error.into())),
     }
 }
+
+/// Decode values returned by the call using ABI of the called contract
+pub fn transform_response(
+    response: CallResponse,
+    contract_class: ContractClass,
+    selector: &Felt,
+) -> Result<TransformedCallResponse> {
+    let transformed = reverse_transform_output(&response.response, contract_class, selector)?;
+
+    Ok(TransformedCallResponse {
+        response: response.response,
+        response_decoded: transformed.iter().map(ToString::to_string).collect(),
+    })
+}
@@ -4,6 +4,7 @@ pub mod declare;
 pub mod deploy;
 pub mod invoke;
 pub mod multicall;
+pub mod receipt;
 pub mod script;
 pub mod show_config;
 pub mod tx_status;
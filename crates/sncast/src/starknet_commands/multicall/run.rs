@@ -1,19 +1,22 @@
 use crate::starknet_commands::invoke::execute_calls;
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use camino::Utf8PathBuf;
 use clap::Args;
+use data_transformer::reverse_transform_output;
 use serde::Deserialize;
 use sncast::helpers::constants::UDC_ADDRESS;
 use sncast::helpers::fee::FeeArgs;
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::errors::handle_starknet_command_error;
-use sncast::response::structs::InvokeResponse;
+use sncast::response::structs::MulticallRunResponse;
 use sncast::{WaitForTx, extract_or_generate_salt, udc_uniqueness};
 use starknet::accounts::{Account, SingleOwnerAccount};
-use starknet::core::types::Call;
+use starknet::core::types::{
+    BlockId, BlockTag, Call, ContractClass, ExecuteInvocation, TransactionTrace,
+};
 use starknet::core::utils::{get_selector_from_name, get_udc_deployed_address};
-use starknet::providers::JsonRpcClient;
 use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
 use starknet::signers::LocalWallet;
 use starknet_types_core::felt::Felt;
 use std::collections::HashMap;
@@ -28,6 +31,10 @@ pub struct Run {
     #[command(flatten)]
     pub fee_args: FeeArgs,
 
+    /// Do not decode values returned by the calls using ABIs of the called contracts
+    #[arg(long)]
+    pub raw: bool,
+
     #[command(flatten)]
     pub rpc: RpcArgs,
 }
@@ -59,7 +66,7 @@ pub async fn run(
     run: Box<Run>,
     account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
     wait_config: WaitForTx,
-) -> Result<InvokeResponse> {
+) -> Result<MulticallRunResponse> {
     let fee_args = run.fee_args.clone();
 
     let contents = std::fs::read_to_string(&run.path)?;
@@ -130,9 +137,54 @@ pub async fn run(
         }
     }
 
-    execute_calls(account, parsed_calls, fee_args, None, wait_config)
+    let response = execute_calls(account, parsed_calls, fee_args, None, wait_config)
         .await
-        .map_err(handle_starknet_command_error)
+        .map_err(handle_starknet_command_error)?;
+
+    Ok(MulticallRunResponse {
+        transaction_hash: response.transaction_hash,
+        response_decoded: None,
+    })
+}
+
+/// Decodes values returned by each call of an executed multicall transaction using ABIs of
+/// the called contracts. Values returned by Cairo Zero contracts are printed as raw felts.
+pub async fn decode_results(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: Felt,
+) -> Result<Vec<Vec<String>>> {
+    let TransactionTrace::Invoke(trace) = provider.trace_transaction(transaction_hash).await?
+    else {
+        bail!("Transaction {transaction_hash:#x} is not an invoke transaction");
+    };
+    let ExecuteInvocation::Success(execute_invocation) = trace.execute_invocation else {
+        bail!("Transaction {transaction_hash:#x} was reverted");
+    };
+
+    let mut results = vec![];
+    // Calls made by `__execute__` of the account are the calls of the multicall
+    for call in execute_invocation.calls {
+        let contract_class = provider
+            .get_class(BlockId::Tag(BlockTag::Latest), call.class_hash)
+            .await?;
+
+        let decoded = match contract_class {
+            ContractClass::Sierra(_) => {
+                reverse_transform_output(&call.result, contract_class, &call.entry_point_selector)?
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect()
+            }
+            ContractClass::Legacy(_) => call
+                .result
+                .iter()
+                .map(|value| format!("{value:#x}"))
+                .collect(),
+        };
+        results.push(decoded);
+    }
+
+    Ok(results)
 }
 
 fn parse_inputs(inputs: &Vec<Input>, contracts: &HashMap<String, String>) -> Result<Vec<Felt>> {
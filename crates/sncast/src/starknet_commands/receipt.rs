@@ -0,0 +1,158 @@
+use clap::Args;
+use conversions::IntoConv;
+use conversions::byte_array::ByteArray;
+use data_transformer::reverse_transform_event;
+use data_transformer::reverse_transformer::Struct;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::{
+    EventResponse, GetEventsResponse, ReceiptEvent, ReceiptEventField, ReceiptResponse,
+};
+use starknet::core::types::{
+    BlockId, BlockTag, ContractClass, Event, ReceiptBlock, TransactionReceipt,
+};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+
+#[derive(Args)]
+#[command(about = "Get events emitted by a transaction")]
+pub struct Receipt {
+    /// Hash of the transaction
+    pub transaction_hash: Felt,
+
+    /// Print events as raw keys and data, without decoding them using the contract ABI
+    #[arg(long)]
+    pub raw: bool,
+
+    #[command(flatten)]
+    pub rpc: RpcArgs,
+}
+
+/// Event emitted by a transaction, decoded if the ABI of the contract that emitted it describes it
+pub struct TransactionEvent {
+    pub event: Event,
+    pub decoded: Option<Struct>,
+}
+
+pub async fn get_transaction_events(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: Felt,
+    decode: bool,
+) -> Result<Vec<TransactionEvent>, StarknetCommandError> {
+    let receipt = provider
+        .get_transaction_receipt(transaction_hash)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+
+    // Contracts could have been upgraded since, so classes are fetched at the block of the transaction
+    let block_id = match receipt.block {
+        ReceiptBlock::Pending => BlockId::Tag(BlockTag::Pending),
+        ReceiptBlock::Block { block_hash, .. } => BlockId::Hash(block_hash),
+    };
+
+    let events = match receipt.receipt {
+        TransactionReceipt::Invoke(receipt) => receipt.events,
+        TransactionReceipt::L1Handler(receipt) => receipt.events,
+        TransactionReceipt::Declare(receipt) => receipt.events,
+        TransactionReceipt::Deploy(receipt) => receipt.events,
+        TransactionReceipt::DeployAccount(receipt) => receipt.events,
+    };
+
+    // Many events are usually emitted by the same contract, e.g. the fee token.
+    // Events of contracts whose class can't be fetched are left raw.
+    let mut classes: HashMap<Felt, Option<ContractClass>> = HashMap::new();
+    let mut transaction_events = vec![];
+
+    for event in events {
+        let decoded = if decode {
+            if !classes.contains_key(&event.from_address) {
+                let contract_class =
+                    get_contract_class_by_address(provider, event.from_address, block_id)
+                        .await
+                        .ok();
+                classes.insert(event.from_address, contract_class);
+            }
+
+            classes[&event.from_address]
+                .clone()
+                .and_then(|contract_class| decode_event(&event, contract_class))
+        } else {
+            None
+        };
+
+        transaction_events.push(TransactionEvent { event, decoded });
+    }
+
+    Ok(transaction_events)
+}
+
+pub async fn get_events(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: Felt,
+    decode: bool,
+) -> Result<GetEventsResponse, StarknetCommandError> {
+    let events = get_transaction_events(provider, transaction_hash, decode)
+        .await?
+        .into_iter()
+        .map(|TransactionEvent { event, decoded }| EventResponse {
+            from_address: event.from_address,
+            keys: event.keys,
+            data: event.data,
+            decoded: decoded.map(|decoded| ByteArray::from(decoded.to_string().as_str())),
+        })
+        .collect();
+
+    Ok(GetEventsResponse { events })
+}
+
+async fn get_contract_class_by_address(
+    provider: &JsonRpcClient<HttpTransport>,
+    address: Felt,
+    block_id: BlockId,
+) -> anyhow::Result<ContractClass> {
+    let class_hash = provider.get_class_hash_at(block_id, address).await?;
+    Ok(provider.get_class(block_id, class_hash).await?)
+}
+
+/// Events that are not described in the ABI (or come from Cairo Zero contracts) are left raw
+fn decode_event(event: &Event, contract_class: ContractClass) -> Option<Struct> {
+    if matches!(contract_class, ContractClass::Legacy(_)) {
+        return None;
+    }
+
+    reverse_transform_event(&event.keys, &event.data, contract_class).ok()
+}
+
+#[must_use]
+pub fn build_receipt_response(events: Vec<TransactionEvent>) -> ReceiptResponse {
+    let events = events
+        .into_iter()
+        .map(|TransactionEvent { event, decoded }| {
+            let (name, fields) = decoded
+                .map(|decoded| {
+                    let fields = decoded
+                        .fields
+                        .into_iter()
+                        .map(|field| ReceiptEventField {
+                            name: field.name,
+                            value: field.value.to_string(),
+                        })
+                        .collect();
+                    (decoded.name, fields)
+                })
+                .unzip();
+
+            ReceiptEvent {
+                from_address: event.from_address.into_(),
+                name,
+                fields,
+                keys: event.keys,
+                data: event.data,
+            }
+        })
+        .collect();
+
+    ReceiptResponse { events }
+}
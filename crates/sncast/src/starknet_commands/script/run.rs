@@ -1,5 +1,5 @@
 use crate::starknet_commands::declare::Declare;
-use crate::starknet_commands::{call, declare, deploy, invoke, tx_status};
+use crate::starknet_commands::{call, declare, deploy, invoke, receipt, tx_status};
 use crate::{WaitForTx, get_account};
 use anyhow::{Context, Result, anyhow};
 use blockifier::execution::contract_class::TrackedResource;
@@ -254,6 +254,19 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(tx_status_result))
             }
+            "get_events" => {
+                let transaction_hash = input_reader.read()?;
+
+                let get_events_result = self.tokio_runtime.block_on(receipt::get_events(
+                    self.provider,
+                    transaction_hash,
+                    true,
+                ));
+
+                Ok(CheatcodeHandlingResult::from_serializable(
+                    get_events_result,
+                ))
+            }
             _ => Ok(CheatcodeHandlingResult::Forwarded),
         };
 
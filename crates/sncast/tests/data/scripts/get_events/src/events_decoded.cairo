@@ -0,0 +1,13 @@
+use sncast_std::get_events;
+
+fn main() {
+    let succeeded_tx_hash = 0x07d2067cd7675f88493a9d773b456c8d941457ecc2f6201d2fe6b0607daadfd1;
+    let result = get_events(succeeded_tx_hash).unwrap();
+
+    assert!(result.events.len() > 0);
+
+    for event in result.events {
+        assert!(event.decoded.is_some());
+        println!("{}", event);
+    };
+}
@@ -0,0 +1,13 @@
+use sncast_std::{get_events, ScriptCommandError, ProviderError, StarknetError};
+
+fn main() {
+    let incorrect_tx_hash = 0x1;
+    let error = get_events(incorrect_tx_hash).unwrap_err();
+    println!("{:?}", error);
+
+    assert!(
+        ScriptCommandError::ProviderError(
+            ProviderError::StarknetError(StarknetError::TransactionHashNotFound(())),
+        ) == error,
+    )
+}
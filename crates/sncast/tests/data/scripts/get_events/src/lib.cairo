@@ -0,0 +1,2 @@
+mod events_decoded;
+mod incorrect_transaction_hash;
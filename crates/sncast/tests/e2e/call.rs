@@ -29,6 +29,34 @@ fn test_happy_case() {
 
     let snapbox = runner(&args);
 
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: call
+        response: [0x0]
+        response_decoded: [0x0]
+    "});
+}
+
+#[test]
+fn test_happy_case_raw() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "call",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "get",
+        "--calldata",
+        "0x0",
+        "--block-id",
+        "latest",
+        "--raw",
+    ];
+
+    let snapbox = runner(&args);
+
     snapbox.assert().success().stdout_eq(indoc! {r"
         command: call
         response: [0x0]
@@ -56,6 +84,7 @@ fn test_happy_case_cairo_expression_calldata() {
     snapbox.assert().success().stdout_eq(indoc! {r"
         command: call
         response: []
+        response_decoded: []
     "});
 }
 
@@ -97,6 +126,7 @@ async fn test_call_after_storage_changed() {
     snapbox.assert().success().stdout_eq(indoc! {r"
         command: call
         response: [0x3]
+        response_decoded: [0x3]
     "});
 }
 
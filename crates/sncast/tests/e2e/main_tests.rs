@@ -168,6 +168,7 @@ async fn test_happy_case_from_cli_with_sncast_config() {
     snapbox.assert().success().stdout_eq(indoc! {r"
         command: call
         response: [0x0]
+        response_decoded: [0x0]
     "});
 }
 
@@ -195,6 +196,7 @@ async fn test_happy_case_mixed() {
     snapbox.assert().success().stdout_eq(indoc! {r"
         command: call
         response: [0x0]
+        response_decoded: [0x0]
     "});
 }
 
@@ -7,6 +7,7 @@ mod fee;
 mod invoke;
 mod main_tests;
 mod multicall;
+mod receipt;
 mod script;
 mod show_config;
 mod tx_status;
@@ -95,6 +95,50 @@ async fn test_calldata_ids() {
     "});
 }
 
+#[tokio::test]
+async fn test_decodes_results_when_waiting() {
+    let tempdir = create_and_deploy_oz_account().await;
+
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("deploy_invoke_calldata_ids.toml");
+    let path = path.to_str().expect("failed converting path to str");
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--wait",
+        "multicall",
+        "run",
+        "--url",
+        URL,
+        "--path",
+        path,
+    ];
+    let args = apply_test_resource_bounds_flags(args);
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert();
+
+    let stderr_str = output.as_stderr();
+    assert!(
+        stderr_str.is_empty(),
+        "Multicall error, stderr: \n{stderr_str}",
+    );
+
+    output.stdout_matches(indoc! {r"
+        command: multicall run
+        response_decoded: [..]
+        transaction_hash: 0x0[..]
+
+        To see invocation details, visit:
+        transaction: [..]
+    "});
+}
+
 #[tokio::test]
 async fn test_invalid_path() {
     let tempdir = create_and_deploy_oz_account().await;
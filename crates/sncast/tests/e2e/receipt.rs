@@ -0,0 +1,68 @@
+use crate::helpers::constants::URL;
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+
+const SUCCEEDED_TX_HASH: &str =
+    "0x07d2067cd7675f88493a9d773b456c8d941457ecc2f6201d2fe6b0607daadfd1";
+
+#[tokio::test]
+async fn test_incorrect_transaction_hash() {
+    let args = vec!["receipt", "0x1", "--url", URL];
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: receipt
+        error: Transaction with provided hash was not found (does not exist)
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_happy_case() {
+    let args = vec!["receipt", SUCCEEDED_TX_HASH, "--url", URL];
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    // Fee transfer is emitted by every transaction
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: receipt
+        events: [..]fields: [{name: from, value: ContractAddress(0x[..])}, {name: to, value: ContractAddress(0x[..])}, {name: [..], value: [..]_u256}][..]
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_happy_case_raw() {
+    let args = vec!["receipt", SUCCEEDED_TX_HASH, "--raw", "--url", URL];
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: receipt
+        events: [..]keys: [0x99cd8bde557814842a3121e8ddfd433a539b8c9f14bf31ebf108d12e6196e9[..]][..]
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_events_alias() {
+    let args = vec!["events", SUCCEEDED_TX_HASH, "--url", URL];
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: receipt
+        events: [..]name: Transfer[..]
+        "},
+    );
+}
@@ -0,0 +1,51 @@
+use crate::helpers::constants::{SCRIPTS_DIR, URL};
+use crate::helpers::fixtures::copy_script_directory_to_tempdir;
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stdout_contains;
+
+#[tokio::test]
+async fn test_get_events_decoded() {
+    let tempdir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/get_events",
+        Vec::<String>::new(),
+    );
+
+    let script_name = "events_decoded";
+    let args = vec!["script", "run", &script_name, "--url", URL];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        0x[..]: Transfer { from: ContractAddress(0x[..]), to: ContractAddress(0x[..]), [..]_u256 }
+        command: script run
+        status: success
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_get_events_incorrect_transaction_hash() {
+    let tempdir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/get_events",
+        Vec::<String>::new(),
+    );
+
+    let script_name = "incorrect_transaction_hash";
+    let args = vec!["script", "run", &script_name, "--url", URL];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        ScriptCommandError::ProviderError(ProviderError::StarknetError(StarknetError::TransactionHashNotFound(())))
+        command: script run
+        status: success
+        "},
+    );
+}
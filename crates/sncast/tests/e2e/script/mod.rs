@@ -2,6 +2,7 @@ mod call;
 mod declare;
 mod deploy;
 mod general;
+mod get_events;
 mod init;
 mod invoke;
 mod tx_status;
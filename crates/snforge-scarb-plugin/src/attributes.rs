@@ -7,8 +7,13 @@ pub mod fork;
 pub mod fuzzer;
 pub mod ignore;
 pub mod internal_config_statement;
+pub mod invariant;
+pub mod setup;
 pub mod should_panic;
 pub mod test;
+pub mod test_case;
+pub mod timeout;
+pub mod transactional;
 
 pub trait AttributeInfo {
     const ATTR_NAME: &'static str;
@@ -1,7 +1,7 @@
 use self::block_id::{BlockId, BlockIdVariants};
 use crate::{
     args::Arguments,
-    attributes::{AttributeCollector, AttributeInfo, AttributeTypeData},
+    attributes::{AttributeCollector, AttributeInfo, AttributeTypeData, ErrorExt},
     branch,
     cairo_expression::CairoExpression,
     config_statement::extend_with_config_cheatcodes,
@@ -30,6 +30,10 @@ impl AttributeCollector for ForkCollector {
         args: Arguments,
         _warns: &mut Vec<Diagnostic>,
     ) -> Result<String, Diagnostics> {
+        if args.named.contains_key("state_file") {
+            return Ok(state_file_args(db, &args)?);
+        }
+
         let expr = branch!(
             inline_args(db, &args),
             overridden_args(db, &args),
@@ -47,6 +51,7 @@ fn inline_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diagnos
         BlockIdVariants::Hash,
         BlockIdVariants::Number,
         BlockIdVariants::Tag,
+        BlockIdVariants::Timestamp,
     ])?;
     let url = named_args.as_once("url")?;
 
@@ -68,6 +73,25 @@ fn inline_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diagnos
     ))
 }
 
+fn state_file_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diagnostic> {
+    let named_args = args.named_only::<ForkCollector>()?;
+
+    if named_args.len() != 1 {
+        return Err(ForkCollector::error(
+            "<state_file> cannot be used with other arguments",
+        ));
+    }
+
+    let state_file = named_args.as_once("state_file")?;
+    let state_file = String::parse_from_expr::<ForkCollector>(db, state_file, "state_file")?;
+
+    let state_file = state_file.as_cairo_expression();
+
+    Ok(format!(
+        r"snforge_std::_config_types::ForkConfig::StateFile({state_file})"
+    ))
+}
+
 fn from_file_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diagnostic> {
     let &[arg] = args
         .unnamed_only::<ForkCollector>()?
@@ -89,6 +113,7 @@ fn overridden_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Dia
         BlockIdVariants::Hash,
         BlockIdVariants::Number,
         BlockIdVariants::Tag,
+        BlockIdVariants::Timestamp,
     ])?;
 
     let block_id = BlockId::parse_from_expr::<ForkCollector>(db, &block_id, block_id.0.as_ref())?;
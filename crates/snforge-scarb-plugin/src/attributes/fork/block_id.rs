@@ -12,6 +12,7 @@ pub enum BlockIdVariants {
     Hash,
     Number,
     Tag,
+    Timestamp,
 }
 
 impl AsRef<str> for BlockIdVariants {
@@ -20,15 +21,47 @@ impl AsRef<str> for BlockIdVariants {
             Self::Hash => "block_hash",
             Self::Number => "block_number",
             Self::Tag => "block_tag",
+            Self::Timestamp => "block_timestamp",
         }
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum BlockTag {
+    Latest,
+    Pending,
+    L1Accepted,
+}
+
+impl BlockTag {
+    fn from_identifier(identifier: &str) -> Option<Self> {
+        match identifier {
+            "latest" => Some(Self::Latest),
+            "pending" => Some(Self::Pending),
+            "l1_accepted" => Some(Self::L1Accepted),
+            _ => None,
+        }
+    }
+}
+
+impl CairoExpression for BlockTag {
+    fn as_cairo_expression(&self) -> String {
+        let variant = match self {
+            Self::Latest => "Latest",
+            Self::Pending => "Pending",
+            Self::L1Accepted => "L1Accepted",
+        };
+
+        format!("snforge_std::_config_types::BlockTag::{variant}")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum BlockId {
     Hash(Number),
     Number(Number),
-    Tag,
+    Tag(BlockTag),
+    Timestamp(Number),
 }
 
 impl CairoExpression for BlockId {
@@ -42,7 +75,14 @@ impl CairoExpression for BlockId {
                 "snforge_std::_config_types::BlockId::BlockNumber({})",
                 number.as_cairo_expression()
             ),
-            Self::Tag => "snforge_std::_config_types::BlockId::BlockTag".to_string(),
+            Self::Tag(tag) => format!(
+                "snforge_std::_config_types::BlockId::BlockTag({})",
+                tag.as_cairo_expression()
+            ),
+            Self::Timestamp(timestamp) => format!(
+                "snforge_std::_config_types::BlockId::BlockTimestamp({})",
+                timestamp.as_cairo_expression()
+            ),
         }
     }
 }
@@ -61,14 +101,15 @@ impl ParseFromExpr<(BlockIdVariants, &Expr)> for BlockId {
                     if segments.len() == 1 {
                         let segment = segments.last().unwrap();
 
-                        // currently no other tags
-                        if segment.identifier(db).as_str() == "latest" {
-                            return Ok(Self::Tag);
+                        if let Some(tag) =
+                            BlockTag::from_identifier(segment.identifier(db).as_str())
+                        {
+                            return Ok(Self::Tag(tag));
                         }
                     }
                 }
                 Err(ForkCollector::error(format!(
-                    "<{arg_name}> value incorrect, expected: latest",
+                    "<{arg_name}> value incorrect, expected: latest, pending or l1_accepted",
                 )))
             }
             BlockIdVariants::Hash => {
@@ -89,6 +130,15 @@ impl ParseFromExpr<(BlockIdVariants, &Expr)> for BlockId {
 
                 Ok(Self::Number(number))
             }
+            BlockIdVariants::Timestamp => {
+                let timestamp = Number::parse_from_expr::<ForkCollector>(
+                    db,
+                    block_args,
+                    BlockIdVariants::Timestamp.as_ref(),
+                )?;
+
+                Ok(Self::Timestamp(timestamp))
+            }
         }
     }
 }
@@ -0,0 +1,67 @@
+use super::{AttributeInfo, AttributeTypeData, ErrorExt};
+use crate::{
+    args::Arguments,
+    attributes::AttributeCollector,
+    cairo_expression::CairoExpression,
+    config_statement::extend_with_config_cheatcodes,
+    types::{Number, ParseFromExpr},
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use num_bigint::BigInt;
+
+pub struct InvariantCollector;
+
+impl AttributeInfo for InvariantCollector {
+    const ATTR_NAME: &'static str = "invariant";
+}
+
+impl AttributeTypeData for InvariantCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_invariant";
+}
+
+impl AttributeCollector for InvariantCollector {
+    fn args_into_config_expression(
+        db: &dyn SyntaxGroup,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<String, Diagnostics> {
+        let named_args = args.named_only::<Self>()?;
+
+        let runs = named_args
+            .as_once_optional("runs")?
+            .map(|arg| Number::parse_from_expr::<Self>(db, arg, "runs"))
+            .transpose()?;
+
+        let depth = named_args
+            .as_once_optional("depth")?
+            .map(|arg| Number::parse_from_expr::<Self>(db, arg, "depth"))
+            .transpose()?;
+
+        let seed = named_args
+            .as_once_optional("seed")?
+            .map(|arg| Number::parse_from_expr::<Self>(db, arg, "seed"))
+            .transpose()?;
+
+        for (name, value) in [("runs", &runs), ("depth", &depth)] {
+            if let Some(Number(ref value)) = value {
+                if value <= &BigInt::from(0) {
+                    Err(Self::error(format!("{name} must be greater than 0")))?;
+                }
+            }
+        }
+
+        let runs = runs.as_cairo_expression();
+        let depth = depth.as_cairo_expression();
+        let seed = seed.as_cairo_expression();
+
+        Ok(format!(
+            "snforge_std::_config_types::InvariantConfig {{ runs: {runs}, depth: {depth}, seed: {seed} }}"
+        ))
+    }
+}
+
+#[must_use]
+pub fn invariant(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<InvariantCollector>(args, item)
+}
@@ -0,0 +1,56 @@
+use super::{test::has_parameters, AttributeInfo, ErrorExt};
+use crate::{
+    args::Arguments,
+    attributes::test::TestCollector,
+    common::{into_proc_macro_result, with_parsed_values},
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+use cairo_lang_syntax::node::{
+    ast::FunctionWithBody, db::SyntaxGroup, helpers::QueryAttrs, TypedSyntaxNode,
+};
+use indoc::formatdoc;
+
+pub struct SetupCollector;
+
+impl AttributeInfo for SetupCollector {
+    const ATTR_NAME: &'static str = "setup";
+}
+
+#[must_use]
+pub fn setup(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    into_proc_macro_result(args, item, |args, item, warns| {
+        with_parsed_values::<SetupCollector>(args, item, warns, setup_internal)
+    })
+}
+
+#[expect(clippy::ptr_arg)]
+#[expect(clippy::needless_pass_by_value)]
+fn setup_internal(
+    db: &dyn SyntaxGroup,
+    func: &FunctionWithBody,
+    _args_db: &dyn SyntaxGroup,
+    args: Arguments,
+    _warns: &mut Vec<Diagnostic>,
+) -> Result<String, Diagnostics> {
+    args.assert_is_empty::<SetupCollector>()?;
+
+    if has_parameters(db, func) {
+        Err(SetupCollector::error("function can not have parameters"))?;
+    }
+    // `#[test]` may be expanded before `#[setup]`
+    if [TestCollector::ATTR_NAME, "snforge_internal_test_executable"]
+        .iter()
+        .any(|attr| func.attributes(db).has_attr(db, attr))
+    {
+        Err(SetupCollector::error("can not be used with #[test]"))?;
+    }
+
+    let func_item = func.as_syntax_node().get_text(db);
+
+    Ok(formatdoc!(
+        "
+            #[snforge_internal_setup_executable]
+            {func_item}
+        "
+    ))
+}
@@ -1,12 +1,15 @@
 use super::{internal_config_statement::InternalConfigStatementCollector, AttributeInfo, ErrorExt};
 use crate::attributes::fuzzer::wrapper::FuzzerWrapperCollector;
 use crate::attributes::fuzzer::{FuzzerCollector, FuzzerConfigCollector};
+use crate::attributes::test_case::TestCaseCollector;
 use crate::{
     args::Arguments,
     common::{into_proc_macro_result, with_parsed_values},
 };
 use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
-use cairo_lang_syntax::node::{ast::FunctionWithBody, db::SyntaxGroup, Terminal, TypedSyntaxNode};
+use cairo_lang_syntax::node::{
+    ast::FunctionWithBody, db::SyntaxGroup, helpers::QueryAttrs, Terminal, TypedSyntaxNode,
+};
 use indoc::formatdoc;
 use std::env::{self, VarError};
 use std::ops::Not;
@@ -34,21 +37,15 @@ fn test_internal(
     _warns: &mut Vec<Diagnostic>,
 ) -> Result<String, Diagnostics> {
     args.assert_is_empty::<TestCollector>()?;
-    ensure_parameters_only_with_fuzzer_attribute(db, func)?;
+    ensure_parameters_only_with_fuzzer_or_test_case_attribute(db, func)?;
 
     let config = InternalConfigStatementCollector::ATTR_NAME;
 
     let func_item = func.as_syntax_node().get_text(db);
     let name = func.declaration(db).name(db).text(db).to_string();
 
-    let test_filter = get_forge_test_filter().ok();
-
-    let should_run_test = match test_filter {
-        Some(ref filter) => name.contains(filter),
-        None => true,
-    };
-
-    if should_run_test {
+    // cases generated by `#[test_case]` are checked against the filter separately
+    if has_test_case_attribute(db, func) || should_run_test(&name) {
         Ok(formatdoc!(
             "
             #[snforge_internal_test_executable]
@@ -66,24 +63,34 @@ fn test_internal(
     }
 }
 
+pub(crate) fn should_run_test(name: &str) -> bool {
+    match get_forge_test_filter() {
+        Ok(filter) => name.contains(&filter),
+        Err(_) => true,
+    }
+}
+
 fn get_forge_test_filter() -> Result<String, VarError> {
     env::var("SNFORGE_TEST_FILTER")
 }
 
-fn ensure_parameters_only_with_fuzzer_attribute(
+fn ensure_parameters_only_with_fuzzer_or_test_case_attribute(
     db: &dyn SyntaxGroup,
     func: &FunctionWithBody,
 ) -> Result<(), Diagnostic> {
-    if has_parameters(db, func) && no_fuzzer_attribute(db, func) {
+    if has_parameters(db, func)
+        && !has_fuzzer_attribute(db, func)
+        && !has_test_case_attribute(db, func)
+    {
         Err(TestCollector::error(
-            "function with parameters must have #[fuzzer] attribute",
+            "function with parameters must have #[fuzzer] or #[test_case] attribute",
         ))?;
     }
 
     Ok(())
 }
 
-fn has_parameters(db: &dyn SyntaxGroup, func: &FunctionWithBody) -> bool {
+pub(crate) fn has_parameters(db: &dyn SyntaxGroup, func: &FunctionWithBody) -> bool {
     func.declaration(db)
         .signature(db)
         .parameters(db)
@@ -92,24 +99,25 @@ fn has_parameters(db: &dyn SyntaxGroup, func: &FunctionWithBody) -> bool {
         .not()
 }
 
-fn no_fuzzer_attribute(db: &dyn SyntaxGroup, func: &FunctionWithBody) -> bool {
+fn has_test_case_attribute(db: &dyn SyntaxGroup, func: &FunctionWithBody) -> bool {
+    func.attributes(db)
+        .has_attr(db, TestCaseCollector::ATTR_NAME)
+}
+
+pub(crate) fn has_fuzzer_attribute(db: &dyn SyntaxGroup, func: &FunctionWithBody) -> bool {
     const FUZZER_ATTRIBUTES: [&str; 3] = [
         FuzzerCollector::ATTR_NAME,
         FuzzerWrapperCollector::ATTR_NAME,
         FuzzerConfigCollector::ATTR_NAME,
     ];
 
-    func.attributes(db)
-        .elements(db)
-        .iter()
-        .any(|attr| {
-            FUZZER_ATTRIBUTES.contains(
-                &attr
-                    .attr(db)
-                    .as_syntax_node()
-                    .get_text_without_trivia(db)
-                    .as_str(),
-            )
-        })
-        .not()
+    func.attributes(db).elements(db).iter().any(|attr| {
+        FUZZER_ATTRIBUTES.contains(
+            &attr
+                .attr(db)
+                .as_syntax_node()
+                .get_text_without_trivia(db)
+                .as_str(),
+        )
+    })
 }
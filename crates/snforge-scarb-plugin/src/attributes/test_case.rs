@@ -0,0 +1,234 @@
+use super::{AttributeInfo, ErrorExt};
+use crate::args::Arguments;
+use crate::attributes::internal_config_statement::InternalConfigStatementCollector;
+use crate::attributes::test::{has_fuzzer_attribute, should_run_test, TestCollector};
+use crate::common::into_proc_macro_result;
+use crate::parse::{parse, parse_args};
+use crate::types::ParseFromExpr;
+use crate::utils::{get_statements, TypedSyntaxNodeAsText};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+use cairo_lang_syntax::node::ast::FunctionWithBody;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use indoc::formatdoc;
+use std::collections::HashSet;
+
+pub struct TestCaseCollector;
+
+impl AttributeInfo for TestCaseCollector {
+    const ATTR_NAME: &'static str = "test_case";
+}
+
+/// Single `#[test_case(...)]` occurrence, with its arguments already rendered as Cairo code
+struct TestCase {
+    suffix: String,
+    values: Vec<String>,
+}
+
+#[must_use]
+pub fn test_case(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    into_proc_macro_result(args, item, test_case_internal)
+}
+
+// Each `#[test_case]` on the function is expanded here at once - the one being processed
+// comes through `args`, all remaining ones are still present in the function attributes
+fn test_case_internal(
+    args: &TokenStream,
+    item: &TokenStream,
+    warns: &mut Vec<Diagnostic>,
+) -> Result<String, Diagnostics> {
+    let item = item.to_string();
+    let (db, func) = parse::<TestCaseCollector>(&item)?;
+    let db = db.upcast();
+
+    if has_fuzzer_attribute(db, &func) {
+        Err(TestCaseCollector::error("cannot be used with #[fuzzer]"))?;
+    }
+
+    let params_count = func
+        .declaration(db)
+        .signature(db)
+        .parameters(db)
+        .elements(db)
+        .len();
+
+    let (args_db, args) = parse_args(&args.to_string());
+    let args_db = args_db.upcast();
+    let args = Arguments::new::<TestCaseCollector>(args_db, args, warns);
+
+    let mut cases = vec![parse_test_case(args_db, &args, 1, params_count)?];
+
+    let attr_list = func.attributes(db);
+
+    for attr in attr_list.query_attr(db, TestCaseCollector::ATTR_NAME) {
+        let args = Arguments::new::<TestCaseCollector>(db, attr.arguments(db), warns);
+
+        cases.push(parse_test_case(db, &args, cases.len() + 1, params_count)?);
+    }
+
+    ensure_unique_names(&cases)?;
+
+    Ok(expand_test_cases(db, &func, &cases))
+}
+
+fn parse_test_case(
+    db: &dyn SyntaxGroup,
+    args: &Arguments,
+    index: usize,
+    params_count: usize,
+) -> Result<TestCase, Diagnostic> {
+    if !args.shorthand.is_empty() || args.named.keys().any(|name| name != "name") {
+        Err(TestCaseCollector::error(
+            "can be used with unnamed arguments and optional <name> argument only",
+        ))?;
+    }
+
+    let name = args
+        .named
+        .as_once_optional("name")?
+        .map(|expr| String::parse_from_expr::<TestCaseCollector>(db, expr, "name"))
+        .transpose()?;
+
+    let values: Vec<String> = args
+        .unnamed()
+        .iter()
+        .map(|(_, expr)| expr.as_text(db).trim().to_string())
+        .collect();
+
+    if values.len() != params_count {
+        Err(TestCaseCollector::error(format!(
+            "expected arguments: {params_count}, got: {}",
+            values.len()
+        )))?;
+    }
+
+    let suffix = match name {
+        Some(name) => {
+            validate_name(&name)?;
+            name
+        }
+        None => format!("case_{index}"),
+    };
+
+    Ok(TestCase { suffix, values })
+}
+
+fn validate_name(name: &str) -> Result<(), Diagnostic> {
+    let is_valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(TestCaseCollector::error(
+            "<name> can only contain lowercase letters, digits and underscores",
+        ))
+    }
+}
+
+fn ensure_unique_names(cases: &[TestCase]) -> Result<(), Diagnostic> {
+    let mut names = HashSet::new();
+
+    for case in cases {
+        if !names.insert(&case.suffix) {
+            Err(TestCaseCollector::error(format!(
+                "test case name <{}> is used more than once",
+                case.suffix
+            )))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn expand_test_cases(db: &dyn SyntaxGroup, func: &FunctionWithBody, cases: &[TestCase]) -> String {
+    let attr_list = func.attributes(db);
+
+    let test_attr = attr_list.find_attr(db, TestCollector::ATTR_NAME);
+    let executable_attrs = attr_list.query_attr(db, "snforge_internal_test_executable");
+    let internal_config_attrs =
+        attr_list.query_attr(db, InternalConfigStatementCollector::ATTR_NAME);
+
+    let case_fn_attrs = attr_list
+        .elements(db)
+        .into_iter()
+        .filter(|attr| {
+            Some(attr) != test_attr.as_ref()
+                && !executable_attrs.contains(attr)
+                && !internal_config_attrs.contains(attr)
+                && attr.attr(db).as_syntax_node().get_text_without_trivia(db)
+                    != TestCaseCollector::ATTR_NAME
+        })
+        .map(|attr| attr.as_text(db))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let vis = func.visibility(db).as_text(db);
+    let name = func.declaration(db).name(db).text(db).to_string();
+    let signature = func.declaration(db).signature(db).as_text(db);
+
+    let actual_body_fn_name = format!("{name}_actual_body");
+
+    let (statements, if_content) = get_statements(db, func);
+
+    let case_fns = cases
+        .iter()
+        .map(|case| {
+            let case_fn_name = format!("{name}_{}", case.suffix);
+            let arguments_list = case.values.join(", ");
+
+            let test_or_executable_attrs = if let Some(test_attr) = &test_attr {
+                test_attr.as_text(db)
+            } else if executable_attrs.is_empty() && internal_config_attrs.is_empty() {
+                // `#[test_case]` implies `#[test]`
+                format!("#[{}]", TestCollector::ATTR_NAME)
+            } else {
+                // `#[test]` was already expanded on the base function,
+                // so the test filter has to be checked against the name of this case
+                let executable_attr = if should_run_test(&case_fn_name) {
+                    "#[snforge_internal_test_executable]"
+                } else {
+                    ""
+                };
+                let internal_config_attr = if internal_config_attrs.is_empty() {
+                    String::new()
+                } else {
+                    format!("#[{}]", InternalConfigStatementCollector::ATTR_NAME)
+                };
+
+                format!("{executable_attr}\n{internal_config_attr}")
+            };
+
+            formatdoc!(
+                "
+                    {test_or_executable_attrs}
+                    {case_fn_attrs}
+                    {vis} fn {case_fn_name}() {{
+                        if snforge_std::_internals::_is_config_run() {{
+                            {if_content}
+
+                            return;
+                        }}
+
+                        {actual_body_fn_name}({arguments_list});
+                    }}
+                "
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    formatdoc!(
+        "
+            {case_fns}
+
+            fn {actual_body_fn_name}{signature} {{
+                {statements}
+            }}
+        "
+    )
+}
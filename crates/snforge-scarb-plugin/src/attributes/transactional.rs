@@ -0,0 +1,37 @@
+use super::{AttributeInfo, AttributeTypeData};
+use crate::{
+    args::Arguments, attributes::AttributeCollector,
+    config_statement::extend_with_config_cheatcodes,
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+
+pub struct TransactionalCollector;
+
+impl AttributeInfo for TransactionalCollector {
+    const ATTR_NAME: &'static str = "transactional";
+}
+
+impl AttributeTypeData for TransactionalCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_transactional";
+}
+
+impl AttributeCollector for TransactionalCollector {
+    fn args_into_config_expression(
+        _db: &dyn SyntaxGroup,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<String, Diagnostics> {
+        args.assert_is_empty::<Self>()?;
+
+        Ok(
+            "snforge_std::_config_types::TransactionalConfig { is_transactional: true }"
+                .to_string(),
+        )
+    }
+}
+
+#[must_use]
+pub fn transactional(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<TransactionalCollector>(args, item)
+}
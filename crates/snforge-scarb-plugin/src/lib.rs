@@ -2,7 +2,9 @@
 use attributes::fuzzer;
 use attributes::{
     available_gas::available_gas, fork::fork, fuzzer::fuzzer, ignore::ignore,
-    internal_config_statement::internal_config_statement, should_panic::should_panic, test::test,
+    internal_config_statement::internal_config_statement, invariant::invariant, setup::setup,
+    should_panic::should_panic, test::test, test_case::test_case, timeout::timeout,
+    transactional::transactional,
 };
 use cairo_lang_macro::{attribute_macro, executable_attribute, ProcMacroResult, TokenStream};
 
@@ -17,6 +19,7 @@ mod types;
 mod utils;
 
 executable_attribute!("snforge_internal_test_executable");
+executable_attribute!("snforge_internal_setup_executable");
 
 #[attribute_macro]
 fn __internal_config_statement(args: TokenStream, item: TokenStream) -> ProcMacroResult {
@@ -58,7 +61,32 @@ fn available_gas(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     available_gas(args, item)
 }
 
+#[attribute_macro]
+fn invariant(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    invariant(args, item)
+}
+
+#[attribute_macro]
+fn setup(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    setup(args, item)
+}
+
 #[attribute_macro]
 fn should_panic(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     should_panic(args, item)
 }
+
+#[attribute_macro]
+fn test_case(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    test_case(args, item)
+}
+
+#[attribute_macro]
+fn timeout(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    timeout(args, item)
+}
+
+#[attribute_macro]
+fn transactional(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    transactional(args, item)
+}
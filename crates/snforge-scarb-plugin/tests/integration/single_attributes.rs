@@ -3,5 +3,10 @@ mod fork;
 mod fuzzer;
 mod ignore;
 mod internal_config_statement;
+mod invariant;
+mod setup;
 mod should_panic;
 mod test;
+mod test_case;
+mod timeout;
+mod transactional;
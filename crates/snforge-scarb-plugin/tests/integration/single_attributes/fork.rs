@@ -16,7 +16,7 @@ fn fails_without_block() {
            Diagnostic::error(formatdoc!(
                 "
                     All options failed
-                    - variant: exactly one of <block_hash> | <block_number> | <block_tag> should be specified, got 0
+                    - variant: exactly one of <block_hash> | <block_number> | <block_tag> | <block_timestamp> should be specified, got 0
                     - variant: #[fork] expected arguments: 1, got: 0
                     - variant: #[fork] can be used with unnamed arguments only
                     Resolve at least one of them
@@ -60,7 +60,7 @@ fn fails_without_args() {
             Diagnostic::error(formatdoc!(
             "
                 All options failed
-                - variant: exactly one of <block_hash> | <block_number> | <block_tag> should be specified, got 0
+                - variant: exactly one of <block_hash> | <block_number> | <block_tag> | <block_timestamp> should be specified, got 0
                 - variant: #[fork] expected arguments: 1, got: 0
                 - variant: #[fork] expected arguments: 1, got: 0
                 Resolve at least one of them
@@ -153,6 +153,140 @@ fn accepts_inline_config() {
     );
 }
 
+#[test]
+fn accepts_block_tag() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(r#"(url: "http://example.com", block_tag: l1_accepted)"#.into());
+
+    let result = fork(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        r#"
+            fn empty_fn() {
+                if snforge_std::_internals::_is_config_run() {
+
+                    let mut data = array![];
+
+                    snforge_std::_config_types::ForkConfig::Inline(
+                        snforge_std::_config_types::InlineForkConfig {
+                            url: "http://example.com/",
+                            block: snforge_std::_config_types::BlockId::BlockTag(snforge_std::_config_types::BlockTag::L1Accepted)
+                        }
+                    )
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_fork'>(data.span());
+
+                    return;
+                }
+            }
+        "#,
+    );
+}
+
+#[test]
+fn fails_with_invalid_block_tag() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(r#"(url: "http://example.com", block_tag: finalized)"#.into());
+
+    let result = fork(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(formatdoc!(
+            "
+                All options failed
+                - variant: #[fork] <block_tag> value incorrect, expected: latest, pending or l1_accepted
+                - variant: #[fork] expected arguments: 1, got: 0
+                - variant: #[fork] can be used with unnamed arguments only
+                Resolve at least one of them
+            "
+        ))],
+    );
+}
+
+#[test]
+fn accepts_block_timestamp() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(r#"("test", block_timestamp: 1700000000)"#.into());
+
+    let result = fork(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        r#"
+            fn empty_fn() {
+                if snforge_std::_internals::_is_config_run() {
+
+                    let mut data = array![];
+
+                    snforge_std::_config_types::ForkConfig::Overridden(
+                        snforge_std::_config_types::OverriddenForkConfig {
+                            block: snforge_std::_config_types::BlockId::BlockTimestamp(0x6553f100),
+                            name: "test"
+                        }
+                    )
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_fork'>(data.span());
+
+                    return;
+                }
+            }
+        "#,
+    );
+}
+
+#[test]
+fn accepts_state_file() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(r#"(state_file: "fixtures/mainnet.json")"#.into());
+
+    let result = fork(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        r#"
+            fn empty_fn() {
+                if snforge_std::_internals::_is_config_run() {
+
+                    let mut data = array![];
+
+                    snforge_std::_config_types::ForkConfig::StateFile("fixtures/mainnet.json")
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_fork'>(data.span());
+
+                    return;
+                }
+            }
+        "#,
+    );
+}
+
+#[test]
+fn fails_with_state_file_and_block() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args =
+        TokenStream::new(r#"(state_file: "fixtures/mainnet.json", block_number: 23)"#.into());
+
+    let result = fork(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[fork] <state_file> cannot be used with other arguments",
+        )],
+    );
+}
+
 #[test]
 fn overriding_config_name_first() {
     let item = TokenStream::new(EMPTY_FN.into());
@@ -0,0 +1,118 @@
+use crate::utils::{assert_diagnostics, assert_output, EMPTY_FN};
+use cairo_lang_macro::{Diagnostic, TokenStream};
+use indoc::formatdoc;
+use snforge_scarb_plugin::attributes::invariant::invariant;
+
+#[test]
+fn works_without_args() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(String::new());
+
+    let result = invariant(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            fn empty_fn() {
+                if snforge_std::_internals::_is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_config_types::InvariantConfig {
+                        runs: Option::None,
+                        depth: Option::None,
+                        seed: Option::None
+                    }
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_invariant'>(data.span());
+
+                    return;
+                }
+            }
+        ",
+    );
+}
+
+#[test]
+fn works_with_all_args() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(runs: 655, depth: 50, seed: 32872357)".into());
+
+    let result = invariant(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            fn empty_fn() {
+                if snforge_std::_internals::_is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_config_types::InvariantConfig {
+                        runs: Option::Some(0x28f),
+                        depth: Option::Some(0x32),
+                        seed: Option::Some(0x1f597a5)
+                    }
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_invariant'>(data.span());
+
+                    return;
+                }
+            }
+        ",
+    );
+}
+
+#[test]
+fn fails_with_zero_depth() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(depth: 0)".into());
+
+    let result = invariant(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[invariant] depth must be greater than 0",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_unnamed_arg() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(123)".into());
+
+    let result = invariant(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[invariant] can be used with named arguments only",
+        )],
+    );
+}
+
+#[test]
+fn is_used_once() {
+    let item = TokenStream::new(formatdoc!(
+        "
+            #[invariant]
+            {EMPTY_FN}
+        "
+    ));
+    let args = TokenStream::new(String::new());
+
+    let result = invariant(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[invariant] can only be used once per item",
+        )],
+    );
+}
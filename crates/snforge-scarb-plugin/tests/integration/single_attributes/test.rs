@@ -63,7 +63,32 @@ fn fails_with_params() {
     assert_diagnostics(
         &result,
         &[Diagnostic::error(
-            "#[test] function with parameters must have #[fuzzer] attribute",
+            "#[test] function with parameters must have #[fuzzer] or #[test_case] attribute",
         )],
     );
 }
+
+#[test]
+fn works_with_params_and_test_case() {
+    let item = TokenStream::new(formatdoc!(
+        "
+            #[test_case(1)]
+            {FN_WITH_SINGLE_FELT252_PARAM}
+        "
+    ));
+    let args = TokenStream::new(String::new());
+
+    let result = test(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            #[snforge_internal_test_executable]
+            #[__internal_config_statement]
+            #[test_case(1)]
+            fn empty_fn(f: felt252){}
+        ",
+    );
+}
@@ -0,0 +1,282 @@
+use crate::utils::{assert_diagnostics, assert_output, EMPTY_FN, FN_WITH_SINGLE_FELT252_PARAM};
+use cairo_lang_macro::{Diagnostic, TokenStream};
+use indoc::formatdoc;
+use snforge_scarb_plugin::attributes::test_case::test_case;
+
+#[test]
+fn expands_single_case() {
+    let item = TokenStream::new(FN_WITH_SINGLE_FELT252_PARAM.into());
+    let args = TokenStream::new("(1)".into());
+
+    let result = test_case(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            #[test]
+            fn empty_fn_case_1() {
+                if snforge_std::_internals::_is_config_run() {
+                    return;
+                }
+
+                empty_fn_actual_body(1);
+            }
+
+            fn empty_fn_actual_body(f: felt252) {}
+        ",
+    );
+}
+
+#[test]
+fn expands_all_cases_in_order() {
+    let item = TokenStream::new(
+        r#"
+            #[test_case(3, 5, name: "named")]
+            #[test_case(7, 9)]
+            fn my_test(a: felt252, b: u32) {
+                assert(a + 2 == b.into(), 'err');
+            }
+        "#
+        .into(),
+    );
+    let args = TokenStream::new("(1, 3)".into());
+
+    let result = test_case(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            #[test]
+            fn my_test_case_1() {
+                if snforge_std::_internals::_is_config_run() {
+                    return;
+                }
+
+                my_test_actual_body(1, 3);
+            }
+
+            #[test]
+            fn my_test_named() {
+                if snforge_std::_internals::_is_config_run() {
+                    return;
+                }
+
+                my_test_actual_body(3, 5);
+            }
+
+            #[test]
+            fn my_test_case_3() {
+                if snforge_std::_internals::_is_config_run() {
+                    return;
+                }
+
+                my_test_actual_body(7, 9);
+            }
+
+            fn my_test_actual_body(a: felt252, b: u32) {
+                assert(a + 2 == b.into(), 'err');
+            }
+        ",
+    );
+}
+
+#[test]
+fn copies_other_attributes_to_each_case() {
+    let item = TokenStream::new(formatdoc!(
+        r#"
+            #[test]
+            #[should_panic]
+            #[fork("test")]
+            #[test_case(2)]
+            {FN_WITH_SINGLE_FELT252_PARAM}
+        "#
+    ));
+    let args = TokenStream::new("(1)".into());
+
+    let result = test_case(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        r#"
+            #[test]
+            #[should_panic]
+            #[fork("test")]
+            fn empty_fn_case_1() {
+                if snforge_std::_internals::_is_config_run() {
+                    return;
+                }
+
+                empty_fn_actual_body(1);
+            }
+
+            #[test]
+            #[should_panic]
+            #[fork("test")]
+            fn empty_fn_case_2() {
+                if snforge_std::_internals::_is_config_run() {
+                    return;
+                }
+
+                empty_fn_actual_body(2);
+            }
+
+            fn empty_fn_actual_body(f: felt252) {}
+        "#,
+    );
+}
+
+#[test]
+fn keeps_config_statements_of_expanded_attributes() {
+    let item = TokenStream::new(
+        "
+            #[snforge_internal_test_executable]
+            fn empty_fn(f: felt252) {
+                if snforge_std::_internals::_is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_config_types::AvailableGasConfig::MaxGas(0x5)
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_available_gas'>(data.span());
+
+                    return;
+                }
+            }
+        "
+        .into(),
+    );
+    let args = TokenStream::new("(1)".into());
+
+    let result = test_case(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            #[snforge_internal_test_executable]
+            fn empty_fn_case_1() {
+                if snforge_std::_internals::_is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_config_types::AvailableGasConfig::MaxGas(0x5)
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_available_gas'>(data.span());
+
+                    return;
+                }
+
+                empty_fn_actual_body(1);
+            }
+
+            fn empty_fn_actual_body(f: felt252) {}
+        ",
+    );
+}
+
+#[test]
+fn fails_with_wrong_number_of_arguments() {
+    let item = TokenStream::new(FN_WITH_SINGLE_FELT252_PARAM.into());
+    let args = TokenStream::new("(1, 2)".into());
+
+    let result = test_case(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[test_case] expected arguments: 1, got: 2",
+        )],
+    );
+}
+
+#[test]
+fn fails_without_parameters() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(1)".into());
+
+    let result = test_case(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[test_case] expected arguments: 0, got: 1",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_invalid_name() {
+    let item = TokenStream::new(FN_WITH_SINGLE_FELT252_PARAM.into());
+    let args = TokenStream::new(r#"(1, name: "Invalid-Name")"#.into());
+
+    let result = test_case(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[test_case] <name> can only contain lowercase letters, digits and underscores",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_duplicated_names() {
+    let item = TokenStream::new(formatdoc!(
+        "
+            #[test_case(2)]
+            {FN_WITH_SINGLE_FELT252_PARAM}
+        "
+    ));
+    let args = TokenStream::new(r#"(1, name: "case_2")"#.into());
+
+    let result = test_case(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[test_case] test case name <case_2> is used more than once",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_unknown_named_argument() {
+    let item = TokenStream::new(FN_WITH_SINGLE_FELT252_PARAM.into());
+    let args = TokenStream::new("(1, runs: 5)".into());
+
+    let result = test_case(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[test_case] can be used with unnamed arguments and optional <name> argument only",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_fuzzer() {
+    let item = TokenStream::new(formatdoc!(
+        "
+            #[fuzzer]
+            {FN_WITH_SINGLE_FELT252_PARAM}
+        "
+    ));
+    let args = TokenStream::new("(1)".into());
+
+    let result = test_case(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[test_case] cannot be used with #[fuzzer]",
+        )],
+    );
+}
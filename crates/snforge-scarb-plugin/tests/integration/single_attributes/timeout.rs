@@ -0,0 +1,82 @@
+use crate::utils::{assert_diagnostics, assert_output, EMPTY_FN};
+use cairo_lang_macro::{Diagnostic, TokenStream};
+use indoc::formatdoc;
+use snforge_scarb_plugin::attributes::timeout::timeout;
+
+#[test]
+fn works_with_seconds() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(seconds: 30)".into());
+
+    let result = timeout(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            fn empty_fn() {
+                if snforge_std::_internals::_is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_config_types::TimeoutConfig {
+                        seconds: 0x1e
+                    }
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_timeout'>(data.span());
+
+                    return;
+                }
+            }
+        ",
+    );
+}
+
+#[test]
+fn fails_without_seconds() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(String::new());
+
+    let result = timeout(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("<seconds> argument is missing")],
+    );
+}
+
+#[test]
+fn fails_with_zero_seconds() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(seconds: 0)".into());
+
+    let result = timeout(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] seconds must be greater than 0",
+        )],
+    );
+}
+
+#[test]
+fn is_used_once() {
+    let item = TokenStream::new(formatdoc!(
+        "
+            #[timeout(seconds: 30)]
+            {EMPTY_FN}
+        "
+    ));
+    let args = TokenStream::new("(seconds: 30)".into());
+
+    let result = timeout(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] can only be used once per item",
+        )],
+    );
+}
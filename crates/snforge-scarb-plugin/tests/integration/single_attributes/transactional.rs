@@ -0,0 +1,69 @@
+use crate::utils::{assert_diagnostics, assert_output, EMPTY_FN};
+use cairo_lang_macro::{Diagnostic, TokenStream};
+use indoc::formatdoc;
+use snforge_scarb_plugin::attributes::transactional::transactional;
+
+#[test]
+fn fails_with_args() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(123)".into());
+
+    let result = transactional(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[transactional] does not accept any arguments",
+        )],
+    );
+}
+
+#[test]
+fn works_without_args() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(String::new());
+
+    let result = transactional(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            fn empty_fn() {
+                if snforge_std::_internals::_is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_config_types::TransactionalConfig {
+                        is_transactional: true
+                    }
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_transactional'>(data.span());
+
+                    return;
+                }
+            }
+        ",
+    );
+}
+
+#[test]
+fn is_used_once() {
+    let item = TokenStream::new(formatdoc!(
+        "
+            #[transactional]
+            {EMPTY_FN}
+        "
+    ));
+    let args = TokenStream::new(String::new());
+
+    let result = transactional(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[transactional] can only be used once per item",
+        )],
+    );
+}
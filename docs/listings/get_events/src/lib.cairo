@@ -0,0 +1,10 @@
+use sncast_std::{get_events};
+
+fn main() {
+    let transaction_hash = 0x00ae35dacba17cde62b8ceb12e3b18f4ab6e103fa2d5e3d9821cb9dc59d59a3c;
+    let result = get_events(transaction_hash).expect('Failed to get events');
+
+    for event in result.events {
+        println!("{}", event);
+    };
+}
@@ -534,6 +534,48 @@ pub fn tx_status(transaction_hash: felt252) -> Result<TxStatusResult, ScriptComm
     result_data
 }
 
+#[derive(Drop, Clone, Debug, Serde, PartialEq)]
+pub struct Event {
+    pub from_address: ContractAddress,
+    pub keys: Array<felt252>,
+    pub data: Array<felt252>,
+    /// Event decoded using the ABI of the emitting contract, if it could be decoded
+    pub decoded: Option<ByteArray>,
+}
+
+impl DisplayEvent of Display<Event> {
+    fn fmt(self: @Event, ref f: Formatter) -> Result<(), Error> {
+        match self.decoded {
+            Option::Some(decoded) => write!(f, "{}: {}", *self.from_address, decoded),
+            Option::None => {
+                write!(f, "{}: keys: ", *self.from_address)?;
+                Debug::fmt(self.keys, ref f)?;
+                write!(f, ", data: ")?;
+                Debug::fmt(self.data, ref f)
+            },
+        }
+    }
+}
+
+#[derive(Drop, Clone, Debug, Serde, PartialEq)]
+pub struct GetEventsResult {
+    pub events: Array<Event>,
+}
+
+pub fn get_events(transaction_hash: felt252) -> Result<GetEventsResult, ScriptCommandError> {
+    let mut inputs = array![transaction_hash];
+
+    let mut buf = handle_cheatcode(cheatcode::<'get_events'>(inputs.span()));
+
+    let mut result_data: Result<GetEventsResult, ScriptCommandError> =
+        match Serde::<Result<GetEventsResult>>::deserialize(ref buf) {
+        Option::Some(result_data) => result_data,
+        Option::None => panic!("get_events deserialize failed"),
+    };
+
+    result_data
+}
+
 fn handle_cheatcode(input: Span<felt252>) -> Span<felt252> {
     let first = *input.at(0);
     let input = input.slice(1, input.len() - 1);
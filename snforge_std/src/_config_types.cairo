@@ -11,11 +11,19 @@ pub enum AvailableGasConfig {
     MaxResourceBounds: AvailableResourceBoundsConfig,
 }
 
+#[derive(Drop, Serde)]
+pub enum BlockTag {
+    Latest,
+    Pending,
+    L1Accepted,
+}
+
 #[derive(Drop, Serde)]
 pub enum BlockId {
-    BlockTag: (),
+    BlockTag: BlockTag,
     BlockHash: felt252,
     BlockNumber: felt252,
+    BlockTimestamp: felt252,
 }
 
 #[derive(Drop, Serde)]
@@ -35,6 +43,7 @@ pub enum ForkConfig {
     Inline: InlineForkConfig,
     Named: ByteArray,
     Overridden: OverriddenForkConfig,
+    StateFile: ByteArray,
 }
 
 #[derive(Drop, Serde)]
@@ -60,3 +69,20 @@ pub struct ShouldPanicConfig {
 pub struct IgnoreConfig {
     pub is_ignored: bool,
 }
+
+#[derive(Drop, Serde)]
+pub struct TransactionalConfig {
+    pub is_transactional: bool,
+}
+
+#[derive(Drop, Serde)]
+pub struct InvariantConfig {
+    pub runs: Option<felt252>,
+    pub depth: Option<felt252>,
+    pub seed: Option<felt252>,
+}
+
+#[derive(Drop, Serde)]
+pub struct TimeoutConfig {
+    pub seconds: felt252,
+}
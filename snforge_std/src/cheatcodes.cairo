@@ -10,6 +10,12 @@ pub mod message_to_l1;
 pub mod generate_random_felt;
 pub mod generate_arg;
 pub mod block_hash;
+pub mod fork;
+pub mod transaction;
+pub mod invariant;
+pub mod snapshot;
+pub mod calls;
+pub mod erc20;
 
 /// Enum used to specify how long the target should be cheated for.
 #[derive(Copy, Drop, Serde, PartialEq, Clone, Debug)]
@@ -97,6 +103,152 @@ pub fn stop_mock_call(contract_address: ContractAddress, function_selector: felt
     >(array![contract_address_felt, function_selector].span());
 }
 
+/// Enum used to specify which calls are mocked.
+#[derive(Copy, Drop, Serde, PartialEq, Clone, Debug)]
+pub enum MockTarget {
+    /// Calls to the contract at the given address.
+    Contract: ContractAddress,
+    /// Library calls to the class with the given hash.
+    LibraryCall: ClassHash,
+}
+
+/// Enum used to specify calldata of the mocked or expected calls.
+/// If multiple mocks match a call, the most specific one is used: `Exact` before `Prefix`,
+/// longer `Prefix` before shorter, and `Any` last.
+#[derive(Drop, Serde, PartialEq, Clone, Debug)]
+pub enum CalldataMatcher {
+    /// Calls with any calldata.
+    Any: (),
+    /// Calls with calldata starting with the given values.
+    Prefix: Array<felt252>,
+    /// Calls with calldata equal to the given values.
+    Exact: Array<felt252>,
+}
+
+/// Enum used to specify the outcome of a mocked call.
+#[derive(Drop, Serde, PartialEq, Clone, Debug)]
+pub enum MockedResult {
+    /// The call returns the given serialized data.
+    Return: Array<felt252>,
+    /// The call panics with the given panic data.
+    Revert: Array<felt252>,
+}
+
+/// Mocks calls to a `function_selector` of the `target` made with calldata matching `calldata`.
+/// Successive calls get successive `results`, once all but the last one are used up,
+/// the last one is used for all further calls.
+/// - `target` - mocked contract address or library call class hash
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+/// - `calldata` - calldata of the calls the mock applies to
+/// - `results` - outcomes of successive calls
+/// - `span` - how long the mock applies for, counted in calls matching it
+pub fn mock_calls(
+    target: MockTarget,
+    function_selector: felt252,
+    calldata: CalldataMatcher,
+    results: Array<MockedResult>,
+    span: CheatSpan,
+) {
+    assert!(results.len() > 0, "cannot mock calls without results");
+    if let CheatSpan::TargetCalls(n_times) = span {
+        assert!(n_times > 0, "cannot mock calls 0 times, n_times argument must be greater than 0");
+    }
+
+    let mut inputs = array![];
+    target.serialize(ref inputs);
+    function_selector.serialize(ref inputs);
+    calldata.serialize(ref inputs);
+    results.serialize(ref inputs);
+    span.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'mock_calls', ()>(inputs.span());
+}
+
+/// Mocks contract calls to a `function_selector` of a contract at the given address,
+/// made with calldata matching `calldata`, indefinitely.
+/// - `contract_address` - targeted contracts' address
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+/// - `calldata` - calldata of the calls the mock applies to
+/// - `ret_data` - data to be returned by the function
+pub fn start_mock_call_when<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: CalldataMatcher,
+    ret_data: T,
+) {
+    let mut ret_data_arr = array![];
+    ret_data.serialize(ref ret_data_arr);
+
+    mock_calls(
+        MockTarget::Contract(contract_address),
+        function_selector,
+        calldata,
+        array![MockedResult::Return(ret_data_arr)],
+        CheatSpan::Indefinite,
+    );
+}
+
+/// Makes contract calls to a `function_selector` of a contract at the given address panic with
+/// `panic_data`, indefinitely.
+/// - `contract_address` - targeted contracts' address
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+/// - `panic_data` - data the calls panic with
+pub fn start_mock_call_revert(
+    contract_address: ContractAddress, function_selector: felt252, panic_data: Array<felt252>,
+) {
+    mock_calls(
+        MockTarget::Contract(contract_address),
+        function_selector,
+        CalldataMatcher::Any,
+        array![MockedResult::Revert(panic_data)],
+        CheatSpan::Indefinite,
+    );
+}
+
+/// Mocks library calls to a `function_selector` of the class with the given hash, indefinitely.
+/// - `class_hash` - targeted class hash
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+/// - `ret_data` - data to be returned by the function
+pub fn start_mock_library_call<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
+    class_hash: ClassHash, function_selector: felt252, ret_data: T,
+) {
+    let mut ret_data_arr = array![];
+    ret_data.serialize(ref ret_data_arr);
+
+    mock_calls(
+        MockTarget::LibraryCall(class_hash),
+        function_selector,
+        CalldataMatcher::Any,
+        array![MockedResult::Return(ret_data_arr)],
+        CheatSpan::Indefinite,
+    );
+}
+
+/// Cancels all mocks of calls to a `function_selector` of the `target`, regardless of their
+/// calldata.
+/// - `target` - mocked contract address or library call class hash
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+pub fn stop_mock_calls(target: MockTarget, function_selector: felt252) {
+    let mut inputs = array![];
+    target.serialize(ref inputs);
+    function_selector.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'stop_mock_calls', ()>(inputs.span());
+}
+
+/// Cancels the `start_mock_library_call` for the function with given name and class hash.
+/// - `class_hash` - targeted class hash
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+pub fn stop_mock_library_call(class_hash: ClassHash, function_selector: felt252) {
+    stop_mock_calls(MockTarget::LibraryCall(class_hash), function_selector);
+}
+
 #[derive(Drop, Serde, PartialEq, Debug)]
 pub enum ReplaceBytecodeError {
     /// Means that the contract does not exist, and thus bytecode cannot be replaced
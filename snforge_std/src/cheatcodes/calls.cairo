@@ -0,0 +1,69 @@
+use starknet::ContractAddress;
+use super::CalldataMatcher;
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+
+/// Creates `CallSpy` instance that spies on all calls made after its creation,
+/// both by the test and by the called contracts.
+pub fn spy_calls() -> CallSpy {
+    execute_cheatcode_and_deserialize::<'spy_calls'>(array![].span())
+}
+
+/// A call to a contract made during the test.
+/// Library calls are not included, but calls made by the called classes are.
+#[derive(Drop, Clone, Serde, Debug, PartialEq)]
+pub struct Call {
+    pub contract_address: ContractAddress,
+    pub function_selector: felt252,
+    pub calldata: Array<felt252>,
+}
+
+/// A call spy structure allowing to get calls made only after its creation.
+#[derive(Drop, Serde)]
+pub struct CallSpy {
+    call_offset: usize,
+}
+
+pub trait CallSpyTrait {
+    /// Gets all calls given [`CallSpy`] spies for, in order of execution.
+    fn get_calls(ref self: CallSpy) -> Array<Call>;
+}
+
+impl CallSpyTraitImpl of CallSpyTrait {
+    fn get_calls(ref self: CallSpy) -> Array<Call> {
+        execute_cheatcode_and_deserialize::<'get_calls'>(array![self.call_offset.into()].span())
+    }
+}
+
+/// Enum used to specify how many times an expected call has to be made.
+#[derive(Copy, Drop, Serde, PartialEq, Clone, Debug)]
+pub enum ExpectedCallCount {
+    /// The call has to be made exactly the given number of times.
+    Exactly: usize,
+    /// The call has to be made at least once.
+    AtLeastOnce: (),
+    /// The call must not be made.
+    Never: (),
+}
+
+/// Expects calls to a `function_selector` of the contract at `contract_address` to be made
+/// after this point of the test. The expectation is verified when the test ends, and the test
+/// fails with a list of the actual calls to the function if it is not met.
+/// - `contract_address` - address of the contract expected to be called
+/// - `function_selector` - hashed name of the expected function (can be obtained with `selector!`
+/// macro)
+/// - `calldata` - calldata of the calls that are counted
+/// - `count` - how many times the matching calls have to be made
+pub fn expect_call(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: CalldataMatcher,
+    count: ExpectedCallCount,
+) {
+    let mut inputs = array![];
+    contract_address.serialize(ref inputs);
+    function_selector.serialize(ref inputs);
+    calldata.serialize(ref inputs);
+    count.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'expect_call', ()>(inputs.span());
+}
@@ -0,0 +1,75 @@
+use starknet::ContractAddress;
+use super::storage::{load, store, map_entry_address};
+
+/// Storage variables of an ERC20 token holding balances and total supply.
+#[derive(Copy, Drop, Serde, PartialEq, Debug)]
+pub struct Erc20StorageLayout {
+    /// Selector of the `Map<ContractAddress, u256>` variable holding balances of accounts
+    pub balances: felt252,
+    /// Selector of the `u256` variable holding total supply
+    pub total_supply: felt252,
+}
+
+/// Storage layout of tokens built with the OpenZeppelin ERC20 component (or its Cairo 0
+/// counterpart), e.g. STRK, ETH and USDC.
+pub impl Erc20StorageLayoutDefault of Default<Erc20StorageLayout> {
+    fn default() -> Erc20StorageLayout {
+        Erc20StorageLayout {
+            balances: selector!("ERC20_balances"), total_supply: selector!("ERC20_total_supply"),
+        }
+    }
+}
+
+/// Sets the balance of `account` in the ERC20 `token` to `amount`, by writing directly into the
+/// storage of the token. Works with tokens deployed in the test and with tokens from the fork.
+/// The token has to use the OpenZeppelin storage layout, see `deal_with_layout` for other
+/// tokens.
+/// - `token` - address of the ERC20 token contract
+/// - `account` - address of the account which balance is set
+/// - `amount` - the new balance
+/// - `adjust_total_supply` - whether the total supply is changed by the difference between
+/// the new and the previous balance. If the total supply is lower than that difference
+/// (e.g. after balances were set without adjusting it), it is set to 0.
+pub fn deal(
+    token: ContractAddress, account: ContractAddress, amount: u256, adjust_total_supply: bool,
+) {
+    deal_with_layout(token, account, amount, adjust_total_supply, Default::default());
+}
+
+/// Same as `deal`, but for tokens storing balances and total supply in the variables given in
+/// `layout`.
+pub fn deal_with_layout(
+    token: ContractAddress,
+    account: ContractAddress,
+    amount: u256,
+    adjust_total_supply: bool,
+    layout: Erc20StorageLayout,
+) {
+    let balance_address = map_entry_address(layout.balances, array![account.into()].span());
+
+    if adjust_total_supply {
+        let previous_balance = load_u256(token, balance_address);
+        let total_supply = load_u256(token, layout.total_supply);
+        let total_supply = if amount >= previous_balance {
+            total_supply + (amount - previous_balance)
+        } else if total_supply >= previous_balance - amount {
+            total_supply - (previous_balance - amount)
+        } else {
+            0
+        };
+        store_u256(token, layout.total_supply, total_supply);
+    }
+
+    store_u256(token, balance_address, amount);
+}
+
+fn load_u256(target: ContractAddress, storage_address: felt252) -> u256 {
+    let mut serialized_value = load(target, storage_address, 2).span();
+    Serde::deserialize(ref serialized_value).unwrap()
+}
+
+fn store_u256(target: ContractAddress, storage_address: felt252, value: u256) {
+    let mut serialized_value = array![];
+    value.serialize(ref serialized_value);
+    store(target, storage_address, serialized_value.span());
+}
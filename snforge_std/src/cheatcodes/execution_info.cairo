@@ -2,11 +2,14 @@ use starknet::ContractAddress;
 use starknet::ResourcesBounds;
 use snforge_std::cheatcodes::CheatSpan;
 use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+use block_gas_prices::BlockGasPrices;
 
 pub mod caller_address;
 pub mod block_number;
 pub mod block_timestamp;
 pub mod sequencer_address;
+pub mod block_gas_prices;
+pub mod use_kzg_da;
 pub mod version;
 pub mod max_fee;
 pub mod signature;
@@ -97,6 +100,8 @@ struct BlockInfoMock {
     block_number: Operation<u64>,
     block_timestamp: Operation<u64>,
     sequencer_address: Operation<ContractAddress>,
+    gas_prices: Operation<BlockGasPrices>,
+    use_kzg_da: Operation<bool>,
 }
 
 impl BlockInfoMockImpl of Default<BlockInfoMock> {
@@ -107,6 +112,8 @@ impl BlockInfoMockImpl of Default<BlockInfoMock> {
             block_number: Operation::Retain,
             block_timestamp: Operation::Retain,
             sequencer_address: Operation::Retain,
+            gas_prices: Operation::Retain,
+            use_kzg_da: Operation::Retain,
         }
     }
 }
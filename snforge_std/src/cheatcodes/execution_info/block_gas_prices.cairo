@@ -0,0 +1,88 @@
+use super::{
+    ExecutionInfoMock, Operation, CheatArguments, CheatSpan, cheat_execution_info, ContractAddress,
+};
+
+/// Gas prices in a single fee token, all of them have to be non-zero.
+#[derive(Copy, Drop, Serde, PartialEq, Debug)]
+pub struct GasPrices {
+    pub l1_gas_price: u128,
+    pub l1_data_gas_price: u128,
+    pub l2_gas_price: u128,
+}
+
+/// Gas prices of a block, denominated in ETH and STRK.
+#[derive(Copy, Drop, Serde, PartialEq, Debug)]
+pub struct BlockGasPrices {
+    pub eth: GasPrices,
+    pub strk: GasPrices,
+}
+
+/// Changes the block gas prices for the given contract address and span.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to cheat
+/// - `gas_prices` - block gas prices to be set
+/// - `span` - instance of `CheatSpan` specifying the number of contract calls with the cheat
+/// applied
+pub fn cheat_block_gas_prices(
+    contract_address: ContractAddress, gas_prices: BlockGasPrices, span: CheatSpan,
+) {
+    assert_non_zero(gas_prices);
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info
+        .block_info
+        .gas_prices =
+            Operation::Start(CheatArguments { value: gas_prices, span, target: contract_address });
+
+    cheat_execution_info(execution_info);
+}
+
+/// Changes the block gas prices.
+/// - `gas_prices` - block gas prices to be set
+pub fn start_cheat_block_gas_prices_global(gas_prices: BlockGasPrices) {
+    assert_non_zero(gas_prices);
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info.block_info.gas_prices = Operation::StartGlobal(gas_prices);
+
+    cheat_execution_info(execution_info);
+}
+
+/// Cancels the `start_cheat_block_gas_prices_global`.
+pub fn stop_cheat_block_gas_prices_global() {
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info.block_info.gas_prices = Operation::StopGlobal;
+
+    cheat_execution_info(execution_info);
+}
+
+/// Changes the block gas prices for the given contract_address.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to cheat
+/// - `gas_prices` - block gas prices to be set
+pub fn start_cheat_block_gas_prices(contract_address: ContractAddress, gas_prices: BlockGasPrices) {
+    cheat_block_gas_prices(contract_address, gas_prices, CheatSpan::Indefinite);
+}
+
+/// Cancels the `cheat_block_gas_prices` / `start_cheat_block_gas_prices` for the given
+/// contract_address.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to stop cheating
+pub fn stop_cheat_block_gas_prices(contract_address: ContractAddress) {
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info.block_info.gas_prices = Operation::Stop(contract_address);
+
+    cheat_execution_info(execution_info);
+}
+
+fn assert_non_zero(gas_prices: BlockGasPrices) {
+    let BlockGasPrices { eth, strk } = gas_prices;
+    assert(
+        eth.l1_gas_price != 0
+            && eth.l1_data_gas_price != 0
+            && eth.l2_gas_price != 0
+            && strk.l1_gas_price != 0
+            && strk.l1_data_gas_price != 0
+            && strk.l2_gas_price != 0,
+        'Gas prices must be non-zero',
+    );
+}
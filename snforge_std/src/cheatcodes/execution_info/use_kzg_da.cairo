@@ -0,0 +1,57 @@
+use super::{
+    ExecutionInfoMock, Operation, CheatArguments, CheatSpan, cheat_execution_info, ContractAddress,
+};
+
+/// Changes whether state diffs are published to L1 in EIP-4844 blobs, for the given contract
+/// address and span.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to cheat
+/// - `use_kzg_da` - whether blobs are used for data availability
+/// - `span` - instance of `CheatSpan` specifying the number of contract calls with the cheat
+/// applied
+pub fn cheat_use_kzg_da(contract_address: ContractAddress, use_kzg_da: bool, span: CheatSpan) {
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info
+        .block_info
+        .use_kzg_da =
+            Operation::Start(CheatArguments { value: use_kzg_da, span, target: contract_address });
+
+    cheat_execution_info(execution_info);
+}
+
+/// Changes whether state diffs are published to L1 in EIP-4844 blobs.
+/// - `use_kzg_da` - whether blobs are used for data availability
+pub fn start_cheat_use_kzg_da_global(use_kzg_da: bool) {
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info.block_info.use_kzg_da = Operation::StartGlobal(use_kzg_da);
+
+    cheat_execution_info(execution_info);
+}
+
+/// Cancels the `start_cheat_use_kzg_da_global`.
+pub fn stop_cheat_use_kzg_da_global() {
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info.block_info.use_kzg_da = Operation::StopGlobal;
+
+    cheat_execution_info(execution_info);
+}
+
+/// Changes whether state diffs are published to L1 in EIP-4844 blobs, for the given
+/// contract_address.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to cheat
+/// - `use_kzg_da` - whether blobs are used for data availability
+pub fn start_cheat_use_kzg_da(contract_address: ContractAddress, use_kzg_da: bool) {
+    cheat_use_kzg_da(contract_address, use_kzg_da, CheatSpan::Indefinite);
+}
+
+/// Cancels the `cheat_use_kzg_da` / `start_cheat_use_kzg_da` for the given contract_address.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to stop cheating
+pub fn stop_cheat_use_kzg_da(contract_address: ContractAddress) {
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info.block_info.use_kzg_da = Operation::Stop(contract_address);
+
+    cheat_execution_info(execution_info);
+}
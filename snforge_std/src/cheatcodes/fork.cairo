@@ -0,0 +1,34 @@
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+pub use super::super::_config_types::{BlockId, BlockTag};
+
+/// Creates a new fork of the network at the given `url` and `block`.
+/// Every fork keeps its own state (deployed contracts, storage, nonces) on top of the forked
+/// network, while declared classes are shared between all forks.
+/// The created fork is not selected, use `select_fork` to switch to it.
+/// - `name` - unique name identifying the fork
+/// - `url` - RPC url of the network to fork
+/// - `block` - block the fork is created at, a number, hash, tag or timestamp like in `#[fork]`
+pub fn create_fork(name: ByteArray, url: ByteArray, block: BlockId) {
+    let mut inputs = array![];
+    name.serialize(ref inputs);
+    url.serialize(ref inputs);
+    block.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'create_fork', ()>(inputs.span());
+}
+
+/// Selects the fork with the given `name`. All following interactions with the state (calls,
+/// deployments, storage reads and writes) are performed on the selected fork.
+/// - `name` - name of the fork passed to `create_fork`
+pub fn select_fork(name: ByteArray) {
+    let mut inputs = array![];
+    name.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'select_fork', ()>(inputs.span());
+}
+
+/// Selects the fork the test started with, i.e. the one configured with `#[fork]` attribute,
+/// or the local state if the test is not forked.
+pub fn select_default_fork() {
+    select_fork("default");
+}
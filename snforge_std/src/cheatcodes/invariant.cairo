@@ -0,0 +1,32 @@
+use starknet::ContractAddress;
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+
+/// Invariants of the tested system, checked before the call sequence and after every call in it
+pub trait Invariants<T> {
+    /// Panics if any invariant of the system does not hold
+    fn check_invariants(self: @T);
+}
+
+/// Adds the contract to contracts called in random call sequences of the invariant test.
+/// All external functions of the contract are called with arguments generated from its ABI.
+/// Can only be used in tests marked with `#[invariant]`.
+/// - `contract_address` - address of the deployed contract, which must be one of the contracts of
+/// the tested package
+pub fn target_contract(contract_address: ContractAddress) {
+    execute_cheatcode_and_deserialize::<
+        'target_contract', (),
+    >(array![contract_address.into()].span());
+}
+
+/// Makes a random sequence of calls to target contracts, checking invariants of `state` before the
+/// sequence and after every call.
+/// Calls which revert are rolled back and don't fail the test.
+/// Can only be used in tests marked with `#[invariant]`.
+/// - `state` - state of the test, used to check the invariants
+pub fn run_invariants<T, +Invariants<T>, +Drop<T>>(state: T) {
+    state.check_invariants();
+
+    while execute_cheatcode_and_deserialize::<'invariant_step', bool>(array![].span()) {
+        state.check_invariants();
+    }
+}
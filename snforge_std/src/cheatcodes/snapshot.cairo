@@ -0,0 +1,18 @@
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+
+/// Saves the current state of the test and returns id of the snapshot.
+/// The snapshot contains contract storage, nonces, class hashes and declared classes of all forks,
+/// as well as active cheats, mocks and events and messages caught by spies.
+pub fn snapshot() -> usize {
+    execute_cheatcode_and_deserialize::<'snapshot'>(array![].span())
+}
+
+/// Restores the state of the test saved in the snapshot with the given `id`.
+/// The snapshot is kept, so the state can be reverted to it multiple times.
+/// - `id` - id of the snapshot returned by `snapshot`
+pub fn revert_to(id: usize) {
+    let mut inputs = array![];
+    id.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'revert_to', ()>(inputs.span());
+}
@@ -1,3 +1,4 @@
+use starknet::contract_address::contract_address_to_felt252;
 use starknet::{ContractAddress, StorageAddress};
 use super::super::_cheatcode::execute_cheatcode_and_deserialize;
 
@@ -58,3 +59,80 @@ pub fn map_entry_address(map_selector: felt252, keys: Span<felt252>) -> felt252
     keys.serialize(ref inputs);
     execute_cheatcode_and_deserialize::<'map_entry_address'>(inputs.span())
 }
+
+/// Keys of storage slots of a contract read and written while storage accesses were recorded.
+/// Each key is listed once, in order of the first access.
+#[derive(Drop, Clone, Serde, Debug, PartialEq)]
+pub struct StorageAccesses {
+    pub reads: Array<felt252>,
+    pub writes: Array<felt252>,
+}
+
+/// Starts recording storage slots read and written by all contracts,
+/// discarding storage accesses recorded before.
+pub fn start_record_storage() {
+    execute_cheatcode_and_deserialize::<'start_record_storage', ()>(array![].span());
+}
+
+/// Stops recording storage accesses, the ones recorded so far can still be retrieved with
+/// `get_storage_accesses`.
+pub fn stop_record_storage() {
+    execute_cheatcode_and_deserialize::<'stop_record_storage', ()>(array![].span());
+}
+
+/// Returns storage slots of the contract at `contract_address` read and written while storage
+/// accesses were recorded.
+/// - `contract_address` - address of the contract whose storage accesses are returned
+pub fn get_storage_accesses(contract_address: ContractAddress) -> StorageAccesses {
+    execute_cheatcode_and_deserialize::<
+        'get_storage_accesses',
+    >(array![contract_address.into()].span())
+}
+
+/// Makes storage reads and writes of the test code access the storage of the contract at
+/// `contract_address`, returns the address whose storage was accessed before.
+fn set_test_storage_address(contract_address: ContractAddress) -> ContractAddress {
+    execute_cheatcode_and_deserialize::<
+        'set_test_storage_address',
+    >(array![contract_address.into()].span())
+}
+
+/// Restores the storage accessed by the test code with `restore_test_storage_address`, or once
+/// destructed when the closure passed to `interact_with_state` panics.
+struct TestStorageAddressGuard {
+    previous_address: ContractAddress,
+}
+
+fn restore_test_storage_address(guard: TestStorageAddressGuard) {
+    let TestStorageAddressGuard { previous_address } = guard;
+    set_test_storage_address(previous_address);
+}
+
+impl TestStorageAddressGuardDestruct of Destruct<TestStorageAddressGuard> {
+    fn destruct(self: TestStorageAddressGuard) nopanic {
+        let mut inputs = ArrayTrait::new();
+        inputs.append(contract_address_to_felt252(self.previous_address));
+        // The result is not deserialized as it could panic, setting the address never fails
+        starknet::testing::cheatcode::<'set_test_storage_address'>(inputs.span());
+    }
+}
+
+/// Runs `f` with storage reads and writes made by the test code accessing the storage of the
+/// contract at `contract_address`. Inside `f`, the state returned by `contract_state_for_testing`
+/// of a contract with the same storage variables can be used to read and write the storage by
+/// variable paths, with addresses and layouts of `Map`, `Vec`, structs and enums computed the
+/// same way the contract computes them.
+/// Calls and deployments can't be made inside `f`. Calls to `interact_with_state` can be nested,
+/// the storage accessed before is restored once `f` returns.
+/// - `contract_address` - address of the contract whose storage is accessed
+/// - `f` - closure accessing the storage, its result is returned
+pub fn interact_with_state<F, +Drop<F>, impl func: core::ops::FnOnce<F, ()>, +Drop<func::Output>>(
+    contract_address: ContractAddress, f: F,
+) -> func::Output {
+    let guard = TestStorageAddressGuard {
+        previous_address: set_test_storage_address(contract_address),
+    };
+    let result = f();
+    restore_test_storage_address(guard);
+    result
+}
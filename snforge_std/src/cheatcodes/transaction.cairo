@@ -0,0 +1,54 @@
+use starknet::ContractAddress;
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+
+/// How transactions sent from the account are signed
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub enum TransactionSigner {
+    /// Transaction hash is signed on the Stark curve with the given private key
+    StarkCurve: felt252,
+    /// The given signature is attached to every transaction as is
+    Signature: Array<felt252>,
+}
+
+#[derive(Drop, Serde, Copy, Debug, PartialEq)]
+pub enum TransactionExecutionStatus {
+    Succeeded,
+    Reverted,
+}
+
+/// Receipt of a transaction sent from the test
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub struct TransactionReceipt {
+    pub transaction_hash: felt252,
+    /// Fee charged from the account, in fri
+    pub actual_fee: u128,
+    pub execution_status: TransactionExecutionStatus,
+    /// Panic data of the reverted call, converted to a string
+    pub revert_reason: Option<ByteArray>,
+}
+
+/// Makes all following calls from the test be sent as `INVOKE` transactions from the account.
+/// Every transaction is validated with `__validate__` of the account, executed with `__execute__`,
+/// charged a fee in STRK and increments the nonce of the account.
+/// Can only be used in tests marked with `#[transactional]` (or with `transactional = true`
+/// in `[tool.snforge]`).
+/// - `account` - address of the deployed account contract
+/// - `signer` - how the transactions are signed
+pub fn set_transaction_account(account: ContractAddress, signer: TransactionSigner) {
+    let mut inputs = array![];
+    account.serialize(ref inputs);
+    signer.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'set_transaction_account', ()>(inputs.span());
+}
+
+/// Makes all following calls from the test be executed directly, without an account
+pub fn remove_transaction_account() {
+    execute_cheatcode_and_deserialize::<'remove_transaction_account', ()>(array![].span());
+}
+
+/// Returns the receipt of the last transaction sent from the test,
+/// or `None` if there was none or it was rejected in `__validate__`
+pub fn last_transaction_receipt() -> Option<TransactionReceipt> {
+    execute_cheatcode_and_deserialize::<'last_transaction_receipt'>(array![].span())
+}
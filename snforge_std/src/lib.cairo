@@ -26,6 +26,13 @@ pub use cheatcodes::message_to_l1::{
 pub use cheatcodes::storage::store;
 pub use cheatcodes::storage::load;
 pub use cheatcodes::storage::map_entry_address;
+pub use cheatcodes::storage::start_record_storage;
+pub use cheatcodes::storage::stop_record_storage;
+pub use cheatcodes::storage::get_storage_accesses;
+pub use cheatcodes::storage::StorageAccesses;
+pub use cheatcodes::storage::interact_with_state;
+
+pub use cheatcodes::erc20::{deal, deal_with_layout, Erc20StorageLayout};
 
 pub use cheatcodes::CheatSpan;
 pub use cheatcodes::ReplaceBytecodeError;
@@ -34,6 +41,15 @@ pub use cheatcodes::test_selector;
 pub use cheatcodes::mock_call;
 pub use cheatcodes::start_mock_call;
 pub use cheatcodes::stop_mock_call;
+pub use cheatcodes::MockTarget;
+pub use cheatcodes::CalldataMatcher;
+pub use cheatcodes::MockedResult;
+pub use cheatcodes::mock_calls;
+pub use cheatcodes::start_mock_call_when;
+pub use cheatcodes::start_mock_call_revert;
+pub use cheatcodes::start_mock_library_call;
+pub use cheatcodes::stop_mock_calls;
+pub use cheatcodes::stop_mock_library_call;
 pub use cheatcodes::replace_bytecode;
 
 pub use cheatcodes::execution_info::caller_address::cheat_caller_address;
@@ -56,6 +72,18 @@ pub use cheatcodes::execution_info::sequencer_address::start_cheat_sequencer_add
 pub use cheatcodes::execution_info::sequencer_address::stop_cheat_sequencer_address;
 pub use cheatcodes::execution_info::sequencer_address::stop_cheat_sequencer_address_global;
 pub use cheatcodes::execution_info::sequencer_address::start_cheat_sequencer_address;
+pub use cheatcodes::execution_info::block_gas_prices::cheat_block_gas_prices;
+pub use cheatcodes::execution_info::block_gas_prices::start_cheat_block_gas_prices_global;
+pub use cheatcodes::execution_info::block_gas_prices::stop_cheat_block_gas_prices;
+pub use cheatcodes::execution_info::block_gas_prices::stop_cheat_block_gas_prices_global;
+pub use cheatcodes::execution_info::block_gas_prices::start_cheat_block_gas_prices;
+pub use cheatcodes::execution_info::block_gas_prices::BlockGasPrices;
+pub use cheatcodes::execution_info::block_gas_prices::GasPrices;
+pub use cheatcodes::execution_info::use_kzg_da::cheat_use_kzg_da;
+pub use cheatcodes::execution_info::use_kzg_da::start_cheat_use_kzg_da_global;
+pub use cheatcodes::execution_info::use_kzg_da::stop_cheat_use_kzg_da;
+pub use cheatcodes::execution_info::use_kzg_da::stop_cheat_use_kzg_da_global;
+pub use cheatcodes::execution_info::use_kzg_da::start_cheat_use_kzg_da;
 pub use cheatcodes::execution_info::version::cheat_transaction_version;
 pub use cheatcodes::execution_info::version::start_cheat_transaction_version_global;
 pub use cheatcodes::execution_info::version::stop_cheat_transaction_version;
@@ -130,6 +158,23 @@ pub use cheatcodes::block_hash::stop_cheat_block_hash;
 pub use cheatcodes::block_hash::stop_cheat_block_hash_global;
 pub use cheatcodes::block_hash::start_cheat_block_hash;
 
+pub use cheatcodes::fork::create_fork;
+pub use cheatcodes::fork::select_fork;
+pub use cheatcodes::fork::select_default_fork;
+pub use cheatcodes::fork::BlockId;
+pub use cheatcodes::fork::BlockTag;
+
+pub use cheatcodes::transaction::{
+    set_transaction_account, remove_transaction_account, last_transaction_receipt,
+    TransactionSigner, TransactionReceipt, TransactionExecutionStatus,
+};
+
+pub use cheatcodes::invariant::{target_contract, run_invariants, Invariants};
+
+pub use cheatcodes::snapshot::{snapshot, revert_to};
+
+pub use cheatcodes::calls::{spy_calls, Call, CallSpy, CallSpyTrait, ExpectedCallCount, expect_call};
+
 pub mod fuzzable;
 
 pub mod fs;
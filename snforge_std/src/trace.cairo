@@ -1,5 +1,6 @@
 use core::starknet::ContractAddress;
 use super::_cheatcode::execute_cheatcode_and_deserialize;
+use super::cheatcodes::events::Event;
 
 /// Tree-like structure which contains all of the starknet calls and sub-calls along with the
 /// results
@@ -9,6 +10,10 @@ pub struct CallTrace {
     /// All the calls that happened in the scope of `entry_point`
     pub nested_calls: Array<CallTrace>,
     pub result: CallResult,
+    /// Storage reads and writes made in the scope of `entry_point`, excluding nested calls
+    pub storage_accesses: Array<StorageAccess>,
+    /// Events emitted in the scope of `entry_point`, excluding nested calls
+    pub events: Array<(ContractAddress, Event)>,
 }
 
 /// A single function entry point summary
@@ -64,6 +69,27 @@ pub enum CallFailure {
     Error: ByteArray,
 }
 
+/// Read or write of a single storage slot
+#[derive(Drop, Serde, PartialEq, Clone, Debug)]
+pub struct StorageAccess {
+    pub kind: StorageAccessKind,
+    /// Contract which storage was accessed
+    pub contract_address: ContractAddress,
+    /// Address of the storage slot
+    pub key: felt252,
+    /// Value of the slot before the access
+    pub old_value: felt252,
+    /// Value of the slot after the access, equal to `old_value` for reads
+    pub new_value: felt252,
+}
+
+/// Denotes type of the storage access
+#[derive(Drop, Serde, PartialEq, Clone, Debug)]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+}
+
 /// Returns current call trace of the test, up to the last call made to a contract
 pub fn get_call_trace() -> CallTrace {
     execute_cheatcode_and_deserialize::<'get_call_trace'>(array![].span())
@@ -114,6 +140,28 @@ impl DisplayCallType of Display<CallType> {
     }
 }
 
+impl DisplayStorageAccess of Display<StorageAccess> {
+    fn fmt(self: @StorageAccess, ref f: Formatter) -> Result<(), Error> {
+        match self.kind {
+            StorageAccessKind::Read => {
+                write!(f, "Storage read: key: ")?;
+                Display::fmt(self.key, ref f)?;
+                write!(f, ", value: ")?;
+                Display::fmt(self.old_value, ref f)?;
+            },
+            StorageAccessKind::Write => {
+                write!(f, "Storage write: key: ")?;
+                Display::fmt(self.key, ref f)?;
+                write!(f, ", old value: ")?;
+                Display::fmt(self.old_value, ref f)?;
+                write!(f, ", new value: ")?;
+                Display::fmt(self.new_value, ref f)?;
+            },
+        };
+        Result::Ok(())
+    }
+}
+
 impl DisplayCallTrace of Display<CallTrace> {
     fn fmt(self: @CallTrace, ref f: Formatter) -> Result<(), Error> {
         Display::fmt(@IndentedCallTrace { struct_ref: self, base_indents: 0 }, ref f).unwrap();
@@ -160,6 +208,25 @@ impl DisplayIndentedCallTrace of Display<Indented<CallTrace>> {
         }
         write!(f, "]").unwrap();
 
+        let storage_accesses = (*self.struct_ref.storage_accesses).span();
+        for storage_access in storage_accesses {
+            write!(f, "\n").unwrap();
+            write_indents_to_formatter(*self.base_indents, ref f);
+            Display::fmt(storage_access, ref f).unwrap();
+        };
+
+        let events = (*self.struct_ref.events).span();
+        for (from, event) in events {
+            write!(f, "\n").unwrap();
+            write_indents_to_formatter(*self.base_indents, ref f);
+            write!(f, "Event: from: ").unwrap();
+            Debug::fmt(from, ref f).unwrap();
+            write!(f, ", keys: ").unwrap();
+            Debug::fmt(event.keys, ref f).unwrap();
+            write!(f, ", data: ").unwrap();
+            Debug::fmt(event.data, ref f).unwrap();
+        };
+
         write!(f, "\n").unwrap();
         Display::fmt(
             @IndentedCallResult {